@@ -13,4 +13,18 @@ pub enum GameReviewError {
     ReviewNotFound = 4,
     /// Unauthorized access or not initialized
     Unauthorized = 5,
+    /// User has already reported this review
+    AlreadyReported = 6,
+    /// User deleted their review of this game too recently to review it again
+    ReviewCooldown = 7,
+    /// User has already voted this review helpful
+    AlreadyVoted = 8,
+    /// The game's review policy does not allow reviews to be edited
+    UpdatesDisabled = 9,
+    /// User edited their review of this game too recently to edit it again
+    EditCooldown = 10,
+    /// The moderation contract bans the user from reviews
+    Banned = 11,
+    /// The moderation contract could not be reached and checks fail closed
+    ModerationUnavailable = 12,
 }
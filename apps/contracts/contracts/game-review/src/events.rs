@@ -1,4 +1,4 @@
-use crate::types::Review;
+use crate::types::{ModerationAction, Review};
 use soroban_sdk::{Address, Env, Symbol};
 
 /// Contract event emissions
@@ -28,6 +28,19 @@ impl GameReviewEvents {
         env.events().publish(topics, data);
     }
 
+    /// Emits event when a reviewer edits their review
+    ///
+    /// # Arguments
+    /// * `env` - Reference to the contract environment
+    /// * `game_id` - Identifier of the game being reviewed
+    /// * `user` - Address of the reviewer
+    /// * `review` - The review after the update
+    pub fn emit_review_updated(env: &Env, game_id: u32, user: &Address, review: &Review) {
+        let topics = (Symbol::new(&env, "review_updated"), game_id);
+        let data = (user.clone(), review.clone());
+        env.events().publish(topics, data);
+    }
+
     /// Emits event when a review is deleted
     ///
     /// # Arguments
@@ -39,6 +52,63 @@ impl GameReviewEvents {
         env.events().publish(topics, user.clone());
     }
 
+    /// Emits event when a user reports a review
+    ///
+    /// # Arguments
+    /// * `env` - Reference to the contract environment
+    /// * `game_id` - Identifier of the game
+    /// * `review_id` - ID of the reported review
+    /// * `reporter` - Address of the reporting user
+    pub fn emit_review_reported(env: &Env, game_id: u32, review_id: u32, reporter: &Address) {
+        let topics = (Symbol::new(&env, "review_reported"), game_id);
+        let data = (review_id, reporter.clone());
+        env.events().publish(topics, data);
+    }
+
+    /// Emits event when a user votes a review helpful
+    ///
+    /// # Arguments
+    /// * `env` - Reference to the contract environment
+    /// * `game_id` - Identifier of the game
+    /// * `review_id` - ID of the review
+    /// * `voter` - Address of the voting user
+    pub fn emit_review_voted(env: &Env, game_id: u32, review_id: u32, voter: &Address) {
+        let topics = (Symbol::new(&env, "review_voted_helpful"), game_id);
+        let data = (review_id, voter.clone());
+        env.events().publish(topics, data);
+    }
+
+    /// Emits event when the admin moderates a review
+    ///
+    /// # Arguments
+    /// * `env` - Reference to the contract environment
+    /// * `game_id` - Identifier of the game
+    /// * `review_id` - ID of the moderated review
+    /// * `action` - Action the admin took
+    pub fn emit_review_moderated(
+        env: &Env,
+        game_id: u32,
+        review_id: u32,
+        action: ModerationAction,
+    ) {
+        let topics = (Symbol::new(&env, "review_moderated"), game_id);
+        let data = (review_id, action);
+        env.events().publish(topics, data);
+    }
+
+    /// Emits event when the admin archives old reviews of a game
+    ///
+    /// # Arguments
+    /// * `env` - Reference to the contract environment
+    /// * `game_id` - Identifier of the game
+    /// * `archived` - Number of reviews archived
+    /// * `next_id` - Review ID the next archive pass resumes from
+    pub fn emit_reviews_archived(env: &Env, game_id: u32, archived: u32, next_id: u32) {
+        let topics = (Symbol::new(env, "reviews_archived"), game_id);
+        let data = (archived, next_id);
+        env.events().publish(topics, data);
+    }
+
     /// Emits event when admin is changed
     ///
     /// # Arguments
@@ -46,7 +116,7 @@ impl GameReviewEvents {
     /// * `old_admin` - Address of the previous admin
     /// * `new_admin` - Address of the new admin
     pub fn emit_admin_changed(env: &Env, old_admin: &Address, new_admin: &Address) {
-        let topics = (Symbol::new(&env, "admin_changed"),);
+        let topics = (Symbol::new(env, "admin_changed"),);
         let data = (old_admin, new_admin);
         env.events().publish(topics, data);
     }
@@ -1,7 +1,24 @@
-use soroban_sdk::{Address, Bytes, Env, Vec};
+use soroban_sdk::{contractclient, Address, Bytes, Env, Vec};
 
 use crate::errors::GameReviewError;
-use crate::types::Review;
+use crate::types::{
+    ModerationAction, ModerationConfig, ModerationScope, Review, ReviewFeedPage, ReviewPolicy,
+    ReviewStatus, ReviewerStats,
+};
+
+/// Interface of the game ownership contract consulted for verified reviews.
+#[contractclient(name = "GameOwnershipClient")]
+pub trait GameOwnershipInterface {
+    /// Returns true if `owner` owns the game
+    fn owns_game(env: Env, owner: Address, game_id: u32) -> bool;
+}
+
+/// Interface of the platform moderation contract consulted before accepting review activity.
+#[contractclient(name = "ModerationClient")]
+pub trait ModerationInterface {
+    /// Returns true if `address` is banned from `scope`
+    fn is_banned(env: Env, address: Address, scope: ModerationScope) -> bool;
+}
 
 /// Interface for the Game Review contract.
 pub trait GameReviewTrait {
@@ -37,8 +54,135 @@ pub trait GameReviewTrait {
     /// * `Result<Address, GameReviewError>` - Admin address if initialized, Error otherwise
     fn get_admin(env: Env) -> Result<Address, GameReviewError>;
 
+    /// Set the game ownership contract used to verify reviews
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `contract` - Address of the ownership contract
+    ///
+    /// # Returns
+    /// * `Result<(), GameReviewError>` - Ok if set, Error if unauthorized
+    ///
+    /// # Authentication
+    /// * Requires authorization from admin
+    fn set_ownership_contract(env: Env, contract: Address) -> Result<(), GameReviewError>;
+
+    /// Get the game ownership contract, if one is configured
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    ///
+    /// # Returns
+    /// * `Option<Address>` - Ownership contract address
+    fn get_ownership_contract(env: Env) -> Option<Address>;
+
+    /// Set the platform moderation contract whose review bans are enforced (admin only)
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `contract` - Address of the moderation contract
+    /// * `fail_open` - Whether to accept activity when the moderation contract call fails
+    ///
+    /// # Returns
+    /// * `Result<(), GameReviewError>` - Ok if set, Error if unauthorized
+    ///
+    /// # Authentication
+    /// * Requires authorization from admin
+    fn set_moderation_contract(
+        env: Env,
+        contract: Address,
+        fail_open: bool,
+    ) -> Result<(), GameReviewError>;
+
+    /// Get the platform moderation contract and its fail-open toggle, if one is configured
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    ///
+    /// # Returns
+    /// * `Option<ModerationConfig>` - Moderation contract and fail-open toggle
+    fn get_moderation_config(env: Env) -> Option<ModerationConfig>;
+
+    /// Set the prior and weight of the smoothed game score (admin only)
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `prior` - Prior rating scaled by 100 (100-500)
+    /// * `weight` - Weight of the prior, in reviews
+    ///
+    /// # Returns
+    /// * `Result<(), GameReviewError>` - Ok if set, Error if invalid or unauthorized
+    ///
+    /// # Authentication
+    /// * Requires authorization from admin
+    fn set_score_params(env: Env, prior: u32, weight: u32) -> Result<(), GameReviewError>;
+
+    /// Get the prior and weight of the smoothed game score
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    ///
+    /// # Returns
+    /// * `(u32, u32)` - Prior rating scaled by 100 and its weight in reviews
+    fn get_score_params(env: Env) -> (u32, u32);
+
+    /// Set how long a user must wait to review a game again after deleting their review (admin only)
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `game_id` - Unique identifier for the game
+    /// * `cooldown` - Cooldown in seconds, 0 to disable
+    ///
+    /// # Returns
+    /// * `Result<(), GameReviewError>` - Ok if set, Error if unauthorized
+    ///
+    /// # Authentication
+    /// * Requires authorization from admin
+    fn set_review_cooldown(env: Env, game_id: u32, cooldown: u64) -> Result<(), GameReviewError>;
+
+    /// Get the re-review cooldown of a game
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `game_id` - Unique identifier for the game
+    ///
+    /// # Returns
+    /// * `u64` - Cooldown in seconds (0 if none is configured)
+    fn get_review_cooldown(env: Env, game_id: u32) -> u64;
+
+    /// Set the review policy of a game
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `game_id` - Unique identifier for the game
+    /// * `policy` - Comment length limits and edit rules for the game's reviews
+    ///
+    /// # Returns
+    /// * `Result<(), GameReviewError>` - Ok if set, Error if unauthorized or the length limits are inverted
+    ///
+    /// # Authentication
+    /// * Requires authorization from admin
+    fn set_review_policy(
+        env: Env,
+        game_id: u32,
+        policy: ReviewPolicy,
+    ) -> Result<(), GameReviewError>;
+
+    /// Get the review policy of a game
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `game_id` - Unique identifier for the game
+    ///
+    /// # Returns
+    /// * `ReviewPolicy` - The game's policy (no limits if none is configured)
+    fn get_review_policy(env: Env, game_id: u32) -> ReviewPolicy;
+
     /// Add a new review for a game
     ///
+    /// The review is marked verified when the configured ownership contract
+    /// reports that the user owns the game.
+    ///
     /// # Arguments
     /// * `env` - The contract environment
     /// * `user` - Address of the reviewer
@@ -47,7 +191,8 @@ pub trait GameReviewTrait {
     /// * `comment` - Text content of the review
     ///
     /// # Returns
-    /// * `Result<(), GameReviewError>` - Ok if successful, Error if invalid or duplicate
+    /// * `Result<(), GameReviewError>` - Ok if successful, Error if invalid, duplicate, in cooldown,
+    ///   banned by the moderation contract or the comment length breaks the game's policy
     ///
     /// # Authentication
     /// * Requires authorization from the user
@@ -59,19 +204,192 @@ pub trait GameReviewTrait {
         comment: Bytes,
     ) -> Result<(), GameReviewError>;
 
-    /// Delete a review from the system (admin only)
+    /// Update the rating and comment of an existing review
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `reviewer` - Address of the reviewer
+    /// * `game_id` - Unique identifier for the game
+    /// * `rating` - New rating value (1-5 stars)
+    /// * `comment` - New text content of the review
+    ///
+    /// # Returns
+    /// * `Result<(), GameReviewError>` - Ok if updated, Error if invalid, not found, banned by
+    ///   the moderation contract or not allowed by the game's policy
+    ///
+    /// # Authentication
+    /// * Requires authorization from the reviewer
+    fn update_review(
+        env: Env,
+        reviewer: Address,
+        game_id: u32,
+        rating: u32,
+        comment: Bytes,
+    ) -> Result<(), GameReviewError>;
+
+    /// Delete the caller's own review of a game
+    ///
+    /// Starts the game's re-review cooldown for the reviewer.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `reviewer` - Address of the reviewer
+    /// * `game_id` - Unique identifier for the game
+    ///
+    /// # Returns
+    /// * `Result<(), GameReviewError>` - Ok if deleted, Error if not found
+    ///
+    /// # Authentication
+    /// * Requires authorization from the reviewer
+    fn delete_review(env: Env, reviewer: Address, game_id: u32) -> Result<(), GameReviewError>;
+
+    /// Remove any review from the system (admin only)
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `game_id` - Unique identifier for the game
+    /// * `review_id` - Identifier for the specific review
+    ///
+    /// # Returns
+    /// * `Result<(), GameReviewError>` - Ok if removed, Error if not found or unauthorized
+    ///
+    /// # Authentication
+    /// * Requires authorization from admin
+    fn remove_review(env: Env, game_id: u32, review_id: u32) -> Result<(), GameReviewError>;
+
+    /// Archive reviews created before a timestamp (admin only)
+    ///
+    /// Archived reviews leave the game listing and their author's feed but
+    /// still count towards the game totals. Their authors keep them and may
+    /// still update or delete them.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `game_id` - Unique identifier for the game
+    /// * `before` - Reviews with an older timestamp are archived
+    /// * `start_id` - Review ID to start scanning from
+    /// * `limit` - Maximum number of review IDs to scan
+    ///
+    /// # Returns
+    /// * `Result<u32, GameReviewError>` - Review ID to resume from, Error if invalid or unauthorized
+    ///
+    /// # Authentication
+    /// * Requires authorization from admin
+    fn archive_old_reviews(
+        env: Env,
+        game_id: u32,
+        before: u64,
+        start_id: u32,
+        limit: u32,
+    ) -> Result<u32, GameReviewError>;
+
+    /// Report a review for moderation
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `reporter` - Address of the reporting user
+    /// * `game_id` - Unique identifier for the game
+    /// * `review_id` - Identifier for the specific review
+    ///
+    /// # Returns
+    /// * `Result<(), GameReviewError>` - Ok if reported, Error if not found, own review or already reported
+    ///
+    /// # Authentication
+    /// * Requires authorization from the reporter
+    fn report_review(
+        env: Env,
+        reporter: Address,
+        game_id: u32,
+        review_id: u32,
+    ) -> Result<(), GameReviewError>;
+
+    /// Vote a review helpful, raising its author's reputation
     ///
     /// # Arguments
     /// * `env` - The contract environment
+    /// * `voter` - Address of the voting user
     /// * `game_id` - Unique identifier for the game
     /// * `review_id` - Identifier for the specific review
     ///
     /// # Returns
-    /// * `Result<(), GameReviewError>` - Ok if deleted, Error if not found or unauthorized
+    /// * `Result<(), GameReviewError>` - Ok if voted, Error if not found, own review, already voted
+    ///   or banned by the moderation contract
+    ///
+    /// # Authentication
+    /// * Requires authorization from the voter
+    fn vote_helpful(
+        env: Env,
+        voter: Address,
+        game_id: u32,
+        review_id: u32,
+    ) -> Result<(), GameReviewError>;
+
+    /// Get the number of helpful votes of a review
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `game_id` - Unique identifier for the game
+    /// * `review_id` - Identifier for the specific review
+    ///
+    /// # Returns
+    /// * `u32` - Number of helpful votes (0 if none)
+    fn get_helpful_votes(env: Env, game_id: u32, review_id: u32) -> u32;
+
+    /// Get the review count, reputation and average helpfulness of a reviewer
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `reviewer` - Address of the reviewer
+    ///
+    /// # Returns
+    /// * `ReviewerStats` - Stats of the reviewer, all zero if they never reviewed
+    fn get_reviewer_stats(env: Env, reviewer: Address) -> ReviewerStats;
+
+    /// Hide, restore or remove a review (admin only)
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `game_id` - Unique identifier for the game
+    /// * `review_id` - Identifier for the specific review
+    /// * `action` - Moderation action to take
+    ///
+    /// # Returns
+    /// * `Result<(), GameReviewError>` - Ok if moderated, Error if not found or unauthorized
     ///
     /// # Authentication
     /// * Requires authorization from admin
-    fn delete_review(env: Env, game_id: u32, review_id: u32) -> Result<(), GameReviewError>;
+    fn moderate_review(
+        env: Env,
+        game_id: u32,
+        review_id: u32,
+        action: ModerationAction,
+    ) -> Result<(), GameReviewError>;
+
+    /// Get the moderation status of a review
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `game_id` - Unique identifier for the game
+    /// * `review_id` - Identifier for the specific review
+    ///
+    /// # Returns
+    /// * `Result<ReviewStatus, GameReviewError>` - Status if found, Error if not found
+    fn get_review_status(
+        env: Env,
+        game_id: u32,
+        review_id: u32,
+    ) -> Result<ReviewStatus, GameReviewError>;
+
+    /// Get the number of reports filed against a review
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `game_id` - Unique identifier for the game
+    /// * `review_id` - Identifier for the specific review
+    ///
+    /// # Returns
+    /// * `u32` - Number of reports (0 if none)
+    fn get_report_count(env: Env, game_id: u32, review_id: u32) -> u32;
 
     /// Get a specific review by game ID and user
     ///
@@ -84,7 +402,7 @@ pub trait GameReviewTrait {
     /// * `Result<Review, GameReviewError>` - Review if found, Error if not found
     fn get_review(env: Env, game_id: u32, user: Address) -> Result<Review, GameReviewError>;
 
-    /// Get a paginated list of reviews for a game
+    /// Get a paginated list of reviews for a game, leaving out hidden reviews
     ///
     /// # Arguments
     /// * `env` - The contract environment
@@ -96,6 +414,20 @@ pub trait GameReviewTrait {
     /// * `Vec<Review>` - Collection of reviews, empty if none found
     fn get_reviews(env: Env, game_id: u32, skip: u32, limit: u32) -> Vec<Review>;
 
+    /// Get a page of a user's reviews across all games, most recently added or updated first
+    ///
+    /// Hidden reviews are left out but still count towards the page size.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `user` - Address of the reviewer
+    /// * `cursor` - Number of feed entries already read, 0 for the first page
+    /// * `limit` - Most feed entries to scan, capped at `MAX_FEED_PAGE_SIZE`
+    ///
+    /// # Returns
+    /// * `ReviewFeedPage` - Reviews with their game ids and the cursor of the next page
+    fn get_reviews_by_user(env: Env, user: Address, cursor: u32, limit: u32) -> ReviewFeedPage;
+
     /// Check if a user has already reviewed a specific game
     ///
     /// # Arguments
@@ -107,7 +439,7 @@ pub trait GameReviewTrait {
     /// * `bool` - true if user has already reviewed, false otherwise
     fn has_reviewed(env: Env, user: Address, game_id: u32) -> bool;
 
-    /// Get the total number of reviews for a game
+    /// Get the number of reviews of a game that are not hidden
     ///
     /// # Arguments
     /// * `env` - The contract environment
@@ -117,7 +449,7 @@ pub trait GameReviewTrait {
     /// * `u32` - Number of reviews (0 if none)
     fn get_game_review_count(env: Env, game_id: u32) -> u32;
 
-    /// Get the average rating for a game
+    /// Get the average rating of a game over reviews that are not hidden
     ///
     /// # Arguments
     /// * `env` - The contract environment
@@ -126,4 +458,34 @@ pub trait GameReviewTrait {
     /// # Returns
     /// * `u32` - Average rating from 1-5 (0 if no reviews)
     fn get_game_rating(env: Env, game_id: u32) -> u32;
+
+    /// Get the average rating of a game over verified reviews that are not hidden
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `game_id` - Unique identifier for the game
+    ///
+    /// # Returns
+    /// * `u32` - Average rating from 1-5 (0 if no verified reviews)
+    fn get_game_rating_verified_only(env: Env, game_id: u32) -> u32;
+
+    /// Get the Bayesian smoothed score of a game over reviews that are not hidden
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `game_id` - Unique identifier for the game
+    ///
+    /// # Returns
+    /// * `u32` - `(C·m + Σratings) / (C + n)` scaled by 100 (0 if there is nothing to average)
+    fn get_game_score(env: Env, game_id: u32) -> u32;
+
+    /// Get the rating summary of several games in one call
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `game_ids` - Games to summarize, at most `MAX_BULK_RATINGS`; the rest are ignored
+    ///
+    /// # Returns
+    /// * `Vec<(u32, u32, u32)>` - (game_id, average rating x100, review count) per game, in request order
+    fn get_ratings_bulk(env: Env, game_ids: Vec<u32>) -> Vec<(u32, u32, u32)>;
 }
@@ -4,7 +4,11 @@
 ///
 /// This contract allows:
 /// - Users to submit game reviews with ratings (1-5) and comments
-/// - Admins to moderate content by deleting inappropriate reviews
+/// - Users to update or delete their own reviews
+/// - Users to report reviews, and admins to hide, restore or remove them
+/// - Platform moderation bans to keep users from writing or voting on reviews
+/// - Users to vote reviews helpful, building their authors' reputation
+/// - Admins to archive reviews older than a given time
 /// - Anyone to query reviews and game ratings
 pub mod errors;
 pub mod events;
@@ -16,9 +20,19 @@ use errors::GameReviewError;
 use soroban_sdk::{contract, contractimpl, Address, Bytes, Env, Vec};
 
 use events::GameReviewEvents;
-use interface::GameReviewTrait;
+use interface::{GameOwnershipClient, GameReviewTrait, ModerationClient};
 use storage::GameReviewStorage;
-use types::Review;
+use types::{
+    ModerationAction, ModerationConfig, ModerationScope, Review, ReviewFeedPage, ReviewPolicy,
+    ReviewStatus, ReviewerStats,
+};
+
+/// Most games `get_ratings_bulk` summarizes in one call, keeping it within the read footprint
+pub const MAX_BULK_RATINGS: u32 = 40;
+/// Most review IDs `archive_old_reviews` scans in one call
+pub const MAX_ARCHIVE_SCAN: u32 = 20;
+/// Most feed entries `get_reviews_by_user` scans in one call
+pub const MAX_FEED_PAGE_SIZE: u32 = 20;
 
 /// Game Review smart contract main implementation
 #[contract]
@@ -75,8 +89,200 @@ impl GameReviewTrait for GameReview {
         Ok(())
     }
 
+    /// Sets the game ownership contract used to verify reviews
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `contract` - Address of the ownership contract
+    ///
+    /// # Returns
+    /// * `Result<(), GameReviewError>` - Ok if set, Error if unauthorized
+    ///
+    /// # Authentication
+    /// * Requires authorization from admin
+    fn set_ownership_contract(env: Env, contract: Address) -> Result<(), GameReviewError> {
+        let admin = Self::require_admin(&env)?;
+        admin.require_auth();
+
+        GameReviewStorage::set_ownership_contract(&env, &contract);
+
+        Ok(())
+    }
+
+    /// Gets the game ownership contract, if one is configured
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    ///
+    /// # Returns
+    /// * `Option<Address>` - Ownership contract address
+    fn get_ownership_contract(env: Env) -> Option<Address> {
+        GameReviewStorage::get_ownership_contract(&env)
+    }
+
+    /// Sets the platform moderation contract whose review bans are enforced
+    ///
+    /// With `fail_open`, users are let through when the moderation contract
+    /// call fails; otherwise their activity is rejected until it answers.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `contract` - Address of the moderation contract
+    /// * `fail_open` - Whether to accept activity when the moderation contract call fails
+    ///
+    /// # Returns
+    /// * `Result<(), GameReviewError>` - Ok if set, Error if unauthorized
+    ///
+    /// # Authentication
+    /// * Requires authorization from admin
+    fn set_moderation_contract(
+        env: Env,
+        contract: Address,
+        fail_open: bool,
+    ) -> Result<(), GameReviewError> {
+        let admin = Self::require_admin(&env)?;
+        admin.require_auth();
+
+        GameReviewStorage::set_moderation_config(
+            &env,
+            &ModerationConfig {
+                contract,
+                fail_open,
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Gets the platform moderation contract and its fail-open toggle, if one is configured
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    ///
+    /// # Returns
+    /// * `Option<ModerationConfig>` - Moderation contract and fail-open toggle
+    fn get_moderation_config(env: Env) -> Option<ModerationConfig> {
+        GameReviewStorage::get_moderation_config(&env)
+    }
+
+    /// Sets the prior and weight of the smoothed game score
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `prior` - Prior rating scaled by 100 (100-500)
+    /// * `weight` - Weight of the prior, in reviews
+    ///
+    /// # Returns
+    /// * `Result<(), GameReviewError>` - Ok if set, Error if invalid or unauthorized
+    ///
+    /// # Authentication
+    /// * Requires authorization from admin
+    fn set_score_params(env: Env, prior: u32, weight: u32) -> Result<(), GameReviewError> {
+        let admin = Self::require_admin(&env)?;
+        admin.require_auth();
+
+        if !(100..=500).contains(&prior) {
+            return Err(GameReviewError::InvalidInput);
+        }
+
+        GameReviewStorage::set_score_params(&env, prior, weight);
+
+        Ok(())
+    }
+
+    /// Gets the prior and weight of the smoothed game score
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    ///
+    /// # Returns
+    /// * `(u32, u32)` - Prior rating scaled by 100 and its weight in reviews
+    fn get_score_params(env: Env) -> (u32, u32) {
+        GameReviewStorage::get_score_params(&env)
+    }
+
+    /// Sets how long a user must wait to review a game again after deleting their review
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `game_id` - Unique identifier for the game
+    /// * `cooldown` - Cooldown in seconds, 0 to disable
+    ///
+    /// # Returns
+    /// * `Result<(), GameReviewError>` - Ok if set, Error if unauthorized
+    ///
+    /// # Authentication
+    /// * Requires authorization from admin
+    fn set_review_cooldown(env: Env, game_id: u32, cooldown: u64) -> Result<(), GameReviewError> {
+        let admin = Self::require_admin(&env)?;
+        admin.require_auth();
+
+        GameReviewStorage::set_review_cooldown(&env, game_id, cooldown);
+
+        Ok(())
+    }
+
+    /// Gets the re-review cooldown of a game
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `game_id` - Unique identifier for the game
+    ///
+    /// # Returns
+    /// * `u64` - Cooldown in seconds (0 if none is configured)
+    fn get_review_cooldown(env: Env, game_id: u32) -> u64 {
+        GameReviewStorage::get_review_cooldown(&env, game_id)
+    }
+
+    /// Sets the review policy of a game
+    ///
+    /// The policy applies to reviews added or edited after it is set.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `game_id` - Unique identifier for the game
+    /// * `policy` - Comment length limits and edit rules for the game's reviews
+    ///
+    /// # Returns
+    /// * `Result<(), GameReviewError>` - Ok if set, Error if unauthorized or `min_comment_len` exceeds `max_comment_len`
+    ///
+    /// # Authentication
+    /// * Requires authorization from admin
+    fn set_review_policy(
+        env: Env,
+        game_id: u32,
+        policy: ReviewPolicy,
+    ) -> Result<(), GameReviewError> {
+        let admin = Self::require_admin(&env)?;
+        admin.require_auth();
+
+        if policy.min_comment_len > policy.max_comment_len {
+            return Err(GameReviewError::InvalidInput);
+        }
+        GameReviewStorage::set_review_policy(&env, game_id, &policy);
+
+        Ok(())
+    }
+
+    /// Gets the review policy of a game
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `game_id` - Unique identifier for the game
+    ///
+    /// # Returns
+    /// * `ReviewPolicy` - The game's policy (no limits if none is configured)
+    fn get_review_policy(env: Env, game_id: u32) -> ReviewPolicy {
+        GameReviewStorage::get_review_policy(&env, game_id)
+    }
+
     /// Adds a review for a game
     ///
+    /// The review is marked verified when the configured ownership contract
+    /// reports that the user owns the game. Reviews are unverified when no
+    /// ownership contract is set or the call fails. The comment length must
+    /// lie within the limits of the game's review policy.
+    ///
     /// # Arguments
     /// * `env` - The contract environment
     /// * `user` - The address of the reviewer
@@ -85,7 +291,7 @@ impl GameReviewTrait for GameReview {
     /// * `comment` - Text content of the review
     ///
     /// # Returns
-    /// * `Result<(), GameReviewError>` - Ok if added, Error if invalid input or already reviewed
+    /// * `Result<(), GameReviewError>` - Ok if added, Error if invalid input, already reviewed or in cooldown
     ///
     /// # Authentication
     /// * Requires authorization from the user
@@ -98,14 +304,26 @@ impl GameReviewTrait for GameReview {
     ) -> Result<(), GameReviewError> {
         // Require authentication from the user
         user.require_auth();
+        Self::require_not_banned(&env, &user)?;
 
-        // Validate rating (1-5 stars)
-        if rating < 1 || rating > 5 {
+        // Validate rating (1-5 stars) and the comment length
+        if !(1..=5).contains(&rating) {
+            return Err(GameReviewError::InvalidInput);
+        }
+        let policy = GameReviewStorage::get_review_policy(&env, game_id);
+        if !Self::fits_policy(&policy, &comment) {
             return Err(GameReviewError::InvalidInput);
         }
 
-        let total_reviews = GameReviewStorage::get_review_count(&env, game_id);
-        let review_id = total_reviews + 1;
+        // Users who deleted their review must wait out the game's cooldown
+        if let Some(deleted_at) = GameReviewStorage::get_deleted_at(&env, game_id, &user) {
+            let cooldown = GameReviewStorage::get_review_cooldown(&env, game_id);
+            if env.ledger().timestamp() < deleted_at.saturating_add(cooldown) {
+                return Err(GameReviewError::ReviewCooldown);
+            }
+        }
+
+        let review_id = GameReviewStorage::get_next_review_id(&env, game_id);
 
         // Create a new review
         let review = Review {
@@ -114,6 +332,7 @@ impl GameReviewTrait for GameReview {
             rating,
             comment,
             timestamp: env.ledger().timestamp(),
+            verified: Self::owns_game(&env, &user, game_id),
         };
 
         // Check if user has already reviewed this game
@@ -125,21 +344,23 @@ impl GameReviewTrait for GameReview {
             // Add new review and update counts/ratings
             GameReviewStorage::set_review(&env, game_id, &user, &review);
             GameReviewStorage::set_indexed_reviews(&env, game_id, review_id, &review);
+            GameReviewStorage::touch_user_review(&env, game_id, &review);
+            GameReviewStorage::set_next_review_id(&env, game_id, review_id + 1);
 
-            // Update the review count
-            let current_count = GameReviewStorage::get_review_count(&env, game_id);
-            let new_count = current_count + 1;
-            GameReviewStorage::set_review_count(&env, game_id, new_count);
-
-            // Update average rating
-            let current_avg = GameReviewStorage::get_average_rating(&env, game_id);
-            let new_avg = ((current_avg * current_count) + rating) / new_count;
-            GameReviewStorage::set_average_rating(&env, game_id, new_avg);
-
-            //Update total ratings
-            let current_total_ratings = GameReviewStorage::get_total_ratings(&env, game_id);
-            let new_total_ratings = current_total_ratings + rating;
-            GameReviewStorage::set_total_ratings(&env, game_id, new_total_ratings);
+            // Update the review count, total and average rating
+            let new_count = GameReviewStorage::get_review_count(&env, game_id) + 1;
+            let new_total_ratings = GameReviewStorage::get_total_ratings(&env, game_id) + rating;
+            Self::set_rating_totals(&env, game_id, new_total_ratings, new_count);
+            Self::update_verified_ratings(&env, game_id, &review, None, Some(rating));
+            let (reviews, reputation, helpful_votes) =
+                GameReviewStorage::get_reviewer_stats(&env, &user);
+            GameReviewStorage::set_reviewer_stats(
+                &env,
+                &user,
+                reviews + 1,
+                reputation,
+                helpful_votes,
+            );
 
             // Emit event
             GameReviewEvents::emit_review_added(&env, game_id, &user, &review);
@@ -148,7 +369,119 @@ impl GameReviewTrait for GameReview {
         Ok(())
     }
 
-    /// Deletes a review from the system
+    /// Updates the rating and comment of an existing review
+    ///
+    /// The review keeps its id and creation timestamp. The game's review
+    /// policy may forbid edits, require a minimum time between them (counted
+    /// from the review's creation for the first edit) and limit the comment
+    /// length.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `reviewer` - The address of the reviewer
+    /// * `game_id` - Unique identifier for the game
+    /// * `rating` - New rating value (1-5 stars)
+    /// * `comment` - New text content of the review
+    ///
+    /// # Returns
+    /// * `Result<(), GameReviewError>` - Ok if updated, Error if invalid input, not found, edits are
+    ///   disabled or the edit cooldown has not passed
+    ///
+    /// # Authentication
+    /// * Requires authorization from the reviewer
+    fn update_review(
+        env: Env,
+        reviewer: Address,
+        game_id: u32,
+        rating: u32,
+        comment: Bytes,
+    ) -> Result<(), GameReviewError> {
+        reviewer.require_auth();
+        Self::require_not_banned(&env, &reviewer)?;
+
+        if !(1..=5).contains(&rating) {
+            return Err(GameReviewError::InvalidInput);
+        }
+        let policy = GameReviewStorage::get_review_policy(&env, game_id);
+        if !Self::fits_policy(&policy, &comment) {
+            return Err(GameReviewError::InvalidInput);
+        }
+        if !Self::has_reviewed(env.clone(), reviewer.clone(), game_id) {
+            return Err(GameReviewError::ReviewNotFound);
+        }
+        if !policy.allow_updates {
+            return Err(GameReviewError::UpdatesDisabled);
+        }
+
+        let mut review = GameReviewStorage::get_review(&env, game_id, &reviewer);
+        // An edit time left over from an earlier, deleted review predates this one
+        let now = env.ledger().timestamp();
+        let last_edit = GameReviewStorage::get_edited_at(&env, game_id, &reviewer)
+            .unwrap_or(0)
+            .max(review.timestamp);
+        if now < last_edit.saturating_add(policy.edit_cooldown_secs) {
+            return Err(GameReviewError::EditCooldown);
+        }
+        GameReviewStorage::set_edited_at(&env, game_id, &reviewer, now);
+
+        let old_rating = review.rating;
+        review.rating = rating;
+        review.comment = comment;
+        GameReviewStorage::set_review(&env, game_id, &reviewer, &review);
+        GameReviewStorage::set_indexed_reviews(&env, game_id, review.id, &review);
+        GameReviewStorage::touch_user_review(&env, game_id, &review);
+        // An edit brings an archived review back into the listings
+        GameReviewStorage::remove_archived_review(&env, game_id, review.id);
+
+        // Swap the old rating for the new one; the review count is unchanged.
+        // Hidden reviews are not part of the totals.
+        if GameReviewStorage::get_review_status(&env, game_id, review.id) != ReviewStatus::Hidden {
+            let count = GameReviewStorage::get_review_count(&env, game_id);
+            let total_ratings = GameReviewStorage::get_total_ratings(&env, game_id);
+            Self::set_rating_totals(
+                &env,
+                game_id,
+                total_ratings.saturating_sub(old_rating) + rating,
+                count,
+            );
+            Self::update_verified_ratings(&env, game_id, &review, Some(old_rating), Some(rating));
+        }
+
+        GameReviewEvents::emit_review_updated(&env, game_id, &reviewer, &review);
+
+        Ok(())
+    }
+
+    /// Deletes the reviewer's own review of a game
+    ///
+    /// The reviewer may submit a new review once the game's re-review
+    /// cooldown has passed.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `reviewer` - The address of the reviewer
+    /// * `game_id` - Unique identifier for the game
+    ///
+    /// # Returns
+    /// * `Result<(), GameReviewError>` - Ok if deleted, Error if not found
+    ///
+    /// # Authentication
+    /// * Requires authorization from the reviewer
+    fn delete_review(env: Env, reviewer: Address, game_id: u32) -> Result<(), GameReviewError> {
+        reviewer.require_auth();
+
+        if !Self::has_reviewed(env.clone(), reviewer.clone(), game_id) {
+            return Err(GameReviewError::ReviewNotFound);
+        }
+
+        let review = GameReviewStorage::get_review(&env, game_id, &reviewer);
+        Self::discard_review(&env, game_id, &review);
+        GameReviewStorage::set_deleted_at(&env, game_id, &reviewer, env.ledger().timestamp());
+
+        Ok(())
+    }
+
+    /// Removes any review from the system
     ///
     /// # Arguments
     /// * `env` - The contract environment
@@ -156,61 +489,327 @@ impl GameReviewTrait for GameReview {
     /// * `review_id` - Identifier for the specific review
     ///
     /// # Returns
-    /// * `Result<(), GameReviewError>` - Ok if deleted, Error if not found or unauthorized
+    /// * `Result<(), GameReviewError>` - Ok if removed, Error if not found or unauthorized
     ///
     /// # Authentication
     /// * Requires authorization from admin
-    fn delete_review(env: Env, game_id: u32, review_id: u32) -> Result<(), GameReviewError> {
-        // Only admin can delete a review
+    fn remove_review(env: Env, game_id: u32, review_id: u32) -> Result<(), GameReviewError> {
+        // Only admin can remove someone else's review
         let admin = Self::require_admin(&env)?;
         admin.require_auth();
 
-        // Check if review exists
-        let key = types::DataKey::IndexedReviews(game_id, review_id);
-        if !env.storage().persistent().has(&key) {
-            return Err(GameReviewError::ReviewNotFound);
+        let review = Self::get_indexed_review(&env, game_id, review_id)?;
+        Self::discard_review(&env, game_id, &review);
+        Self::penalize_reviewer(&env, &review.reviewer);
+
+        Ok(())
+    }
+
+    /// Archives reviews created before a timestamp
+    ///
+    /// Archived reviews leave the game listing and their author's feed but
+    /// still count towards the game totals and the author's stats.
+    ///
+    /// Scans at most `limit` review IDs (capped at `MAX_ARCHIVE_SCAN`) from
+    /// `start_id`, so a large game is archived over several calls by
+    /// passing the returned ID back in.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `game_id` - Unique identifier for the game
+    /// * `before` - Reviews with an older timestamp are archived
+    /// * `start_id` - Review ID to start scanning from
+    /// * `limit` - Maximum number of review IDs to scan
+    ///
+    /// # Returns
+    /// * `Result<u32, GameReviewError>` - Review ID to resume from, Error if invalid or unauthorized
+    ///
+    /// # Authentication
+    /// * Requires authorization from admin
+    fn archive_old_reviews(
+        env: Env,
+        game_id: u32,
+        before: u64,
+        start_id: u32,
+        limit: u32,
+    ) -> Result<u32, GameReviewError> {
+        let admin = Self::require_admin(&env)?;
+        admin.require_auth();
+
+        if limit == 0 {
+            return Err(GameReviewError::InvalidInput);
         }
 
-        // Get the review to find the reviewer and rating
-        let review = GameReviewStorage::get_indexed_reviews(&env, game_id, review_id);
-        let reviewer = review.reviewer.clone();
-        let rating = review.rating;
+        let next_id = GameReviewStorage::get_next_review_id(&env, game_id);
+        let start_id = start_id.max(1);
+        let end_id = start_id
+            .saturating_add(limit.min(MAX_ARCHIVE_SCAN))
+            .min(next_id);
+        let mut archived = 0;
+        for review_id in start_id..end_id {
+            let key = types::DataKey::IndexedReviews(game_id, review_id);
+            if !env.storage().persistent().has(&key) {
+                continue;
+            }
+            let review = GameReviewStorage::get_indexed_reviews(&env, game_id, review_id);
+            if review.timestamp < before {
+                GameReviewStorage::archive_review(&env, game_id, &review);
+                archived += 1;
+            }
+        }
 
-        // Delete the review from both indexes
-        let key_by_user = types::DataKey::Reviews(game_id, reviewer.clone());
-        let key_by_id = types::DataKey::IndexedReviews(game_id, review_id);
+        let resume_id = end_id.max(start_id);
+        GameReviewEvents::emit_reviews_archived(&env, game_id, archived, resume_id);
 
-        env.storage().persistent().remove(&key_by_user);
-        env.storage().persistent().remove(&key_by_id);
+        Ok(resume_id)
+    }
 
-        // Update the review count
-        let current_count = GameReviewStorage::get_review_count(&env, game_id);
-        if current_count > 0 {
-            GameReviewStorage::set_review_count(&env, game_id, current_count - 1);
+    /// Reports a review for moderation
+    ///
+    /// The first report marks a visible review as `Reported`; the review
+    /// stays listed until the admin acts on it.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `reporter` - The address of the reporting user
+    /// * `game_id` - Unique identifier for the game
+    /// * `review_id` - Identifier for the specific review
+    ///
+    /// # Returns
+    /// * `Result<(), GameReviewError>` - Ok if reported, Error if not found, own review or already reported
+    ///
+    /// # Authentication
+    /// * Requires authorization from the reporter
+    fn report_review(
+        env: Env,
+        reporter: Address,
+        game_id: u32,
+        review_id: u32,
+    ) -> Result<(), GameReviewError> {
+        reporter.require_auth();
+
+        let review = Self::get_indexed_review(&env, game_id, review_id)?;
+        if review.reviewer == reporter {
+            return Err(GameReviewError::InvalidInput);
+        }
+        if GameReviewStorage::has_reported(&env, game_id, review_id, &reporter) {
+            return Err(GameReviewError::AlreadyReported);
         }
 
-        // Update total ratings and average rating
+        GameReviewStorage::set_reported(&env, game_id, review_id, &reporter);
+        let reports = GameReviewStorage::get_report_count(&env, game_id, review_id) + 1;
+        GameReviewStorage::set_report_count(&env, game_id, review_id, reports);
+        if GameReviewStorage::get_review_status(&env, game_id, review_id) == ReviewStatus::Visible {
+            GameReviewStorage::set_review_status(&env, game_id, review_id, ReviewStatus::Reported);
+        }
+
+        GameReviewEvents::emit_review_reported(&env, game_id, review_id, &reporter);
+
+        Ok(())
+    }
+
+    /// Votes a review helpful, raising its author's reputation
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `voter` - The address of the voting user
+    /// * `game_id` - Unique identifier for the game
+    /// * `review_id` - Identifier for the specific review
+    ///
+    /// # Returns
+    /// * `Result<(), GameReviewError>` - Ok if voted, Error if not found, own review or already voted
+    ///
+    /// # Authentication
+    /// * Requires authorization from the voter
+    fn vote_helpful(
+        env: Env,
+        voter: Address,
+        game_id: u32,
+        review_id: u32,
+    ) -> Result<(), GameReviewError> {
+        voter.require_auth();
+        Self::require_not_banned(&env, &voter)?;
+
+        let review = Self::get_indexed_review(&env, game_id, review_id)?;
+        if review.reviewer == voter {
+            return Err(GameReviewError::InvalidInput);
+        }
+        if GameReviewStorage::has_voted(&env, game_id, review_id, &voter) {
+            return Err(GameReviewError::AlreadyVoted);
+        }
+
+        GameReviewStorage::set_voted(&env, game_id, review_id, &voter);
+        let votes = GameReviewStorage::get_helpful_votes(&env, game_id, review_id) + 1;
+        GameReviewStorage::set_helpful_votes(&env, game_id, review_id, votes);
+        let (reviews, reputation, helpful_votes) =
+            GameReviewStorage::get_reviewer_stats(&env, &review.reviewer);
+        GameReviewStorage::set_reviewer_stats(
+            &env,
+            &review.reviewer,
+            reviews,
+            reputation.saturating_add(1),
+            helpful_votes + 1,
+        );
+
+        GameReviewEvents::emit_review_voted(&env, game_id, review_id, &voter);
+
+        Ok(())
+    }
+
+    /// Gets the number of helpful votes of a review
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `game_id` - Unique identifier for the game
+    /// * `review_id` - Identifier for the specific review
+    ///
+    /// # Returns
+    /// * `u32` - Number of helpful votes (0 if none)
+    fn get_helpful_votes(env: Env, game_id: u32, review_id: u32) -> u32 {
+        GameReviewStorage::get_helpful_votes(&env, game_id, review_id)
+    }
+
+    /// Gets the review count, reputation and average helpfulness of a reviewer
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `reviewer` - Address of the reviewer
+    ///
+    /// # Returns
+    /// * `ReviewerStats` - Stats of the reviewer, all zero if they never reviewed
+    fn get_reviewer_stats(env: Env, reviewer: Address) -> ReviewerStats {
+        let (review_count, reputation, helpful_votes) =
+            GameReviewStorage::get_reviewer_stats(&env, &reviewer);
+        let avg_helpfulness = (helpful_votes * 100).checked_div(review_count).unwrap_or(0);
+        ReviewerStats {
+            review_count,
+            reputation,
+            avg_helpfulness,
+        }
+    }
+
+    /// Hides, restores or removes a review
+    ///
+    /// Hiding takes the review out of listings and the game rating, and
+    /// restoring puts it back. Repeating an action has no further effect.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `game_id` - Unique identifier for the game
+    /// * `review_id` - Identifier for the specific review
+    /// * `action` - Moderation action to take
+    ///
+    /// # Returns
+    /// * `Result<(), GameReviewError>` - Ok if moderated, Error if not found or unauthorized
+    ///
+    /// # Authentication
+    /// * Requires authorization from admin
+    fn moderate_review(
+        env: Env,
+        game_id: u32,
+        review_id: u32,
+        action: ModerationAction,
+    ) -> Result<(), GameReviewError> {
+        let admin = Self::require_admin(&env)?;
+        admin.require_auth();
+
+        let review = Self::get_indexed_review(&env, game_id, review_id)?;
+        let hidden =
+            GameReviewStorage::get_review_status(&env, game_id, review_id) == ReviewStatus::Hidden;
+        let count = GameReviewStorage::get_review_count(&env, game_id);
         let total_ratings = GameReviewStorage::get_total_ratings(&env, game_id);
-        if total_ratings >= rating {
-            let new_total_ratings = total_ratings - rating;
-            GameReviewStorage::set_total_ratings(&env, game_id, new_total_ratings);
-
-            // Only recalculate average if we still have reviews
-            if current_count > 1 {
-                let new_avg = new_total_ratings / (current_count - 1);
-                GameReviewStorage::set_average_rating(&env, game_id, new_avg);
-            } else {
-                // If this was the last review, reset the average to 0
-                GameReviewStorage::set_average_rating(&env, game_id, 0);
+
+        match action {
+            ModerationAction::Hide => {
+                if !hidden {
+                    Self::set_rating_totals(
+                        &env,
+                        game_id,
+                        total_ratings.saturating_sub(review.rating),
+                        count.saturating_sub(1),
+                    );
+                    Self::update_verified_ratings(
+                        &env,
+                        game_id,
+                        &review,
+                        Some(review.rating),
+                        None,
+                    );
+                }
+                GameReviewStorage::set_review_status(
+                    &env,
+                    game_id,
+                    review_id,
+                    ReviewStatus::Hidden,
+                );
+            }
+            ModerationAction::Restore => {
+                if hidden {
+                    Self::set_rating_totals(
+                        &env,
+                        game_id,
+                        total_ratings + review.rating,
+                        count + 1,
+                    );
+                    Self::update_verified_ratings(
+                        &env,
+                        game_id,
+                        &review,
+                        None,
+                        Some(review.rating),
+                    );
+                }
+                GameReviewStorage::set_review_status(
+                    &env,
+                    game_id,
+                    review_id,
+                    ReviewStatus::Visible,
+                );
+            }
+            ModerationAction::Remove => {
+                Self::discard_review(&env, game_id, &review);
+                Self::penalize_reviewer(&env, &review.reviewer);
             }
         }
 
-        // Emit deletion event
-        GameReviewEvents::emit_review_deleted(&env, game_id, &reviewer);
+        GameReviewEvents::emit_review_moderated(&env, game_id, review_id, action);
 
         Ok(())
     }
 
+    /// Gets the moderation status of a review
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `game_id` - Unique identifier for the game
+    /// * `review_id` - Identifier for the specific review
+    ///
+    /// # Returns
+    /// * `Result<ReviewStatus, GameReviewError>` - Status if found, Error if not found
+    fn get_review_status(
+        env: Env,
+        game_id: u32,
+        review_id: u32,
+    ) -> Result<ReviewStatus, GameReviewError> {
+        Self::get_indexed_review(&env, game_id, review_id)?;
+        Ok(GameReviewStorage::get_review_status(
+            &env, game_id, review_id,
+        ))
+    }
+
+    /// Gets the number of reports filed against a review
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `game_id` - Unique identifier for the game
+    /// * `review_id` - Identifier for the specific review
+    ///
+    /// # Returns
+    /// * `u32` - Number of reports (0 if none)
+    fn get_report_count(env: Env, game_id: u32, review_id: u32) -> u32 {
+        GameReviewStorage::get_report_count(&env, game_id, review_id)
+    }
+
     /// Gets a specific review by game and user
     ///
     /// # Arguments
@@ -225,10 +824,11 @@ impl GameReviewTrait for GameReview {
             return Err(GameReviewError::ReviewNotFound);
         }
 
-        Ok(GameReviewStorage::get_review(&env, game_id, &user))
+        let review = GameReviewStorage::get_review(&env, game_id, &user);
+        Ok(GameReviewStorage::get_archived_review(&env, game_id, review.id).unwrap_or(review))
     }
 
-    /// Gets a paginated list of reviews for a game
+    /// Gets a paginated list of reviews for a game, leaving out hidden reviews
     ///
     /// # Arguments
     /// * `env` - The contract environment
@@ -242,27 +842,69 @@ impl GameReviewTrait for GameReview {
         let total_reviews = GameReviewStorage::get_review_count(&env, game_id);
         let mut reviews = Vec::new(&env);
 
-        if total_reviews == 0 {
+        if total_reviews == 0 || skip >= total_reviews || limit == 0 {
             return reviews;
         }
 
-        // Convert to 1-based for review IDs
-        let start_id = skip + 1;
-        if start_id > total_reviews {
-            return reviews;
+        // Review IDs are 1-based and deleted reviews leave gaps, so walk
+        // the IDs in order and count only listed reviews that still exist
+        let next_id = GameReviewStorage::get_next_review_id(&env, game_id);
+        let mut skipped = 0;
+        for i in 1..next_id {
+            let key = types::DataKey::IndexedReviews(game_id, i);
+            if !env.storage().persistent().has(&key)
+                || GameReviewStorage::get_review_status(&env, game_id, i) == ReviewStatus::Hidden
+            {
+                continue;
+            }
+            if skipped < skip {
+                skipped += 1;
+                continue;
+            }
+            reviews.push_back(GameReviewStorage::get_indexed_reviews(&env, game_id, i));
+            if reviews.len() == limit {
+                break;
+            }
         }
 
-        let end_id = (start_id + limit - 1).min(total_reviews);
+        reviews
+    }
 
-        for i in start_id..=end_id {
-            let key = types::DataKey::IndexedReviews(game_id, i);
-            if env.storage().persistent().has(&key) {
-                let review = GameReviewStorage::get_indexed_reviews(&env, game_id, i);
-                reviews.push_back(review);
+    /// Gets a page of a user's reviews across all games, most recently added or updated first
+    ///
+    /// Hidden reviews are left out but still count towards the page size.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `user` - Address of the reviewer
+    /// * `cursor` - Number of feed entries already read, 0 for the first page
+    /// * `limit` - Most feed entries to scan, capped at `MAX_FEED_PAGE_SIZE`
+    ///
+    /// # Returns
+    /// * `ReviewFeedPage` - Reviews with their game ids and the cursor of the next page
+    fn get_reviews_by_user(env: Env, user: Address, cursor: u32, limit: u32) -> ReviewFeedPage {
+        let index = GameReviewStorage::get_user_reviews(&env, &user);
+        let mut reviews = Vec::new(&env);
+        let end = cursor
+            .saturating_add(limit.min(MAX_FEED_PAGE_SIZE))
+            .min(index.len());
+        for position in cursor..end {
+            let Some((game_id, review_id)) = index.get(index.len() - 1 - position) else {
+                continue;
+            };
+            if GameReviewStorage::get_review_status(&env, game_id, review_id)
+                == ReviewStatus::Hidden
+            {
+                continue;
             }
+            let review = GameReviewStorage::get_indexed_reviews(&env, game_id, review_id);
+            reviews.push_back((game_id, review));
         }
 
-        reviews
+        ReviewFeedPage {
+            reviews,
+            next_cursor: (end < index.len()).then_some(end),
+        }
     }
 
     /// Checks if a user has already reviewed a specific game
@@ -280,7 +922,7 @@ impl GameReviewTrait for GameReview {
         env.storage().persistent().has(&key)
     }
 
-    /// Gets the total number of reviews for a game
+    /// Gets the number of reviews of a game that are not hidden
     ///
     /// # Arguments
     /// * `env` - The contract environment
@@ -292,7 +934,7 @@ impl GameReviewTrait for GameReview {
         GameReviewStorage::get_review_count(&env, game_id)
     }
 
-    /// Gets the average rating for a game
+    /// Gets the average rating of a game over reviews that are not hidden
     ///
     /// # Arguments
     /// * `env` - The contract environment
@@ -304,6 +946,65 @@ impl GameReviewTrait for GameReview {
         GameReviewStorage::get_average_rating(&env, game_id)
     }
 
+    /// Gets the average rating of a game over verified reviews that are not hidden
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `game_id` - Unique identifier for the game
+    ///
+    /// # Returns
+    /// * `u32` - Average rating from 1-5 (0 if no verified reviews)
+    fn get_game_rating_verified_only(env: Env, game_id: u32) -> u32 {
+        let (count, total) = GameReviewStorage::get_verified_ratings(&env, game_id);
+        total.checked_div(count).unwrap_or(0)
+    }
+
+    /// Gets the Bayesian smoothed score of a game over reviews that are not hidden
+    ///
+    /// The prior `m` pulls games with few reviews towards it, so two 5-star
+    /// reviews don't outrank hundreds of slightly lower ones.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `game_id` - Unique identifier for the game
+    ///
+    /// # Returns
+    /// * `u32` - `(C·m + Σratings) / (C + n)` scaled by 100 (0 if there is nothing to average)
+    fn get_game_score(env: Env, game_id: u32) -> u32 {
+        let (prior, weight) = GameReviewStorage::get_score_params(&env);
+        let count = GameReviewStorage::get_review_count(&env, game_id) as u64;
+        let total_ratings = GameReviewStorage::get_total_ratings(&env, game_id) as u64;
+
+        let divisor = weight as u64 + count;
+        if divisor == 0 {
+            return 0;
+        }
+        ((weight as u64 * prior as u64 + total_ratings * 100) / divisor) as u32
+    }
+
+    /// Gets the rating summary of several games in one call
+    ///
+    /// Only reviews that are not hidden count, as in `get_game_rating`, but
+    /// the average keeps two decimals.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `game_ids` - Games to summarize, at most `MAX_BULK_RATINGS`; the rest are ignored
+    ///
+    /// # Returns
+    /// * `Vec<(u32, u32, u32)>` - (game_id, average rating x100, review count) per game, in request order
+    fn get_ratings_bulk(env: Env, game_ids: Vec<u32>) -> Vec<(u32, u32, u32)> {
+        let mut ratings = Vec::new(&env);
+        for game_id in game_ids.iter().take(MAX_BULK_RATINGS as usize) {
+            let count = GameReviewStorage::get_review_count(&env, game_id);
+            let total_ratings = GameReviewStorage::get_total_ratings(&env, game_id);
+            let average = (total_ratings * 100).checked_div(count).unwrap_or(0);
+            ratings.push_back((game_id, average, count));
+        }
+
+        ratings
+    }
+
     /// Gets the current admin address
     ///
     /// # Arguments
@@ -321,6 +1022,193 @@ impl GameReviewTrait for GameReview {
 }
 
 impl GameReview {
+    /// Stores the rating total, review count and the average derived from them
+    ///
+    /// # Arguments
+    /// * `env` - Reference to the contract environment
+    /// * `game_id` - Identifier of the game
+    /// * `total_ratings` - Sum of all ratings of the game
+    /// * `count` - Number of reviews of the game
+    fn set_rating_totals(env: &Env, game_id: u32, total_ratings: u32, count: u32) {
+        GameReviewStorage::set_review_count(env, game_id, count);
+        GameReviewStorage::set_total_ratings(env, game_id, total_ratings);
+        let average = total_ratings.checked_div(count).unwrap_or(0);
+        GameReviewStorage::set_average_rating(env, game_id, average);
+    }
+
+    /// Moves a verified review's rating in or out of the verified totals
+    ///
+    /// Does nothing for unverified reviews.
+    ///
+    /// # Arguments
+    /// * `env` - Reference to the contract environment
+    /// * `game_id` - Identifier of the game
+    /// * `review` - Review whose rating changes
+    /// * `removed` - Rating leaving the totals, if any
+    /// * `added` - Rating entering the totals, if any
+    fn update_verified_ratings(
+        env: &Env,
+        game_id: u32,
+        review: &Review,
+        removed: Option<u32>,
+        added: Option<u32>,
+    ) {
+        if !review.verified {
+            return;
+        }
+
+        let (mut count, mut total) = GameReviewStorage::get_verified_ratings(env, game_id);
+        if let Some(rating) = removed {
+            count = count.saturating_sub(1);
+            total = total.saturating_sub(rating);
+        }
+        if let Some(rating) = added {
+            count += 1;
+            total += rating;
+        }
+        GameReviewStorage::set_verified_ratings(env, game_id, count, total);
+    }
+
+    /// Checks a comment against the length limits of a review policy
+    ///
+    /// # Arguments
+    /// * `policy` - Review policy of the game
+    /// * `comment` - Text content of the review
+    ///
+    /// # Returns
+    /// * `bool` - true if the comment length lies within the policy's limits
+    fn fits_policy(policy: &ReviewPolicy, comment: &Bytes) -> bool {
+        (policy.min_comment_len..=policy.max_comment_len).contains(&comment.len())
+    }
+
+    /// Asks the ownership contract whether a user owns a game
+    ///
+    /// # Arguments
+    /// * `env` - Reference to the contract environment
+    /// * `user` - Address of the reviewer
+    /// * `game_id` - Identifier of the game
+    ///
+    /// # Returns
+    /// * `bool` - true only if an ownership contract is set and confirms ownership
+    fn owns_game(env: &Env, user: &Address, game_id: u32) -> bool {
+        let Some(contract) = GameReviewStorage::get_ownership_contract(env) else {
+            return false;
+        };
+        let client = GameOwnershipClient::new(env, &contract);
+        matches!(client.try_owns_game(user, &game_id), Ok(Ok(true)))
+    }
+
+    /// Rejects users the platform moderation contract bans from reviews
+    ///
+    /// # Arguments
+    /// * `env` - Reference to the contract environment
+    /// * `user` - Address of the acting user
+    ///
+    /// # Returns
+    /// * `Result<(), GameReviewError>` - Ok if no moderation contract is set or it does not ban the
+    ///   user, `Banned` if it does, `ModerationUnavailable` if the call fails and checks fail closed
+    fn require_not_banned(env: &Env, user: &Address) -> Result<(), GameReviewError> {
+        let Some(config) = GameReviewStorage::get_moderation_config(env) else {
+            return Ok(());
+        };
+        let client = ModerationClient::new(env, &config.contract);
+        match client.try_is_banned(user, &ModerationScope::Reviews) {
+            Ok(Ok(false)) => Ok(()),
+            Ok(Ok(true)) => Err(GameReviewError::Banned),
+            _ if config.fail_open => Ok(()),
+            _ => Err(GameReviewError::ModerationUnavailable),
+        }
+    }
+
+    /// Removes a review, takes it out of the game totals and emits the deletion
+    ///
+    /// # Arguments
+    /// * `env` - Reference to the contract environment
+    /// * `game_id` - Identifier of the game
+    /// * `review` - Review to remove
+    fn discard_review(env: &Env, game_id: u32, review: &Review) {
+        Self::erase_review(env, game_id, review);
+        GameReviewEvents::emit_review_deleted(env, game_id, &review.reviewer);
+    }
+
+    /// Removes a review and takes it out of the game totals and its author's stats
+    ///
+    /// Hidden reviews were already taken out of the totals when hidden.
+    ///
+    /// # Arguments
+    /// * `env` - Reference to the contract environment
+    /// * `game_id` - Identifier of the game
+    /// * `review` - Review to remove
+    fn erase_review(env: &Env, game_id: u32, review: &Review) {
+        let hidden =
+            GameReviewStorage::get_review_status(env, game_id, review.id) == ReviewStatus::Hidden;
+        let votes = GameReviewStorage::get_helpful_votes(env, game_id, review.id);
+        GameReviewStorage::remove_review(env, game_id, review);
+
+        let (reviews, reputation, helpful_votes) =
+            GameReviewStorage::get_reviewer_stats(env, &review.reviewer);
+        GameReviewStorage::set_reviewer_stats(
+            env,
+            &review.reviewer,
+            reviews.saturating_sub(1),
+            reputation,
+            helpful_votes.saturating_sub(votes),
+        );
+
+        if !hidden {
+            let count = GameReviewStorage::get_review_count(env, game_id);
+            let total_ratings = GameReviewStorage::get_total_ratings(env, game_id);
+            Self::set_rating_totals(
+                env,
+                game_id,
+                total_ratings.saturating_sub(review.rating),
+                count.saturating_sub(1),
+            );
+            Self::update_verified_ratings(env, game_id, review, Some(review.rating), None);
+        }
+    }
+
+    /// Lowers a reviewer's reputation after moderation removed one of their reviews
+    ///
+    /// # Arguments
+    /// * `env` - Reference to the contract environment
+    /// * `reviewer` - Address of the reviewer
+    fn penalize_reviewer(env: &Env, reviewer: &Address) {
+        let (reviews, reputation, helpful_votes) =
+            GameReviewStorage::get_reviewer_stats(env, reviewer);
+        GameReviewStorage::set_reviewer_stats(
+            env,
+            reviewer,
+            reviews,
+            reputation.saturating_sub(1),
+            helpful_votes,
+        );
+    }
+
+    /// Loads a review by its id
+    ///
+    /// # Arguments
+    /// * `env` - Reference to the contract environment
+    /// * `game_id` - Identifier of the game
+    /// * `review_id` - ID of the review
+    ///
+    /// # Returns
+    /// * `Result<Review, GameReviewError>` - Review if found, Error if not found
+    fn get_indexed_review(
+        env: &Env,
+        game_id: u32,
+        review_id: u32,
+    ) -> Result<Review, GameReviewError> {
+        let key = types::DataKey::IndexedReviews(game_id, review_id);
+        if !env.storage().persistent().has(&key) {
+            return Err(GameReviewError::ReviewNotFound);
+        }
+
+        Ok(GameReviewStorage::get_indexed_reviews(
+            env, game_id, review_id,
+        ))
+    }
+
     /// Helper function to validate admin access
     ///
     /// # Arguments
@@ -1,5 +1,15 @@
-use crate::types::{DataKey, Review};
-use soroban_sdk::{Address, Env};
+use crate::types::{DataKey, ModerationConfig, Review, ReviewPolicy, ReviewStatus};
+use soroban_sdk::{Address, Bytes, Env, Vec};
+
+/// Prior rating (x100) of the smoothed game score until the admin sets one
+pub const DEFAULT_SCORE_PRIOR: u32 = 300;
+/// Weight in reviews of the score prior until the admin sets one
+pub const DEFAULT_SCORE_WEIGHT: u32 = 10;
+
+/// Remaining ledgers below which reading a review extends its TTL (~30 days)
+pub const REVIEW_TTL_THRESHOLD: u32 = 518_400;
+/// Ledgers a review's TTL is extended to when read (~180 days)
+pub const REVIEW_TTL_EXTEND_TO: u32 = 3_110_400;
 
 /// Storage operations for the Game Review contract
 pub struct GameReviewStorage;
@@ -39,7 +49,7 @@ impl GameReviewStorage {
         env.storage().persistent().set(&key, review);
     }
 
-    /// Retrieves a review by game and user
+    /// Retrieves a review by game and user, extending its TTL
     ///
     /// # Arguments
     /// * `env` - Reference to the contract environment
@@ -50,7 +60,11 @@ impl GameReviewStorage {
     /// * `Review` - The requested review
     pub fn get_review(env: &Env, game_id: u32, user: &Address) -> Review {
         let key = DataKey::Reviews(game_id, user.clone());
-        env.storage().persistent().get(&key).unwrap()
+        let review = env.storage().persistent().get(&key).unwrap();
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, REVIEW_TTL_THRESHOLD, REVIEW_TTL_EXTEND_TO);
+        review
     }
 
     /// Gets the total number of reviews (legacy method)
@@ -125,6 +139,211 @@ impl GameReviewStorage {
         env.storage().persistent().set(&key, &rating);
     }
 
+    /// Sets the game ownership contract consulted for verified reviews
+    ///
+    /// # Arguments
+    /// * `env` - Reference to the contract environment
+    /// * `contract` - Address of the ownership contract
+    pub fn set_ownership_contract(env: &Env, contract: &Address) {
+        let key = DataKey::OwnershipContract;
+        env.storage().instance().set(&key, contract);
+    }
+
+    /// Retrieves the game ownership contract, if one is configured
+    ///
+    /// # Arguments
+    /// * `env` - Reference to the contract environment
+    ///
+    /// # Returns
+    /// * `Option<Address>` - Ownership contract address
+    pub fn get_ownership_contract(env: &Env) -> Option<Address> {
+        let key = DataKey::OwnershipContract;
+        env.storage().instance().get(&key)
+    }
+
+    /// Sets the platform moderation contract consulted before accepting review activity
+    ///
+    /// # Arguments
+    /// * `env` - Reference to the contract environment
+    /// * `config` - Moderation contract and fail-open toggle
+    pub fn set_moderation_config(env: &Env, config: &ModerationConfig) {
+        let key = DataKey::ModerationConfig;
+        env.storage().instance().set(&key, config);
+    }
+
+    /// Retrieves the platform moderation contract, if one is configured
+    ///
+    /// # Arguments
+    /// * `env` - Reference to the contract environment
+    ///
+    /// # Returns
+    /// * `Option<ModerationConfig>` - Moderation contract and fail-open toggle
+    pub fn get_moderation_config(env: &Env) -> Option<ModerationConfig> {
+        let key = DataKey::ModerationConfig;
+        env.storage().instance().get(&key)
+    }
+
+    /// Gets the count and rating sum of verified reviews for a game
+    ///
+    /// # Arguments
+    /// * `env` - Reference to the contract environment
+    /// * `game_id` - Identifier of the game
+    ///
+    /// # Returns
+    /// * `(u32, u32)` - Number of verified reviews and the sum of their ratings
+    pub fn get_verified_ratings(env: &Env, game_id: u32) -> (u32, u32) {
+        let key = DataKey::VerifiedRatings(game_id);
+        env.storage().persistent().get(&key).unwrap_or((0, 0))
+    }
+
+    /// Sets the count and rating sum of verified reviews for a game
+    ///
+    /// # Arguments
+    /// * `env` - Reference to the contract environment
+    /// * `game_id` - Identifier of the game
+    /// * `count` - Number of verified reviews
+    /// * `total` - Sum of their ratings
+    pub fn set_verified_ratings(env: &Env, game_id: u32, count: u32, total: u32) {
+        let key = DataKey::VerifiedRatings(game_id);
+        env.storage().persistent().set(&key, &(count, total));
+    }
+
+    /// Gets the prior rating (x100) and weight used by the smoothed game score
+    ///
+    /// Defaults to a prior of 3.00 stars weighted as 10 reviews.
+    ///
+    /// # Arguments
+    /// * `env` - Reference to the contract environment
+    ///
+    /// # Returns
+    /// * `(u32, u32)` - Prior rating scaled by 100 and its weight in reviews
+    pub fn get_score_params(env: &Env) -> (u32, u32) {
+        let key = DataKey::ScoreParams;
+        env.storage()
+            .instance()
+            .get(&key)
+            .unwrap_or((DEFAULT_SCORE_PRIOR, DEFAULT_SCORE_WEIGHT))
+    }
+
+    /// Sets the prior rating (x100) and weight used by the smoothed game score
+    ///
+    /// # Arguments
+    /// * `env` - Reference to the contract environment
+    /// * `prior` - Prior rating scaled by 100
+    /// * `weight` - Weight of the prior in reviews
+    pub fn set_score_params(env: &Env, prior: u32, weight: u32) {
+        let key = DataKey::ScoreParams;
+        env.storage().instance().set(&key, &(prior, weight));
+    }
+
+    /// Gets the re-review cooldown of a game
+    ///
+    /// # Arguments
+    /// * `env` - Reference to the contract environment
+    /// * `game_id` - Identifier of the game
+    ///
+    /// # Returns
+    /// * `u64` - Cooldown in seconds (0 if none is configured)
+    pub fn get_review_cooldown(env: &Env, game_id: u32) -> u64 {
+        let key = DataKey::ReviewCooldown(game_id);
+        env.storage().persistent().get(&key).unwrap_or(0)
+    }
+
+    /// Sets the re-review cooldown of a game
+    ///
+    /// # Arguments
+    /// * `env` - Reference to the contract environment
+    /// * `game_id` - Identifier of the game
+    /// * `cooldown` - Cooldown in seconds
+    pub fn set_review_cooldown(env: &Env, game_id: u32, cooldown: u64) {
+        let key = DataKey::ReviewCooldown(game_id);
+        env.storage().persistent().set(&key, &cooldown);
+    }
+
+    /// Gets the review policy of a game
+    ///
+    /// # Arguments
+    /// * `env` - Reference to the contract environment
+    /// * `game_id` - Identifier of the game
+    ///
+    /// # Returns
+    /// * `ReviewPolicy` - The configured policy, or one without limits if none is set
+    pub fn get_review_policy(env: &Env, game_id: u32) -> ReviewPolicy {
+        let key = DataKey::ReviewPolicy(game_id);
+        env.storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or(ReviewPolicy {
+                min_comment_len: 0,
+                max_comment_len: u32::MAX,
+                edit_cooldown_secs: 0,
+                allow_updates: true,
+            })
+    }
+
+    /// Sets the review policy of a game
+    ///
+    /// # Arguments
+    /// * `env` - Reference to the contract environment
+    /// * `game_id` - Identifier of the game
+    /// * `policy` - Review rules to enforce
+    pub fn set_review_policy(env: &Env, game_id: u32, policy: &ReviewPolicy) {
+        let key = DataKey::ReviewPolicy(game_id);
+        env.storage().persistent().set(&key, policy);
+    }
+
+    /// Gets when a user last edited their review of a game
+    ///
+    /// # Arguments
+    /// * `env` - Reference to the contract environment
+    /// * `game_id` - Identifier of the game
+    /// * `user` - Address of the reviewer
+    ///
+    /// # Returns
+    /// * `Option<u64>` - Timestamp of the last edit, if the user ever edited a review of the game
+    pub fn get_edited_at(env: &Env, game_id: u32, user: &Address) -> Option<u64> {
+        let key = DataKey::EditedAt(game_id, user.clone());
+        env.storage().persistent().get(&key)
+    }
+
+    /// Records when a user edited their review of a game
+    ///
+    /// # Arguments
+    /// * `env` - Reference to the contract environment
+    /// * `game_id` - Identifier of the game
+    /// * `user` - Address of the reviewer
+    /// * `timestamp` - Ledger timestamp of the edit
+    pub fn set_edited_at(env: &Env, game_id: u32, user: &Address, timestamp: u64) {
+        let key = DataKey::EditedAt(game_id, user.clone());
+        env.storage().persistent().set(&key, &timestamp);
+    }
+
+    /// Gets when a user last deleted their review of a game
+    ///
+    /// # Arguments
+    /// * `env` - Reference to the contract environment
+    /// * `game_id` - Identifier of the game
+    /// * `user` - Address of the reviewer
+    ///
+    /// # Returns
+    /// * `Option<u64>` - Ledger timestamp of the deletion, if any
+    pub fn get_deleted_at(env: &Env, game_id: u32, user: &Address) -> Option<u64> {
+        let key = DataKey::DeletedAt(game_id, user.clone());
+        env.storage().persistent().get(&key)
+    }
+
+    /// Records when a user deleted their review of a game
+    ///
+    /// # Arguments
+    /// * `env` - Reference to the contract environment
+    /// * `game_id` - Identifier of the game
+    /// * `user` - Address of the reviewer
+    /// * `timestamp` - Ledger timestamp of the deletion
+    pub fn set_deleted_at(env: &Env, game_id: u32, user: &Address, timestamp: u64) {
+        let key = DataKey::DeletedAt(game_id, user.clone());
+        env.storage().persistent().set(&key, &timestamp);
+    }
+
     /// Gets the number of reviews for a game
     ///
     /// # Arguments
@@ -149,7 +368,7 @@ impl GameReviewStorage {
         env.storage().persistent().set(&key, &count);
     }
 
-    /// Gets a review by its ID
+    /// Gets a review by its ID, extending its TTL
     ///
     /// # Arguments
     /// * `env` - Reference to the contract environment
@@ -160,7 +379,11 @@ impl GameReviewStorage {
     /// * `Review` - The requested review
     pub fn get_indexed_reviews(env: &Env, game_id: u32, review_id: u32) -> Review {
         let key = DataKey::IndexedReviews(game_id, review_id);
-        env.storage().persistent().get(&key).unwrap()
+        let review = env.storage().persistent().get(&key).unwrap();
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, REVIEW_TTL_THRESHOLD, REVIEW_TTL_EXTEND_TO);
+        review
     }
 
     /// Stores a review by its ID
@@ -174,4 +397,321 @@ impl GameReviewStorage {
         let key = DataKey::IndexedReviews(game_id, review_id);
         env.storage().persistent().set(&key, review);
     }
+
+    /// Gets the id the next review of a game will receive
+    ///
+    /// Ids are never reused after a review is deleted. Games reviewed before
+    /// this counter existed continue after their review count.
+    ///
+    /// # Arguments
+    /// * `env` - Reference to the contract environment
+    /// * `game_id` - Identifier of the game
+    ///
+    /// # Returns
+    /// * `u32` - Next review id
+    pub fn get_next_review_id(env: &Env, game_id: u32) -> u32 {
+        let key = DataKey::NextReviewId(game_id);
+        env.storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or_else(|| Self::get_review_count(env, game_id) + 1)
+    }
+
+    /// Sets the id the next review of a game will receive
+    ///
+    /// # Arguments
+    /// * `env` - Reference to the contract environment
+    /// * `game_id` - Identifier of the game
+    /// * `review_id` - Next review id
+    pub fn set_next_review_id(env: &Env, game_id: u32, review_id: u32) {
+        let key = DataKey::NextReviewId(game_id);
+        env.storage().persistent().set(&key, &review_id);
+    }
+
+    /// Removes a review from the per-game indexes and its author's review index
+    ///
+    /// # Arguments
+    /// * `env` - Reference to the contract environment
+    /// * `game_id` - Identifier of the game
+    /// * `review` - Review to remove
+    pub fn remove_review(env: &Env, game_id: u32, review: &Review) {
+        let key_by_user = DataKey::Reviews(game_id, review.reviewer.clone());
+        let key_by_id = DataKey::IndexedReviews(game_id, review.id);
+        env.storage().persistent().remove(&key_by_user);
+        env.storage().persistent().remove(&key_by_id);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::ReviewStatus(game_id, review.id));
+        env.storage()
+            .persistent()
+            .remove(&DataKey::ReportCount(game_id, review.id));
+        env.storage()
+            .persistent()
+            .remove(&DataKey::HelpfulVotes(game_id, review.id));
+        Self::remove_archived_review(env, game_id, review.id);
+        Self::drop_user_review(env, game_id, review);
+    }
+
+    /// Moves a review out of the listings and its author's feed into cold storage
+    ///
+    /// The author's entry is kept as a tombstone without the comment, so the
+    /// review still counts as written and can be updated or deleted later.
+    ///
+    /// # Arguments
+    /// * `env` - Reference to the contract environment
+    /// * `game_id` - Identifier of the game
+    /// * `review` - Review to archive
+    pub fn archive_review(env: &Env, game_id: u32, review: &Review) {
+        let key = DataKey::ArchivedReviews(game_id, review.id);
+        env.storage().persistent().set(&key, review);
+
+        let mut tombstone = review.clone();
+        tombstone.comment = Bytes::new(env);
+        Self::set_review(env, game_id, &review.reviewer, &tombstone);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::IndexedReviews(game_id, review.id));
+        Self::drop_user_review(env, game_id, review);
+    }
+
+    /// Gets an archived review by its ID
+    ///
+    /// # Arguments
+    /// * `env` - Reference to the contract environment
+    /// * `game_id` - Identifier of the game
+    /// * `review_id` - ID of the review
+    ///
+    /// # Returns
+    /// * `Option<Review>` - The archived review, `None` if it is not archived
+    pub fn get_archived_review(env: &Env, game_id: u32, review_id: u32) -> Option<Review> {
+        let key = DataKey::ArchivedReviews(game_id, review_id);
+        env.storage().persistent().get(&key)
+    }
+
+    /// Removes a review from cold storage once it is updated or deleted
+    ///
+    /// # Arguments
+    /// * `env` - Reference to the contract environment
+    /// * `game_id` - Identifier of the game
+    /// * `review_id` - ID of the review
+    pub fn remove_archived_review(env: &Env, game_id: u32, review_id: u32) {
+        let key = DataKey::ArchivedReviews(game_id, review_id);
+        env.storage().persistent().remove(&key);
+    }
+
+    /// Removes a review from its author's review index
+    ///
+    /// # Arguments
+    /// * `env` - Reference to the contract environment
+    /// * `game_id` - Identifier of the game
+    /// * `review` - Review to remove
+    fn drop_user_review(env: &Env, game_id: u32, review: &Review) {
+        let mut reviews = Self::get_user_reviews(env, &review.reviewer);
+        if let Some(index) = reviews.first_index_of((game_id, review.id)) {
+            reviews.remove(index);
+            let key = DataKey::UserReviews(review.reviewer.clone());
+            env.storage().persistent().set(&key, &reviews);
+        }
+    }
+
+    /// Gets the (game id, review id) of every review of a user
+    ///
+    /// # Arguments
+    /// * `env` - Reference to the contract environment
+    /// * `user` - Address of the reviewer
+    ///
+    /// # Returns
+    /// * `Vec<(u32, u32)>` - Reviews ordered by when they were last added or updated, oldest first
+    pub fn get_user_reviews(env: &Env, user: &Address) -> Vec<(u32, u32)> {
+        let key = DataKey::UserReviews(user.clone());
+        env.storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or(Vec::new(env))
+    }
+
+    /// Moves a review to the newest end of its author's review index, adding it if missing
+    ///
+    /// # Arguments
+    /// * `env` - Reference to the contract environment
+    /// * `game_id` - Identifier of the game
+    /// * `review` - Review that was added or updated
+    pub fn touch_user_review(env: &Env, game_id: u32, review: &Review) {
+        let mut reviews = Self::get_user_reviews(env, &review.reviewer);
+        if let Some(index) = reviews.first_index_of((game_id, review.id)) {
+            reviews.remove(index);
+        }
+        reviews.push_back((game_id, review.id));
+        let key = DataKey::UserReviews(review.reviewer.clone());
+        env.storage().persistent().set(&key, &reviews);
+    }
+
+    /// Gets the moderation status of a review
+    ///
+    /// # Arguments
+    /// * `env` - Reference to the contract environment
+    /// * `game_id` - Identifier of the game
+    /// * `review_id` - ID of the review
+    ///
+    /// # Returns
+    /// * `ReviewStatus` - Current status, `Visible` if never reported or moderated
+    pub fn get_review_status(env: &Env, game_id: u32, review_id: u32) -> ReviewStatus {
+        let key = DataKey::ReviewStatus(game_id, review_id);
+        env.storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or(ReviewStatus::Visible)
+    }
+
+    /// Sets the moderation status of a review
+    ///
+    /// # Arguments
+    /// * `env` - Reference to the contract environment
+    /// * `game_id` - Identifier of the game
+    /// * `review_id` - ID of the review
+    /// * `status` - New status
+    pub fn set_review_status(env: &Env, game_id: u32, review_id: u32, status: ReviewStatus) {
+        let key = DataKey::ReviewStatus(game_id, review_id);
+        env.storage().persistent().set(&key, &status);
+    }
+
+    /// Gets the number of reports filed against a review
+    ///
+    /// # Arguments
+    /// * `env` - Reference to the contract environment
+    /// * `game_id` - Identifier of the game
+    /// * `review_id` - ID of the review
+    ///
+    /// # Returns
+    /// * `u32` - Number of reports (0 if none)
+    pub fn get_report_count(env: &Env, game_id: u32, review_id: u32) -> u32 {
+        let key = DataKey::ReportCount(game_id, review_id);
+        env.storage().persistent().get(&key).unwrap_or(0)
+    }
+
+    /// Sets the number of reports filed against a review
+    ///
+    /// # Arguments
+    /// * `env` - Reference to the contract environment
+    /// * `game_id` - Identifier of the game
+    /// * `review_id` - ID of the review
+    /// * `count` - Count to set
+    pub fn set_report_count(env: &Env, game_id: u32, review_id: u32, count: u32) {
+        let key = DataKey::ReportCount(game_id, review_id);
+        env.storage().persistent().set(&key, &count);
+    }
+
+    /// Checks whether a user has reported a review
+    ///
+    /// # Arguments
+    /// * `env` - Reference to the contract environment
+    /// * `game_id` - Identifier of the game
+    /// * `review_id` - ID of the review
+    /// * `reporter` - Address of the user
+    ///
+    /// # Returns
+    /// * `bool` - true if the user has reported the review
+    pub fn has_reported(env: &Env, game_id: u32, review_id: u32, reporter: &Address) -> bool {
+        let key = DataKey::Reported(game_id, review_id, reporter.clone());
+        env.storage().persistent().has(&key)
+    }
+
+    /// Records that a user has reported a review
+    ///
+    /// # Arguments
+    /// * `env` - Reference to the contract environment
+    /// * `game_id` - Identifier of the game
+    /// * `review_id` - ID of the review
+    /// * `reporter` - Address of the user
+    pub fn set_reported(env: &Env, game_id: u32, review_id: u32, reporter: &Address) {
+        let key = DataKey::Reported(game_id, review_id, reporter.clone());
+        env.storage().persistent().set(&key, &true);
+    }
+
+    /// Gets the number of helpful votes of a review
+    ///
+    /// # Arguments
+    /// * `env` - Reference to the contract environment
+    /// * `game_id` - Identifier of the game
+    /// * `review_id` - ID of the review
+    ///
+    /// # Returns
+    /// * `u32` - Number of helpful votes (0 if none)
+    pub fn get_helpful_votes(env: &Env, game_id: u32, review_id: u32) -> u32 {
+        let key = DataKey::HelpfulVotes(game_id, review_id);
+        env.storage().persistent().get(&key).unwrap_or(0)
+    }
+
+    /// Sets the number of helpful votes of a review
+    ///
+    /// # Arguments
+    /// * `env` - Reference to the contract environment
+    /// * `game_id` - Identifier of the game
+    /// * `review_id` - ID of the review
+    /// * `votes` - Number of helpful votes
+    pub fn set_helpful_votes(env: &Env, game_id: u32, review_id: u32, votes: u32) {
+        let key = DataKey::HelpfulVotes(game_id, review_id);
+        env.storage().persistent().set(&key, &votes);
+    }
+
+    /// Checks whether a user has voted a review helpful
+    ///
+    /// # Arguments
+    /// * `env` - Reference to the contract environment
+    /// * `game_id` - Identifier of the game
+    /// * `review_id` - ID of the review
+    /// * `voter` - Address of the user
+    ///
+    /// # Returns
+    /// * `bool` - true if the user has voted the review helpful
+    pub fn has_voted(env: &Env, game_id: u32, review_id: u32, voter: &Address) -> bool {
+        let key = DataKey::Voted(game_id, review_id, voter.clone());
+        env.storage().persistent().has(&key)
+    }
+
+    /// Records that a user has voted a review helpful
+    ///
+    /// # Arguments
+    /// * `env` - Reference to the contract environment
+    /// * `game_id` - Identifier of the game
+    /// * `review_id` - ID of the review
+    /// * `voter` - Address of the user
+    pub fn set_voted(env: &Env, game_id: u32, review_id: u32, voter: &Address) {
+        let key = DataKey::Voted(game_id, review_id, voter.clone());
+        env.storage().persistent().set(&key, &true);
+    }
+
+    /// Gets the review count, reputation and helpful votes of a reviewer
+    ///
+    /// # Arguments
+    /// * `env` - Reference to the contract environment
+    /// * `reviewer` - Address of the reviewer
+    ///
+    /// # Returns
+    /// * `(u32, i32, u32)` - Current reviews, reputation and helpful votes on current reviews
+    pub fn get_reviewer_stats(env: &Env, reviewer: &Address) -> (u32, i32, u32) {
+        let key = DataKey::ReviewerStats(reviewer.clone());
+        env.storage().persistent().get(&key).unwrap_or((0, 0, 0))
+    }
+
+    /// Sets the review count, reputation and helpful votes of a reviewer
+    ///
+    /// # Arguments
+    /// * `env` - Reference to the contract environment
+    /// * `reviewer` - Address of the reviewer
+    /// * `review_count` - Number of current reviews
+    /// * `reputation` - Reputation score
+    /// * `helpful_votes` - Helpful votes on current reviews
+    pub fn set_reviewer_stats(
+        env: &Env,
+        reviewer: &Address,
+        review_count: u32,
+        reputation: i32,
+        helpful_votes: u32,
+    ) {
+        let key = DataKey::ReviewerStats(reviewer.clone());
+        env.storage()
+            .persistent()
+            .set(&key, &(review_count, reputation, helpful_votes));
+    }
 }
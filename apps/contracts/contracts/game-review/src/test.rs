@@ -12,6 +12,7 @@ use soroban_sdk::{
     testutils::{Events, Ledger},
     Address, Bytes, Env, Symbol,
 };
+use types::{ReviewPolicy, ReviewerStats};
 
 fn test_address(env: &Env, _seed: &str) -> Address {
     Address::generate(env)
@@ -293,3 +294,1064 @@ fn test_get_reviews_pagination() {
         assert_eq!(reviews.len(), 0);
     });
 }
+
+#[test]
+fn test_update_review() {
+    let (env, contract_id, _admin) = setup_env_and_contract();
+    let user1 = test_address(&env, "user11a");
+    let user2 = test_address(&env, "user11b");
+    let game_id = 11u32;
+    env.ledger().set_timestamp(100);
+    env.as_contract(&contract_id, || {
+        GameReview::add_review(
+            env.clone(),
+            user1.clone(),
+            game_id,
+            5,
+            Bytes::from_slice(&env, b"Great"),
+        )
+        .unwrap();
+        GameReview::add_review(
+            env.clone(),
+            user2.clone(),
+            game_id,
+            3,
+            Bytes::from_slice(&env, b"Fine"),
+        )
+        .unwrap();
+    });
+    env.ledger().set_timestamp(200);
+    env.as_contract(&contract_id, || {
+        let comment = Bytes::from_slice(&env, b"Got boring");
+        GameReview::update_review(env.clone(), user1.clone(), game_id, 1, comment.clone()).unwrap();
+
+        let review = GameReview::get_review(env.clone(), game_id, user1.clone()).unwrap();
+        assert_eq!(review.rating, 1);
+        assert_eq!(review.comment, comment);
+        assert_eq!(review.id, 1);
+        assert_eq!(review.timestamp, 100);
+        assert_eq!(
+            GameReview::get_reviews(env.clone(), game_id, 0, 1)
+                .get(0)
+                .unwrap(),
+            review
+        );
+
+        // Count is unchanged and the average reflects the new rating: (1+3)/2
+        assert_eq!(GameReview::get_game_review_count(env.clone(), game_id), 2);
+        assert_eq!(GameReview::get_game_rating(env.clone(), game_id), 2);
+
+        let found = env.events().all().iter().any(|(_, topics, _)| {
+            topics.iter().any(|topic| {
+                Symbol::try_from_val(&env, &topic)
+                    .map(|sym| sym == Symbol::new(&env, "review_updated"))
+                    .unwrap_or(false)
+            })
+        });
+        assert!(found, "review_updated event not found");
+    });
+}
+
+#[test]
+fn test_update_review_errors() {
+    let (env, contract_id, _admin) = setup_env_and_contract();
+    let user = test_address(&env, "user12");
+    let game_id = 12u32;
+    env.as_contract(&contract_id, || {
+        let err =
+            GameReview::update_review(env.clone(), user.clone(), game_id, 4, Bytes::new(&env))
+                .unwrap_err();
+        assert_eq!(err, GameReviewError::ReviewNotFound);
+    });
+    env.as_contract(&contract_id, || {
+        GameReview::add_review(env.clone(), user.clone(), game_id, 4, Bytes::new(&env)).unwrap();
+    });
+    env.as_contract(&contract_id, || {
+        let err =
+            GameReview::update_review(env.clone(), user.clone(), game_id, 6, Bytes::new(&env))
+                .unwrap_err();
+        assert_eq!(err, GameReviewError::InvalidInput);
+    });
+}
+
+#[test]
+fn test_delete_own_review() {
+    let (env, contract_id, _admin) = setup_env_and_contract();
+    let user1 = test_address(&env, "user13a");
+    let user2 = test_address(&env, "user13b");
+    let game_id = 13u32;
+    env.as_contract(&contract_id, || {
+        GameReview::add_review(
+            env.clone(),
+            user1.clone(),
+            game_id,
+            5,
+            Bytes::from_slice(&env, b"A"),
+        )
+        .unwrap();
+        GameReview::add_review(
+            env.clone(),
+            user2.clone(),
+            game_id,
+            2,
+            Bytes::from_slice(&env, b"B"),
+        )
+        .unwrap();
+    });
+    env.as_contract(&contract_id, || {
+        GameReview::delete_review(env.clone(), user1.clone(), game_id).unwrap();
+        assert!(!GameReview::has_reviewed(
+            env.clone(),
+            user1.clone(),
+            game_id
+        ));
+        assert_eq!(GameReview::get_game_review_count(env.clone(), game_id), 1);
+        assert_eq!(GameReview::get_game_rating(env.clone(), game_id), 2);
+    });
+    env.as_contract(&contract_id, || {
+        let err = GameReview::delete_review(env.clone(), user1.clone(), game_id).unwrap_err();
+        assert_eq!(err, GameReviewError::ReviewNotFound);
+    });
+    env.as_contract(&contract_id, || {
+        // Reviewing again gets a fresh id and leaves the other review intact
+        GameReview::add_review(
+            env.clone(),
+            user1.clone(),
+            game_id,
+            4,
+            Bytes::from_slice(&env, b"C"),
+        )
+        .unwrap();
+        assert_eq!(
+            GameReview::get_review(env.clone(), game_id, user1.clone())
+                .unwrap()
+                .id,
+            3
+        );
+        assert_eq!(
+            GameReview::get_review(env.clone(), game_id, user2.clone())
+                .unwrap()
+                .id,
+            2
+        );
+        assert_eq!(GameReview::get_game_review_count(env.clone(), game_id), 2);
+        assert_eq!(GameReview::get_game_rating(env.clone(), game_id), 3);
+
+        // Listings skip the deleted review's id
+        let reviews = GameReview::get_reviews(env.clone(), game_id, 0, 10);
+        assert_eq!(reviews.len(), 2);
+        assert_eq!(reviews.get(0).unwrap().id, 2);
+        assert_eq!(reviews.get(1).unwrap().id, 3);
+        let reviews = GameReview::get_reviews(env.clone(), game_id, 1, 10);
+        assert_eq!(reviews.len(), 1);
+        assert_eq!(reviews.get(0).unwrap().id, 3);
+    });
+}
+
+#[test]
+fn test_admin_remove_review() {
+    let (env, contract_id, _admin) = setup_env_and_contract();
+    let user = test_address(&env, "user14");
+    let game_id = 14u32;
+    env.as_contract(&contract_id, || {
+        GameReview::add_review(
+            env.clone(),
+            user.clone(),
+            game_id,
+            1,
+            Bytes::from_slice(&env, b"Spam"),
+        )
+        .unwrap();
+    });
+    env.as_contract(&contract_id, || {
+        GameReview::remove_review(env.clone(), game_id, 1).unwrap();
+        assert!(!GameReview::has_reviewed(
+            env.clone(),
+            user.clone(),
+            game_id
+        ));
+        assert_eq!(GameReview::get_game_review_count(env.clone(), game_id), 0);
+        assert_eq!(GameReview::get_game_rating(env.clone(), game_id), 0);
+    });
+    env.as_contract(&contract_id, || {
+        let err = GameReview::remove_review(env.clone(), game_id, 1).unwrap_err();
+        assert_eq!(err, GameReviewError::ReviewNotFound);
+    });
+}
+
+#[test]
+fn test_report_and_moderate_review() {
+    let (env, contract_id, _admin) = setup_env_and_contract();
+    let user1 = test_address(&env, "user15");
+    let user2 = test_address(&env, "user16");
+    let reporter = test_address(&env, "reporter");
+    let game_id = 15u32;
+    env.as_contract(&contract_id, || {
+        GameReview::add_review(
+            env.clone(),
+            user1.clone(),
+            game_id,
+            1,
+            Bytes::from_slice(&env, b"Spam"),
+        )
+        .unwrap();
+        GameReview::add_review(
+            env.clone(),
+            user2.clone(),
+            game_id,
+            5,
+            Bytes::from_slice(&env, b"Great"),
+        )
+        .unwrap();
+    });
+    env.as_contract(&contract_id, || {
+        GameReview::report_review(env.clone(), reporter.clone(), game_id, 1).unwrap();
+        assert_eq!(
+            GameReview::get_review_status(env.clone(), game_id, 1),
+            Ok(ReviewStatus::Reported)
+        );
+        assert_eq!(GameReview::get_report_count(env.clone(), game_id, 1), 1);
+        // Reported reviews stay listed until moderated
+        assert_eq!(
+            GameReview::get_reviews(env.clone(), game_id, 0, 10).len(),
+            2
+        );
+    });
+    env.as_contract(&contract_id, || {
+        GameReview::moderate_review(env.clone(), game_id, 1, ModerationAction::Hide).unwrap();
+        assert_eq!(
+            GameReview::get_review_status(env.clone(), game_id, 1),
+            Ok(ReviewStatus::Hidden)
+        );
+        let found = env.events().all().iter().any(|(_, topics, _)| {
+            topics.iter().any(|topic| {
+                Symbol::try_from_val(&env, &topic)
+                    .map(|sym| sym == Symbol::new(&env, "review_moderated"))
+                    .unwrap_or(false)
+            })
+        });
+        assert!(found, "review_moderated event not found");
+        assert_eq!(GameReview::get_game_review_count(env.clone(), game_id), 1);
+        assert_eq!(GameReview::get_game_rating(env.clone(), game_id), 5);
+        let reviews = GameReview::get_reviews(env.clone(), game_id, 0, 10);
+        assert_eq!(reviews.len(), 1);
+        assert_eq!(reviews.get(0).unwrap().id, 2);
+    });
+    env.as_contract(&contract_id, || {
+        // Editing a hidden review leaves the game totals alone
+        GameReview::update_review(
+            env.clone(),
+            user1.clone(),
+            game_id,
+            2,
+            Bytes::from_slice(&env, b"Edited"),
+        )
+        .unwrap();
+        assert_eq!(GameReview::get_game_rating(env.clone(), game_id), 5);
+    });
+    env.as_contract(&contract_id, || {
+        GameReview::moderate_review(env.clone(), game_id, 1, ModerationAction::Restore).unwrap();
+        assert_eq!(
+            GameReview::get_review_status(env.clone(), game_id, 1),
+            Ok(ReviewStatus::Visible)
+        );
+        assert_eq!(GameReview::get_game_review_count(env.clone(), game_id), 2);
+        assert_eq!(GameReview::get_game_rating(env.clone(), game_id), 3);
+        assert_eq!(
+            GameReview::get_reviews(env.clone(), game_id, 0, 10).len(),
+            2
+        );
+    });
+    env.as_contract(&contract_id, || {
+        GameReview::moderate_review(env.clone(), game_id, 1, ModerationAction::Hide).unwrap();
+    });
+    env.as_contract(&contract_id, || {
+        // Removing a hidden review does not take its rating out twice
+        GameReview::moderate_review(env.clone(), game_id, 1, ModerationAction::Remove).unwrap();
+        assert!(!GameReview::has_reviewed(
+            env.clone(),
+            user1.clone(),
+            game_id
+        ));
+        assert_eq!(GameReview::get_game_review_count(env.clone(), game_id), 1);
+        assert_eq!(GameReview::get_game_rating(env.clone(), game_id), 5);
+        assert_eq!(
+            GameReview::get_review_status(env.clone(), game_id, 1),
+            Err(GameReviewError::ReviewNotFound)
+        );
+    });
+}
+
+#[test]
+fn test_report_review_errors() {
+    let (env, contract_id, _admin) = setup_env_and_contract();
+    let user = test_address(&env, "user17");
+    let reporter = test_address(&env, "reporter");
+    let game_id = 17u32;
+    env.as_contract(&contract_id, || {
+        GameReview::add_review(
+            env.clone(),
+            user.clone(),
+            game_id,
+            3,
+            Bytes::from_slice(&env, b"Fine"),
+        )
+        .unwrap();
+    });
+    env.as_contract(&contract_id, || {
+        let err = GameReview::report_review(env.clone(), user.clone(), game_id, 1).unwrap_err();
+        assert_eq!(err, GameReviewError::InvalidInput);
+    });
+    env.as_contract(&contract_id, || {
+        let err = GameReview::report_review(env.clone(), reporter.clone(), game_id, 9).unwrap_err();
+        assert_eq!(err, GameReviewError::ReviewNotFound);
+    });
+    env.as_contract(&contract_id, || {
+        GameReview::report_review(env.clone(), reporter.clone(), game_id, 1).unwrap();
+    });
+    env.as_contract(&contract_id, || {
+        let err = GameReview::report_review(env.clone(), reporter.clone(), game_id, 1).unwrap_err();
+        assert_eq!(err, GameReviewError::AlreadyReported);
+        assert_eq!(GameReview::get_report_count(env.clone(), game_id, 1), 1);
+    });
+    env.as_contract(&contract_id, || {
+        let err = GameReview::moderate_review(env.clone(), game_id, 9, ModerationAction::Hide)
+            .unwrap_err();
+        assert_eq!(err, GameReviewError::ReviewNotFound);
+    });
+}
+
+mod ownership {
+    use soroban_sdk::{contract, contractimpl, Address, Env};
+
+    /// Minimal game library that records which users own which games
+    #[contract]
+    pub struct MockOwnership;
+
+    #[contractimpl]
+    impl MockOwnership {
+        pub fn grant(env: Env, owner: Address, game_id: u32) {
+            env.storage().instance().set(&(owner, game_id), &true);
+        }
+
+        pub fn owns_game(env: Env, owner: Address, game_id: u32) -> bool {
+            env.storage().instance().has(&(owner, game_id))
+        }
+    }
+}
+
+#[test]
+fn test_verified_reviews() {
+    let (env, contract_id, _admin) = setup_env_and_contract();
+    let owner = test_address(&env, "owner");
+    let visitor = test_address(&env, "visitor");
+    let game_id = 18u32;
+    let ownership_id = env.register(ownership::MockOwnership, ());
+    ownership::MockOwnershipClient::new(&env, &ownership_id).grant(&owner, &game_id);
+
+    env.as_contract(&contract_id, || {
+        // Without an ownership contract no review is verified
+        assert_eq!(GameReview::get_ownership_contract(env.clone()), None);
+        GameReview::set_ownership_contract(env.clone(), ownership_id.clone()).unwrap();
+        assert_eq!(
+            GameReview::get_ownership_contract(env.clone()),
+            Some(ownership_id.clone())
+        );
+    });
+    env.as_contract(&contract_id, || {
+        GameReview::add_review(
+            env.clone(),
+            owner.clone(),
+            game_id,
+            5,
+            Bytes::from_slice(&env, b"Played it"),
+        )
+        .unwrap();
+        GameReview::add_review(
+            env.clone(),
+            visitor.clone(),
+            game_id,
+            1,
+            Bytes::from_slice(&env, b"Never played"),
+        )
+        .unwrap();
+
+        assert!(
+            GameReview::get_review(env.clone(), game_id, owner.clone())
+                .unwrap()
+                .verified
+        );
+        assert!(
+            !GameReview::get_review(env.clone(), game_id, visitor.clone())
+                .unwrap()
+                .verified
+        );
+        assert_eq!(GameReview::get_game_rating(env.clone(), game_id), 3);
+        assert_eq!(
+            GameReview::get_game_rating_verified_only(env.clone(), game_id),
+            5
+        );
+    });
+    env.as_contract(&contract_id, || {
+        GameReview::update_review(
+            env.clone(),
+            owner.clone(),
+            game_id,
+            3,
+            Bytes::from_slice(&env, b"Played more"),
+        )
+        .unwrap();
+        assert_eq!(
+            GameReview::get_game_rating_verified_only(env.clone(), game_id),
+            3
+        );
+    });
+    env.as_contract(&contract_id, || {
+        GameReview::moderate_review(env.clone(), game_id, 1, ModerationAction::Hide).unwrap();
+        assert_eq!(
+            GameReview::get_game_rating_verified_only(env.clone(), game_id),
+            0
+        );
+    });
+    env.as_contract(&contract_id, || {
+        GameReview::moderate_review(env.clone(), game_id, 1, ModerationAction::Restore).unwrap();
+        assert_eq!(
+            GameReview::get_game_rating_verified_only(env.clone(), game_id),
+            3
+        );
+    });
+    env.as_contract(&contract_id, || {
+        GameReview::delete_review(env.clone(), owner.clone(), game_id).unwrap();
+        assert_eq!(
+            GameReview::get_game_rating_verified_only(env.clone(), game_id),
+            0
+        );
+        assert_eq!(GameReview::get_game_rating(env.clone(), game_id), 1);
+    });
+}
+
+#[test]
+fn test_game_score() {
+    let (env, contract_id, _admin) = setup_env_and_contract();
+    let game_id = 1u32;
+
+    env.as_contract(&contract_id, || {
+        // Without reviews the score is the default prior
+        assert_eq!(GameReview::get_score_params(env.clone()), (300, 10));
+        assert_eq!(GameReview::get_game_score(env.clone(), game_id), 300);
+
+        for _ in 0..2 {
+            let user = test_address(&env, "user");
+            GameReview::add_review(
+                env.clone(),
+                user,
+                game_id,
+                5,
+                Bytes::from_slice(&env, b"Perfect"),
+            )
+            .unwrap();
+        }
+        // (10 * 300 + 100 * 10) / (10 + 2)
+        assert_eq!(GameReview::get_game_score(env.clone(), game_id), 333);
+    });
+    env.as_contract(&contract_id, || {
+        assert_eq!(
+            GameReview::set_score_params(env.clone(), 600, 2),
+            Err(GameReviewError::InvalidInput)
+        );
+    });
+    env.as_contract(&contract_id, || {
+        GameReview::set_score_params(env.clone(), 400, 2).unwrap();
+        assert_eq!(GameReview::get_score_params(env.clone()), (400, 2));
+        // (2 * 400 + 100 * 10) / (2 + 2)
+        assert_eq!(GameReview::get_game_score(env.clone(), game_id), 450);
+    });
+    env.as_contract(&contract_id, || {
+        // With no prior weight an unreviewed game has no score
+        GameReview::set_score_params(env.clone(), 400, 0).unwrap();
+        assert_eq!(GameReview::get_game_score(env.clone(), game_id), 500);
+        assert_eq!(GameReview::get_game_score(env.clone(), 2), 0);
+    });
+}
+
+#[test]
+fn test_get_reviews_by_user() {
+    let (env, contract_id, _admin) = setup_env_and_contract();
+    let user = test_address(&env, "user");
+    let feed_games = |page: &types::ReviewFeedPage| -> Vec<u32> {
+        page.reviews.iter().map(|(game_id, _)| game_id).collect()
+    };
+
+    for game_id in [1u32, 2, 3] {
+        env.as_contract(&contract_id, || {
+            let comment = Bytes::from_slice(&env, b"Fun");
+            GameReview::add_review(env.clone(), user.clone(), game_id, 4, comment).unwrap();
+        });
+    }
+    env.as_contract(&contract_id, || {
+        let comment = Bytes::from_slice(&env, b"Even better");
+        GameReview::update_review(env.clone(), user.clone(), 1, 5, comment).unwrap();
+    });
+
+    env.as_contract(&contract_id, || {
+        let page = GameReview::get_reviews_by_user(env.clone(), user.clone(), 0, 2);
+        assert_eq!(feed_games(&page), [1, 3]);
+        assert_eq!(page.reviews.get(0).unwrap().1.rating, 5);
+        assert_eq!(page.next_cursor, Some(2));
+        let page = GameReview::get_reviews_by_user(env.clone(), user.clone(), 2, 2);
+        assert_eq!(feed_games(&page), [2]);
+        assert_eq!(page.next_cursor, None);
+    });
+
+    env.as_contract(&contract_id, || {
+        GameReview::delete_review(env.clone(), user.clone(), 3).unwrap();
+    });
+    env.as_contract(&contract_id, || {
+        GameReview::moderate_review(env.clone(), 2, 1, ModerationAction::Hide).unwrap();
+        let page = GameReview::get_reviews_by_user(env.clone(), user.clone(), 0, 10);
+        assert_eq!(feed_games(&page), [1]);
+        assert_eq!(page.next_cursor, None);
+        let other = test_address(&env, "other");
+        let page = GameReview::get_reviews_by_user(env.clone(), other, 0, 10);
+        assert!(page.reviews.is_empty());
+    });
+}
+
+#[test]
+fn test_review_policy() {
+    let (env, contract_id, _admin) = setup_env_and_contract();
+    let user = test_address(&env, "user");
+    let game_id = 1u32;
+    env.ledger().set_timestamp(1_000);
+    let policy = ReviewPolicy {
+        min_comment_len: 5,
+        max_comment_len: 20,
+        edit_cooldown_secs: 600,
+        allow_updates: true,
+    };
+
+    env.as_contract(&contract_id, || {
+        assert!(GameReview::get_review_policy(env.clone(), game_id).allow_updates);
+        let inverted = ReviewPolicy {
+            min_comment_len: 30,
+            ..policy.clone()
+        };
+        let err = GameReview::set_review_policy(env.clone(), game_id, inverted).unwrap_err();
+        assert_eq!(err, GameReviewError::InvalidInput);
+    });
+    env.as_contract(&contract_id, || {
+        GameReview::set_review_policy(env.clone(), game_id, policy.clone()).unwrap();
+        assert_eq!(GameReview::get_review_policy(env.clone(), game_id), policy);
+    });
+    for comment in [&b"Bad"[..], &b"Far too long for this game"[..]] {
+        env.as_contract(&contract_id, || {
+            let comment = Bytes::from_slice(&env, comment);
+            let err =
+                GameReview::add_review(env.clone(), user.clone(), game_id, 1, comment).unwrap_err();
+            assert_eq!(err, GameReviewError::InvalidInput);
+        });
+    }
+    env.as_contract(&contract_id, || {
+        // Other games keep no limits
+        let short = Bytes::from_slice(&env, b"Bad");
+        GameReview::add_review(env.clone(), user.clone(), 2, 1, short).unwrap();
+    });
+    env.as_contract(&contract_id, || {
+        let comment = Bytes::from_slice(&env, b"Not great");
+        GameReview::add_review(env.clone(), user.clone(), game_id, 2, comment).unwrap();
+    });
+
+    // The first edit waits out the cooldown from the review's creation
+    env.ledger().set_timestamp(1_599);
+    env.as_contract(&contract_id, || {
+        let comment = Bytes::from_slice(&env, b"Grew on me");
+        let err =
+            GameReview::update_review(env.clone(), user.clone(), game_id, 4, comment).unwrap_err();
+        assert_eq!(err, GameReviewError::EditCooldown);
+    });
+    env.ledger().set_timestamp(1_600);
+    env.as_contract(&contract_id, || {
+        let comment = Bytes::from_slice(&env, b"Grew on me");
+        GameReview::update_review(env.clone(), user.clone(), game_id, 4, comment).unwrap();
+    });
+    env.ledger().set_timestamp(2_000);
+    env.as_contract(&contract_id, || {
+        let comment = Bytes::from_slice(&env, b"Actually great");
+        let err =
+            GameReview::update_review(env.clone(), user.clone(), game_id, 5, comment).unwrap_err();
+        assert_eq!(err, GameReviewError::EditCooldown);
+    });
+
+    env.as_contract(&contract_id, || {
+        let frozen = ReviewPolicy {
+            allow_updates: false,
+            ..policy.clone()
+        };
+        GameReview::set_review_policy(env.clone(), game_id, frozen).unwrap();
+    });
+    env.ledger().set_timestamp(5_000);
+    env.as_contract(&contract_id, || {
+        let comment = Bytes::from_slice(&env, b"Actually great");
+        let err =
+            GameReview::update_review(env.clone(), user.clone(), game_id, 5, comment).unwrap_err();
+        assert_eq!(err, GameReviewError::UpdatesDisabled);
+        let review = GameReview::get_review(env.clone(), game_id, user.clone()).unwrap();
+        assert_eq!(review.rating, 4);
+    });
+}
+
+#[test]
+fn test_review_cooldown_after_delete() {
+    let (env, contract_id, _admin) = setup_env_and_contract();
+    let user = test_address(&env, "user");
+    let game_id = 1u32;
+    env.ledger().set_timestamp(1_000);
+
+    env.as_contract(&contract_id, || {
+        assert_eq!(GameReview::get_review_cooldown(env.clone(), game_id), 0);
+        GameReview::set_review_cooldown(env.clone(), game_id, 3_600).unwrap();
+        assert_eq!(GameReview::get_review_cooldown(env.clone(), game_id), 3_600);
+        // Other games keep no cooldown
+        assert_eq!(GameReview::get_review_cooldown(env.clone(), 2), 0);
+    });
+    env.as_contract(&contract_id, || {
+        GameReview::add_review(
+            env.clone(),
+            user.clone(),
+            game_id,
+            1,
+            Bytes::from_slice(&env, b"Bad"),
+        )
+        .unwrap();
+    });
+    env.as_contract(&contract_id, || {
+        GameReview::delete_review(env.clone(), user.clone(), game_id).unwrap();
+    });
+    env.ledger().set_timestamp(4_599);
+    env.as_contract(&contract_id, || {
+        let err = GameReview::add_review(
+            env.clone(),
+            user.clone(),
+            game_id,
+            5,
+            Bytes::from_slice(&env, b"Great"),
+        )
+        .unwrap_err();
+        assert_eq!(err, GameReviewError::ReviewCooldown);
+    });
+    env.as_contract(&contract_id, || {
+        // The cooldown is per game
+        GameReview::add_review(
+            env.clone(),
+            user.clone(),
+            2,
+            5,
+            Bytes::from_slice(&env, b"Great"),
+        )
+        .unwrap();
+    });
+    env.ledger().set_timestamp(4_600);
+    env.as_contract(&contract_id, || {
+        GameReview::add_review(
+            env.clone(),
+            user.clone(),
+            game_id,
+            5,
+            Bytes::from_slice(&env, b"Great"),
+        )
+        .unwrap();
+        assert_eq!(GameReview::get_game_rating(env.clone(), game_id), 5);
+    });
+}
+
+#[test]
+fn test_get_ratings_bulk() {
+    let (env, contract_id, _admin) = setup_env_and_contract();
+
+    env.as_contract(&contract_id, || {
+        for rating in [5u32, 4, 4] {
+            let user = test_address(&env, "user");
+            GameReview::add_review(
+                env.clone(),
+                user,
+                1,
+                rating,
+                Bytes::from_slice(&env, b"Review"),
+            )
+            .unwrap();
+        }
+        let user = test_address(&env, "user");
+        GameReview::add_review(env.clone(), user, 2, 2, Bytes::from_slice(&env, b"Meh")).unwrap();
+    });
+    env.as_contract(&contract_id, || {
+        let ratings = GameReview::get_ratings_bulk(env.clone(), soroban_sdk::vec![&env, 2, 3, 1]);
+        assert_eq!(
+            ratings,
+            soroban_sdk::vec![&env, (2, 200, 1), (3, 0, 0), (1, 433, 3)]
+        );
+    });
+    env.as_contract(&contract_id, || {
+        // Requests are capped at MAX_BULK_RATINGS games
+        let mut game_ids = soroban_sdk::Vec::new(&env);
+        for game_id in 0..MAX_BULK_RATINGS + 5 {
+            game_ids.push_back(game_id);
+        }
+        assert_eq!(
+            GameReview::get_ratings_bulk(env.clone(), game_ids).len(),
+            MAX_BULK_RATINGS
+        );
+    });
+}
+
+#[test]
+fn test_review_ttl_extended_on_read() {
+    use soroban_sdk::testutils::storage::Persistent as _;
+
+    let (env, contract_id, _admin) = setup_env_and_contract();
+    let user = test_address(&env, "user");
+    let game_id = 1u32;
+
+    env.as_contract(&contract_id, || {
+        GameReview::add_review(
+            env.clone(),
+            user.clone(),
+            game_id,
+            4,
+            Bytes::from_slice(&env, b"Good"),
+        )
+        .unwrap();
+    });
+    env.as_contract(&contract_id, || {
+        let key = types::DataKey::Reviews(game_id, user.clone());
+        assert!(env.storage().persistent().get_ttl(&key) < storage::REVIEW_TTL_THRESHOLD);
+
+        GameReview::get_review(env.clone(), game_id, user.clone()).unwrap();
+        assert_eq!(
+            env.storage().persistent().get_ttl(&key),
+            storage::REVIEW_TTL_EXTEND_TO
+        );
+    });
+}
+
+#[test]
+fn test_archive_old_reviews() {
+    let (env, contract_id, _admin) = setup_env_and_contract();
+    let game_id = 1u32;
+    let users = [
+        test_address(&env, "user1"),
+        test_address(&env, "user2"),
+        test_address(&env, "user3"),
+    ];
+
+    for (i, user) in users.iter().enumerate() {
+        env.ledger().set_timestamp(100 * (i as u64 + 1));
+        env.as_contract(&contract_id, || {
+            GameReview::add_review(
+                env.clone(),
+                user.clone(),
+                game_id,
+                i as u32 + 1,
+                Bytes::from_slice(&env, b"Review"),
+            )
+            .unwrap();
+        });
+    }
+    env.as_contract(&contract_id, || {
+        assert_eq!(
+            GameReview::archive_old_reviews(env.clone(), game_id, 250, 1, 0),
+            Err(GameReviewError::InvalidInput)
+        );
+    });
+    env.as_contract(&contract_id, || {
+        // Only the first review is scanned
+        assert_eq!(
+            GameReview::archive_old_reviews(env.clone(), game_id, 250, 1, 1),
+            Ok(2)
+        );
+        assert_eq!(GameReview::get_reviews(env.clone(), game_id, 0, 10).len(), 2);
+    });
+    env.as_contract(&contract_id, || {
+        // The scan stops at the last review id
+        assert_eq!(
+            GameReview::archive_old_reviews(env.clone(), game_id, 250, 2, 10),
+            Ok(4)
+        );
+        // Archived reviews still count and still belong to their authors
+        assert!(GameReview::has_reviewed(
+            env.clone(),
+            users[1].clone(),
+            game_id
+        ));
+        assert_eq!(GameReview::get_game_review_count(env.clone(), game_id), 3);
+        assert_eq!(GameReview::get_game_rating(env.clone(), game_id), 2);
+        let archived = GameReview::get_review(env.clone(), game_id, users[1].clone()).unwrap();
+        assert_eq!(archived.comment, Bytes::from_slice(&env, b"Review"));
+
+        let reviews = GameReview::get_reviews(env.clone(), game_id, 0, 10);
+        assert_eq!(reviews.len(), 1);
+        assert_eq!(reviews.get(0).unwrap().reviewer, users[2]);
+        let feed = GameReview::get_reviews_by_user(env.clone(), users[0].clone(), 0, 10);
+        assert_eq!(feed.reviews.len(), 0);
+    });
+}
+
+#[test]
+fn test_archived_review_can_be_updated_or_deleted() {
+    let (env, contract_id, _admin) = setup_env_and_contract();
+    let game_id = 1u32;
+    let editor = test_address(&env, "editor");
+    let deleter = test_address(&env, "deleter");
+
+    env.ledger().set_timestamp(100);
+    env.as_contract(&contract_id, || {
+        GameReview::set_review_cooldown(env.clone(), game_id, 1_000).unwrap();
+    });
+    for user in [&editor, &deleter] {
+        env.as_contract(&contract_id, || {
+            GameReview::add_review(
+                env.clone(),
+                user.clone(),
+                game_id,
+                4,
+                Bytes::from_slice(&env, b"Review"),
+            )
+            .unwrap();
+        });
+    }
+    env.ledger().set_timestamp(200);
+    env.as_contract(&contract_id, || {
+        assert_eq!(
+            GameReview::archive_old_reviews(env.clone(), game_id, 150, 1, 10),
+            Ok(3)
+        );
+        assert_eq!(GameReview::get_reviews(env.clone(), game_id, 0, 10).len(), 0);
+    });
+
+    env.as_contract(&contract_id, || {
+        // Archived reviewers cannot review again
+        assert_eq!(
+            GameReview::add_review(
+                env.clone(),
+                editor.clone(),
+                game_id,
+                1,
+                Bytes::from_slice(&env, b"Again"),
+            ),
+            Err(GameReviewError::UserHasReviewed)
+        );
+    });
+    env.as_contract(&contract_id, || {
+        // An edit brings the review back into the listing
+        GameReview::update_review(
+            env.clone(),
+            editor.clone(),
+            game_id,
+            2,
+            Bytes::from_slice(&env, b"Edited"),
+        )
+        .unwrap();
+        let reviews = GameReview::get_reviews(env.clone(), game_id, 0, 10);
+        assert_eq!(reviews.len(), 1);
+        assert_eq!(reviews.get(0).unwrap().comment, Bytes::from_slice(&env, b"Edited"));
+        assert_eq!(GameReview::get_game_rating(env.clone(), game_id), 3);
+    });
+
+    env.as_contract(&contract_id, || {
+        // Deleting an archived review updates the totals and starts the cooldown
+        GameReview::delete_review(env.clone(), deleter.clone(), game_id).unwrap();
+        assert_eq!(GameReview::get_game_review_count(env.clone(), game_id), 1);
+        assert_eq!(GameReview::get_game_rating(env.clone(), game_id), 2);
+    });
+    env.as_contract(&contract_id, || {
+        assert_eq!(
+            GameReview::add_review(
+                env.clone(),
+                deleter.clone(),
+                game_id,
+                5,
+                Bytes::from_slice(&env, b"Again"),
+            ),
+            Err(GameReviewError::ReviewCooldown)
+        );
+    });
+}
+
+#[test]
+fn test_reviewer_reputation() {
+    let (env, contract_id, _admin) = setup_env_and_contract();
+    let author = test_address(&env, "author");
+    let voter1 = test_address(&env, "voter1");
+    let voter2 = test_address(&env, "voter2");
+
+    for game_id in [1u32, 2] {
+        env.as_contract(&contract_id, || {
+            GameReview::add_review(
+                env.clone(),
+                author.clone(),
+                game_id,
+                4,
+                Bytes::from_slice(&env, b"Solid"),
+            )
+            .unwrap();
+        });
+    }
+    env.as_contract(&contract_id, || {
+        GameReview::vote_helpful(env.clone(), voter1.clone(), 1, 1).unwrap();
+        GameReview::vote_helpful(env.clone(), voter2.clone(), 1, 1).unwrap();
+    });
+    env.as_contract(&contract_id, || {
+        GameReview::vote_helpful(env.clone(), voter1.clone(), 2, 1).unwrap();
+        assert_eq!(GameReview::get_helpful_votes(env.clone(), 1, 1), 2);
+        assert_eq!(
+            GameReview::get_reviewer_stats(env.clone(), author.clone()),
+            ReviewerStats {
+                review_count: 2,
+                reputation: 3,
+                avg_helpfulness: 150,
+            }
+        );
+    });
+    env.as_contract(&contract_id, || {
+        assert_eq!(
+            GameReview::vote_helpful(env.clone(), voter1.clone(), 1, 1),
+            Err(GameReviewError::AlreadyVoted)
+        );
+        assert_eq!(
+            GameReview::vote_helpful(env.clone(), author.clone(), 1, 1),
+            Err(GameReviewError::InvalidInput)
+        );
+    });
+    env.as_contract(&contract_id, || {
+        assert_eq!(
+            GameReview::vote_helpful(env.clone(), voter1.clone(), 1, 9),
+            Err(GameReviewError::ReviewNotFound)
+        );
+    });
+    env.as_contract(&contract_id, || {
+        // A moderated removal costs reputation and drops the review's votes
+        GameReview::moderate_review(env.clone(), 1, 1, ModerationAction::Remove).unwrap();
+        assert_eq!(
+            GameReview::get_reviewer_stats(env.clone(), author.clone()),
+            ReviewerStats {
+                review_count: 1,
+                reputation: 2,
+                avg_helpfulness: 100,
+            }
+        );
+    });
+    env.as_contract(&contract_id, || {
+        // Deleting their own review keeps the author's reputation
+        GameReview::delete_review(env.clone(), author.clone(), 2).unwrap();
+        assert_eq!(
+            GameReview::get_reviewer_stats(env.clone(), author.clone()),
+            ReviewerStats {
+                review_count: 0,
+                reputation: 2,
+                avg_helpfulness: 0,
+            }
+        );
+    });
+}
+
+mod moderation {
+    use crate::types::ModerationScope;
+    use soroban_sdk::{contract, contractimpl, symbol_short, Address, Env};
+
+    /// Minimal moderation contract with a ban list that can be taken offline
+    #[contract]
+    pub struct MockModeration;
+
+    #[contractimpl]
+    impl MockModeration {
+        pub fn ban(env: Env, address: Address) {
+            env.storage().instance().set(&address, &true);
+        }
+
+        pub fn set_offline(env: Env, offline: bool) {
+            env.storage().instance().set(&symbol_short!("offline"), &offline);
+        }
+
+        pub fn is_banned(env: Env, address: Address, scope: ModerationScope) -> bool {
+            if env
+                .storage()
+                .instance()
+                .get(&symbol_short!("offline"))
+                .unwrap_or(false)
+            {
+                panic!("moderation offline");
+            }
+            scope == ModerationScope::Reviews && env.storage().instance().has(&address)
+        }
+    }
+}
+
+#[test]
+fn test_moderation_bans() {
+    let (env, contract_id, _admin) = setup_env_and_contract();
+    let banned = test_address(&env, "banned");
+    let author = test_address(&env, "author");
+    let game_id = 4u32;
+    let moderation_id = env.register(moderation::MockModeration, ());
+    let moderation = moderation::MockModerationClient::new(&env, &moderation_id);
+    moderation.ban(&banned);
+
+    env.as_contract(&contract_id, || {
+        GameReview::add_review(
+            env.clone(),
+            author.clone(),
+            game_id,
+            4,
+            Bytes::from_slice(&env, b"Solid"),
+        )
+        .unwrap();
+        GameReview::set_moderation_contract(env.clone(), moderation_id.clone(), false).unwrap();
+        assert_eq!(
+            GameReview::get_moderation_config(env.clone()),
+            Some(ModerationConfig {
+                contract: moderation_id.clone(),
+                fail_open: false,
+            })
+        );
+    });
+    env.as_contract(&contract_id, || {
+        let err = GameReview::add_review(
+            env.clone(),
+            banned.clone(),
+            game_id,
+            1,
+            Bytes::from_slice(&env, b"Spam"),
+        )
+        .unwrap_err();
+        assert_eq!(err, GameReviewError::Banned);
+    });
+    env.as_contract(&contract_id, || {
+        let err = GameReview::vote_helpful(env.clone(), banned.clone(), game_id, 1).unwrap_err();
+        assert_eq!(err, GameReviewError::Banned);
+        assert!(!GameReview::has_reviewed(env.clone(), banned.clone(), game_id));
+    });
+    env.as_contract(&contract_id, || {
+        GameReview::update_review(
+            env.clone(),
+            author.clone(),
+            game_id,
+            5,
+            Bytes::from_slice(&env, b"Great"),
+        )
+        .unwrap();
+    });
+
+    // Failing closed rejects everyone while the moderation contract is down
+    moderation.set_offline(&true);
+    env.as_contract(&contract_id, || {
+        let err = GameReview::vote_helpful(env.clone(), test_address(&env, "voter"), game_id, 1)
+            .unwrap_err();
+        assert_eq!(err, GameReviewError::ModerationUnavailable);
+    });
+    env.as_contract(&contract_id, || {
+        GameReview::set_moderation_contract(env.clone(), moderation_id.clone(), true).unwrap();
+    });
+    env.as_contract(&contract_id, || {
+        GameReview::vote_helpful(env.clone(), test_address(&env, "voter"), game_id, 1).unwrap();
+        assert_eq!(GameReview::get_helpful_votes(env.clone(), game_id, 1), 1);
+    });
+}
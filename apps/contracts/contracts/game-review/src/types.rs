@@ -1,4 +1,4 @@
-use soroban_sdk::{contracttype, Address, Bytes};
+use soroban_sdk::{contracttype, Address, Bytes, Vec};
 
 /// Represents a single game review
 #[contracttype]
@@ -14,6 +14,86 @@ pub struct Review {
     pub comment: Bytes,
     /// Ledger timestamp when review was created
     pub timestamp: u64,
+    /// Whether the ownership contract confirmed the reviewer owns the game
+    pub verified: bool,
+}
+
+/// Moderation state of a review
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ReviewStatus {
+    /// Listed and counted in the game rating
+    Visible,
+    /// Reported by at least one user and awaiting moderation, still listed
+    Reported,
+    /// Hidden by the admin, left out of listings and the game rating
+    Hidden,
+}
+
+/// Action the admin takes on a review
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ModerationAction {
+    /// Hide the review from listings and the game rating
+    Hide,
+    /// Make the review visible again and clear its pending reports
+    Restore,
+    /// Delete the review permanently
+    Remove,
+}
+
+/// Area of the platform a moderation ban applies to
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ModerationScope {
+    /// Writing, editing and voting on reviews
+    Reviews,
+}
+
+/// Platform moderation contract consulted before accepting review activity
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ModerationConfig {
+    /// Address of the moderation contract
+    pub contract: Address,
+    /// Whether to let users through when the moderation contract call fails
+    pub fail_open: bool,
+}
+
+/// Reputation summary of a reviewer
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReviewerStats {
+    /// Number of reviews the user currently has
+    pub review_count: u32,
+    /// Helpful votes received, minus one for each review removed by moderation
+    pub reputation: i32,
+    /// Average helpful votes per current review, scaled by 100
+    pub avg_helpfulness: u32,
+}
+
+/// Review rules of a game, set by the admin
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReviewPolicy {
+    /// Fewest bytes a review comment may have
+    pub min_comment_len: u32,
+    /// Most bytes a review comment may have
+    pub max_comment_len: u32,
+    /// Seconds a reviewer must wait between edits of their review
+    pub edit_cooldown_secs: u64,
+    /// Whether reviewers may edit their reviews at all
+    pub allow_updates: bool,
+}
+
+/// One page of a user's cross-game review feed
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReviewFeedPage {
+    /// Reviews with their game ids, most recently added or updated first
+    pub reviews: Vec<(u32, Review)>,
+    /// Cursor for the next page, or `None` once the feed is exhausted
+    pub next_cursor: Option<u32>,
 }
 
 /// Storage keys for the contract
@@ -32,4 +112,38 @@ pub enum DataKey {
     Reviews(u32, Address),
     /// Key for accessing reviews by game and review id
     IndexedReviews(u32, u32),
+    /// Key for the id the next review of a game will receive
+    NextReviewId(u32),
+    /// Key for the moderation status of a review by game and review id
+    ReviewStatus(u32, u32),
+    /// Key for the number of reports against a review by game and review id
+    ReportCount(u32, u32),
+    /// Key marking that a user has reported a review
+    Reported(u32, u32, Address),
+    /// Key for the game ownership contract consulted for verified reviews
+    OwnershipContract,
+    /// Key for the (count, sum of ratings) of verified reviews per game
+    VerifiedRatings(u32),
+    /// Key for the (prior x100, weight) used by the smoothed game score
+    ScoreParams,
+    /// Key for the seconds a user must wait to re-review a game after deleting their review
+    ReviewCooldown(u32),
+    /// Key for when a user last deleted their review of a game
+    DeletedAt(u32, Address),
+    /// Key for the number of helpful votes of a review by game and review id
+    HelpfulVotes(u32, u32),
+    /// Key marking that a user has voted a review helpful
+    Voted(u32, u32, Address),
+    /// Key for the (review count, reputation, helpful votes) of a reviewer
+    ReviewerStats(Address),
+    /// Key for the review policy of a game
+    ReviewPolicy(u32),
+    /// Key for when a user last edited their review of a game
+    EditedAt(u32, Address),
+    /// Key for the (game id, review id) of every review of a user, oldest activity first
+    UserReviews(Address),
+    /// Key for the platform moderation contract and its fail-open toggle
+    ModerationConfig,
+    /// Key for archived reviews by game and review id, kept out of listings
+    ArchivedReviews(u32, u32),
 }
@@ -0,0 +1,125 @@
+use soroban_sdk::{contracttype, Address, Env, String, Symbol};
+use crate::errors::Error;
+use crate::types::Achievement;
+
+/// Remaining ledgers below which an achievement, reporter or player's
+/// progress is extended when used (~7 days)
+pub(crate) const TTL_THRESHOLD: u32 = 120_960;
+/// Ledgers those entries are extended to (~30 days)
+///
+/// Every report extends the player's progress, so only badges of players
+/// who stopped playing for a month can lapse.
+pub(crate) const TTL_EXTEND_TO: u32 = 518_400;
+
+#[contracttype]
+#[derive(Clone)]
+enum DataKey {
+    Admin,
+    Achievement(String),
+    Reporter(String, Address),
+}
+
+/// Set the contract admin, who defines achievements and trusts reporters
+pub fn initialize(env: &Env, admin: Address) -> Result<(), Error> {
+    if env.storage().instance().has(&DataKey::Admin) {
+        return Err(Error::AlreadyInitialized);
+    }
+    admin.require_auth();
+    env.storage().instance().set(&DataKey::Admin, &admin);
+    Ok(())
+}
+
+pub fn get_admin(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::Admin)
+}
+
+/// Define a new achievement, admin only
+pub fn define_achievement(env: &Env, achievement: Achievement) -> Result<(), Error> {
+    let admin = get_admin(env).ok_or(Error::Unauthorized)?;
+    admin.require_auth();
+    if achievement.threshold == 0 {
+        return Err(Error::InvalidParameters);
+    }
+
+    let key = DataKey::Achievement(achievement.id.clone());
+    if env.storage().persistent().has(&key) {
+        return Err(Error::AchievementExists);
+    }
+    env.storage().persistent().set(&key, &achievement);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD, TTL_EXTEND_TO);
+
+    // Emit event
+    env.events().publish(
+        (Symbol::new(env, "achievement_defined"), achievement.game_id),
+        (achievement.id, achievement.threshold),
+    );
+
+    Ok(())
+}
+
+/// Get an achievement's definition
+pub fn get_achievement(env: &Env, achievement_id: String) -> Result<Achievement, Error> {
+    let key = DataKey::Achievement(achievement_id);
+    let achievement = env.storage().persistent().get(&key).ok_or(Error::AchievementNotFound)?;
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD, TTL_EXTEND_TO);
+    Ok(achievement)
+}
+
+/// Trust a game contract or server to report progress on a game's achievements, admin only
+pub fn add_reporter(env: &Env, game_id: String, reporter: Address) -> Result<(), Error> {
+    let admin = get_admin(env).ok_or(Error::Unauthorized)?;
+    admin.require_auth();
+
+    let key = DataKey::Reporter(game_id.clone(), reporter.clone());
+    env.storage().persistent().set(&key, &true);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD, TTL_EXTEND_TO);
+
+    // Emit event
+    env.events().publish(
+        (Symbol::new(env, "reporter_added"), game_id),
+        reporter,
+    );
+
+    Ok(())
+}
+
+/// Stop trusting a reporter for a game, admin only
+pub fn remove_reporter(env: &Env, game_id: String, reporter: Address) -> Result<(), Error> {
+    let admin = get_admin(env).ok_or(Error::Unauthorized)?;
+    admin.require_auth();
+
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Reporter(game_id.clone(), reporter.clone()));
+
+    // Emit event
+    env.events().publish(
+        (Symbol::new(env, "reporter_removed"), game_id),
+        reporter,
+    );
+
+    Ok(())
+}
+
+/// Check whether an address may report progress on a game's achievements
+///
+/// The admin always may.
+pub fn is_reporter(env: &Env, game_id: String, reporter: Address) -> bool {
+    if get_admin(env).as_ref() == Some(&reporter) {
+        return true;
+    }
+    let key = DataKey::Reporter(game_id, reporter);
+    if !env.storage().persistent().has(&key) {
+        return false;
+    }
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD, TTL_EXTEND_TO);
+    true
+}
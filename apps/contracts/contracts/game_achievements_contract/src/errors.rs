@@ -0,0 +1,17 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    /// Contract admin already set
+    AlreadyInitialized = 1,
+    /// Unauthorized operation
+    Unauthorized = 2,
+    /// Invalid parameters
+    InvalidParameters = 3,
+    /// Achievement already exists
+    AchievementExists = 4,
+    /// Achievement not found
+    AchievementNotFound = 5,
+}
@@ -0,0 +1,77 @@
+#![no_std]
+
+use soroban_sdk::{contract, contractimpl, Address, Env, String, Vec};
+
+pub mod errors;
+pub mod types;
+pub mod achievements;
+pub mod progress;
+
+use errors::Error;
+use types::*;
+
+#[contract]
+pub struct GameAchievementsContract;
+
+#[contractimpl]
+impl GameAchievementsContract {
+    /// Set the contract admin, who defines achievements and trusts reporters
+    pub fn initialize(env: Env, admin: Address) -> Result<(), Error> {
+        achievements::initialize(&env, admin)
+    }
+
+    /// Define a new achievement, admin only
+    pub fn define_achievement(env: Env, achievement: Achievement) -> Result<(), Error> {
+        achievements::define_achievement(&env, achievement)
+    }
+
+    /// Get an achievement's definition
+    pub fn get_achievement(env: Env, achievement_id: String) -> Result<Achievement, Error> {
+        achievements::get_achievement(&env, achievement_id)
+    }
+
+    /// Trust a game contract or server to report progress on a game's achievements, admin only
+    pub fn add_reporter(env: Env, game_id: String, reporter: Address) -> Result<(), Error> {
+        achievements::add_reporter(&env, game_id, reporter)
+    }
+
+    /// Stop trusting a reporter for a game, admin only
+    pub fn remove_reporter(env: Env, game_id: String, reporter: Address) -> Result<(), Error> {
+        achievements::remove_reporter(&env, game_id, reporter)
+    }
+
+    /// Check whether an address may report progress on a game's achievements
+    pub fn is_reporter(env: Env, game_id: String, reporter: Address) -> bool {
+        achievements::is_reporter(&env, game_id, reporter)
+    }
+
+    /// Report a player's progress towards an achievement, returning whether it unlocked
+    pub fn report_progress(
+        env: Env,
+        reporter: Address,
+        player: Address,
+        achievement_id: String,
+        amount: u64,
+    ) -> Result<bool, Error> {
+        progress::report_progress(&env, reporter, player, achievement_id, amount)
+    }
+
+    /// Get a player's progress towards an achievement
+    pub fn get_progress(env: Env, player: Address, achievement_id: String) -> u64 {
+        progress::get_progress(&env, player, achievement_id)
+    }
+
+    /// Get the badges a player unlocked in a game with pagination, oldest first
+    pub fn get_player_achievements(
+        env: Env,
+        player: Address,
+        game_id: String,
+        page: u32,
+        page_size: u32,
+    ) -> Vec<Badge> {
+        progress::get_player_achievements(&env, player, game_id, page, page_size)
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,109 @@
+use soroban_sdk::{contracttype, Address, Env, String, Symbol, Vec};
+use crate::achievements::{self, TTL_EXTEND_TO, TTL_THRESHOLD};
+use crate::errors::Error;
+use crate::types::{Badge, Criteria};
+
+#[contracttype]
+#[derive(Clone)]
+enum DataKey {
+    Progress(Address, String),
+    /// Badges of a player in one game, in unlock order
+    Badges(Address, String),
+}
+
+/// Report a player's progress towards an achievement as a trusted reporter of its game
+///
+/// Cumulative achievements add `amount` to the player's progress, single
+/// ones keep the best amount reported. Progress stops counting once the
+/// achievement unlocks. Returns whether this report unlocked it.
+pub fn report_progress(
+    env: &Env,
+    reporter: Address,
+    player: Address,
+    achievement_id: String,
+    amount: u64,
+) -> Result<bool, Error> {
+    reporter.require_auth();
+    let achievement = achievements::get_achievement(env, achievement_id.clone())?;
+    if !achievements::is_reporter(env, achievement.game_id.clone(), reporter) {
+        return Err(Error::Unauthorized);
+    }
+
+    let current = get_progress(env, player.clone(), achievement_id.clone());
+    if current >= achievement.threshold {
+        return Ok(false);
+    }
+    let progress = match achievement.criteria {
+        Criteria::Cumulative => current.saturating_add(amount),
+        Criteria::Single => current.max(amount),
+    };
+    let key = DataKey::Progress(player.clone(), achievement_id.clone());
+    env.storage().persistent().set(&key, &progress);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD, TTL_EXTEND_TO);
+
+    // Emit event
+    env.events().publish(
+        (Symbol::new(env, "progress_reported"), player.clone()),
+        (achievement_id.clone(), progress),
+    );
+
+    if progress < achievement.threshold {
+        return Ok(false);
+    }
+    let badge = Badge {
+        achievement_id: achievement_id.clone(),
+        game_id: achievement.game_id.clone(),
+        unlocked_at: env.ledger().timestamp(),
+    };
+    let mut badges = get_badges(env, &player, &achievement.game_id);
+    badges.push_back(badge);
+    let key = DataKey::Badges(player.clone(), achievement.game_id.clone());
+    env.storage().persistent().set(&key, &badges);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD, TTL_EXTEND_TO);
+
+    // Emit event
+    env.events().publish(
+        (Symbol::new(env, "achievement_unlocked"), player),
+        (achievement_id, achievement.game_id),
+    );
+
+    Ok(true)
+}
+
+/// Get a player's progress towards an achievement
+pub fn get_progress(env: &Env, player: Address, achievement_id: String) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Progress(player, achievement_id))
+        .unwrap_or(0)
+}
+
+/// Get the badges a player unlocked in a game with pagination, oldest first
+pub fn get_player_achievements(
+    env: &Env,
+    player: Address,
+    game_id: String,
+    page: u32,
+    page_size: u32,
+) -> Vec<Badge> {
+    let badges = get_badges(env, &player, &game_id);
+    let start = page.saturating_mul(page_size).min(badges.len());
+    let end = start.saturating_add(page_size).min(badges.len());
+    badges.slice(start..end)
+}
+
+// Helper functions
+fn get_badges(env: &Env, player: &Address, game_id: &String) -> Vec<Badge> {
+    let key = DataKey::Badges(player.clone(), game_id.clone());
+    let Some(badges) = env.storage().persistent().get(&key) else {
+        return Vec::new(env);
+    };
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD, TTL_EXTEND_TO);
+    badges
+}
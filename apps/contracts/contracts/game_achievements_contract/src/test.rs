@@ -0,0 +1,171 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, Env, String};
+
+/// Register the contract and trust a reporter for the tests' game
+fn setup_game(env: &Env) -> (GameAchievementsContractClient<'_>, Address) {
+    env.mock_all_auths();
+    let client = GameAchievementsContractClient::new(env, &env.register(GameAchievementsContract, ()));
+    client.initialize(&Address::generate(env));
+    let reporter = Address::generate(env);
+    client.add_reporter(&String::from_str(env, "game"), &reporter);
+    (client, reporter)
+}
+
+fn achievement(env: &Env, id: &str, criteria: Criteria, threshold: u64) -> Achievement {
+    Achievement {
+        id: String::from_str(env, id),
+        game_id: String::from_str(env, "game"),
+        name: String::from_str(env, id),
+        criteria,
+        threshold,
+    }
+}
+
+#[test]
+fn test_unlock_achievements() {
+    let env = Env::default();
+    let (client, reporter) = setup_game(&env);
+    let game_id = String::from_str(&env, "game");
+    let player = Address::generate(&env);
+    client.define_achievement(&achievement(&env, "slayer", Criteria::Cumulative, 10));
+    client.define_achievement(&achievement(&env, "high_score", Criteria::Single, 500));
+    let slayer = String::from_str(&env, "slayer");
+    let high_score = String::from_str(&env, "high_score");
+
+    env.ledger().set_timestamp(100);
+    assert!(!client.report_progress(&reporter, &player, &slayer, &4));
+    assert!(!client.report_progress(&reporter, &player, &high_score, &300));
+    assert!(!client.report_progress(&reporter, &player, &high_score, &200));
+    assert_eq!(client.get_progress(&player, &high_score), 300);
+
+    env.ledger().set_timestamp(200);
+    assert!(client.report_progress(&reporter, &player, &slayer, &6));
+    assert!(!client.report_progress(&reporter, &player, &slayer, &6));
+    assert_eq!(client.get_progress(&player, &slayer), 10);
+    env.ledger().set_timestamp(300);
+    assert!(client.report_progress(&reporter, &player, &high_score, &800));
+
+    let badges = client.get_player_achievements(&player, &game_id, &0, &10);
+    assert_eq!(badges.len(), 2);
+    assert_eq!(badges.get(0).unwrap().achievement_id, slayer);
+    assert_eq!(badges.get(0).unwrap().unlocked_at, 200);
+    let page = client.get_player_achievements(&player, &game_id, &1, &1);
+    assert_eq!(page.get(0).unwrap().achievement_id, high_score);
+    assert!(client.get_player_achievements(&player, &game_id, &2, &1).is_empty());
+    assert!(client
+        .get_player_achievements(&player, &String::from_str(&env, "other"), &0, &10)
+        .is_empty());
+}
+
+#[test]
+fn test_achievement_validation() {
+    let env = Env::default();
+    let (client, reporter) = setup_game(&env);
+    let player = Address::generate(&env);
+    assert_eq!(
+        client.try_report_progress(&reporter, &player, &String::from_str(&env, "missing"), &1),
+        Err(Ok(Error::AchievementNotFound))
+    );
+
+    // Unlocking on the first report needs a threshold of at least one
+    for criteria in [Criteria::Cumulative, Criteria::Single] {
+        assert_eq!(
+            client.try_define_achievement(&achievement(&env, "slayer", criteria, 0)),
+            Err(Ok(Error::InvalidParameters))
+        );
+    }
+    assert_eq!(
+        client.try_get_achievement(&String::from_str(&env, "slayer")),
+        Err(Ok(Error::AchievementNotFound))
+    );
+}
+
+#[test]
+fn test_duplicate_achievement_id() {
+    let env = Env::default();
+    let (client, _) = setup_game(&env);
+    let slayer = String::from_str(&env, "slayer");
+    client.define_achievement(&achievement(&env, "slayer", Criteria::Cumulative, 10));
+
+    // IDs are global, so another game can't reuse one either
+    assert_eq!(
+        client.try_define_achievement(&achievement(&env, "slayer", Criteria::Single, 5)),
+        Err(Ok(Error::AchievementExists))
+    );
+    let elsewhere = Achievement {
+        game_id: String::from_str(&env, "other"),
+        ..achievement(&env, "slayer", Criteria::Cumulative, 10)
+    };
+    assert_eq!(client.try_define_achievement(&elsewhere), Err(Ok(Error::AchievementExists)));
+    let kept = client.get_achievement(&slayer);
+    assert_eq!((kept.criteria, kept.threshold), (Criteria::Cumulative, 10));
+    assert_eq!(kept.game_id, String::from_str(&env, "game"));
+}
+
+#[test]
+fn test_untrusted_and_removed_reporters() {
+    let env = Env::default();
+    let (client, reporter) = setup_game(&env);
+    let game_id = String::from_str(&env, "game");
+    let player = Address::generate(&env);
+    let slayer = String::from_str(&env, "slayer");
+    client.define_achievement(&achievement(&env, "slayer", Criteria::Cumulative, 10));
+
+    let stranger = Address::generate(&env);
+    assert!(!client.is_reporter(&game_id, &stranger));
+    assert_eq!(
+        client.try_report_progress(&stranger, &player, &slayer, &1),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    // Only the admin trusts reporters, and trusted ones still sign their reports
+    env.set_auths(&[]);
+    assert!(client.try_add_reporter(&game_id, &stranger).is_err());
+    assert!(client.try_report_progress(&reporter, &player, &slayer, &1).is_err());
+    env.mock_all_auths();
+    client.report_progress(&reporter, &player, &slayer, &1);
+
+    client.remove_reporter(&game_id, &reporter);
+    assert!(!client.is_reporter(&game_id, &reporter));
+    assert_eq!(
+        client.try_report_progress(&reporter, &player, &slayer, &1),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(client.get_progress(&player, &slayer), 1);
+}
+
+#[test]
+fn test_reporters_are_scoped_to_their_game() {
+    let env = Env::default();
+    let (client, reporter) = setup_game(&env);
+    let other_game = String::from_str(&env, "other");
+    let other_reporter = Address::generate(&env);
+    let player = Address::generate(&env);
+    client.add_reporter(&other_game, &other_reporter);
+    client.define_achievement(&achievement(&env, "slayer", Criteria::Cumulative, 10));
+    client.define_achievement(&Achievement {
+        game_id: other_game.clone(),
+        ..achievement(&env, "speedrun", Criteria::Single, 60)
+    });
+    let slayer = String::from_str(&env, "slayer");
+    let speedrun = String::from_str(&env, "speedrun");
+
+    assert!(!client.is_reporter(&String::from_str(&env, "game"), &other_reporter));
+    assert!(!client.is_reporter(&other_game, &reporter));
+    assert_eq!(
+        client.try_report_progress(&other_reporter, &player, &slayer, &10),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_report_progress(&reporter, &player, &speedrun, &60),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert!(client.report_progress(&other_reporter, &player, &speedrun, &60));
+    assert_eq!(client.get_progress(&player, &slayer), 0);
+    assert!(client
+        .get_player_achievements(&player, &String::from_str(&env, "game"), &0, &10)
+        .is_empty());
+}
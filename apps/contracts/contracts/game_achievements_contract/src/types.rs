@@ -0,0 +1,31 @@
+use soroban_sdk::{contracttype, String};
+
+/// How reported progress counts towards an achievement's threshold
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum Criteria {
+    /// Reported amounts add up, as for kills or matches played
+    Cumulative,
+    /// Best single reported amount counts, as for a score in one run
+    Single,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Achievement {
+    pub id: String,
+    pub game_id: String,
+    pub name: String,
+    pub criteria: Criteria,
+    /// Progress at which the achievement unlocks
+    pub threshold: u64,
+}
+
+/// An achievement a player unlocked, kept on chain as their badge
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Badge {
+    pub achievement_id: String,
+    pub game_id: String,
+    pub unlocked_at: u64,
+}
@@ -0,0 +1,64 @@
+use soroban_sdk::{contracttype, Address, Env, String, Vec};
+use crate::errors::Error;
+use crate::tournaments;
+use crate::types::{AuditAction, AuditEntry};
+
+#[contracttype]
+#[derive(Clone)]
+enum DataKey {
+    Entry(String, u32),
+    Count(String),
+}
+
+/// Append an organizer action to a tournament's audit log
+///
+/// Entries are never changed or removed once written.
+pub fn record(env: &Env, tournament_id: String, actor: Option<Address>, action: AuditAction) {
+    let count = get_audit_log_count(env, tournament_id.clone());
+    let entry = AuditEntry {
+        action,
+        actor,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.storage()
+        .persistent()
+        .set(&DataKey::Entry(tournament_id.clone(), count), &entry);
+    env.storage()
+        .persistent()
+        .set(&DataKey::Count(tournament_id), &(count + 1));
+}
+
+/// Get a tournament's audit log with pagination, oldest entry first
+pub fn get_audit_log(
+    env: &Env,
+    tournament_id: String,
+    page: u32,
+    page_size: u32,
+) -> Result<Vec<AuditEntry>, Error> {
+    tournaments::get_tournament_info(env, tournament_id.clone())?;
+    let count = get_audit_log_count(env, tournament_id.clone());
+
+    let start_index = page.saturating_mul(page_size);
+    let end_index = start_index.saturating_add(page_size).min(count);
+
+    let mut result = Vec::new(env);
+    for i in start_index..end_index {
+        if let Some(entry) = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Entry(tournament_id.clone(), i))
+        {
+            result.push_back(entry);
+        }
+    }
+
+    Ok(result)
+}
+
+/// Get the number of entries in a tournament's audit log
+pub fn get_audit_log_count(env: &Env, tournament_id: String) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Count(tournament_id))
+        .unwrap_or(0)
+}
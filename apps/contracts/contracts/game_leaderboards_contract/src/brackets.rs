@@ -0,0 +1,251 @@
+use soroban_sdk::{contracttype, Address, Env, IntoVal, String, Symbol, TryFromVal, Val, Vec};
+use crate::audit;
+use crate::errors::Error;
+use crate::leaderboard;
+use crate::registration;
+use crate::tournaments;
+use crate::types::{AuditAction, Bracket, BracketMatch};
+use crate::{TTL_EXTEND_TO, TTL_THRESHOLD};
+
+/// Most players a bracket can be seeded with
+pub const MAX_BRACKET_SIZE: u32 = 256;
+
+#[contracttype]
+#[derive(Clone)]
+enum DataKey {
+    Bracket(String),
+    /// Bracket and position of a match id
+    Match(u64),
+    NextMatchId,
+}
+
+/// Seed a single-elimination bracket with the top `top_n` players of a game's global leaderboard
+///
+/// Only the admin may seed brackets, and the admin reports their results.
+pub fn seed_bracket_from_leaderboard(
+    env: &Env,
+    bracket_id: String,
+    game_id: String,
+    top_n: u32,
+) -> Result<Bracket, Error> {
+    let admin = tournaments::get_admin(env).ok_or(Error::Unauthorized)?;
+    admin.require_auth();
+
+    if env.storage().persistent().has(&DataKey::Bracket(bracket_id.clone())) {
+        return Err(Error::BracketExists);
+    }
+    if !(2..=MAX_BRACKET_SIZE).contains(&top_n) {
+        return Err(Error::InvalidParameters);
+    }
+
+    let entries = leaderboard::get_global_leaderboard(env, game_id.clone(), 0, top_n)?;
+    if entries.len() < 2 {
+        return Err(Error::InvalidParameters);
+    }
+
+    let mut seeds = Vec::new(env);
+    for entry in entries.iter() {
+        seeds.push_back(entry.player_id);
+    }
+
+    Ok(create_bracket(env, bracket_id, game_id, admin, seeds))
+}
+
+/// Seed a single-elimination bracket for a tournament from its registered players
+///
+/// Only the creator may generate the bracket, and the creator reports its
+/// results. Players on the tournament leaderboard are seeded by rank,
+/// followed by the other registered players in registration order. The
+/// bracket is stored under the tournament id.
+pub fn generate_bracket(env: &Env, tournament_id: String) -> Result<Bracket, Error> {
+    let tournament = tournaments::get_tournament_info(env, tournament_id.clone())?;
+    tournament.creator.require_auth();
+
+    if tournament.cancelled {
+        return Err(Error::TournamentCancelled);
+    }
+    if env.storage().persistent().has(&DataKey::Bracket(tournament_id.clone())) {
+        return Err(Error::BracketExists);
+    }
+
+    let count = registration::get_registration_count(env, tournament_id.clone());
+    if !(2..=MAX_BRACKET_SIZE).contains(&count) {
+        return Err(Error::InvalidParameters);
+    }
+    let registered = registration::get_registrants(env, tournament_id.clone(), 0, count);
+
+    let mut seeds = Vec::new(env);
+    for entry in leaderboard::get_tournament_entries(env, &tournament, 0, registered.len()).iter() {
+        if registered.contains(&entry.player_id) {
+            seeds.push_back(entry.player_id);
+        }
+    }
+    for player_id in registered.iter() {
+        if !seeds.contains(&player_id) {
+            seeds.push_back(player_id);
+        }
+    }
+
+    audit::record(
+        env,
+        tournament_id.clone(),
+        Some(tournament.creator.clone()),
+        AuditAction::BracketGenerated,
+    );
+    Ok(create_bracket(env, tournament_id, tournament.game_id, tournament.creator, seeds))
+}
+
+/// Report the winner of a bracket match as the bracket's organizer
+///
+/// The winner moves on to their next match, or becomes the champion after the final.
+pub fn report_match_result(env: &Env, match_id: u64, winner: Address) -> Result<Bracket, Error> {
+    let (bracket_id, index): (String, u32) = load(env, &DataKey::Match(match_id)).ok_or(Error::MatchNotFound)?;
+    let mut bracket = get_bracket(env, bracket_id.clone())?;
+    bracket.organizer.require_auth();
+
+    let game = bracket.matches.get(index).unwrap();
+    if game.winner.is_some() {
+        return Err(Error::MatchDecided);
+    }
+    let (Some(high), Some(low)) = (game.high.clone(), game.low.clone()) else {
+        return Err(Error::MatchNotReady);
+    };
+    let seed = if winner == high {
+        game.high_seed
+    } else if winner == low {
+        game.low_seed
+    } else {
+        return Err(Error::InvalidParameters);
+    };
+
+    advance(env, &mut bracket, index, winner.clone(), seed);
+    save_bracket(env, &bracket);
+
+    // Emit event
+    env.events().publish(
+        (Symbol::new(env, "match_reported"), bracket_id),
+        (match_id, winner),
+    );
+
+    Ok(bracket)
+}
+
+/// Get a bracket by id; a tournament's bracket has the tournament's id
+pub fn get_bracket(env: &Env, bracket_id: String) -> Result<Bracket, Error> {
+    load(env, &DataKey::Bracket(bracket_id)).ok_or(Error::BracketNotFound)
+}
+
+// Helper functions
+
+/// Lay out every round of a bracket for `seeds` and settle the byes
+///
+/// The field is padded to a power of two and seed k meets seed (size + 1 - k),
+/// so 1 plays the last seed and the top seeds get the byes when there are
+/// too few players. Each round is ordered by the best seed that can reach
+/// its matches, and match j of a round with m matches feeds match
+/// min(j, m + 1 - j) of the next, so the top two seeds can only meet in the final.
+fn create_bracket(env: &Env, bracket_id: String, game_id: String, organizer: Address, seeds: Vec<Address>) -> Bracket {
+    let size = seeds.len().next_power_of_two();
+    let mut next_match_id: u64 = load(env, &DataKey::NextMatchId).unwrap_or(1);
+
+    let mut matches = Vec::new(env);
+    let mut round = 1;
+    let mut round_size = size / 2;
+    while round_size > 0 {
+        for high_seed in 1..=round_size {
+            let first_round = round == 1;
+            let low_seed = size + 1 - high_seed;
+            matches.push_back(BracketMatch {
+                id: next_match_id,
+                round,
+                high_seed: if first_round { high_seed } else { 0 },
+                high: if first_round { seeds.get(high_seed - 1) } else { None },
+                low_seed: if first_round && low_seed <= seeds.len() { low_seed } else { 0 },
+                low: if first_round { seeds.get(low_seed - 1) } else { None },
+                winner: None,
+            });
+            persist(env, &DataKey::Match(next_match_id), &(bracket_id.clone(), matches.len() - 1));
+            next_match_id += 1;
+        }
+        round += 1;
+        round_size /= 2;
+    }
+    persist(env, &DataKey::NextMatchId, &next_match_id);
+
+    let mut bracket = Bracket {
+        id: bracket_id.clone(),
+        game_id: game_id.clone(),
+        organizer,
+        seeds,
+        matches,
+        champion: None,
+        created_at: env.ledger().timestamp(),
+    };
+    for index in 0..size / 2 {
+        let game = bracket.matches.get(index).unwrap();
+        if game.low.is_none() {
+            advance(env, &mut bracket, index, game.high.unwrap(), game.high_seed);
+        }
+    }
+    save_bracket(env, &bracket);
+
+    // Emit event
+    env.events().publish(
+        (Symbol::new(env, "bracket_seeded"), bracket_id),
+        (game_id, bracket.seeds.len()),
+    );
+
+    bracket
+}
+
+/// Record the winner of the match at `index` and move them into the next round
+fn advance(env: &Env, bracket: &mut Bracket, index: u32, winner: Address, seed: u32) {
+    let mut game = bracket.matches.get(index).unwrap();
+    game.winner = Some(winner.clone());
+    bracket.matches.set(index, game.clone());
+
+    let size = bracket.matches.len() + 1;
+    let round_size = size >> game.round;
+    if round_size == 1 {
+        bracket.champion = Some(winner.clone());
+
+        // Emit event
+        env.events().publish(
+            (Symbol::new(env, "bracket_won"), bracket.id.clone()),
+            winner,
+        );
+        return;
+    }
+
+    let round_start = size - (size >> (game.round - 1));
+    let position = index - round_start + 1;
+    let next_index = round_start + round_size + position.min(round_size + 1 - position) - 1;
+    let mut next = bracket.matches.get(next_index).unwrap();
+    if position <= round_size / 2 {
+        next.high = Some(winner);
+        next.high_seed = seed;
+    } else {
+        next.low = Some(winner);
+        next.low_seed = seed;
+    }
+    bracket.matches.set(next_index, next);
+}
+
+fn save_bracket(env: &Env, bracket: &Bracket) {
+    persist(env, &DataKey::Bracket(bracket.id.clone()), bracket);
+}
+
+fn load<V: TryFromVal<Env, Val>>(env: &Env, key: &DataKey) -> Option<V> {
+    let value = env.storage().persistent().get(key)?;
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+    Some(value)
+}
+
+fn persist<V: IntoVal<Env, Val>>(env: &Env, key: &DataKey, value: &V) {
+    env.storage().persistent().set(key, value);
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
@@ -18,4 +18,64 @@ pub enum Error {
     InvalidParameters = 6,
     /// Unauthorized operation
     Unauthorized = 7,
+    /// Standings snapshot already locked for this tournament
+    SnapshotLocked = 8,
+    /// No standings snapshot locked for this tournament
+    SnapshotNotFound = 9,
+    /// Tournament prize pool already paid out
+    AlreadyFinalized = 10,
+    /// No prize pool locked for this tournament
+    NoPrizePool = 11,
+    /// Player already registered for this tournament
+    AlreadyRegistered = 12,
+    /// Player has not registered for this tournament
+    NotRegistered = 13,
+    /// Score submitted without the signature the tournament requires
+    MissingSignature = 14,
+    /// Score nonce not above the player's last nonce for this tournament
+    NonceUsed = 15,
+    /// Contract admin already set
+    AlreadyInitialized = 16,
+    /// Tournament has been cancelled
+    TournamentCancelled = 17,
+    /// Player is not ranked high enough to attach a replay
+    ReplayNotEligible = 18,
+    /// Season not found for this game
+    SeasonNotFound = 19,
+    /// Game's current season has not ended yet
+    SeasonActive = 20,
+    /// Bracket already exists
+    BracketExists = 21,
+    /// Bracket not found
+    BracketNotFound = 22,
+    /// Tournament is not played in teams
+    TeamsNotEnabled = 23,
+    /// Team already exists in this tournament
+    TeamExists = 24,
+    /// Team not found in this tournament
+    TeamNotFound = 25,
+    /// Team has no room for more members
+    TeamFull = 26,
+    /// Player is already on a team in this tournament
+    AlreadyInTeam = 27,
+    /// Player must be on a team to submit scores in this tournament
+    NotInTeam = 28,
+    /// Bracket match not found
+    MatchNotFound = 29,
+    /// Bracket match is still waiting for one of its players
+    MatchNotReady = 30,
+    /// Bracket match already has a winner
+    MatchDecided = 31,
+    /// Recurring tournament not found
+    RecurringNotFound = 32,
+    /// Storage is already on the current layout
+    AlreadyMigrated = 33,
+    /// Player has used up their submissions in this tournament
+    TooManySubmissions = 34,
+    /// Player submitted again before the tournament's minimum interval
+    SubmissionTooSoon = 35,
+    /// Score is above the tournament's highest valid score
+    ScoreOutOfBounds = 36,
+    /// Player has no entry fee left to reclaim from this tournament
+    NoRefund = 37,
 } 
\ No newline at end of file
@@ -0,0 +1,59 @@
+use soroban_sdk::{contracttype, Address, Env, String, Symbol};
+use crate::errors::Error;
+use crate::tournaments;
+use crate::{TTL_EXTEND_TO, TTL_THRESHOLD};
+
+#[contracttype]
+#[derive(Clone)]
+enum DataKey {
+    /// Game whose scores a game contract may push
+    GameContract(Address),
+}
+
+/// Let a game contract push scores for a game, admin only
+pub fn register_game_contract(env: &Env, game_id: String, game_contract: Address) -> Result<(), Error> {
+    let admin = tournaments::get_admin(env).ok_or(Error::Unauthorized)?;
+    admin.require_auth();
+
+    let key = DataKey::GameContract(game_contract.clone());
+    env.storage().persistent().set(&key, &game_id);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD, TTL_EXTEND_TO);
+
+    // Emit event
+    env.events().publish(
+        (Symbol::new(env, "game_contract_registered"), game_id),
+        game_contract,
+    );
+
+    Ok(())
+}
+
+/// Stop a game contract from pushing scores, admin only
+pub fn unregister_game_contract(env: &Env, game_contract: Address) -> Result<(), Error> {
+    let admin = tournaments::get_admin(env).ok_or(Error::Unauthorized)?;
+    admin.require_auth();
+
+    let key = DataKey::GameContract(game_contract.clone());
+    let game_id: String = env.storage().persistent().get(&key).ok_or(Error::Unauthorized)?;
+    env.storage().persistent().remove(&key);
+
+    // Emit event
+    env.events().publish(
+        (Symbol::new(env, "game_contract_unregistered"), game_id),
+        game_contract,
+    );
+
+    Ok(())
+}
+
+/// Get the game a contract is registered to push scores for, if any
+pub fn get_registered_game(env: &Env, game_contract: Address) -> Option<String> {
+    let key = DataKey::GameContract(game_contract);
+    let game_id = env.storage().persistent().get(&key)?;
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD, TTL_EXTEND_TO);
+    Some(game_id)
+}
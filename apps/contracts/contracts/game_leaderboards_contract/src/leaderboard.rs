@@ -1,12 +1,52 @@
-use soroban_sdk::{contracttype, Address, Env, String, Vec};
+use soroban_sdk::{contracttype, Address, BytesN, Env, String, Symbol, Vec};
 use crate::errors::Error;
-use crate::types::{LeaderboardEntry, Tournament};
+use crate::seasons;
+use crate::tournaments;
+use crate::types::{LeaderboardEntry, RankChange, ScoringMode, TieBreak, Tournament};
+use crate::{TTL_EXTEND_TO, TTL_THRESHOLD};
+
+/// Most entries stored in one bucket before it splits in two
+pub const BUCKET_SIZE: u32 = 32;
+/// Most entries kept on a game's global leaderboard
+pub const GLOBAL_LEADERBOARD_SIZE: u32 = 1000;
+
+/// A ranked list of entries, stored in buckets ordered by score
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Board {
+    Tournament(String),
+    /// All-time best scores of a game
+    Global(String),
+    /// Best scores of a game within one season
+    Season(String, u32),
+    /// Elo ratings of a game
+    Rating(String),
+}
 
 #[contracttype]
 #[derive(Clone)]
 enum DataKey {
-    Tournament(String),
-    GlobalLeaderboard(String),
+    BoardIndex(Board),
+    Bucket(Board, u32),
+    Entry(Board, Address),
+}
+
+/// Location of one bucket and the score of its lowest-ranked entry
+#[contracttype]
+#[derive(Clone)]
+struct BucketMeta {
+    id: u32,
+    len: u32,
+    last: u64,
+}
+
+/// Buckets of a board from best to worst scores
+#[contracttype]
+#[derive(Clone)]
+struct BoardIndex {
+    buckets: Vec<BucketMeta>,
+    next_id: u32,
+    len: u32,
 }
 
 /// Get tournament leaderboard with pagination
@@ -16,121 +56,542 @@ pub fn get_leaderboard(
     page: u32,
     page_size: u32,
 ) -> Result<Vec<LeaderboardEntry>, Error> {
-    let tournament = get_tournament(env, tournament_id)?;
-    
-    let start_index = page * page_size;
-    let end_index = (start_index + page_size).min(tournament.entries.len());
-    
-    let mut result = Vec::new(env);
-    for i in start_index..end_index {
-        if let Some(entry) = tournament.entries.get(i) {
-            result.push_back(entry);
-        }
-    }
-    
-    Ok(result)
+    let tournament = tournaments::get_tournament_info(env, tournament_id)?;
+    Ok(get_tournament_entries(
+        env,
+        &tournament,
+        page.saturating_mul(page_size),
+        page_size,
+    ))
 }
 
 /// Get global leaderboard for a game
+///
+/// Once a game runs seasons this is the current season's board, empty
+/// between seasons. Games without seasons get the all-time board.
 pub fn get_global_leaderboard(
     env: &Env,
     game_id: String,
     page: u32,
     page_size: u32,
 ) -> Result<Vec<LeaderboardEntry>, Error> {
-    let global_leaderboard = get_global_leaderboard_internal(env, game_id);
-    
-    let start_index = page * page_size;
-    let end_index = (start_index + page_size).min(global_leaderboard.len());
-    
+    let Some(board) = global_board(env, game_id) else {
+        return Ok(Vec::new(env));
+    };
+    Ok(get_entries(
+        env,
+        &board,
+        page.saturating_mul(page_size),
+        page_size,
+    ))
+}
+
+/// Get the all-time leaderboard for a game, across every season
+pub fn get_all_time_leaderboard(
+    env: &Env,
+    game_id: String,
+    page: u32,
+    page_size: u32,
+) -> Result<Vec<LeaderboardEntry>, Error> {
+    let board = Board::Global(game_id);
+    Ok(get_entries(
+        env,
+        &board,
+        page.saturating_mul(page_size),
+        page_size,
+    ))
+}
+
+/// Get a player's rank and score on a tournament's leaderboard, if they have one
+pub fn get_player_rank(env: &Env, tournament_id: String, player_id: Address) -> Result<Option<(u32, u64)>, Error> {
+    let tournament = tournaments::get_tournament_info(env, tournament_id.clone())?;
+    let board = Board::Tournament(tournament_id);
+    Ok(get_rank(
+        env,
+        &board,
+        tournament.scoring_mode,
+        tournament.tie_break,
+        &player_id,
+    ))
+}
+
+/// Get a player's rank and score on a game's global leaderboard, if they have one
+pub fn get_global_rank(env: &Env, game_id: String, player_id: Address) -> Option<(u32, u64)> {
+    let board = global_board(env, game_id)?;
+    get_rank(
+        env,
+        &board,
+        ScoringMode::Highest,
+        TieBreak::EarliestFirst,
+        &player_id,
+    )
+}
+
+/// Update global leaderboard with new score
+///
+/// Returns the player's global rank, `None` if they are not on the board.
+pub fn update_global_leaderboard(
+    env: &Env,
+    game_id: &String,
+    player_id: &Address,
+    score: u64,
+    proof: &Option<BytesN<32>>,
+) -> Option<u32> {
+    let board = Board::Global(game_id.clone());
+    let rank = record_score(
+        env,
+        &board,
+        ScoringMode::Highest,
+        TieBreak::EarliestFirst,
+        player_id,
+        score,
+        proof,
+        GLOBAL_LEADERBOARD_SIZE,
+    );
+    seasons::record_season_score(env, game_id, player_id, score, proof);
+    rank
+}
+
+/// Get `count` entries of a board starting at zero-based position `start`
+///
+/// Only the buckets overlapping the requested range are read. Ranks are
+/// filled in from the entries' positions.
+pub fn get_entries(env: &Env, board: &Board, start: u32, count: u32) -> Vec<LeaderboardEntry> {
+    let index = get_index(env, board);
+    let end = start.saturating_add(count).min(index.len);
     let mut result = Vec::new(env);
-    for i in start_index..end_index {
-        if let Some(entry) = global_leaderboard.get(i) {
-            result.push_back(entry);
+
+    let mut offset = 0;
+    for meta in index.buckets.iter() {
+        if offset >= end {
+            break;
+        }
+        if offset + meta.len > start {
+            let bucket = get_bucket(env, board, meta.id);
+            for (slot, mut entry) in bucket.iter().enumerate() {
+                let position = offset + slot as u32;
+                if position >= start && position < end {
+                    entry.rank = position + 1;
+                    result.push_back(entry);
+                }
+            }
         }
+        offset += meta.len;
     }
-    
-    Ok(result)
+
+    result
 }
 
-/// Update global leaderboard with new score
-pub fn update_global_leaderboard(env: &Env, game_id: &String, player_id: &Address, score: u64) {
-    let mut leaderboard = get_global_leaderboard_internal(env, game_id.clone());
-    
-    // Find existing entry
-    let mut found_index: Option<u32> = None;
-    for i in 0..leaderboard.len() {
-        if let Some(entry) = leaderboard.get(i) {
-            if entry.player_id == *player_id {
-                found_index = Some(i);
-                break;
-            }
+/// Get `count` entries of a tournament's board starting at `start`, ranked by its tie-break rule
+pub fn get_tournament_entries(env: &Env, tournament: &Tournament, start: u32, count: u32) -> Vec<LeaderboardEntry> {
+    let board = Board::Tournament(tournament.id.clone());
+    let mut entries = get_entries(env, &board, start, count);
+    if tournament.tie_break == TieBreak::Shared {
+        share_tied_ranks(env, &board, tournament.scoring_mode, &mut entries);
+    }
+    entries
+}
+
+/// Get the number of entries on a board
+pub fn get_entry_count(env: &Env, board: &Board) -> u32 {
+    get_index(env, board).len
+}
+
+/// Get a player's score on a board, if they have an entry
+pub fn get_score(env: &Env, board: &Board, player_id: &Address) -> Option<u64> {
+    let key = DataKey::Entry(board.clone(), player_id.clone());
+    let score = env.storage().persistent().get(&key)?;
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD, TTL_EXTEND_TO);
+    Some(score)
+}
+
+/// Give entries with equal scores the rank of the first of them
+///
+/// `entries` must be a contiguous run of the board, as returned by
+/// `get_entries`.
+pub fn share_tied_ranks(env: &Env, board: &Board, mode: ScoringMode, entries: &mut Vec<LeaderboardEntry>) {
+    let index = get_index(env, board);
+    let mut previous: Option<LeaderboardEntry> = None;
+    for i in 0..entries.len() {
+        let mut entry = entries.get(i).unwrap();
+        entry.rank = match &previous {
+            Some(previous) if previous.score == entry.score => previous.rank,
+            Some(_) => entry.rank,
+            None => shared_rank(env, board, &index, mode, entry.score),
+        };
+        entries.set(i, entry.clone());
+        previous = Some(entry);
+    }
+}
+
+/// Get a player's rank and score on a board ordered by `mode`
+///
+/// Only the buckets that can hold the player's score are read.
+pub fn get_rank(
+    env: &Env,
+    board: &Board,
+    mode: ScoringMode,
+    tie_break: TieBreak,
+    player_id: &Address,
+) -> Option<(u32, u64)> {
+    let score = get_score(env, board, player_id)?;
+    let index = get_index(env, board);
+    let rank = match tie_break {
+        TieBreak::Shared => shared_rank(env, board, &index, mode, score),
+        _ => find_rank(env, board, &index, mode, player_id, score)?,
+    };
+    Some((rank, score))
+}
+
+/// Record a player's submission on a board ordered by `mode`
+///
+/// The mode decides how the submission combines with the player's current
+/// entry, and which scores rank first. The new entry is placed by binary
+/// search, after equal scores unless `tie_break` puts the latest
+/// submission first. Boards longer than `max_len` drop their lowest-ranked
+/// entries. Every change to the player's entry publishes a `rank_changed`
+/// event with their previous and new rank. Returns the player's rank, or
+/// `None` if the entry fell off the board.
+pub fn record_score(
+    env: &Env,
+    board: &Board,
+    mode: ScoringMode,
+    tie_break: TieBreak,
+    player_id: &Address,
+    score: u64,
+    proof: &Option<BytesN<32>>,
+    max_len: u32,
+) -> Option<u32> {
+    let mut index = get_index(env, board);
+    let entry_key = DataKey::Entry(board.clone(), player_id.clone());
+    let current = get_score(env, board, player_id);
+    let mut previous_rank = None;
+    let mut score = score;
+    if let Some(current) = current {
+        score = match mode {
+            ScoringMode::Highest | ScoringMode::Lowest if !beats(mode, score, current) => current,
+            ScoringMode::Cumulative => current.saturating_add(score),
+            _ => score,
+        };
+        if score == current {
+            return current_rank(env, board, &index, mode, tie_break, player_id, current);
         }
+        previous_rank = current_rank(env, board, &index, mode, tie_break, player_id, current);
+        remove_entry(env, board, &mut index, mode, player_id, current);
     }
-    
-    if let Some(index) = found_index {
-        let mut entry = leaderboard.get(index).unwrap();
-        if score > entry.score {
-            entry.score = score;
-            leaderboard.set(index, entry);
+
+    let entry = LeaderboardEntry {
+        player_id: player_id.clone(),
+        score,
+        rank: 0,
+        proof: proof.clone(),
+        submitted_at: env.ledger().timestamp(),
+    };
+    let rank = insert_entry(env, board, &mut index, mode, tie_break, entry);
+    env.storage().persistent().set(&entry_key, &score);
+    env.storage()
+        .persistent()
+        .extend_ttl(&entry_key, TTL_THRESHOLD, TTL_EXTEND_TO);
+
+    while index.len > max_len {
+        if let Some(dropped) = pop_last(env, board, &mut index) {
+            env.storage()
+                .persistent()
+                .remove(&DataKey::Entry(board.clone(), dropped.player_id));
         }
+    }
+    save_index(env, board, &index);
+
+    let new_rank = if rank > max_len {
+        None
+    } else if tie_break == TieBreak::Shared {
+        Some(shared_rank(env, board, &index, mode, score))
     } else {
-        let new_entry = LeaderboardEntry {
+        Some(rank)
+    };
+
+    // Emit event
+    env.events().publish(
+        (Symbol::new(env, "rank_changed"), board.clone()),
+        RankChange {
             player_id: player_id.clone(),
             score,
-            rank: 1,
-        };
-        leaderboard.push_back(new_entry);
+            previous_rank,
+            new_rank,
+            entered_top_10: entered_top(previous_rank, new_rank, 10),
+            entered_top_100: entered_top(previous_rank, new_rank, 100),
+        },
+    );
+
+    new_rank
+}
+
+/// Check whether `score` ranks strictly ahead of `other` under `mode`
+pub fn beats(mode: ScoringMode, score: u64, other: u64) -> bool {
+    match mode {
+        ScoringMode::Lowest => score < other,
+        _ => score > other,
     }
-    
-    // Sort and update ranks
-    sort_entries(env, &mut leaderboard);
-    
-    // Keep only top 1000
-    while leaderboard.len() > 1000 {
-        leaderboard.pop_back();
+}
+
+// Helper functions
+/// Board that `get_global_leaderboard` shows for a game, `None` between seasons
+fn global_board(env: &Env, game_id: String) -> Option<Board> {
+    match seasons::get_current_season(env, game_id.clone()) {
+        Some(season) => Some(Board::Season(game_id, season.season_id)),
+        None if seasons::has_seasons(env, &game_id) => None,
+        None => Some(Board::Global(game_id)),
     }
-    
-    env.storage()
-        .instance()
-        .set(&DataKey::GlobalLeaderboard(game_id.clone()), &leaderboard);
-}
-
-/// Sort entries by score (descending) and update ranks
-pub fn sort_entries(_env: &Env, entries: &mut Vec<LeaderboardEntry>) {
-    // Simple bubble sort for small collections
-    let len = entries.len();
-    for i in 0..len {
-        for j in 0..len.saturating_sub(1).saturating_sub(i) {
-            if let (Some(entry1), Some(entry2)) = (entries.get(j), entries.get(j + 1)) {
-                if entry1.score < entry2.score {
-                    entries.set(j, entry2);
-                    entries.set(j + 1, entry1);
-                }
+}
+
+/// Rank of a player's existing entry with `score`
+fn current_rank(
+    env: &Env,
+    board: &Board,
+    index: &BoardIndex,
+    mode: ScoringMode,
+    tie_break: TieBreak,
+    player_id: &Address,
+    score: u64,
+) -> Option<u32> {
+    match tie_break {
+        TieBreak::Shared => Some(shared_rank(env, board, index, mode, score)),
+        _ => find_rank(env, board, index, mode, player_id, score),
+    }
+}
+
+/// Check whether a move from `previous` to `new` brought a player into the top `n`
+fn entered_top(previous: Option<u32>, new: Option<u32>, n: u32) -> bool {
+    new.is_some_and(|new| new <= n) && previous.is_none_or(|previous| previous > n)
+}
+
+fn goes_before(mode: ScoringMode, tie_break: TieBreak, score: u64, other: u64) -> bool {
+    beats(mode, score, other) || (tie_break == TieBreak::LatestFirst && score == other)
+}
+
+/// Rank shared by every entry with `score`, one more than the entries beating it
+fn shared_rank(env: &Env, board: &Board, index: &BoardIndex, mode: ScoringMode, score: u64) -> u32 {
+    let position = first_bucket(index, |last| !beats(mode, last, score));
+    let mut rank = offset_of(index, position) + 1;
+    if let Some(meta) = index.buckets.get(position) {
+        for entry in get_bucket(env, board, meta.id).iter() {
+            if !beats(mode, entry.score, score) {
+                break;
             }
+            rank += 1;
         }
     }
-    
-    // Update ranks
-    for i in 0..len {
-        if let Some(mut entry) = entries.get(i) {
-            entry.rank = i + 1;
-            entries.set(i, entry);
+    rank
+}
+
+fn get_index(env: &Env, board: &Board) -> BoardIndex {
+    let key = DataKey::BoardIndex(board.clone());
+    let Some(index) = env.storage().persistent().get(&key) else {
+        return BoardIndex {
+            buckets: Vec::new(env),
+            next_id: 0,
+            len: 0,
+        };
+    };
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD, TTL_EXTEND_TO);
+    index
+}
+
+fn save_index(env: &Env, board: &Board, index: &BoardIndex) {
+    let key = DataKey::BoardIndex(board.clone());
+    env.storage().persistent().set(&key, index);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+fn get_bucket(env: &Env, board: &Board, id: u32) -> Vec<LeaderboardEntry> {
+    let key = DataKey::Bucket(board.clone(), id);
+    let Some(bucket) = env.storage().persistent().get(&key) else {
+        return Vec::new(env);
+    };
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD, TTL_EXTEND_TO);
+    bucket
+}
+
+/// Find the first bucket whose last score satisfies `reaches`
+///
+/// Buckets run from best to worst, so `reaches` must flip from false to
+/// true at most once along the index.
+fn first_bucket(index: &BoardIndex, reaches: impl Fn(u64) -> bool) -> u32 {
+    let (mut low, mut high) = (0, index.buckets.len());
+    while low < high {
+        let mid = (low + high) / 2;
+        if reaches(index.buckets.get(mid).unwrap().last) {
+            high = mid;
+        } else {
+            low = mid + 1;
         }
     }
+    low
 }
 
-// Helper functions
-fn get_tournament(env: &Env, tournament_id: String) -> Result<Tournament, Error> {
-    env.storage()
-        .instance()
-        .get(&DataKey::Tournament(tournament_id))
-        .ok_or(Error::TournamentNotFound)
+/// Number of entries in the buckets before `position`
+fn offset_of(index: &BoardIndex, position: u32) -> u32 {
+    let mut offset = 0;
+    for i in 0..position {
+        offset += index.buckets.get(i).unwrap().len;
+    }
+    offset
+}
+
+fn insert_entry(
+    env: &Env,
+    board: &Board,
+    index: &mut BoardIndex,
+    mode: ScoringMode,
+    tie_break: TieBreak,
+    entry: LeaderboardEntry,
+) -> u32 {
+    if index.buckets.is_empty() {
+        index.buckets.push_back(BucketMeta {
+            id: index.next_id,
+            len: 0,
+            last: entry.score,
+        });
+        index.next_id += 1;
+    }
+
+    // First bucket ending behind the score, or the last bucket to append to
+    let position = first_bucket(index, |last| goes_before(mode, tie_break, entry.score, last))
+        .min(index.buckets.len() - 1);
+    let meta = index.buckets.get(position).unwrap();
+    let mut bucket = get_bucket(env, board, meta.id);
+
+    let (mut low, mut high) = (0, bucket.len());
+    while low < high {
+        let mid = (low + high) / 2;
+        if goes_before(mode, tie_break, entry.score, bucket.get(mid).unwrap().score) {
+            high = mid;
+        } else {
+            low = mid + 1;
+        }
+    }
+    bucket.insert(low, entry);
+
+    let rank = offset_of(index, position) + low + 1;
+    store_bucket(env, board, index, position, bucket);
+    index.len += 1;
+    rank
+}
+
+fn remove_entry(
+    env: &Env,
+    board: &Board,
+    index: &mut BoardIndex,
+    mode: ScoringMode,
+    player_id: &Address,
+    score: u64,
+) {
+    // Equal scores can span buckets, so scan on from the first bucket reaching the score
+    let mut position = first_bucket(index, |last| !beats(mode, last, score));
+    while position < index.buckets.len() {
+        let meta = index.buckets.get(position).unwrap();
+        let mut bucket = get_bucket(env, board, meta.id);
+        if let Some(slot) = bucket
+            .iter()
+            .position(|entry| entry.player_id == *player_id)
+        {
+            bucket.remove(slot as u32);
+            store_bucket(env, board, index, position, bucket);
+            index.len -= 1;
+            return;
+        }
+        position += 1;
+    }
 }
 
-fn get_global_leaderboard_internal(env: &Env, game_id: String) -> Vec<LeaderboardEntry> {
+fn find_rank(
+    env: &Env,
+    board: &Board,
+    index: &BoardIndex,
+    mode: ScoringMode,
+    player_id: &Address,
+    score: u64,
+) -> Option<u32> {
+    let mut position = first_bucket(index, |last| !beats(mode, last, score));
+    let mut offset = offset_of(index, position);
+    while position < index.buckets.len() {
+        let meta = index.buckets.get(position).unwrap();
+        let bucket = get_bucket(env, board, meta.id);
+        if let Some(slot) = bucket
+            .iter()
+            .position(|entry| entry.player_id == *player_id)
+        {
+            return Some(offset + slot as u32 + 1);
+        }
+        offset += meta.len;
+        position += 1;
+    }
+    None
+}
+
+fn pop_last(env: &Env, board: &Board, index: &mut BoardIndex) -> Option<LeaderboardEntry> {
+    let position = index.buckets.len().checked_sub(1)?;
+    let meta = index.buckets.get(position)?;
+    let mut bucket = get_bucket(env, board, meta.id);
+    let entry = bucket.pop_back()?;
+    store_bucket(env, board, index, position, bucket);
+    index.len -= 1;
+    Some(entry)
+}
+
+/// Write a bucket back, dropping it when empty and splitting it when over `BUCKET_SIZE`
+fn store_bucket(
+    env: &Env,
+    board: &Board,
+    index: &mut BoardIndex,
+    position: u32,
+    bucket: Vec<LeaderboardEntry>,
+) {
+    let id = index.buckets.get(position).unwrap().id;
+    if bucket.is_empty() {
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Bucket(board.clone(), id));
+        index.buckets.remove(position);
+        return;
+    }
+
+    if bucket.len() > BUCKET_SIZE {
+        let half = bucket.len() / 2;
+        let tail_id = index.next_id;
+        index.next_id += 1;
+        index.buckets.set(
+            position,
+            write_bucket(env, board, id, &bucket.slice(0..half)),
+        );
+        index.buckets.insert(
+            position + 1,
+            write_bucket(env, board, tail_id, &bucket.slice(half..)),
+        );
+    } else {
+        index
+            .buckets
+            .set(position, write_bucket(env, board, id, &bucket));
+    }
+}
+
+fn write_bucket(env: &Env, board: &Board, id: u32, bucket: &Vec<LeaderboardEntry>) -> BucketMeta {
+    let key = DataKey::Bucket(board.clone(), id);
+    env.storage().persistent().set(&key, bucket);
     env.storage()
-        .instance()
-        .get(&DataKey::GlobalLeaderboard(game_id))
-        .unwrap_or(Vec::new(env))
-} 
\ No newline at end of file
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD, TTL_EXTEND_TO);
+    BucketMeta {
+        id,
+        len: bucket.len(),
+        last: bucket.last().map(|entry| entry.score).unwrap_or(0),
+    }
+}
@@ -1,29 +1,111 @@
 #![no_std]
+// The generated contract client mirrors create_tournament's argument list
+#![allow(clippy::too_many_arguments)]
 
-use soroban_sdk::{contract, contractimpl, Address, Env, String, Vec};
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, String, Vec};
 
 pub mod errors;
 pub mod types;
+pub mod audit;
+pub mod brackets;
+pub mod games;
 pub mod leaderboard;
+pub mod migration;
+pub mod predictions;
+pub mod prizes;
+pub mod ratings;
+pub mod recurring;
+pub mod registration;
+pub mod replays;
 pub mod scores;
+pub mod seasons;
+pub mod snapshots;
+pub mod stats;
+pub mod teams;
 pub mod tournaments;
 
 use errors::Error;
 use types::*;
 
+/// Remaining ledgers below which a tournament, season or rating entry is
+/// extended when read or written (~7 days)
+pub const TTL_THRESHOLD: u32 = 120_960;
+/// Ledgers those entries are extended to, keeping finished tournaments and
+/// their histories around for a month after their last use (~30 days)
+pub const TTL_EXTEND_TO: u32 = 518_400;
+
 #[contract]
 pub struct GameLeaderboardContract;
 
 #[contractimpl]
 impl GameLeaderboardContract {
-    /// Submit a score to a tournament
+    /// Set the contract admin, who approves tournament creators and may cancel any tournament
+    pub fn initialize(env: Env, admin: Address) -> Result<(), Error> {
+        tournaments::initialize(&env, admin)
+    }
+
+    /// Move up to `limit` leaderboard entries of version 1 tournaments and global boards to the current layout as the admin
+    ///
+    /// Run after upgrading and initializing a contract deployed before the
+    /// move, until it returns 0. Returns the number of tournaments still
+    /// waiting to be migrated.
+    pub fn migrate_v1_to_v2(env: Env, limit: u32) -> Result<u32, Error> {
+        migration::migrate_v1_to_v2(&env, limit)
+    }
+
+    /// Submit a score to a registered tournament, optionally with the hash of the replay that produced it
+    ///
+    /// Tournaments with a server key need the game server's `signature` over
+    /// (player_id, tournament_id, score, nonce), with a nonce above the last one used.
+    /// Only `ScoringMode::Highest` tournaments feed the game's global and season leaderboards.
     pub fn submit_score(
         env: Env,
         tournament_id: String,
         player_id: Address,
         score: u64,
+        proof: Option<BytesN<32>>,
+        nonce: u64,
+        signature: Option<Signature>,
     ) -> Result<u32, Error> {
-        scores::submit_score(&env, tournament_id, player_id, score)
+        scores::submit_score(&env, tournament_id, player_id, score, proof, nonce, signature)
+    }
+
+    /// Record a match score pushed by a registered game contract on the game's global leaderboard
+    ///
+    /// Only `game_contract` itself may call this, typically at the end of a
+    /// match. Returns the player's global rank, 0 if they are not on the board.
+    pub fn submit_score_from_game(env: Env, game_contract: Address, player_id: Address, score: u64) -> Result<u32, Error> {
+        scores::submit_score_from_game(&env, game_contract, player_id, score)
+    }
+
+    /// Let a game contract push scores for a game, admin only
+    pub fn register_game_contract(env: Env, game_id: String, game_contract: Address) -> Result<(), Error> {
+        games::register_game_contract(&env, game_id, game_contract)
+    }
+
+    /// Stop a game contract from pushing scores, admin only
+    pub fn unregister_game_contract(env: Env, game_contract: Address) -> Result<(), Error> {
+        games::unregister_game_contract(&env, game_contract)
+    }
+
+    /// Get the game a contract is registered to push scores for, if any
+    pub fn get_registered_game(env: Env, game_contract: Address) -> Option<String> {
+        games::get_registered_game(&env, game_contract)
+    }
+
+    /// Register for a tournament, paying its entry fee into the prize pool if it has one
+    pub fn register_for_tournament(env: Env, player_id: Address, tournament_id: String) -> Result<(), Error> {
+        registration::register_for_tournament(&env, player_id, tournament_id)
+    }
+
+    /// Get the players registered for a tournament with pagination
+    pub fn get_registered_players(
+        env: Env,
+        tournament_id: String,
+        page: u32,
+        page_size: u32,
+    ) -> Result<Vec<Address>, Error> {
+        registration::get_registered_players(&env, tournament_id, page, page_size)
     }
 
     /// Get tournament leaderboard with pagination
@@ -36,6 +118,35 @@ impl GameLeaderboardContract {
         leaderboard::get_leaderboard(&env, tournament_id, page, page_size)
     }
 
+    /// Get a player's rank and score in a tournament without paging the leaderboard
+    pub fn get_player_rank(
+        env: Env,
+        tournament_id: String,
+        player_id: Address,
+    ) -> Result<Option<(u32, u64)>, Error> {
+        leaderboard::get_player_rank(&env, tournament_id, player_id)
+    }
+
+    /// Get a player's rank and score on a game's global leaderboard
+    pub fn get_global_rank(env: Env, game_id: String, player_id: Address) -> Option<(u32, u64)> {
+        leaderboard::get_global_rank(&env, game_id, player_id)
+    }
+
+    /// Attach the content hash of a replay to a player's top-ranked tournament entry
+    pub fn attach_replay(
+        env: Env,
+        tournament_id: String,
+        player_id: Address,
+        hash: BytesN<32>,
+    ) -> Result<(), Error> {
+        replays::attach_replay(&env, tournament_id, player_id, hash)
+    }
+
+    /// Get the top entries of a tournament with their attached replays
+    pub fn get_top_entries(env: Env, tournament_id: String, count: u32) -> Result<Vec<TopEntry>, Error> {
+        replays::get_top_entries(&env, tournament_id, count)
+    }
+
     /// Get global leaderboard for a game
     pub fn get_global_leaderboard(
         env: Env,
@@ -46,16 +157,263 @@ impl GameLeaderboardContract {
         leaderboard::get_global_leaderboard(&env, game_id, page, page_size)
     }
 
-    /// Create a new tournament
+    /// Get the all-time leaderboard for a game, across every season
+    pub fn get_all_time_leaderboard(
+        env: Env,
+        game_id: String,
+        page: u32,
+        page_size: u32,
+    ) -> Result<Vec<LeaderboardEntry>, Error> {
+        leaderboard::get_all_time_leaderboard(&env, game_id, page, page_size)
+    }
+
+    /// Seed a bracket as the admin from the top players of a game's global leaderboard, 1 vs N, 2 vs N-1 and so on
+    pub fn seed_bracket_from_leaderboard(
+        env: Env,
+        bracket_id: String,
+        game_id: String,
+        top_n: u32,
+    ) -> Result<Bracket, Error> {
+        brackets::seed_bracket_from_leaderboard(&env, bracket_id, game_id, top_n)
+    }
+
+    /// Seed a single-elimination bracket from a tournament's registered players as its creator
+    pub fn generate_bracket(env: Env, tournament_id: String) -> Result<Bracket, Error> {
+        brackets::generate_bracket(&env, tournament_id)
+    }
+
+    /// Report the winner of a bracket match as the bracket's organizer
+    pub fn report_match_result(env: Env, match_id: u64, winner: Address) -> Result<Bracket, Error> {
+        brackets::report_match_result(&env, match_id, winner)
+    }
+
+    /// Get a bracket with every round, by bracket id or by tournament id
+    pub fn get_bracket(env: Env, bracket_id: String) -> Result<Bracket, Error> {
+        brackets::get_bracket(&env, bracket_id)
+    }
+
+    /// Start a new season of a game's global leaderboard as the admin, archiving the last one
+    pub fn start_season(env: Env, game_id: String, season_id: u32, duration: u64) -> Result<Season, Error> {
+        seasons::start_season(&env, game_id, season_id, duration)
+    }
+
+    /// Get the running season of a game, if any
+    pub fn get_current_season(env: Env, game_id: String) -> Option<Season> {
+        seasons::get_current_season(&env, game_id)
+    }
+
+    /// Get a season of a game, running or archived
+    pub fn get_season(env: Env, game_id: String, season_id: u32) -> Result<Season, Error> {
+        seasons::get_season(&env, game_id, season_id)
+    }
+
+    /// Get the ids of a game's archived seasons, oldest first
+    pub fn get_archived_seasons(env: Env, game_id: String) -> Vec<u32> {
+        seasons::get_archived_seasons(&env, game_id)
+    }
+
+    /// Get the leaderboard of one season of a game
+    pub fn get_season_leaderboard(
+        env: Env,
+        game_id: String,
+        season_id: u32,
+        page: u32,
+        page_size: u32,
+    ) -> Result<Vec<LeaderboardEntry>, Error> {
+        seasons::get_season_leaderboard(&env, game_id, season_id, page, page_size)
+    }
+
+    /// Set the most Elo rating points one match of a game moves, admin only, at most 400
+    pub fn set_k_factor(env: Env, game_id: String, k_factor: u32) -> Result<(), Error> {
+        ratings::set_k_factor(&env, game_id, k_factor)
+    }
+
+    /// Get the most Elo rating points one match of a game moves
+    pub fn get_k_factor(env: Env, game_id: String) -> u32 {
+        ratings::get_k_factor(&env, game_id)
+    }
+
+    /// Apply a match result to both players' Elo ratings, as an approved creator or the game's registered contract
+    ///
+    /// Returns the new ratings of the winner and the loser.
+    pub fn report_head_to_head(
+        env: Env,
+        reporter: Address,
+        game_id: String,
+        winner: Address,
+        loser: Address,
+    ) -> Result<(u32, u32), Error> {
+        ratings::report_head_to_head(&env, reporter, game_id, winner, loser)
+    }
+
+    /// Get a player's Elo rating in a game
+    pub fn get_rating(env: Env, game_id: String, player_id: Address) -> u32 {
+        ratings::get_rating(&env, game_id, player_id)
+    }
+
+    /// Get a game's players sorted by Elo rating with pagination
+    pub fn get_rating_leaderboard(env: Env, game_id: String, page: u32, page_size: u32) -> Vec<LeaderboardEntry> {
+        ratings::get_rating_leaderboard(&env, game_id, page, page_size)
+    }
+
+    /// Let an address create tournaments for a game, admin only
+    pub fn approve_creator(env: Env, game_id: String, creator: Address) -> Result<(), Error> {
+        tournaments::approve_creator(&env, game_id, creator)
+    }
+
+    /// Take back an address's approval to create tournaments for a game, admin only
+    pub fn revoke_creator(env: Env, game_id: String, creator: Address) -> Result<(), Error> {
+        tournaments::revoke_creator(&env, game_id, creator)
+    }
+
+    /// Check whether an address may create tournaments for a game
+    pub fn is_approved_creator(env: Env, game_id: String, creator: Address) -> bool {
+        tournaments::is_approved_creator(&env, game_id, creator)
+    }
+
+    /// Create a new tournament with scoring and tie-break rules, optionally locking a token prize pool and requiring signed scores
     pub fn create_tournament(
         env: Env,
+        creator: Address,
         tournament_id: String,
         game_id: String,
         start_time: u64,
         end_time: u64,
         max_entries: u32,
+        scoring_mode: ScoringMode,
+        tie_break: TieBreak,
+        prize_pool: Option<PrizePool>,
+        server_key: Option<BytesN<32>>,
+    ) -> Result<(), Error> {
+        tournaments::create_tournament(
+            &env,
+            creator,
+            tournament_id,
+            game_id,
+            start_time,
+            end_time,
+            max_entries,
+            scoring_mode,
+            tie_break,
+            prize_pool,
+            server_key,
+        )
+    }
+
+    /// Change the end time and entry cap of a tournament that has not ended
+    pub fn update_tournament(
+        env: Env,
+        tournament_id: String,
+        new_end_time: u64,
+        new_max_entries: u32,
+    ) -> Result<(), Error> {
+        tournaments::update_tournament(&env, tournament_id, new_end_time, new_max_entries)
+    }
+
+    /// Create a tournament from a template that starts over with a fresh leaderboard every `period_secs`
+    pub fn create_recurring_tournament(
+        env: Env,
+        template: TournamentTemplate,
+        period_secs: u64,
+    ) -> Result<RecurringTournament, Error> {
+        recurring::create_recurring_tournament(&env, template, period_secs)
+    }
+
+    /// Get a recurring tournament's template and schedule
+    pub fn get_recurring_tournament(env: Env, template_id: String) -> Result<RecurringTournament, Error> {
+        recurring::get_recurring_tournament(&env, template_id)
+    }
+
+    /// Get the running instance of a recurring tournament, creating it on first look-up
+    pub fn get_current_instance(env: Env, template_id: String) -> Result<Tournament, Error> {
+        recurring::get_current_instance(&env, template_id)
+    }
+
+    /// Get up to `count` earlier instances of a recurring tournament, newest first
+    pub fn get_previous_instances(env: Env, template_id: String, count: u32) -> Result<Vec<Tournament>, Error> {
+        recurring::get_previous_instances(&env, template_id, count)
+    }
+
+    /// Limit submissions per player, the time between them and the highest valid score before a tournament starts, as its creator
+    pub fn set_submission_limits(
+        env: Env,
+        tournament_id: String,
+        max_submissions_per_player: u32,
+        min_interval_secs: u64,
+        max_valid_score: u64,
     ) -> Result<(), Error> {
-        tournaments::create_tournament(&env, tournament_id, game_id, start_time, end_time, max_entries)
+        tournaments::set_submission_limits(
+            &env,
+            tournament_id,
+            max_submissions_per_player,
+            min_interval_secs,
+            max_valid_score,
+        )
+    }
+
+    /// Make a tournament that has not started a team tournament, as its creator
+    pub fn enable_teams(env: Env, tournament_id: String, config: TeamConfig) -> Result<(), Error> {
+        teams::enable_teams(&env, tournament_id, config)
+    }
+
+    /// Get the team rules of a tournament, if it is played in teams
+    pub fn get_team_config(env: Env, tournament_id: String) -> Option<TeamConfig> {
+        teams::get_team_config(&env, tournament_id)
+    }
+
+    /// Create a team in a team tournament, with the registered captain as first member
+    pub fn create_team(env: Env, tournament_id: String, team_id: String, captain: Address) -> Result<Team, Error> {
+        teams::create_team(&env, tournament_id, team_id, captain)
+    }
+
+    /// Join a team in a team tournament the player has registered for
+    pub fn join_team(env: Env, tournament_id: String, team_id: String, player_id: Address) -> Result<Team, Error> {
+        teams::join_team(&env, tournament_id, team_id, player_id)
+    }
+
+    /// Get a team of a tournament
+    pub fn get_team(env: Env, tournament_id: String, team_id: String) -> Result<Team, Error> {
+        teams::get_team(&env, tournament_id, team_id)
+    }
+
+    /// Get the id of the team a player is on in a tournament
+    pub fn get_player_team(env: Env, tournament_id: String, player_id: Address) -> Option<String> {
+        teams::get_player_team(&env, tournament_id, player_id)
+    }
+
+    /// Get a tournament's team leaderboard with pagination
+    pub fn get_team_leaderboard(
+        env: Env,
+        tournament_id: String,
+        page: u32,
+        page_size: u32,
+    ) -> Result<Vec<TeamEntry>, Error> {
+        teams::get_team_leaderboard(&env, tournament_id, page, page_size)
+    }
+
+    /// Cancel a tournament as its creator or the admin, refunding the prize pool to its funder
+    pub fn cancel_tournament(env: Env, caller: Address, tournament_id: String) -> Result<(), Error> {
+        tournaments::cancel_tournament(&env, caller, tournament_id)
+    }
+
+    /// Reclaim a player's entry fee from a cancelled tournament
+    pub fn claim_refund(env: Env, player_id: Address, tournament_id: String) -> Result<i128, Error> {
+        prizes::claim_refund(&env, player_id, tournament_id)
+    }
+
+    /// Get a tournament's audit log of organizer actions with pagination, oldest first
+    pub fn get_audit_log(
+        env: Env,
+        tournament_id: String,
+        page: u32,
+        page_size: u32,
+    ) -> Result<Vec<AuditEntry>, Error> {
+        audit::get_audit_log(&env, tournament_id, page, page_size)
+    }
+
+    /// Get the number of entries in a tournament's audit log
+    pub fn get_audit_log_count(env: Env, tournament_id: String) -> u32 {
+        audit::get_audit_log_count(&env, tournament_id)
     }
 
     /// Get tournament info
@@ -63,18 +421,61 @@ impl GameLeaderboardContract {
         tournaments::get_tournament_info(&env, tournament_id)
     }
 
+    /// Get the prize pool locked for a tournament
+    pub fn get_prize_pool(env: Env, tournament_id: String) -> Result<PrizePool, Error> {
+        prizes::get_prize_pool(&env, tournament_id)
+    }
+
+    /// Pay out the prize pool of an ended tournament to its top-ranked players
+    pub fn finalize_tournament(env: Env, tournament_id: String) -> Result<Vec<(Address, i128)>, Error> {
+        prizes::finalize_tournament(&env, tournament_id)
+    }
+
     /// Get tournament results
     pub fn get_tournament_results(env: Env, tournament_id: String) -> Result<Vec<LeaderboardEntry>, Error> {
         tournaments::get_tournament_results(&env, tournament_id)
     }
 
+    /// Freeze the top of an ended tournament's final standings and return the SHA-256 hash they are anchored by
+    pub fn snapshot_leaderboard(env: Env, tournament_id: String) -> Result<BytesN<32>, Error> {
+        snapshots::snapshot_leaderboard(&env, tournament_id)
+    }
+
+    /// Get the final standings snapshot of a tournament
+    pub fn get_leaderboard_snapshot(env: Env, tournament_id: String) -> Result<StandingsSnapshot, Error> {
+        snapshots::get_leaderboard_snapshot(&env, tournament_id)
+    }
+
+    /// Check standings against the final snapshot of a tournament
+    pub fn verify_snapshot(env: Env, tournament_id: String, data: Vec<LeaderboardEntry>) -> bool {
+        snapshots::verify_snapshot(&env, tournament_id, data)
+    }
+
     /// Get player's score history
     pub fn get_player_history(env: Env, player_id: Address) -> Vec<PlayerScore> {
         scores::get_player_history(&env, player_id)
     }
 
+    /// Get a player's statistics in a game
+    pub fn get_player_stats(env: Env, player_id: Address, game_id: String) -> PlayerHistory {
+        stats::get_player_stats(&env, player_id, game_id)
+    }
+
     /// Get list of all tournaments
     pub fn get_tournaments(env: Env) -> Vec<String> {
         tournaments::get_tournaments(&env)
     }
-}
\ No newline at end of file
+
+    /// Lock the current standings of an active tournament as its creator or the admin and return their hash
+    pub fn lock_predictions_snapshot(env: Env, caller: Address, tournament_id: String) -> Result<BytesN<32>, Error> {
+        predictions::lock_predictions_snapshot(&env, caller, tournament_id)
+    }
+
+    /// Get the locked standings snapshot of a tournament
+    pub fn get_predictions_snapshot(env: Env, tournament_id: String) -> Result<StandingsSnapshot, Error> {
+        predictions::get_predictions_snapshot(&env, tournament_id)
+    }
+}
+
+#[cfg(test)]
+mod test;
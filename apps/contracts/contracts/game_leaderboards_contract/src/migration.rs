@@ -0,0 +1,267 @@
+use soroban_sdk::{contracttype, Address, Env, IntoVal, String, Symbol, TryFromVal, Val, Vec};
+use crate::errors::Error;
+use crate::leaderboard::{self, Board, GLOBAL_LEADERBOARD_SIZE};
+use crate::registration;
+use crate::scores::MAX_PLAYER_HISTORY;
+use crate::tournaments;
+use crate::types::{PlayerScore, ScoringMode, TieBreak, Tournament};
+use crate::{TTL_EXTEND_TO, TTL_THRESHOLD};
+
+/// Storage layout version of contracts that keep tournaments in persistent storage
+pub const STORAGE_VERSION: u32 = 2;
+
+#[contracttype]
+#[derive(Clone)]
+enum DataKey {
+    StorageVersion,
+}
+
+/// Keys of the entries version 1 kept in instance storage
+///
+/// Encoded like the modules' own keys, so each entry keeps its key when it
+/// moves to persistent storage.
+#[contracttype]
+#[derive(Clone)]
+enum LegacyKey {
+    Tournament(String),
+    TournamentList,
+    GlobalLeaderboard(String),
+    PlayerScores(Address),
+}
+
+/// Tournament as version 1 stored it, with its whole leaderboard inline
+#[contracttype]
+#[derive(Clone)]
+struct TournamentV1 {
+    id: String,
+    game_id: String,
+    start_time: u64,
+    end_time: u64,
+    max_entries: u32,
+    entries: Vec<EntryV1>,
+}
+
+/// Leaderboard entry as version 1 stored it, best score first
+#[contracttype]
+#[derive(Clone)]
+struct EntryV1 {
+    player_id: Address,
+    score: u64,
+    rank: u32,
+}
+
+/// Score history entry as version 1 stored it, before replay proofs
+#[contracttype]
+#[derive(Clone)]
+struct PlayerScoreV1 {
+    score: u64,
+    timestamp: u64,
+    game_id: String,
+    tournament_id: String,
+}
+
+/// Move up to `limit` version 1 leaderboard entries to the current layout
+///
+/// Tournaments leave instance storage one at a time: first their game's
+/// global leaderboard, then their own entries, whose players are registered
+/// and whose score histories move along. A finished tournament is written
+/// back with the admin as its creator, highest-score ranking and no limits,
+/// and counts as one more entry. Only the admin may migrate. Call again
+/// until it returns 0, the number of tournaments still waiting. Contracts
+/// initialized on the current layout have nothing to migrate.
+pub fn migrate_v1_to_v2(env: &Env, limit: u32) -> Result<u32, Error> {
+    let admin = tournaments::get_admin(env).ok_or(Error::Unauthorized)?;
+    admin.require_auth();
+
+    if get_storage_version(env) >= STORAGE_VERSION {
+        return Err(Error::AlreadyMigrated);
+    }
+    if limit == 0 {
+        return Err(Error::InvalidParameters);
+    }
+
+    let mut pending: Vec<String> = env
+        .storage()
+        .instance()
+        .get(&LegacyKey::TournamentList)
+        .unwrap_or(Vec::new(env));
+    let mut budget = limit;
+    while let Some(tournament_id) = pending.first() {
+        let tournament_key = LegacyKey::Tournament(tournament_id.clone());
+        let Some(mut v1) = env.storage().instance().get::<_, TournamentV1>(&tournament_key) else {
+            pending.pop_front();
+            continue;
+        };
+
+        let global_key = LegacyKey::GlobalLeaderboard(v1.game_id.clone());
+        if let Some(mut global) = env.storage().instance().get::<_, Vec<EntryV1>>(&global_key) {
+            let board = Board::Global(v1.game_id.clone());
+            let moved = global.len().min(budget);
+            for entry in global.slice(0..moved).iter() {
+                leaderboard::record_score(
+                    env,
+                    &board,
+                    ScoringMode::Highest,
+                    TieBreak::EarliestFirst,
+                    &entry.player_id,
+                    entry.score,
+                    &None,
+                    GLOBAL_LEADERBOARD_SIZE,
+                );
+            }
+            budget -= moved;
+            global = global.slice(moved..);
+            if !global.is_empty() {
+                env.storage().instance().set(&global_key, &global);
+                break;
+            }
+            env.storage().instance().remove(&global_key);
+        }
+        if budget == 0 {
+            break;
+        }
+
+        let board = Board::Tournament(tournament_id.clone());
+        let moved = v1.entries.len().min(budget);
+        for entry in v1.entries.slice(0..moved).iter() {
+            leaderboard::record_score(
+                env,
+                &board,
+                ScoringMode::Highest,
+                TieBreak::EarliestFirst,
+                &entry.player_id,
+                entry.score,
+                &None,
+                v1.max_entries,
+            );
+            registration::record_registration(env, tournament_id.clone(), &entry.player_id);
+            move_history(env, &entry.player_id);
+        }
+        budget -= moved;
+        v1.entries = v1.entries.slice(moved..);
+        if !v1.entries.is_empty() {
+            env.storage().instance().set(&tournament_key, &v1);
+            break;
+        }
+
+        env.storage().instance().remove(&tournament_key);
+        put(env, &tournament_key, &upgrade_tournament(v1, admin.clone()));
+        // Tournaments created since the upgrade are already listed in persistent storage
+        let mut list: Vec<String> = env
+            .storage()
+            .persistent()
+            .get(&LegacyKey::TournamentList)
+            .unwrap_or(Vec::new(env));
+        list.push_back(tournament_id);
+        put(env, &LegacyKey::TournamentList, &list);
+        pending.pop_front();
+        budget = budget.saturating_sub(1);
+        if budget == 0 {
+            break;
+        }
+    }
+
+    if !pending.is_empty() {
+        env.storage().instance().set(&LegacyKey::TournamentList, &pending);
+        return Ok(pending.len());
+    }
+    env.storage().instance().remove(&LegacyKey::TournamentList);
+    mark_migrated(env);
+
+    // Emit event
+    env.events().publish(
+        (Symbol::new(env, "storage_migrated"),),
+        STORAGE_VERSION,
+    );
+
+    Ok(0)
+}
+
+/// Record that a freshly initialized contract starts on the current layout
+///
+/// Contracts still holding version 1 tournaments in instance storage stay
+/// on version 1 until they are migrated.
+pub fn init_storage_version(env: &Env) {
+    if !env.storage().instance().has(&LegacyKey::TournamentList) {
+        mark_migrated(env);
+    }
+}
+
+/// Record that the contract's storage is on the current layout
+pub fn mark_migrated(env: &Env) {
+    env.storage()
+        .instance()
+        .set(&DataKey::StorageVersion, &STORAGE_VERSION);
+}
+
+/// Get the storage layout version, 1 for contracts that were never migrated
+pub fn get_storage_version(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::StorageVersion)
+        .unwrap_or(1)
+}
+
+// Helper functions
+fn take<T: TryFromVal<Env, Val>>(env: &Env, key: &LegacyKey) -> Option<T> {
+    let value = env.storage().instance().get(key)?;
+    env.storage().instance().remove(key);
+    Some(value)
+}
+
+fn put<T: IntoVal<Env, Val>>(env: &Env, key: &LegacyKey, value: &T) {
+    env.storage().persistent().set(key, value);
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+fn upgrade_tournament(v1: TournamentV1, creator: Address) -> Tournament {
+    Tournament {
+        id: v1.id,
+        game_id: v1.game_id,
+        creator,
+        start_time: v1.start_time,
+        end_time: v1.end_time,
+        max_entries: v1.max_entries,
+        scoring_mode: ScoringMode::Highest,
+        tie_break: TieBreak::EarliestFirst,
+        server_key: None,
+        cancelled: false,
+        max_submissions_per_player: 0,
+        min_interval_secs: 0,
+        max_valid_score: 0,
+    }
+}
+
+/// Move a player's score history, if still in instance storage, giving each score an empty proof
+///
+/// Scores submitted since the upgrade are already in persistent storage
+/// under the same key, so the older version 1 scores go in front of them
+/// and only the last `MAX_PLAYER_HISTORY` are kept.
+fn move_history(env: &Env, player_id: &Address) {
+    let key = LegacyKey::PlayerScores(player_id.clone());
+    let Some(history) = take::<Vec<PlayerScoreV1>>(env, &key) else {
+        return;
+    };
+    let mut merged = Vec::new(env);
+    for score in history.iter() {
+        merged.push_back(PlayerScore {
+            score: score.score,
+            timestamp: score.timestamp,
+            game_id: score.game_id,
+            tournament_id: score.tournament_id,
+            proof: None,
+        });
+    }
+    let current: Vec<PlayerScore> = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Vec::new(env));
+    merged.append(&current);
+    while merged.len() > MAX_PLAYER_HISTORY {
+        merged.pop_front();
+    }
+    put(env, &key, &merged);
+}
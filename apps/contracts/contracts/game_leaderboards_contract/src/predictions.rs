@@ -0,0 +1,65 @@
+use soroban_sdk::{contracttype, Address, BytesN, Env, String, Symbol};
+use crate::errors::Error;
+use crate::snapshots;
+use crate::tournaments;
+use crate::types::StandingsSnapshot;
+use crate::{TTL_EXTEND_TO, TTL_THRESHOLD};
+
+#[contracttype]
+#[derive(Clone)]
+enum DataKey {
+    PredictionSnapshot(String),
+}
+
+/// Lock the current standings of an active tournament for prediction markets.
+///
+/// Only the tournament's creator or the contract admin may lock, and a
+/// tournament can only be locked once, so the stored snapshot and its hash
+/// stay fixed for any external contract that anchors bets to them. Like the
+/// final snapshot, only the top `MAX_SNAPSHOT_ENTRIES` entries are stored.
+pub fn lock_predictions_snapshot(env: &Env, caller: Address, tournament_id: String) -> Result<BytesN<32>, Error> {
+    caller.require_auth();
+
+    let tournament = tournaments::get_tournament_info(env, tournament_id.clone())?;
+    if caller != tournament.creator && tournaments::get_admin(env).as_ref() != Some(&caller) {
+        return Err(Error::Unauthorized);
+    }
+
+    if tournament.cancelled {
+        return Err(Error::TournamentCancelled);
+    }
+
+    let current_time = env.ledger().timestamp();
+    if current_time < tournament.start_time || current_time > tournament.end_time {
+        return Err(Error::TournamentNotActive);
+    }
+
+    let key = DataKey::PredictionSnapshot(tournament_id.clone());
+    if env.storage().persistent().has(&key) {
+        return Err(Error::SnapshotLocked);
+    }
+
+    let snapshot = snapshots::capture_standings(env, &tournament, current_time);
+    env.storage().persistent().set(&key, &snapshot);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD, TTL_EXTEND_TO);
+
+    // Emit event
+    env.events().publish(
+        (Symbol::new(env, "standings_snapshot"), tournament_id),
+        (snapshot.hash.clone(), current_time, snapshot.total_entries),
+    );
+
+    Ok(snapshot.hash)
+}
+
+/// Get the locked standings snapshot of a tournament
+pub fn get_predictions_snapshot(env: &Env, tournament_id: String) -> Result<StandingsSnapshot, Error> {
+    let key = DataKey::PredictionSnapshot(tournament_id);
+    let snapshot = env.storage().persistent().get(&key).ok_or(Error::SnapshotNotFound)?;
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD, TTL_EXTEND_TO);
+    Ok(snapshot)
+}
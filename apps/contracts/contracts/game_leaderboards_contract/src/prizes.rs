@@ -0,0 +1,269 @@
+use soroban_sdk::{contracttype, token, Address, Env, String, Symbol, Vec};
+use crate::audit;
+use crate::errors::Error;
+use crate::registration;
+use crate::snapshots;
+use crate::tournaments;
+use crate::types::{AuditAction, PrizePool};
+use crate::{TTL_EXTEND_TO, TTL_THRESHOLD};
+
+/// Basis points making up a whole prize pool
+pub const TOTAL_SPLIT_BPS: u32 = 10_000;
+
+#[contracttype]
+#[derive(Clone)]
+enum DataKey {
+    PrizePool(String),
+    Finalized(String),
+    RefundClaimed(String, Address),
+}
+
+/// Check a prize pool before its tournament is created
+///
+/// The split needs one non-zero share per paid rank, no more ranks than the
+/// tournament has entries or its final snapshot keeps, and shares adding up
+/// to the whole pool. A pool may start empty if entry fees will fill it.
+pub fn validate_prize_pool(pool: &PrizePool, max_entries: u32) -> Result<(), Error> {
+    if pool.amount < 0 || pool.entry_fee < 0 || (pool.amount == 0 && pool.entry_fee == 0) {
+        return Err(Error::InvalidParameters);
+    }
+    let paid_ranks = pool.split_bps.len();
+    if paid_ranks == 0 || paid_ranks > max_entries || paid_ranks > snapshots::MAX_SNAPSHOT_ENTRIES {
+        return Err(Error::InvalidParameters);
+    }
+
+    let mut total: u32 = 0;
+    for share in pool.split_bps.iter() {
+        if share == 0 {
+            return Err(Error::InvalidParameters);
+        }
+        total = total.saturating_add(share);
+    }
+    if total != TOTAL_SPLIT_BPS {
+        return Err(Error::InvalidParameters);
+    }
+
+    Ok(())
+}
+
+/// Move the prize pool from its funder into the contract
+pub fn lock_prize_pool(env: &Env, tournament_id: String, pool: &PrizePool) {
+    pool.funder.require_auth();
+    if pool.amount > 0 {
+        token::Client::new(env, &pool.token).transfer(
+            &pool.funder,
+            env.current_contract_address(),
+            &pool.amount,
+        );
+    }
+    save_prize_pool(env, tournament_id.clone(), pool);
+
+    // Emit event
+    env.events().publish(
+        (Symbol::new(env, "prize_pool_locked"), tournament_id),
+        (pool.token.clone(), pool.amount),
+    );
+}
+
+/// Get the prize pool escrowed for a tournament
+pub fn get_prize_pool(env: &Env, tournament_id: String) -> Result<PrizePool, Error> {
+    let key = DataKey::PrizePool(tournament_id);
+    let pool = env.storage().persistent().get(&key).ok_or(Error::NoPrizePool)?;
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD, TTL_EXTEND_TO);
+    Ok(pool)
+}
+
+/// Collect a player's entry fee into the prize pool, if the tournament charges one
+///
+/// Returns the fee paid, 0 for tournaments without a pool or entry fee.
+pub fn collect_entry_fee(env: &Env, tournament_id: String, player_id: &Address) -> i128 {
+    let Ok(mut pool) = get_prize_pool(env, tournament_id.clone()) else {
+        return 0;
+    };
+    if pool.entry_fee == 0 {
+        return 0;
+    }
+
+    token::Client::new(env, &pool.token).transfer(
+        player_id,
+        env.current_contract_address(),
+        &pool.entry_fee,
+    );
+    pool.amount += pool.entry_fee;
+    save_prize_pool(env, tournament_id, &pool);
+
+    pool.entry_fee
+}
+
+/// Pay out the prize pool of an ended tournament to its top-ranked players
+///
+/// Ranks are read from the tournament's final snapshot, which is taken now
+/// if nobody took it yet. Each paid rank receives its share of the pool,
+/// with the rounding dust going to first place. Players sharing a rank under
+/// `TieBreak::Shared` split the shares of the places they cover evenly, the
+/// dust of that split going to the first of them listed. Shares of ranks
+/// nobody reached are refunded to the funder. Returns the payout of each
+/// rewarded player, first place first.
+pub fn finalize_tournament(env: &Env, tournament_id: String) -> Result<Vec<(Address, i128)>, Error> {
+    let tournament = tournaments::get_tournament_info(env, tournament_id.clone())?;
+    if tournament.cancelled {
+        return Err(Error::TournamentCancelled);
+    }
+    if env.ledger().timestamp() <= tournament.end_time {
+        return Err(Error::TournamentNotActive);
+    }
+
+    let finalized_key = DataKey::Finalized(tournament_id.clone());
+    if env.storage().persistent().has(&finalized_key) {
+        return Err(Error::AlreadyFinalized);
+    }
+    let pool = get_prize_pool(env, tournament_id.clone())?;
+    env.storage().persistent().set(&finalized_key, &true);
+    env.storage()
+        .persistent()
+        .extend_ttl(&finalized_key, TTL_THRESHOLD, TTL_EXTEND_TO);
+
+    let mut shares: Vec<i128> = Vec::new(env);
+    let mut allotted: i128 = 0;
+    for bps in pool.split_bps.iter() {
+        let share = pool.amount * bps as i128 / TOTAL_SPLIT_BPS as i128;
+        allotted += share;
+        shares.push_back(share);
+    }
+    if let Some(first) = shares.get(0) {
+        shares.set(0, first + (pool.amount - allotted));
+    }
+
+    let winners = snapshots::final_standings(env, tournament_id.clone())?.entries;
+    let client = token::Client::new(env, &pool.token);
+    let contract = env.current_contract_address();
+    let mut payouts = Vec::new(env);
+    let mut paid: i128 = 0;
+    let mut start: u32 = 0;
+    while start < shares.len() && start < winners.len() {
+        let rank = winners.get_unchecked(start).rank;
+        let mut end = start + 1;
+        while end < winners.len() && winners.get_unchecked(end).rank == rank {
+            end += 1;
+        }
+
+        let mut tied_total: i128 = 0;
+        for place in start..end.min(shares.len()) {
+            tied_total += shares.get_unchecked(place);
+        }
+        let tied = (end - start) as i128;
+        let each = tied_total / tied;
+        for place in start..end {
+            let entry = winners.get_unchecked(place);
+            let share = if place == start { tied_total - each * (tied - 1) } else { each };
+            if share == 0 {
+                continue;
+            }
+            client.transfer(&contract, &entry.player_id, &share);
+            env.events().publish(
+                (Symbol::new(env, "prize_paid"), tournament_id.clone()),
+                (entry.player_id.clone(), entry.rank, share),
+            );
+            payouts.push_back((entry.player_id, share));
+            paid += share;
+        }
+        start = end;
+    }
+    let refund = pool.amount - paid;
+    if refund > 0 {
+        client.transfer(&contract, &pool.funder, &refund);
+        env.events().publish(
+            (Symbol::new(env, "prize_refunded"), tournament_id.clone()),
+            (pool.funder.clone(), refund),
+        );
+    }
+
+    audit::record(env, tournament_id.clone(), None, AuditAction::Finalized(pool.amount - refund));
+
+    // Emit event
+    env.events().publish(
+        (Symbol::new(env, "tournament_finalized"), tournament_id),
+        (pool.amount - refund, payouts.len()),
+    );
+
+    Ok(payouts)
+}
+
+/// Return the escrow of a cancelled tournament
+///
+/// The funder gets the pool back minus the entry fees, which stay escrowed
+/// for each registered player to reclaim with `claim_refund`. Does nothing
+/// for tournaments without a pool.
+pub fn refund_prize_pool(env: &Env, tournament_id: String) {
+    let Ok(mut pool) = get_prize_pool(env, tournament_id.clone()) else {
+        return;
+    };
+
+    let fees = pool.entry_fee * registration::get_registration_count(env, tournament_id.clone()) as i128;
+    let remaining = pool.amount - fees;
+    if remaining > 0 {
+        token::Client::new(env, &pool.token).transfer(
+            &env.current_contract_address(),
+            &pool.funder,
+            &remaining,
+        );
+        env.events().publish(
+            (Symbol::new(env, "prize_refunded"), tournament_id.clone()),
+            (pool.funder.clone(), remaining),
+        );
+    }
+
+    pool.amount = fees;
+    save_prize_pool(env, tournament_id, &pool);
+}
+
+/// Pay a registered player of a cancelled tournament their entry fee back
+///
+/// Each player claims once. Returns the amount refunded.
+pub fn claim_refund(env: &Env, player_id: Address, tournament_id: String) -> Result<i128, Error> {
+    player_id.require_auth();
+
+    let tournament = tournaments::get_tournament_info(env, tournament_id.clone())?;
+    if !tournament.cancelled {
+        return Err(Error::NoRefund);
+    }
+    let mut pool = get_prize_pool(env, tournament_id.clone())?;
+    if pool.entry_fee == 0 || !registration::is_registered(env, tournament_id.clone(), &player_id) {
+        return Err(Error::NoRefund);
+    }
+    let claimed_key = DataKey::RefundClaimed(tournament_id.clone(), player_id.clone());
+    if env.storage().persistent().has(&claimed_key) {
+        return Err(Error::NoRefund);
+    }
+
+    env.storage().persistent().set(&claimed_key, &true);
+    env.storage()
+        .persistent()
+        .extend_ttl(&claimed_key, TTL_THRESHOLD, TTL_EXTEND_TO);
+    pool.amount -= pool.entry_fee;
+    save_prize_pool(env, tournament_id.clone(), &pool);
+    token::Client::new(env, &pool.token).transfer(
+        &env.current_contract_address(),
+        &player_id,
+        &pool.entry_fee,
+    );
+
+    // Emit event
+    env.events().publish(
+        (Symbol::new(env, "entry_fee_refunded"), tournament_id),
+        (player_id, pool.entry_fee),
+    );
+
+    Ok(pool.entry_fee)
+}
+
+// Helper functions
+fn save_prize_pool(env: &Env, tournament_id: String, pool: &PrizePool) {
+    let key = DataKey::PrizePool(tournament_id);
+    env.storage().persistent().set(&key, pool);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
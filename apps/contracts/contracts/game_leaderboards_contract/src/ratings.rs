@@ -0,0 +1,149 @@
+use soroban_sdk::{contracttype, Address, Env, String, Symbol, Vec};
+use crate::errors::Error;
+use crate::games;
+use crate::leaderboard::{self, Board, GLOBAL_LEADERBOARD_SIZE};
+use crate::tournaments;
+use crate::types::{LeaderboardEntry, ScoringMode, TieBreak};
+use crate::{TTL_EXTEND_TO, TTL_THRESHOLD};
+
+/// Rating of a player with no reported matches in a game
+pub const DEFAULT_RATING: u32 = 1200;
+/// Most rating points one match moves when a game sets no K-factor
+pub const DEFAULT_K_FACTOR: u32 = 32;
+/// Highest K-factor a game may set
+pub const MAX_K_FACTOR: u32 = 400;
+
+/// Rating gap between two steps of `EXPECTED_BPS`
+const EXPECTED_STEP: u32 = 50;
+/// Expected score in basis points of a player rated `n * EXPECTED_STEP` above their opponent
+const EXPECTED_BPS: [u32; 17] = [
+    5000, 5715, 6401, 7034, 7597, 8083, 8490, 8823, 9091, 9302, 9468, 9595, 9693, 9768, 9825, 9868, 9901,
+];
+
+#[contracttype]
+#[derive(Clone)]
+enum DataKey {
+    Rating(String, Address),
+    KFactor(String),
+}
+
+/// Set the most rating points one match of a game moves, admin only
+///
+/// The K-factor must be between 1 and `MAX_K_FACTOR`.
+pub fn set_k_factor(env: &Env, game_id: String, k_factor: u32) -> Result<(), Error> {
+    let admin = tournaments::get_admin(env).ok_or(Error::Unauthorized)?;
+    admin.require_auth();
+    if k_factor == 0 || k_factor > MAX_K_FACTOR {
+        return Err(Error::InvalidParameters);
+    }
+
+    let key = DataKey::KFactor(game_id);
+    env.storage().persistent().set(&key, &k_factor);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD, TTL_EXTEND_TO);
+    Ok(())
+}
+
+/// Get the most rating points one match of a game moves
+pub fn get_k_factor(env: &Env, game_id: String) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::KFactor(game_id))
+        .unwrap_or(DEFAULT_K_FACTOR)
+}
+
+/// Get a player's Elo rating in a game, `DEFAULT_RATING` until they played a rated match
+pub fn get_rating(env: &Env, game_id: String, player_id: Address) -> u32 {
+    let key = DataKey::Rating(game_id, player_id);
+    let Some(rating) = env.storage().persistent().get(&key) else {
+        return DEFAULT_RATING;
+    };
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD, TTL_EXTEND_TO);
+    rating
+}
+
+/// Apply the result of a match between two players to their Elo ratings
+///
+/// The reporter must be an approved creator of the game or its registered
+/// game contract. The winner gains `k * (1 - expected)` points, rounded,
+/// and the loser drops by the same amount, never below zero. Returns the
+/// new ratings of the winner and the loser.
+pub fn report_head_to_head(
+    env: &Env,
+    reporter: Address,
+    game_id: String,
+    winner: Address,
+    loser: Address,
+) -> Result<(u32, u32), Error> {
+    reporter.require_auth();
+    let is_game_contract = games::get_registered_game(env, reporter.clone()).as_ref() == Some(&game_id);
+    if !is_game_contract && !tournaments::is_approved_creator(env, game_id.clone(), reporter) {
+        return Err(Error::Unauthorized);
+    }
+    if winner == loser {
+        return Err(Error::InvalidParameters);
+    }
+
+    let winner_rating = get_rating(env, game_id.clone(), winner.clone());
+    let loser_rating = get_rating(env, game_id.clone(), loser.clone());
+    let delta = rating_change(get_k_factor(env, game_id.clone()), winner_rating, loser_rating);
+    let winner_rating = winner_rating.saturating_add(delta);
+    let loser_rating = loser_rating.saturating_sub(delta);
+    save_rating(env, &game_id, &winner, winner_rating);
+    save_rating(env, &game_id, &loser, loser_rating);
+
+    // Emit event
+    env.events().publish(
+        (Symbol::new(env, "ratings_updated"), game_id),
+        (winner, winner_rating, loser, loser_rating),
+    );
+
+    Ok((winner_rating, loser_rating))
+}
+
+/// Get a game's players sorted by rating, highest first
+pub fn get_rating_leaderboard(env: &Env, game_id: String, page: u32, page_size: u32) -> Vec<LeaderboardEntry> {
+    leaderboard::get_entries(
+        env,
+        &Board::Rating(game_id),
+        page.saturating_mul(page_size),
+        page_size,
+    )
+}
+
+/// Rating points a winner rated `winner` takes from a loser rated `loser`
+///
+/// The expected score is read from `EXPECTED_BPS` and interpolated between
+/// its steps, so the update only needs integer math.
+pub fn rating_change(k_factor: u32, winner: u32, loser: u32) -> u32 {
+    let gap = winner.abs_diff(loser);
+    let step = (gap / EXPECTED_STEP) as usize;
+    let favourite_bps = match (EXPECTED_BPS.get(step), EXPECTED_BPS.get(step + 1)) {
+        (Some(low), Some(high)) => low + (high - low) * (gap % EXPECTED_STEP) / EXPECTED_STEP,
+        _ => EXPECTED_BPS[EXPECTED_BPS.len() - 1],
+    };
+    let expected_bps = if winner >= loser { favourite_bps } else { 10_000 - favourite_bps };
+    (k_factor * (10_000 - expected_bps) + 5_000) / 10_000
+}
+
+// Helper functions
+fn save_rating(env: &Env, game_id: &String, player_id: &Address, rating: u32) {
+    let key = DataKey::Rating(game_id.clone(), player_id.clone());
+    env.storage().persistent().set(&key, &rating);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD, TTL_EXTEND_TO);
+    leaderboard::record_score(
+        env,
+        &Board::Rating(game_id.clone()),
+        ScoringMode::Latest,
+        TieBreak::EarliestFirst,
+        player_id,
+        rating as u64,
+        &None,
+        GLOBAL_LEADERBOARD_SIZE,
+    );
+}
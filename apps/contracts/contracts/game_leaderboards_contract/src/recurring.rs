@@ -0,0 +1,174 @@
+use soroban_sdk::{contracttype, Env, IntoVal, String, Symbol, TryFromVal, Val, Vec};
+use crate::errors::Error;
+use crate::tournaments;
+use crate::types::{RecurringTournament, Tournament, TournamentTemplate};
+use crate::{TTL_EXTEND_TO, TTL_THRESHOLD};
+
+/// Longest template id, leaving room for the instance suffix in tournament ids
+pub const MAX_TEMPLATE_ID_LEN: u32 = 32;
+
+#[contracttype]
+#[derive(Clone)]
+enum DataKey {
+    Recurring(String),
+    /// Numbers of the instances created so far, oldest first
+    Instances(String),
+}
+
+/// Create a tournament that restarts every `period_secs`, with the first period starting now
+///
+/// Instance n is an ordinary tournament with the id `<template id>#<n>`,
+/// created the first time it is looked up while it runs. The template's
+/// creator must be approved for its game.
+pub fn create_recurring_tournament(
+    env: &Env,
+    template: TournamentTemplate,
+    period_secs: u64,
+) -> Result<RecurringTournament, Error> {
+    template.creator.require_auth();
+    if !tournaments::is_approved_creator(env, template.game_id.clone(), template.creator.clone()) {
+        return Err(Error::Unauthorized);
+    }
+
+    let key = DataKey::Recurring(template.id.clone());
+    if env.storage().persistent().has(&key) {
+        return Err(Error::TournamentExists);
+    }
+    if template.id.is_empty() || template.id.len() > MAX_TEMPLATE_ID_LEN {
+        return Err(Error::InvalidParameters);
+    }
+    if period_secs < 2 {
+        return Err(Error::InvalidParameters);
+    }
+    if template.max_entries == 0 || template.max_entries > 10000 {
+        return Err(Error::InvalidParameters);
+    }
+
+    let recurring = RecurringTournament {
+        template,
+        period_secs,
+        start_time: env.ledger().timestamp(),
+    };
+    persist(env, &key, &recurring);
+
+    // Emit event
+    env.events().publish(
+        (Symbol::new(env, "recurring_created"), recurring.template.id.clone()),
+        (recurring.template.game_id.clone(), period_secs),
+    );
+
+    Ok(recurring)
+}
+
+/// Get a recurring tournament's template and schedule
+pub fn get_recurring_tournament(env: &Env, template_id: String) -> Result<RecurringTournament, Error> {
+    load(env, &DataKey::Recurring(template_id)).ok_or(Error::RecurringNotFound)
+}
+
+/// Get the instance running now, creating it on first look-up
+///
+/// No new instances are created once the template's creator loses their
+/// approval for its game.
+pub fn get_current_instance(env: &Env, template_id: String) -> Result<Tournament, Error> {
+    let recurring = get_recurring_tournament(env, template_id.clone())?;
+    let number = (env.ledger().timestamp() - recurring.start_time) / recurring.period_secs;
+    let tournament_id = instance_id(env, &template_id, number);
+    if let Ok(tournament) = tournaments::get_tournament_info(env, tournament_id.clone()) {
+        return Ok(tournament);
+    }
+
+    let template = recurring.template;
+    if !tournaments::is_approved_creator(env, template.game_id.clone(), template.creator.clone()) {
+        return Err(Error::Unauthorized);
+    }
+    let start_time = recurring.start_time + number * recurring.period_secs;
+    let tournament = Tournament {
+        id: tournament_id,
+        game_id: template.game_id,
+        creator: template.creator,
+        start_time,
+        end_time: start_time + recurring.period_secs - 1,
+        max_entries: template.max_entries,
+        scoring_mode: template.scoring_mode,
+        tie_break: template.tie_break,
+        server_key: template.server_key,
+        cancelled: false,
+        max_submissions_per_player: template.max_submissions_per_player,
+        min_interval_secs: template.min_interval_secs,
+        max_valid_score: template.max_valid_score,
+    };
+    tournaments::add_tournament(env, &tournament);
+
+    let instances_key = DataKey::Instances(template_id);
+    let mut instances: Vec<u64> = load(env, &instances_key).unwrap_or(Vec::new(env));
+    instances.push_back(number);
+    persist(env, &instances_key, &instances);
+
+    Ok(tournament)
+}
+
+/// Get up to `count` instances before the current period, newest first
+///
+/// Periods nobody looked up never got an instance and are skipped.
+pub fn get_previous_instances(env: &Env, template_id: String, count: u32) -> Result<Vec<Tournament>, Error> {
+    let recurring = get_recurring_tournament(env, template_id.clone())?;
+    let current = (env.ledger().timestamp() - recurring.start_time) / recurring.period_secs;
+    let instances: Vec<u64> = load(env, &DataKey::Instances(template_id.clone())).unwrap_or(Vec::new(env));
+
+    let mut result = Vec::new(env);
+    for number in instances.iter().rev() {
+        if result.len() >= count {
+            break;
+        }
+        if number >= current {
+            continue;
+        }
+        result.push_back(tournaments::get_tournament_info(env, instance_id(env, &template_id, number))?);
+    }
+
+    Ok(result)
+}
+
+// Helper functions
+
+/// Tournament id of instance `number`, `<template id>#<number>`
+fn instance_id(env: &Env, template_id: &String, number: u64) -> String {
+    let mut buf = [0u8; MAX_TEMPLATE_ID_LEN as usize + 21];
+    let mut len = template_id.len() as usize;
+    template_id.copy_into_slice(&mut buf[..len]);
+    buf[len] = b'#';
+    len += 1;
+
+    let mut digits = [0u8; 20];
+    let mut digit_count = 0;
+    let mut rest = number;
+    loop {
+        digits[digit_count] = b'0' + (rest % 10) as u8;
+        digit_count += 1;
+        rest /= 10;
+        if rest == 0 {
+            break;
+        }
+    }
+    for digit in digits[..digit_count].iter().rev() {
+        buf[len] = *digit;
+        len += 1;
+    }
+
+    String::from_bytes(env, &buf[..len])
+}
+
+fn load<V: TryFromVal<Env, Val>>(env: &Env, key: &DataKey) -> Option<V> {
+    let value = env.storage().persistent().get(key)?;
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+    Some(value)
+}
+
+fn persist<V: IntoVal<Env, Val>>(env: &Env, key: &DataKey, value: &V) {
+    env.storage().persistent().set(key, value);
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
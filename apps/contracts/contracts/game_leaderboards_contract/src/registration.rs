@@ -0,0 +1,128 @@
+use soroban_sdk::{contracttype, Address, Env, IntoVal, String, Symbol, Val, Vec};
+use crate::errors::Error;
+use crate::prizes;
+use crate::tournaments;
+use crate::{TTL_EXTEND_TO, TTL_THRESHOLD};
+
+#[contracttype]
+#[derive(Clone)]
+enum DataKey {
+    /// Position of a registered player in the tournament's registration order
+    Registered(String, Address),
+    /// Player registered at a position of the tournament's registration order
+    Registrant(String, u32),
+    RegistrationCount(String),
+}
+
+/// Register a player for a tournament that has not ended yet
+///
+/// The tournament's `max_entries` caps registrations. Tournaments whose
+/// prize pool charges an entry fee take it from the player into the pool.
+pub fn register_for_tournament(env: &Env, player_id: Address, tournament_id: String) -> Result<(), Error> {
+    player_id.require_auth();
+
+    let tournament = tournaments::get_tournament_info(env, tournament_id.clone())?;
+    if tournament.cancelled {
+        return Err(Error::TournamentCancelled);
+    }
+    if env.ledger().timestamp() > tournament.end_time {
+        return Err(Error::TournamentNotActive);
+    }
+
+    if is_registered(env, tournament_id.clone(), &player_id) {
+        return Err(Error::AlreadyRegistered);
+    }
+    if get_registration_count(env, tournament_id.clone()) >= tournament.max_entries {
+        return Err(Error::TournamentFull);
+    }
+
+    let fee = prizes::collect_entry_fee(env, tournament_id.clone(), &player_id);
+    record_registration(env, tournament_id.clone(), &player_id);
+
+    // Emit event
+    env.events().publish(
+        (Symbol::new(env, "player_registered"), tournament_id),
+        (player_id, fee),
+    );
+
+    Ok(())
+}
+
+/// Add a player to a tournament's registrations, unless already registered
+pub fn record_registration(env: &Env, tournament_id: String, player_id: &Address) {
+    if is_registered(env, tournament_id.clone(), player_id) {
+        return;
+    }
+
+    let position = get_registration_count(env, tournament_id.clone());
+    persist(env, &DataKey::Registered(tournament_id.clone(), player_id.clone()), &position);
+    persist(env, &DataKey::Registrant(tournament_id.clone(), position), player_id);
+    persist(env, &DataKey::RegistrationCount(tournament_id), &(position + 1));
+}
+
+/// Check whether a player has registered for a tournament
+pub fn is_registered(env: &Env, tournament_id: String, player_id: &Address) -> bool {
+    let key = DataKey::Registered(tournament_id, player_id.clone());
+    if !env.storage().persistent().has(&key) {
+        return false;
+    }
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD, TTL_EXTEND_TO);
+    true
+}
+
+/// Get the players registered for a tournament with pagination, in registration order
+pub fn get_registered_players(
+    env: &Env,
+    tournament_id: String,
+    page: u32,
+    page_size: u32,
+) -> Result<Vec<Address>, Error> {
+    tournaments::get_tournament_info(env, tournament_id.clone())?;
+    Ok(get_registrants(
+        env,
+        tournament_id,
+        page.saturating_mul(page_size),
+        page_size,
+    ))
+}
+
+/// Get the number of players registered for a tournament
+pub fn get_registration_count(env: &Env, tournament_id: String) -> u32 {
+    let key = DataKey::RegistrationCount(tournament_id);
+    let Some(count) = env.storage().persistent().get(&key) else {
+        return 0;
+    };
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD, TTL_EXTEND_TO);
+    count
+}
+
+/// Get `count` players registered for a tournament starting at position `start`, in registration order
+pub fn get_registrants(env: &Env, tournament_id: String, start: u32, count: u32) -> Vec<Address> {
+    let end = start
+        .saturating_add(count)
+        .min(get_registration_count(env, tournament_id.clone()));
+
+    let mut result = Vec::new(env);
+    for position in start..end {
+        let key = DataKey::Registrant(tournament_id.clone(), position);
+        if let Some(player_id) = env.storage().persistent().get(&key) {
+            env.storage()
+                .persistent()
+                .extend_ttl(&key, TTL_THRESHOLD, TTL_EXTEND_TO);
+            result.push_back(player_id);
+        }
+    }
+    result
+}
+
+// Helper functions
+fn persist<V: IntoVal<Env, Val>>(env: &Env, key: &DataKey, value: &V) {
+    env.storage().persistent().set(key, value);
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
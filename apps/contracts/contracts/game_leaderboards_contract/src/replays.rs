@@ -0,0 +1,83 @@
+use soroban_sdk::{contracttype, Address, BytesN, Env, String, Symbol, Vec};
+use crate::errors::Error;
+use crate::leaderboard;
+use crate::tournaments;
+use crate::types::TopEntry;
+
+/// Ranks that may attach a replay and appear in the top entries
+pub const REPLAY_TOP_N: u32 = 10;
+
+#[contracttype]
+#[derive(Clone)]
+enum DataKey {
+    Replay(String, Address),
+}
+
+/// Replay pointer and the score it was recorded for
+#[contracttype]
+#[derive(Clone)]
+struct Replay {
+    score: u64,
+    hash: BytesN<32>,
+}
+
+/// Attach the content hash of a replay to a player's top-ranked entry
+///
+/// The player must currently rank within the top `REPLAY_TOP_N`. The
+/// replay is tied to the entry's score, so it stops showing once the
+/// player's score changes.
+pub fn attach_replay(env: &Env, tournament_id: String, player_id: Address, hash: BytesN<32>) -> Result<(), Error> {
+    player_id.require_auth();
+
+    let tournament = tournaments::get_tournament_info(env, tournament_id.clone())?;
+    let board = leaderboard::Board::Tournament(tournament_id.clone());
+    let (rank, score) = leaderboard::get_rank(
+        env,
+        &board,
+        tournament.scoring_mode,
+        tournament.tie_break,
+        &player_id,
+    )
+    .ok_or(Error::ReplayNotEligible)?;
+    if rank > REPLAY_TOP_N {
+        return Err(Error::ReplayNotEligible);
+    }
+
+    env.storage().persistent().set(
+        &DataKey::Replay(tournament_id.clone(), player_id.clone()),
+        &Replay {
+            score,
+            hash: hash.clone(),
+        },
+    );
+
+    // Emit event
+    env.events().publish(
+        (Symbol::new(env, "replay_attached"), tournament_id),
+        (player_id, rank, hash),
+    );
+
+    Ok(())
+}
+
+/// Get up to `count` top entries of a tournament with their replays
+pub fn get_top_entries(env: &Env, tournament_id: String, count: u32) -> Result<Vec<TopEntry>, Error> {
+    let tournament = tournaments::get_tournament_info(env, tournament_id.clone())?;
+    let entries = leaderboard::get_tournament_entries(env, &tournament, 0, count.min(REPLAY_TOP_N));
+
+    let mut result = Vec::new(env);
+    for entry in entries.iter() {
+        let replay: Option<Replay> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Replay(tournament_id.clone(), entry.player_id.clone()));
+        result.push_back(TopEntry {
+            replay: replay
+                .filter(|replay| replay.score == entry.score)
+                .map(|replay| replay.hash),
+            entry,
+        });
+    }
+
+    Ok(result)
+}
@@ -1,25 +1,48 @@
-use soroban_sdk::{contracttype, Address, Env, String, Symbol, Vec};
+use soroban_sdk::{contracttype, xdr::ToXdr, Address, BytesN, Env, String, Symbol, Vec};
 use crate::errors::Error;
-use crate::types::{LeaderboardEntry, PlayerScore, Tournament};
+use crate::games;
+use crate::types::{PlayerScore, ScoringMode, Signature, Tournament};
 use crate::leaderboard;
+use crate::registration;
+use crate::stats;
+use crate::teams;
+use crate::tournaments;
+use crate::{TTL_EXTEND_TO, TTL_THRESHOLD};
+
+/// Scores kept in each player's history, most recent last
+pub const MAX_PLAYER_HISTORY: u32 = 100;
 
 #[contracttype]
 #[derive(Clone)]
 enum DataKey {
-    Tournament(String),
     PlayerScores(Address),
+    ScoreNonce(String, Address),
+    /// Number of scores a player submitted to a tournament and when the last one came in
+    Submissions(String, Address),
 }
 
-/// Submit a score to a tournament
+/// Submit a score to a tournament the player has registered for
+///
+/// The tournament's scoring mode decides how the score combines with the
+/// player's entry. Global and season leaderboards rank best single scores,
+/// so only `ScoringMode::Highest` tournaments update them. Scores from other
+/// modes still count towards the player's history and statistics.
 pub fn submit_score(
     env: &Env,
     tournament_id: String,
     player_id: Address,
     score: u64,
+    proof: Option<BytesN<32>>,
+    nonce: u64,
+    signature: Option<Signature>,
 ) -> Result<u32, Error> {
     player_id.require_auth();
     
-    let mut tournament = get_tournament(env, tournament_id.clone())?;
+    let tournament = tournaments::get_tournament_info(env, tournament_id.clone())?;
+    
+    if tournament.cancelled {
+        return Err(Error::TournamentCancelled);
+    }
     
     // Check tournament timing
     let current_time = env.ledger().timestamp();
@@ -27,45 +50,43 @@ pub fn submit_score(
         return Err(Error::TournamentNotActive);
     }
     
-    // Find existing entry or create new one
-    let mut found_index: Option<u32> = None;
-    for i in 0..tournament.entries.len() {
-        if let Some(entry) = tournament.entries.get(i) {
-            if entry.player_id == player_id {
-                found_index = Some(i);
-                break;
-            }
-        }
+    // Registration enforces max_entries, so every registered player has room
+    if !registration::is_registered(env, tournament_id.clone(), &player_id) {
+        return Err(Error::NotRegistered);
+    }
+    if teams::get_team_config(env, tournament_id.clone()).is_some()
+        && teams::get_player_team(env, tournament_id.clone(), player_id.clone()).is_none()
+    {
+        return Err(Error::NotInTeam);
     }
     
-    let rank = if let Some(index) = found_index {
-        // Update existing entry if new score is better
-        let mut entry = tournament.entries.get(index).unwrap();
-        if score > entry.score {
-            entry.score = score;
-            tournament.entries.set(index, entry);
-        }
-        index + 1 // Current rank (will be updated after sorting)
-    } else {
-        // Add new entry
-        if tournament.entries.len() >= tournament.max_entries {
-            return Err(Error::TournamentFull);
-        }
-        
-        let new_entry = LeaderboardEntry {
-            player_id: player_id.clone(),
-            score,
-            rank: 1,
-        };
-        tournament.entries.push_back(new_entry);
-        1
-    };
+    let submissions_key = DataKey::Submissions(tournament_id.clone(), player_id.clone());
+    let (submissions, last_submitted): (u32, u64) =
+        env.storage().persistent().get(&submissions_key).unwrap_or((0, 0));
+    check_limits(&tournament, submissions, last_submitted, current_time, score)?;
     
-    // Sort entries by score (descending) and update ranks
-    leaderboard::sort_entries(env, &mut tournament.entries);
+    validate_score(env, &tournament, &tournament_id, &player_id, score, nonce, signature)?;
+    env.storage()
+        .persistent()
+        .set(&submissions_key, &(submissions + 1, current_time));
+    env.storage()
+        .persistent()
+        .extend_ttl(&submissions_key, TTL_THRESHOLD, TTL_EXTEND_TO);
     
-    // Save updated tournament
-    save_tournament(env, tournament_id.clone(), &tournament);
+    // Record the score on the tournament board
+    let board = leaderboard::Board::Tournament(tournament_id.clone());
+    let rank = leaderboard::record_score(
+        env,
+        &board,
+        tournament.scoring_mode,
+        tournament.tie_break,
+        &player_id,
+        score,
+        &proof,
+        u32::MAX,
+    )
+    .unwrap_or(0);
+    teams::update_team_score(env, &tournament, &player_id);
     
     // Save player score to history
     let player_score = PlayerScore {
@@ -73,74 +94,144 @@ pub fn submit_score(
         timestamp: current_time,
         game_id: tournament.game_id.clone(),
         tournament_id: tournament_id.clone(),
+        proof: proof.clone(),
     };
     add_player_score(env, player_id.clone(), player_score);
+    stats::record_submission(env, &tournament.game_id, tournament.scoring_mode, &player_id, score);
     
-    // Update global leaderboard
-    leaderboard::update_global_leaderboard(env, &tournament.game_id, &player_id, score);
+    // Update global leaderboard, which only ranks best single scores
+    if tournament.scoring_mode == ScoringMode::Highest {
+        leaderboard::update_global_leaderboard(env, &tournament.game_id, &player_id, score, &proof);
+    }
     
     // Emit event
     env.events().publish(
         (Symbol::new(env, "score_submitted"), player_id.clone()),
-        (tournament_id, score),
+        (tournament_id, score, proof),
     );
     
     Ok(rank)
 }
 
+/// Record a score pushed by a registered game contract at the end of a match
+///
+/// The score counts towards the player's history, statistics and the
+/// global and season leaderboards of the contract's game, but no
+/// tournament. Only the game contract itself can make the call, so the
+/// score never comes from the client.
+pub fn submit_score_from_game(
+    env: &Env,
+    game_contract: Address,
+    player_id: Address,
+    score: u64,
+) -> Result<u32, Error> {
+    game_contract.require_auth();
+    let game_id = games::get_registered_game(env, game_contract.clone()).ok_or(Error::Unauthorized)?;
+
+    let player_score = PlayerScore {
+        score,
+        timestamp: env.ledger().timestamp(),
+        game_id: game_id.clone(),
+        tournament_id: String::from_str(env, ""),
+        proof: None,
+    };
+    add_player_score(env, player_id.clone(), player_score);
+    stats::record_submission(env, &game_id, ScoringMode::Highest, &player_id, score);
+    let rank = leaderboard::update_global_leaderboard(env, &game_id, &player_id, score, &None).unwrap_or(0);
+
+    // Emit event
+    env.events().publish(
+        (Symbol::new(env, "game_score_submitted"), player_id),
+        (game_id, game_contract, score),
+    );
+
+    Ok(rank)
+}
+
 /// Get player's score history
 pub fn get_player_history(env: &Env, player_id: Address) -> Vec<PlayerScore> {
     get_player_scores(env, player_id)
 }
 
-/// Validate score submission (placeholder for anti-cheat)
+/// Check the game server's signature on a score submission
+///
+/// Tournaments without a server key accept any score. Otherwise the
+/// signature must cover the XDR of (player_id, tournament_id, score, nonce)
+/// and the nonce must be above the last one the player used in the
+/// tournament. An invalid signature aborts the call.
 pub fn validate_score(
-    _env: &Env,
-    _player_id: &Address,
-    _score: u64,
-    _timestamp: u64,
-    _signature: Option<Vec<u8>>,
-) -> bool {
-    // Placeholder for signature verification
-    // In a real implementation, this would:
-    // 1. Verify the signature against the score data
-    // 2. Check timestamp to prevent replay attacks
-    // 3. Validate score is within reasonable bounds
-    // 4. Potentially check against off-chain oracles
-    true
-}
+    env: &Env,
+    tournament: &Tournament,
+    tournament_id: &String,
+    player_id: &Address,
+    score: u64,
+    nonce: u64,
+    signature: Option<Signature>,
+) -> Result<(), Error> {
+    let Some(server_key) = &tournament.server_key else {
+        return Ok(());
+    };
+    let signature = signature.ok_or(Error::MissingSignature)?;
 
-// Helper functions
-fn get_tournament(env: &Env, tournament_id: String) -> Result<Tournament, Error> {
+    let nonce_key = DataKey::ScoreNonce(tournament_id.clone(), player_id.clone());
+    let last_nonce: Option<u64> = env.storage().persistent().get(&nonce_key);
+    if last_nonce.is_some_and(|last_nonce| nonce <= last_nonce) {
+        return Err(Error::NonceUsed);
+    }
+
+    let message = (player_id.clone(), tournament_id.clone(), score, nonce).to_xdr(env);
+    env.crypto().ed25519_verify(server_key, &message, &signature);
+    env.storage().persistent().set(&nonce_key, &nonce);
     env.storage()
-        .instance()
-        .get(&DataKey::Tournament(tournament_id))
-        .ok_or(Error::TournamentNotFound)
+        .persistent()
+        .extend_ttl(&nonce_key, TTL_THRESHOLD, TTL_EXTEND_TO);
+
+    Ok(())
 }
 
-fn save_tournament(env: &Env, tournament_id: String, tournament: &Tournament) {
-    env.storage()
-        .instance()
-        .set(&DataKey::Tournament(tournament_id), tournament);
+// Helper functions
+fn check_limits(
+    tournament: &Tournament,
+    submissions: u32,
+    last_submitted: u64,
+    now: u64,
+    score: u64,
+) -> Result<(), Error> {
+    if tournament.max_valid_score > 0 && score > tournament.max_valid_score {
+        return Err(Error::ScoreOutOfBounds);
+    }
+    if tournament.max_submissions_per_player > 0 && submissions >= tournament.max_submissions_per_player {
+        return Err(Error::TooManySubmissions);
+    }
+    if submissions > 0 && now < last_submitted.saturating_add(tournament.min_interval_secs) {
+        return Err(Error::SubmissionTooSoon);
+    }
+    Ok(())
 }
 
 fn get_player_scores(env: &Env, player_id: Address) -> Vec<PlayerScore> {
+    let key = DataKey::PlayerScores(player_id);
+    let Some(scores) = env.storage().persistent().get(&key) else {
+        return Vec::new(env);
+    };
     env.storage()
-        .instance()
-        .get(&DataKey::PlayerScores(player_id))
-        .unwrap_or(Vec::new(env))
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD, TTL_EXTEND_TO);
+    scores
 }
 
 fn add_player_score(env: &Env, player_id: Address, score: PlayerScore) {
     let mut scores = get_player_scores(env, player_id.clone());
     scores.push_back(score);
     
-    // Keep only last 100 scores per player
-    while scores.len() > 100 {
+    // Keep only the most recent scores per player
+    while scores.len() > MAX_PLAYER_HISTORY {
         scores.pop_front();
     }
     
+    let key = DataKey::PlayerScores(player_id);
+    env.storage().persistent().set(&key, &scores);
     env.storage()
-        .instance()
-        .set(&DataKey::PlayerScores(player_id), &scores);
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD, TTL_EXTEND_TO);
 } 
\ No newline at end of file
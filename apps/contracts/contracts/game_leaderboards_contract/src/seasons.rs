@@ -0,0 +1,174 @@
+use soroban_sdk::{contracttype, Address, BytesN, Env, IntoVal, String, Symbol, TryFromVal, Val, Vec};
+use crate::errors::Error;
+use crate::leaderboard::{self, Board, GLOBAL_LEADERBOARD_SIZE};
+use crate::tournaments;
+use crate::types::{LeaderboardEntry, ScoringMode, Season, TieBreak};
+use crate::{TTL_EXTEND_TO, TTL_THRESHOLD};
+
+#[contracttype]
+#[derive(Clone)]
+enum DataKey {
+    Season(String, u32),
+    LatestSeason(String),
+    Archive(String),
+}
+
+/// Start a new season of a game's global leaderboard, lasting `duration` seconds
+///
+/// Only the admin may start seasons. Season ids must increase, and the
+/// previous season must have run out; it is archived on the way.
+pub fn start_season(env: &Env, game_id: String, season_id: u32, duration: u64) -> Result<Season, Error> {
+    let admin = tournaments::get_admin(env).ok_or(Error::Unauthorized)?;
+    admin.require_auth();
+
+    if duration == 0 {
+        return Err(Error::InvalidParameters);
+    }
+    if let Some(mut latest) = get_latest_season(env, &game_id) {
+        if season_id <= latest.season_id {
+            return Err(Error::InvalidParameters);
+        }
+        if !archive_if_expired(env, &mut latest) {
+            return Err(Error::SeasonActive);
+        }
+    }
+
+    let start_time = env.ledger().timestamp();
+    let season = Season {
+        game_id: game_id.clone(),
+        season_id,
+        start_time,
+        end_time: start_time.saturating_add(duration),
+        archived: false,
+    };
+    save_season(env, &season);
+    persist(env, &DataKey::LatestSeason(game_id.clone()), &season_id);
+
+    // Emit event
+    env.events().publish(
+        (Symbol::new(env, "season_started"), game_id),
+        (season_id, season.end_time),
+    );
+
+    Ok(season)
+}
+
+/// Get the running season of a game, archiving it first if it has run out
+pub fn get_current_season(env: &Env, game_id: String) -> Option<Season> {
+    let mut season = get_latest_season(env, &game_id)?;
+    if archive_if_expired(env, &mut season) {
+        return None;
+    }
+    Some(season)
+}
+
+/// Get a season of a game, running or archived
+pub fn get_season(env: &Env, game_id: String, season_id: u32) -> Result<Season, Error> {
+    let mut season = load(env, &DataKey::Season(game_id, season_id)).ok_or(Error::SeasonNotFound)?;
+    // Archive on read so a finished season never looks current
+    archive_if_expired(env, &mut season);
+    Ok(season)
+}
+
+/// Get the ids of a game's archived seasons, oldest first
+pub fn get_archived_seasons(env: &Env, game_id: String) -> Vec<u32> {
+    // Archives the latest season if it has just run out
+    get_current_season(env, game_id.clone());
+    load(env, &DataKey::Archive(game_id)).unwrap_or(Vec::new(env))
+}
+
+/// Get the leaderboard of one season of a game, running or archived
+pub fn get_season_leaderboard(
+    env: &Env,
+    game_id: String,
+    season_id: u32,
+    page: u32,
+    page_size: u32,
+) -> Result<Vec<LeaderboardEntry>, Error> {
+    get_season(env, game_id.clone(), season_id)?;
+    let board = Board::Season(game_id, season_id);
+    Ok(leaderboard::get_entries(
+        env,
+        &board,
+        page.saturating_mul(page_size),
+        page_size,
+    ))
+}
+
+/// Check whether a game has ever started a season
+pub fn has_seasons(env: &Env, game_id: &String) -> bool {
+    load::<u32>(env, &DataKey::LatestSeason(game_id.clone())).is_some()
+}
+
+/// Record a score on the running season's board of a game, if any
+pub fn record_season_score(
+    env: &Env,
+    game_id: &String,
+    player_id: &Address,
+    score: u64,
+    proof: &Option<BytesN<32>>,
+) {
+    if let Some(season) = get_current_season(env, game_id.clone()) {
+        let board = Board::Season(game_id.clone(), season.season_id);
+        leaderboard::record_score(
+            env,
+            &board,
+            ScoringMode::Highest,
+            TieBreak::EarliestFirst,
+            player_id,
+            score,
+            proof,
+            GLOBAL_LEADERBOARD_SIZE,
+        );
+    }
+}
+
+// Helper functions
+fn get_latest_season(env: &Env, game_id: &String) -> Option<Season> {
+    let season_id: u32 = load(env, &DataKey::LatestSeason(game_id.clone()))?;
+    load(env, &DataKey::Season(game_id.clone(), season_id))
+}
+
+fn save_season(env: &Env, season: &Season) {
+    persist(env, &DataKey::Season(season.game_id.clone(), season.season_id), season);
+}
+
+/// Archive a season whose end time has passed, returning whether it is archived
+fn archive_if_expired(env: &Env, season: &mut Season) -> bool {
+    if season.archived {
+        return true;
+    }
+    if env.ledger().timestamp() < season.end_time {
+        return false;
+    }
+
+    season.archived = true;
+    save_season(env, season);
+    let archive_key = DataKey::Archive(season.game_id.clone());
+    let mut archive: Vec<u32> = load(env, &archive_key).unwrap_or(Vec::new(env));
+    archive.push_back(season.season_id);
+    persist(env, &archive_key, &archive);
+
+    // Emit event
+    env.events().publish(
+        (Symbol::new(env, "season_archived"), season.game_id.clone()),
+        season.season_id,
+    );
+
+    true
+}
+
+fn load<V: TryFromVal<Env, Val>>(env: &Env, key: &DataKey) -> Option<V> {
+    let value = env.storage().persistent().get(key)?;
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+    Some(value)
+}
+
+fn persist<V: IntoVal<Env, Val>>(env: &Env, key: &DataKey, value: &V) {
+    env.storage().persistent().set(key, value);
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
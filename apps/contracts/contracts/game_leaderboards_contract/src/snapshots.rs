@@ -0,0 +1,117 @@
+use soroban_sdk::{contracttype, xdr::ToXdr, BytesN, Env, String, Symbol, Vec};
+use crate::errors::Error;
+use crate::leaderboard::{self, Board};
+use crate::stats;
+use crate::tournaments;
+use crate::types::{LeaderboardEntry, StandingsSnapshot, Tournament};
+use crate::{TTL_EXTEND_TO, TTL_THRESHOLD};
+
+/// Most standings entries a snapshot stores, counted from first place
+///
+/// Prize pools pay at most this many ranks, so the final snapshot always
+/// holds every paid player while staying within one storage entry.
+pub const MAX_SNAPSHOT_ENTRIES: u32 = 100;
+
+#[contracttype]
+#[derive(Clone)]
+enum DataKey {
+    FinalSnapshot(String),
+}
+
+/// Freeze the final standings of an ended tournament and return their hash
+///
+/// Anyone may take the snapshot once the tournament is over. It can only be
+/// taken once, so prize payouts can always be audited against it. Only the
+/// top `MAX_SNAPSHOT_ENTRIES` entries are stored and hashed.
+pub fn snapshot_leaderboard(env: &Env, tournament_id: String) -> Result<BytesN<32>, Error> {
+    let snapshot = take_final_snapshot(env, tournament_id)?;
+    Ok(snapshot.hash)
+}
+
+/// Get the final standings snapshot of a tournament
+pub fn get_leaderboard_snapshot(env: &Env, tournament_id: String) -> Result<StandingsSnapshot, Error> {
+    let key = DataKey::FinalSnapshot(tournament_id);
+    let snapshot = env.storage().persistent().get(&key).ok_or(Error::SnapshotNotFound)?;
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD, TTL_EXTEND_TO);
+    Ok(snapshot)
+}
+
+/// Check standings against the final snapshot of a tournament
+///
+/// Returns whether `entries`, the top `MAX_SNAPSHOT_ENTRIES` of the final
+/// standings, hash to the stored snapshot hash, false if no snapshot was taken.
+pub fn verify_snapshot(env: &Env, tournament_id: String, entries: Vec<LeaderboardEntry>) -> bool {
+    let Ok(snapshot) = get_leaderboard_snapshot(env, tournament_id.clone()) else {
+        return false;
+    };
+    standings_hash(env, &tournament_id, snapshot.locked_at, &entries) == snapshot.hash
+}
+
+/// Get the final snapshot of an ended tournament, taking it first if needed
+pub fn final_standings(env: &Env, tournament_id: String) -> Result<StandingsSnapshot, Error> {
+    match get_leaderboard_snapshot(env, tournament_id.clone()) {
+        Ok(snapshot) => Ok(snapshot),
+        Err(_) => take_final_snapshot(env, tournament_id),
+    }
+}
+
+/// Capture the top of a tournament's standings at `locked_at`
+pub fn capture_standings(env: &Env, tournament: &Tournament, locked_at: u64) -> StandingsSnapshot {
+    let entries = leaderboard::get_tournament_entries(env, tournament, 0, MAX_SNAPSHOT_ENTRIES);
+    let hash = standings_hash(env, &tournament.id, locked_at, &entries);
+    StandingsSnapshot {
+        tournament_id: tournament.id.clone(),
+        locked_at,
+        entries,
+        total_entries: leaderboard::get_entry_count(env, &Board::Tournament(tournament.id.clone())),
+        hash,
+    }
+}
+
+/// Hash standings as the XDR of (tournament_id, locked_at, entries)
+pub fn standings_hash(
+    env: &Env,
+    tournament_id: &String,
+    locked_at: u64,
+    entries: &Vec<LeaderboardEntry>,
+) -> BytesN<32> {
+    let payload = (tournament_id.clone(), locked_at, entries.clone()).to_xdr(env);
+    env.crypto().sha256(&payload).into()
+}
+
+// Helper functions
+fn take_final_snapshot(env: &Env, tournament_id: String) -> Result<StandingsSnapshot, Error> {
+    let tournament = tournaments::get_tournament_info(env, tournament_id.clone())?;
+    if tournament.cancelled {
+        return Err(Error::TournamentCancelled);
+    }
+    let current_time = env.ledger().timestamp();
+    if current_time <= tournament.end_time {
+        return Err(Error::TournamentNotActive);
+    }
+
+    let key = DataKey::FinalSnapshot(tournament_id.clone());
+    if env.storage().persistent().has(&key) {
+        return Err(Error::SnapshotLocked);
+    }
+
+    let snapshot = capture_standings(env, &tournament, current_time);
+    env.storage().persistent().set(&key, &snapshot);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD, TTL_EXTEND_TO);
+    // The final snapshot is taken once, whether or not a prize pool is paid out
+    if let Some(winner) = snapshot.entries.get(0) {
+        stats::record_win(env, &tournament, &winner.player_id);
+    }
+
+    // Emit event
+    env.events().publish(
+        (Symbol::new(env, "leaderboard_snapshot"), tournament_id),
+        (snapshot.hash.clone(), current_time, snapshot.total_entries),
+    );
+
+    Ok(snapshot)
+}
@@ -0,0 +1,61 @@
+use soroban_sdk::{contracttype, Address, Env, String};
+use crate::leaderboard;
+use crate::types::{PlayerHistory, ScoringMode, Tournament};
+use crate::{TTL_EXTEND_TO, TTL_THRESHOLD};
+
+#[contracttype]
+#[derive(Clone)]
+enum DataKey {
+    Stats(Address, String),
+}
+
+/// Get a player's statistics in a game, all zero if they never played it
+pub fn get_player_stats(env: &Env, player_id: Address, game_id: String) -> PlayerHistory {
+    let key = DataKey::Stats(player_id.clone(), game_id.clone());
+    let Some(stats) = env.storage().persistent().get(&key) else {
+        return PlayerHistory {
+            player_id,
+            game_id,
+            total_games: 0,
+            best_score: 0,
+            total_score: 0,
+            average_score: 0,
+            tournaments_won: 0,
+        };
+    };
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD, TTL_EXTEND_TO);
+    stats
+}
+
+/// Count a score submission towards the player's statistics in a game
+///
+/// The best score follows the scoring mode the score was submitted under,
+/// so a lower score only counts as best where lower scores rank first.
+pub fn record_submission(env: &Env, game_id: &String, scoring_mode: ScoringMode, player_id: &Address, score: u64) {
+    let mut stats = get_player_stats(env, player_id.clone(), game_id.clone());
+    if stats.total_games == 0 || leaderboard::beats(scoring_mode, score, stats.best_score) {
+        stats.best_score = score;
+    }
+    stats.total_games += 1;
+    stats.total_score = stats.total_score.saturating_add(score);
+    stats.average_score = stats.total_score / stats.total_games as u64;
+    save_player_stats(env, &stats);
+}
+
+/// Count a tournament win towards the player's statistics in its game, once its final standings are frozen
+pub fn record_win(env: &Env, tournament: &Tournament, player_id: &Address) {
+    let mut stats = get_player_stats(env, player_id.clone(), tournament.game_id.clone());
+    stats.tournaments_won += 1;
+    save_player_stats(env, &stats);
+}
+
+// Helper functions
+fn save_player_stats(env: &Env, stats: &PlayerHistory) {
+    let key = DataKey::Stats(stats.player_id.clone(), stats.game_id.clone());
+    env.storage().persistent().set(&key, stats);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
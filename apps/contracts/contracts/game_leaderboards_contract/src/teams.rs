@@ -0,0 +1,278 @@
+use soroban_sdk::{contracttype, Address, Env, IntoVal, String, Symbol, TryFromVal, Val, Vec};
+use crate::audit;
+use crate::errors::Error;
+use crate::leaderboard::{self, Board};
+use crate::registration;
+use crate::tournaments;
+use crate::types::{AuditAction, Team, TeamConfig, TeamEntry, TeamScoring, Tournament};
+use crate::{TTL_EXTEND_TO, TTL_THRESHOLD};
+
+/// Most players one team can hold
+pub const MAX_TEAM_SIZE: u32 = 16;
+/// Most teams one tournament can hold
+pub const MAX_TEAMS: u32 = 256;
+
+#[contracttype]
+#[derive(Clone)]
+enum DataKey {
+    Config(String),
+    Team(String, String),
+    Membership(String, Address),
+    TeamCount(String),
+    TeamBoard(String),
+}
+
+/// Make a tournament that has not started a team tournament
+///
+/// Only the creator may enable teams. Players then need a team to submit
+/// scores, and teams are ranked on their own leaderboard.
+pub fn enable_teams(env: &Env, tournament_id: String, config: TeamConfig) -> Result<(), Error> {
+    let tournament = tournaments::get_tournament_info(env, tournament_id.clone())?;
+    tournament.creator.require_auth();
+
+    if tournament.cancelled {
+        return Err(Error::TournamentCancelled);
+    }
+    if env.ledger().timestamp() >= tournament.start_time {
+        return Err(Error::TournamentNotActive);
+    }
+    if config.max_size == 0 || config.max_size > MAX_TEAM_SIZE {
+        return Err(Error::InvalidParameters);
+    }
+
+    persist(env, &DataKey::Config(tournament_id.clone()), &config);
+    audit::record(
+        env,
+        tournament_id.clone(),
+        Some(tournament.creator),
+        AuditAction::TeamsEnabled(config.scoring, config.max_size),
+    );
+
+    // Emit event
+    env.events().publish(
+        (Symbol::new(env, "teams_enabled"), tournament_id),
+        (config.scoring, config.max_size),
+    );
+
+    Ok(())
+}
+
+/// Get the team rules of a tournament, `None` for solo play
+pub fn get_team_config(env: &Env, tournament_id: String) -> Option<TeamConfig> {
+    load(env, &DataKey::Config(tournament_id))
+}
+
+/// Create a team in a team tournament, with its captain as first member
+///
+/// The captain must have registered for the tournament and must not be on
+/// another team.
+pub fn create_team(env: &Env, tournament_id: String, team_id: String, captain: Address) -> Result<Team, Error> {
+    captain.require_auth();
+
+    get_open_team_tournament(env, tournament_id.clone())?;
+    let team_key = DataKey::Team(tournament_id.clone(), team_id.clone());
+    if env.storage().persistent().has(&team_key) {
+        return Err(Error::TeamExists);
+    }
+    check_can_join(env, &tournament_id, &captain)?;
+
+    let count_key = DataKey::TeamCount(tournament_id.clone());
+    let count: u32 = load(env, &count_key).unwrap_or(0);
+    if count >= MAX_TEAMS {
+        return Err(Error::TournamentFull);
+    }
+
+    let team = Team {
+        id: team_id.clone(),
+        tournament_id: tournament_id.clone(),
+        captain: captain.clone(),
+        members: Vec::from_array(env, [captain.clone()]),
+    };
+    persist(env, &team_key, &team);
+    persist(env, &count_key, &(count + 1));
+    persist(env, &DataKey::Membership(tournament_id.clone(), captain.clone()), &team_id);
+
+    // Emit event
+    env.events().publish(
+        (Symbol::new(env, "team_created"), tournament_id),
+        (team_id, captain),
+    );
+
+    Ok(team)
+}
+
+/// Join a team in a team tournament
+///
+/// The player must have registered for the tournament, and stays on the
+/// team for the rest of it.
+pub fn join_team(env: &Env, tournament_id: String, team_id: String, player_id: Address) -> Result<Team, Error> {
+    player_id.require_auth();
+
+    let config = get_open_team_tournament(env, tournament_id.clone())?;
+    let team_key = DataKey::Team(tournament_id.clone(), team_id.clone());
+    let mut team: Team = load(env, &team_key).ok_or(Error::TeamNotFound)?;
+    check_can_join(env, &tournament_id, &player_id)?;
+
+    if team.members.len() >= config.max_size {
+        return Err(Error::TeamFull);
+    }
+
+    team.members.push_back(player_id.clone());
+    persist(env, &team_key, &team);
+    persist(env, &DataKey::Membership(tournament_id.clone(), player_id.clone()), &team_id);
+
+    // Emit event
+    env.events().publish(
+        (Symbol::new(env, "team_joined"), tournament_id),
+        (team_id, player_id),
+    );
+
+    Ok(team)
+}
+
+/// Get a team of a tournament
+pub fn get_team(env: &Env, tournament_id: String, team_id: String) -> Result<Team, Error> {
+    load(env, &DataKey::Team(tournament_id, team_id)).ok_or(Error::TeamNotFound)
+}
+
+/// Get the id of the team a player is on in a tournament, if any
+pub fn get_player_team(env: &Env, tournament_id: String, player_id: Address) -> Option<String> {
+    load(env, &DataKey::Membership(tournament_id, player_id))
+}
+
+/// Get a tournament's team leaderboard with pagination
+///
+/// Only teams with at least one scoring member are listed.
+pub fn get_team_leaderboard(
+    env: &Env,
+    tournament_id: String,
+    page: u32,
+    page_size: u32,
+) -> Result<Vec<TeamEntry>, Error> {
+    tournaments::get_tournament_info(env, tournament_id.clone())?;
+    if get_team_config(env, tournament_id.clone()).is_none() {
+        return Err(Error::TeamsNotEnabled);
+    }
+    let board = get_team_board(env, tournament_id);
+
+    let start_index = page.saturating_mul(page_size);
+    let end_index = start_index.saturating_add(page_size).min(board.len());
+
+    let mut result = Vec::new(env);
+    for i in start_index..end_index {
+        if let Some(mut entry) = board.get(i) {
+            entry.rank = i + 1;
+            result.push_back(entry);
+        }
+    }
+
+    Ok(result)
+}
+
+/// Refresh the score of a player's team after the player's tournament score changed
+///
+/// The team score combines the members' tournament scores as the team
+/// scoring rule says, and the team board is ordered by the tournament's
+/// scoring mode.
+pub fn update_team_score(env: &Env, tournament: &Tournament, player_id: &Address) {
+    let Some(config) = get_team_config(env, tournament.id.clone()) else {
+        return;
+    };
+    let Some(team_id) = get_player_team(env, tournament.id.clone(), player_id.clone()) else {
+        return;
+    };
+    let Ok(team) = get_team(env, tournament.id.clone(), team_id.clone()) else {
+        return;
+    };
+
+    let board = Board::Tournament(tournament.id.clone());
+    let mut score: Option<u64> = None;
+    for member in team.members.iter() {
+        let Some(member_score) = leaderboard::get_score(env, &board, &member) else {
+            continue;
+        };
+        score = Some(match (score, config.scoring) {
+            (None, _) => member_score,
+            (Some(total), TeamScoring::Sum) => total.saturating_add(member_score),
+            (Some(best), TeamScoring::Best) if leaderboard::beats(tournament.scoring_mode, member_score, best) => {
+                member_score
+            }
+            (Some(best), TeamScoring::Best) => best,
+        });
+    }
+    let Some(score) = score else {
+        return;
+    };
+
+    let mut team_board = get_team_board(env, tournament.id.clone());
+    if let Some(position) = team_board.iter().position(|entry| entry.team_id == team_id) {
+        let current = team_board.get(position as u32).unwrap();
+        if current.score == score {
+            return;
+        }
+        team_board.remove(position as u32);
+    }
+    let entry = TeamEntry {
+        team_id,
+        score,
+        rank: 0,
+        updated_at: env.ledger().timestamp(),
+    };
+    insert_team_entry(&mut team_board, tournament, entry);
+    save_team_board(env, tournament.id.clone(), &team_board);
+}
+
+// Helper functions
+fn get_open_team_tournament(env: &Env, tournament_id: String) -> Result<TeamConfig, Error> {
+    let tournament = tournaments::get_tournament_info(env, tournament_id.clone())?;
+    let config = get_team_config(env, tournament_id).ok_or(Error::TeamsNotEnabled)?;
+    if tournament.cancelled {
+        return Err(Error::TournamentCancelled);
+    }
+    if env.ledger().timestamp() > tournament.end_time {
+        return Err(Error::TournamentNotActive);
+    }
+    Ok(config)
+}
+
+fn check_can_join(env: &Env, tournament_id: &String, player_id: &Address) -> Result<(), Error> {
+    if !registration::is_registered(env, tournament_id.clone(), player_id) {
+        return Err(Error::NotRegistered);
+    }
+    if get_player_team(env, tournament_id.clone(), player_id.clone()).is_some() {
+        return Err(Error::AlreadyInTeam);
+    }
+    Ok(())
+}
+
+fn get_team_board(env: &Env, tournament_id: String) -> Vec<TeamEntry> {
+    load(env, &DataKey::TeamBoard(tournament_id)).unwrap_or(Vec::new(env))
+}
+
+fn save_team_board(env: &Env, tournament_id: String, board: &Vec<TeamEntry>) {
+    persist(env, &DataKey::TeamBoard(tournament_id), board);
+}
+
+fn load<V: TryFromVal<Env, Val>>(env: &Env, key: &DataKey) -> Option<V> {
+    let value = env.storage().persistent().get(key)?;
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+    Some(value)
+}
+
+fn persist<V: IntoVal<Env, Val>>(env: &Env, key: &DataKey, value: &V) {
+    env.storage().persistent().set(key, value);
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+/// Insert a team after every team that scored at least as well
+fn insert_team_entry(board: &mut Vec<TeamEntry>, tournament: &Tournament, entry: TeamEntry) {
+    let position = board
+        .iter()
+        .position(|other| leaderboard::beats(tournament.scoring_mode, entry.score, other.score))
+        .map_or(board.len(), |position| position as u32);
+    board.insert(position, entry);
+}
@@ -0,0 +1,1578 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, BytesN, Env, String};
+
+/// Register the contract and approve a creator for the tests' game
+///
+/// Every auth is mocked, so tests act as the creator, players and the
+/// admin without signing.
+fn setup_with_creator(env: &Env) -> (GameLeaderboardContractClient<'_>, Address) {
+    env.mock_all_auths();
+    let client = GameLeaderboardContractClient::new(env, &env.register(GameLeaderboardContract, ()));
+    client.initialize(&Address::generate(env));
+    let creator = approved_creator(env, &client);
+    (client, creator)
+}
+
+/// Generate a creator approved for the tests' game
+fn approved_creator(env: &Env, client: &GameLeaderboardContractClient) -> Address {
+    let creator = Address::generate(env);
+    client.approve_creator(&String::from_str(env, "game"), &creator);
+    creator
+}
+
+fn setup_tournament(env: &Env, tie_break: TieBreak) -> (GameLeaderboardContractClient<'_>, String) {
+    setup_scored_tournament(env, ScoringMode::Highest, tie_break)
+}
+
+fn setup_scored_tournament(
+    env: &Env,
+    scoring_mode: ScoringMode,
+    tie_break: TieBreak,
+) -> (GameLeaderboardContractClient<'_>, String) {
+    let (client, creator) = setup_with_creator(env);
+    let tournament_id = String::from_str(env, "t1");
+    client.create_tournament(
+        &creator,
+        &tournament_id,
+        &String::from_str(env, "game"),
+        &0,
+        &1000,
+        &100,
+        &scoring_mode,
+        &tie_break,
+        &None,
+        &None,
+    );
+    (client, tournament_id)
+}
+
+/// Register one player per score and submit the scores one second apart,
+/// returning the players and the ranks each submission reported
+fn submit_scores(
+    env: &Env,
+    client: &GameLeaderboardContractClient,
+    tournament_id: &String,
+    scores: &[u64],
+) -> (Vec<Address>, Vec<u32>) {
+    let mut players = Vec::new(env);
+    let mut ranks = Vec::new(env);
+    for (i, score) in scores.iter().enumerate() {
+        env.ledger().set_timestamp(10 + i as u64);
+        let player = Address::generate(env);
+        client.register_for_tournament(&player, tournament_id);
+        ranks.push_back(client.submit_score(tournament_id, &player, score, &None, &0, &None));
+        players.push_back(player);
+    }
+    (players, ranks)
+}
+
+#[test]
+fn test_ties_earliest_first() {
+    let env = Env::default();
+    let (client, tournament_id) = setup_tournament(&env, TieBreak::EarliestFirst);
+    let (players, ranks) = submit_scores(&env, &client, &tournament_id, &[50, 80, 50, 50]);
+
+    // A later equal score ranks below the earlier ones
+    assert_eq!(ranks, Vec::from_array(&env, [1, 1, 3, 4]));
+
+    let board = client.get_leaderboard(&tournament_id, &0, &10);
+    let order = [1, 0, 2, 3];
+    for (i, entry) in board.iter().enumerate() {
+        assert_eq!(entry.player_id, players.get(order[i]).unwrap());
+        assert_eq!(entry.rank, i as u32 + 1);
+        assert_eq!(entry.submitted_at, 10 + order[i] as u64);
+    }
+}
+
+#[test]
+fn test_ties_latest_first() {
+    let env = Env::default();
+    let (client, tournament_id) = setup_tournament(&env, TieBreak::LatestFirst);
+    let (players, ranks) = submit_scores(&env, &client, &tournament_id, &[50, 80, 50, 50]);
+
+    assert_eq!(ranks, Vec::from_array(&env, [1, 1, 2, 2]));
+
+    let board = client.get_leaderboard(&tournament_id, &0, &10);
+    let order = [1, 3, 2, 0];
+    for (i, entry) in board.iter().enumerate() {
+        assert_eq!(entry.player_id, players.get(order[i]).unwrap());
+        assert_eq!(entry.rank, i as u32 + 1);
+    }
+}
+
+#[test]
+fn test_ties_shared() {
+    let env = Env::default();
+    let (client, tournament_id) = setup_tournament(&env, TieBreak::Shared);
+    let (players, ranks) = submit_scores(&env, &client, &tournament_id, &[50, 80, 50, 50, 20]);
+
+    assert_eq!(ranks, Vec::from_array(&env, [1, 1, 2, 2, 5]));
+
+    // Equal scores share the rank of the first of them, in submission order
+    let board = client.get_leaderboard(&tournament_id, &0, &10);
+    let order = [1, 0, 2, 3, 4];
+    let expected = [1, 2, 2, 2, 5];
+    for (i, entry) in board.iter().enumerate() {
+        assert_eq!(entry.player_id, players.get(order[i]).unwrap());
+        assert_eq!(entry.rank, expected[i]);
+    }
+
+    // A page starting inside a run of ties keeps the shared rank
+    let page = client.get_leaderboard(&tournament_id, &3, &1);
+    assert_eq!(page.get(0).unwrap().rank, 2);
+}
+
+/// Check that a tournament board lists `expected` best first, with ranks
+/// matching both the listing and `get_player_rank`
+fn assert_board(client: &GameLeaderboardContractClient, tournament_id: &String, expected: &Vec<(Address, u64)>) {
+    let board = client.get_leaderboard(tournament_id, &0, &100);
+    assert_eq!(board.len(), expected.len());
+    for (i, (player, score)) in expected.iter().enumerate() {
+        let rank = i as u32 + 1;
+        let entry = board.get(i as u32).unwrap();
+        assert_eq!((entry.player_id, entry.score, entry.rank), (player.clone(), score, rank));
+        assert_eq!(client.get_player_rank(tournament_id, &player), Some((rank, score)));
+    }
+
+    // A page starting in the second bucket is numbered from its position
+    let page = client.get_leaderboard(tournament_id, &2, &10);
+    assert_eq!(page.get(0).unwrap().rank, 21);
+    assert_eq!(page.get(0).unwrap().player_id, expected.get(20).unwrap().0);
+}
+
+#[test]
+fn test_board_across_buckets() {
+    let env = Env::default();
+    let (client, tournament_id) = setup_tournament(&env, TieBreak::EarliestFirst);
+
+    // One entry past a full bucket splits it in two
+    let mut scores = [0u64; leaderboard::BUCKET_SIZE as usize + 1];
+    for (i, score) in scores.iter_mut().enumerate() {
+        *score = i as u64 + 1;
+    }
+    let (players, ranks) = submit_scores(&env, &client, &tournament_id, &scores);
+    assert!(ranks.iter().all(|rank| rank == 1));
+    let mut expected = Vec::new(&env);
+    for (i, player) in players.iter().enumerate() {
+        expected.push_front((player, i as u64 + 1));
+    }
+    assert_board(&client, &tournament_id, &expected);
+
+    // Moving every player of the lower bucket to the top empties it, while
+    // the upper bucket fills up and splits again
+    let lower = players.len() - players.len() / 2;
+    let mut moved = expected.slice(0..players.len() - lower);
+    for i in 0..lower {
+        let player = players.get(i).unwrap();
+        env.ledger().set_timestamp(100 + i as u64);
+        let rank = client.submit_score(&tournament_id, &player, &(100 + i as u64), &None, &0, &None);
+        assert_eq!(rank, 1);
+        moved.push_front((player, 100 + i as u64));
+    }
+    assert_board(&client, &tournament_id, &moved);
+}
+
+/// Submit a player's next score a second after the last one
+fn resubmit(env: &Env, client: &GameLeaderboardContractClient, tournament_id: &String, player: &Address, score: u64) -> u32 {
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1);
+    client.submit_score(tournament_id, player, &score, &None, &0, &None)
+}
+
+/// Get the scores on a tournament board, best first
+fn board_scores(env: &Env, client: &GameLeaderboardContractClient, tournament_id: &String) -> Vec<u64> {
+    let mut scores = Vec::new(env);
+    for entry in client.get_leaderboard(tournament_id, &0, &10).iter() {
+        scores.push_back(entry.score);
+    }
+    scores
+}
+
+#[test]
+fn test_lowest_scoring_mode() {
+    let env = Env::default();
+    let (client, tournament_id) = setup_scored_tournament(&env, ScoringMode::Lowest, TieBreak::EarliestFirst);
+    let (players, ranks) = submit_scores(&env, &client, &tournament_id, &[50, 30, 40]);
+    assert_eq!(ranks, Vec::from_array(&env, [1, 1, 2]));
+    assert_eq!(board_scores(&env, &client, &tournament_id), Vec::from_array(&env, [30, 40, 50]));
+
+    // A slower time doesn't replace the best one, a faster one does
+    let last = players.get(0).unwrap();
+    assert_eq!(resubmit(&env, &client, &tournament_id, &last, 60), 3);
+    assert_eq!(resubmit(&env, &client, &tournament_id, &last, 20), 1);
+    assert_eq!(board_scores(&env, &client, &tournament_id), Vec::from_array(&env, [20, 30, 40]));
+
+    // Only highest-score tournaments feed the global leaderboard
+    assert!(client.get_global_leaderboard(&String::from_str(&env, "game"), &0, &10).is_empty());
+}
+
+#[test]
+fn test_cumulative_scoring_mode() {
+    let env = Env::default();
+    let (client, tournament_id) = setup_scored_tournament(&env, ScoringMode::Cumulative, TieBreak::EarliestFirst);
+    let (players, _) = submit_scores(&env, &client, &tournament_id, &[30, 50]);
+
+    // Every submission adds to the player's total
+    let first = players.get(0).unwrap();
+    assert_eq!(resubmit(&env, &client, &tournament_id, &first, 30), 1);
+    assert_eq!(client.get_player_rank(&tournament_id, &first), Some((1, 60)));
+    assert_eq!(board_scores(&env, &client, &tournament_id), Vec::from_array(&env, [60, 50]));
+    assert_eq!(client.get_player_history(&first).len(), 2);
+    assert!(client.get_global_leaderboard(&String::from_str(&env, "game"), &0, &10).is_empty());
+}
+
+#[test]
+fn test_latest_scoring_mode() {
+    let env = Env::default();
+    let (client, tournament_id) = setup_scored_tournament(&env, ScoringMode::Latest, TieBreak::EarliestFirst);
+    let (players, _) = submit_scores(&env, &client, &tournament_id, &[80, 50]);
+
+    // The latest score stands even when it is worse
+    let first = players.get(0).unwrap();
+    assert_eq!(resubmit(&env, &client, &tournament_id, &first, 10), 2);
+    assert_eq!(board_scores(&env, &client, &tournament_id), Vec::from_array(&env, [50, 10]));
+    assert_eq!(resubmit(&env, &client, &tournament_id, &first, 70), 1);
+    assert_eq!(board_scores(&env, &client, &tournament_id), Vec::from_array(&env, [70, 50]));
+    assert!(client.get_global_leaderboard(&String::from_str(&env, "game"), &0, &10).is_empty());
+}
+
+#[test]
+fn test_resubmitting_equal_score_keeps_position() {
+    let env = Env::default();
+    let (client, tournament_id) = setup_tournament(&env, TieBreak::EarliestFirst);
+    let (players, _) = submit_scores(&env, &client, &tournament_id, &[50, 50]);
+
+    // An equal score is not an improvement, so the first submission time stands
+    env.ledger().set_timestamp(100);
+    let rank = client.submit_score(&tournament_id, &players.get(0).unwrap(), &50, &None, &0, &None);
+    assert_eq!(rank, 1);
+
+    let board = client.get_leaderboard(&tournament_id, &0, &10);
+    assert_eq!(board.get(0).unwrap().player_id, players.get(0).unwrap());
+    assert_eq!(board.get(0).unwrap().submitted_at, 10);
+}
+
+fn setup_seasons(env: &Env) -> (GameLeaderboardContractClient<'_>, String) {
+    let (client, creator) = setup_with_creator(env);
+    let game_id = String::from_str(env, "game");
+    client.create_tournament(
+        &creator,
+        &String::from_str(env, "t1"),
+        &game_id,
+        &0,
+        &10_000,
+        &100,
+        &ScoringMode::Highest,
+        &TieBreak::EarliestFirst,
+        &None,
+        &None,
+    );
+    (client, game_id)
+}
+
+fn submit_at(env: &Env, client: &GameLeaderboardContractClient, player: &Address, score: u64, timestamp: u64) {
+    env.ledger().set_timestamp(timestamp);
+    let tournament_id = String::from_str(env, "t1");
+    // Players already registered get AlreadyRegistered, which is fine here
+    let _ = client.try_register_for_tournament(player, &tournament_id);
+    client.submit_score(&tournament_id, player, &score, &None, &0, &None);
+}
+
+#[test]
+fn test_global_leaderboard_without_seasons_is_all_time() {
+    let env = Env::default();
+    let (client, game_id) = setup_seasons(&env);
+    let player = Address::generate(&env);
+    submit_at(&env, &client, &player, 40, 10);
+
+    assert_eq!(client.get_current_season(&game_id), None);
+    let board = client.get_global_leaderboard(&game_id, &0, &10);
+    assert_eq!(board.len(), 1);
+    assert_eq!(board, client.get_all_time_leaderboard(&game_id, &0, &10));
+}
+
+#[test]
+fn test_season_rollover_and_archive() {
+    let env = Env::default();
+    let (client, game_id) = setup_seasons(&env);
+    let (alice, bob) = (Address::generate(&env), Address::generate(&env));
+    submit_at(&env, &client, &alice, 90, 10);
+
+    env.ledger().set_timestamp(100);
+    let season = client.start_season(&game_id, &1, &100);
+    assert_eq!(season.end_time, 200);
+    submit_at(&env, &client, &bob, 50, 150);
+
+    // Only scores from the running season count on the global board
+    let board = client.get_global_leaderboard(&game_id, &0, &10);
+    assert_eq!(board.len(), 1);
+    assert_eq!(board.get(0).unwrap().player_id, bob);
+    assert_eq!(client.get_all_time_leaderboard(&game_id, &0, &10).len(), 2);
+
+    // A new season can't start while the current one runs
+    assert_eq!(client.try_start_season(&game_id, &2, &100), Err(Ok(Error::SeasonActive)));
+
+    // Reading after the end archives the season
+    env.ledger().set_timestamp(200);
+    assert_eq!(client.get_current_season(&game_id), None);
+    assert_eq!(client.get_global_leaderboard(&game_id, &0, &10).len(), 0);
+    assert_eq!(client.get_archived_seasons(&game_id), Vec::from_array(&env, [1]));
+    assert!(client.get_season(&game_id, &1).archived);
+
+    client.start_season(&game_id, &2, &100);
+    submit_at(&env, &client, &alice, 95, 210);
+    assert_eq!(client.get_global_leaderboard(&game_id, &0, &10).get(0).unwrap().player_id, alice);
+
+    // The archived season keeps its standings
+    let archived = client.get_season_leaderboard(&game_id, &1, &0, &10);
+    assert_eq!(archived.len(), 1);
+    assert_eq!(archived.get(0).unwrap().player_id, bob);
+}
+
+#[test]
+fn test_start_season_validation() {
+    let env = Env::default();
+    let (client, game_id) = setup_seasons(&env);
+
+    assert_eq!(client.try_start_season(&game_id, &1, &0), Err(Ok(Error::InvalidParameters)));
+    client.start_season(&game_id, &5, &10);
+
+    // Season ids must increase
+    env.ledger().set_timestamp(20);
+    assert_eq!(client.try_start_season(&game_id, &5, &10), Err(Ok(Error::InvalidParameters)));
+    assert_eq!(
+        client.try_get_season_leaderboard(&game_id, &4, &0, &10),
+        Err(Ok(Error::SeasonNotFound))
+    );
+}
+
+#[test]
+fn test_player_and_global_rank() {
+    let env = Env::default();
+    let (client, tournament_id) = setup_tournament(&env, TieBreak::EarliestFirst);
+    let scores: [u64; 70] = core::array::from_fn(|i| (i as u64 * 37) % 101);
+    let (players, _) = submit_scores(&env, &client, &tournament_id, &scores);
+    let game_id = String::from_str(&env, "game");
+
+    // Spread across several buckets, each lookup matches the paged board
+    let board = client.get_leaderboard(&tournament_id, &0, &100);
+    for entry in board.iter() {
+        let expected = Some((entry.rank, entry.score));
+        assert_eq!(client.get_player_rank(&tournament_id, &entry.player_id), expected);
+        assert_eq!(client.get_global_rank(&game_id, &entry.player_id), expected);
+    }
+
+    let stranger = Address::generate(&env);
+    assert_eq!(client.get_player_rank(&tournament_id, &stranger), None);
+    assert_eq!(client.get_global_rank(&game_id, &stranger), None);
+    assert_eq!(
+        client.try_get_player_rank(&String::from_str(&env, "missing"), &players.get(0).unwrap()),
+        Err(Ok(Error::TournamentNotFound))
+    );
+}
+
+#[test]
+fn test_seed_bracket_from_leaderboard() {
+    let env = Env::default();
+    let (client, game_id) = setup_seasons(&env);
+    let players: [Address; 6] = core::array::from_fn(|_| Address::generate(&env));
+    for (i, player) in players.iter().enumerate() {
+        submit_at(&env, &client, player, 100 - i as u64 * 10, 10 + i as u64);
+    }
+
+    let bracket_id = String::from_str(&env, "playoffs");
+    let bracket = client.seed_bracket_from_leaderboard(&bracket_id, &game_id, &4);
+    assert_eq!(bracket.seeds, Vec::from_slice(&env, &players[..4]));
+    let pairs: [(u32, u32); 2] = [(1, 4), (2, 3)];
+    for (i, (high, low)) in pairs.iter().enumerate() {
+        let game = bracket.matches.get(i as u32).unwrap();
+        assert_eq!((game.high_seed, game.low_seed), (*high, *low));
+        assert_eq!(game.high, Some(players[*high as usize - 1].clone()));
+        assert_eq!(game.low, Some(players[*low as usize - 1].clone()));
+    }
+    assert_eq!(client.get_bracket(&bracket_id), bracket);
+    assert_eq!(
+        client.try_seed_bracket_from_leaderboard(&bracket_id, &game_id, &4),
+        Err(Ok(Error::BracketExists))
+    );
+
+    // Six players fill a bracket of eight, and the top two seeds get byes
+    let bracket = client.seed_bracket_from_leaderboard(&String::from_str(&env, "open"), &game_id, &16);
+    assert_eq!(bracket.matches.len(), 7);
+    assert_eq!(bracket.matches.iter().filter(|game| game.round == 1).count(), 4);
+    assert_eq!(bracket.matches.get(0).unwrap().low, None);
+    assert_eq!(bracket.matches.get(1).unwrap().low, None);
+    assert_eq!(bracket.matches.get(2).unwrap().low, Some(players[5].clone()));
+}
+
+#[test]
+fn test_tournament_bracket_elimination() {
+    let env = Env::default();
+    let (client, tournament_id) = setup_tournament(&env, TieBreak::EarliestFirst);
+    let players: [Address; 3] = core::array::from_fn(|_| Address::generate(&env));
+    for player in players.iter() {
+        client.register_for_tournament(player, &tournament_id);
+    }
+    assert_eq!(
+        client.try_get_bracket(&tournament_id),
+        Err(Ok(Error::BracketNotFound))
+    );
+
+    // Three players fill a bracket of four, so the first seed waits in the final
+    let bracket = client.generate_bracket(&tournament_id);
+    assert_eq!(bracket.seeds, Vec::from_slice(&env, &players));
+    assert_eq!(bracket.matches.len(), 3);
+    let bye = bracket.matches.get(0).unwrap();
+    assert_eq!(bye.winner, Some(players[0].clone()));
+    let semi = bracket.matches.get(1).unwrap();
+    let last = bracket.matches.get(2).unwrap();
+    assert_eq!((last.round, last.high.clone(), last.low.clone()), (2, Some(players[0].clone()), None));
+    assert_eq!(
+        client.try_report_match_result(&last.id, &players[0]),
+        Err(Ok(Error::MatchNotReady))
+    );
+    assert_eq!(
+        client.try_report_match_result(&semi.id, &players[0]),
+        Err(Ok(Error::InvalidParameters))
+    );
+
+    // The third seed's upset carries their seed into the final
+    let bracket = client.report_match_result(&semi.id, &players[2]);
+    let last = bracket.matches.get(2).unwrap();
+    assert_eq!((last.low_seed, last.low.clone()), (3, Some(players[2].clone())));
+    assert_eq!(
+        client.try_report_match_result(&semi.id, &players[1]),
+        Err(Ok(Error::MatchDecided))
+    );
+
+    let bracket = client.report_match_result(&last.id, &players[2]);
+    assert_eq!(bracket.champion, Some(players[2].clone()));
+    assert_eq!(client.get_bracket(&tournament_id), bracket);
+    assert_eq!(client.try_generate_bracket(&tournament_id), Err(Ok(Error::BracketExists)));
+    assert_eq!(client.try_report_match_result(&999, &players[0]), Err(Ok(Error::MatchNotFound)));
+}
+
+fn setup_team_tournament(env: &Env, scoring: TeamScoring) -> (GameLeaderboardContractClient<'_>, String) {
+    let (client, creator) = setup_with_creator(env);
+    let tournament_id = String::from_str(env, "teams");
+    client.create_tournament(
+        &creator,
+        &tournament_id,
+        &String::from_str(env, "game"),
+        &10,
+        &1000,
+        &100,
+        &ScoringMode::Highest,
+        &TieBreak::EarliestFirst,
+        &None,
+        &None,
+    );
+    client.enable_teams(&tournament_id, &TeamConfig { scoring, max_size: 2 });
+    (client, tournament_id)
+}
+
+#[test]
+fn test_team_membership() {
+    let env = Env::default();
+    let (client, tournament_id) = setup_team_tournament(&env, TeamScoring::Sum);
+    let players: [Address; 4] = core::array::from_fn(|_| Address::generate(&env));
+    let red = String::from_str(&env, "red");
+
+    assert_eq!(
+        client.try_create_team(&tournament_id, &red, &players[0]),
+        Err(Ok(Error::NotRegistered))
+    );
+    for player in players.iter() {
+        client.register_for_tournament(player, &tournament_id);
+    }
+    client.create_team(&tournament_id, &red, &players[0]);
+    assert_eq!(
+        client.try_create_team(&tournament_id, &red, &players[1]),
+        Err(Ok(Error::TeamExists))
+    );
+    let team = client.join_team(&tournament_id, &red, &players[1]);
+    assert_eq!(team.members, Vec::from_slice(&env, &players[..2]));
+    assert_eq!(client.try_join_team(&tournament_id, &red, &players[2]), Err(Ok(Error::TeamFull)));
+    assert_eq!(
+        client.try_create_team(&tournament_id, &String::from_str(&env, "blue"), &players[1]),
+        Err(Ok(Error::AlreadyInTeam))
+    );
+    assert_eq!(client.get_player_team(&tournament_id, &players[1]), Some(red));
+
+    // Players without a team can't submit
+    env.ledger().set_timestamp(20);
+    assert_eq!(
+        client.try_submit_score(&tournament_id, &players[3], &10, &None, &0, &None),
+        Err(Ok(Error::NotInTeam))
+    );
+
+    // Teams can only be enabled before the start
+    assert_eq!(
+        client.try_enable_teams(&tournament_id, &TeamConfig { scoring: TeamScoring::Best, max_size: 2 }),
+        Err(Ok(Error::TournamentNotActive))
+    );
+}
+
+#[test]
+fn test_team_leaderboard() {
+    for scoring in [TeamScoring::Sum, TeamScoring::Best] {
+        let env = Env::default();
+        let (client, tournament_id) = setup_team_tournament(&env, scoring);
+        let players: [Address; 4] = core::array::from_fn(|_| Address::generate(&env));
+        let (red, blue) = (String::from_str(&env, "red"), String::from_str(&env, "blue"));
+        for player in players.iter() {
+            client.register_for_tournament(player, &tournament_id);
+        }
+        client.create_team(&tournament_id, &red, &players[0]);
+        client.join_team(&tournament_id, &red, &players[1]);
+        client.create_team(&tournament_id, &blue, &players[2]);
+        client.join_team(&tournament_id, &blue, &players[3]);
+
+        env.ledger().set_timestamp(20);
+        for (player, score) in players.iter().zip([30u64, 30, 50, 5]) {
+            client.submit_score(&tournament_id, player, &score, &None, &0, &None);
+        }
+
+        // Red sums to 60 against blue's 55, but blue has the best single score
+        let board = client.get_team_leaderboard(&tournament_id, &0, &10);
+        let expected = match scoring {
+            TeamScoring::Sum => [(red.clone(), 60), (blue.clone(), 55)],
+            TeamScoring::Best => [(blue.clone(), 50), (red.clone(), 30)],
+        };
+        for (i, (team_id, score)) in expected.iter().enumerate() {
+            let entry = board.get(i as u32).unwrap();
+            assert_eq!((&entry.team_id, entry.score, entry.rank), (team_id, *score, i as u32 + 1));
+        }
+    }
+}
+
+#[test]
+fn test_recurring_tournament_instances() {
+    let env = Env::default();
+    let (client, creator) = setup_with_creator(&env);
+    let template = TournamentTemplate {
+        id: String::from_str(&env, "daily"),
+        game_id: String::from_str(&env, "game"),
+        creator,
+        max_entries: 100,
+        scoring_mode: ScoringMode::Highest,
+        tie_break: TieBreak::EarliestFirst,
+        server_key: None,
+        max_submissions_per_player: 0,
+        min_interval_secs: 0,
+        max_valid_score: 0,
+    };
+    env.ledger().set_timestamp(1_000);
+    client.create_recurring_tournament(&template, &86_400);
+    assert_eq!(
+        client.try_create_recurring_tournament(&template, &86_400),
+        Err(Ok(Error::TournamentExists))
+    );
+
+    let first = client.get_current_instance(&template.id);
+    assert_eq!(first.id, String::from_str(&env, "daily#0"));
+    assert_eq!((first.start_time, first.end_time), (1_000, 87_399));
+    let player = Address::generate(&env);
+    client.register_for_tournament(&player, &first.id);
+    client.submit_score(&first.id, &player, &50, &None, &0, &None);
+
+    // The third day starts with a fresh leaderboard; nobody looked up the second
+    env.ledger().set_timestamp(1_000 + 2 * 86_400 + 5);
+    let third = client.get_current_instance(&template.id);
+    assert_eq!(third.id, String::from_str(&env, "daily#2"));
+    assert_eq!(client.get_current_instance(&template.id), third);
+    assert_eq!(client.get_leaderboard(&third.id, &0, &10).len(), 0);
+    assert_eq!(client.get_leaderboard(&first.id, &0, &10).len(), 1);
+
+    assert_eq!(client.get_previous_instances(&template.id, &5), Vec::from_array(&env, [first]));
+    assert_eq!(client.get_previous_instances(&template.id, &0).len(), 0);
+    assert_eq!(
+        client.try_get_current_instance(&String::from_str(&env, "weekly")),
+        Err(Ok(Error::RecurringNotFound))
+    );
+}
+
+#[test]
+fn test_audit_log() {
+    let env = Env::default();
+    let (client, creator) = setup_with_creator(&env);
+    let tournament_id = String::from_str(&env, "audited");
+    env.ledger().set_timestamp(5);
+    client.create_tournament(
+        &creator,
+        &tournament_id,
+        &String::from_str(&env, "game"),
+        &10,
+        &1000,
+        &100,
+        &ScoringMode::Highest,
+        &TieBreak::EarliestFirst,
+        &None,
+        &None,
+    );
+    client.enable_teams(&tournament_id, &TeamConfig { scoring: TeamScoring::Best, max_size: 3 });
+    env.ledger().set_timestamp(20);
+    client.update_tournament(&tournament_id, &2000, &50);
+    client.cancel_tournament(&creator, &tournament_id);
+
+    let actions = [
+        AuditAction::Created,
+        AuditAction::TeamsEnabled(TeamScoring::Best, 3),
+        AuditAction::Updated(2000, 50),
+        AuditAction::Cancelled,
+    ];
+    assert_eq!(client.get_audit_log_count(&tournament_id), 4);
+    let log = client.get_audit_log(&tournament_id, &0, &10);
+    for (entry, action) in log.iter().zip(actions.iter()) {
+        assert_eq!(entry.action, *action);
+        assert_eq!(entry.actor, Some(creator.clone()));
+    }
+    assert_eq!(log.get(0).unwrap().timestamp, 5);
+    assert_eq!(log.get(3).unwrap().timestamp, 20);
+
+    let page = client.get_audit_log(&tournament_id, &1, &3);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap().action, AuditAction::Cancelled);
+    assert_eq!(
+        client.try_get_audit_log(&String::from_str(&env, "missing"), &0, &10),
+        Err(Ok(Error::TournamentNotFound))
+    );
+}
+
+/// Keys of the version 1 layout, encoded like the contract's own
+#[soroban_sdk::contracttype]
+#[derive(Clone)]
+enum V1Key {
+    Tournament(String),
+    TournamentList,
+    GlobalLeaderboard(String),
+    PlayerScores(Address),
+}
+
+/// Tournament as the version 1 layout stored it, with its leaderboard inline
+#[soroban_sdk::contracttype]
+#[derive(Clone)]
+struct V1Tournament {
+    id: String,
+    game_id: String,
+    start_time: u64,
+    end_time: u64,
+    max_entries: u32,
+    entries: Vec<V1Entry>,
+}
+
+#[soroban_sdk::contracttype]
+#[derive(Clone)]
+struct V1Entry {
+    player_id: Address,
+    score: u64,
+    rank: u32,
+}
+
+#[soroban_sdk::contracttype]
+#[derive(Clone)]
+struct V1PlayerScore {
+    score: u64,
+    timestamp: u64,
+    game_id: String,
+    tournament_id: String,
+}
+
+#[test]
+fn test_migrate_v1_to_v2() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(GameLeaderboardContract, ());
+    let client = GameLeaderboardContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let game_id = String::from_str(&env, "game");
+    let tournament_id = String::from_str(&env, "legacy");
+    let players = [Address::generate(&env), Address::generate(&env), Address::generate(&env)];
+    let entry = |i: usize, score: u64| V1Entry { player_id: players[i].clone(), score, rank: i as u32 + 1 };
+    let v1_tournament = V1Tournament {
+        id: tournament_id.clone(),
+        game_id: game_id.clone(),
+        start_time: 0,
+        end_time: 1000,
+        max_entries: 10,
+        entries: Vec::from_array(&env, [entry(0, 70), entry(1, 50)]),
+    };
+    let history = Vec::from_array(
+        &env,
+        [V1PlayerScore {
+            score: 70,
+            timestamp: 3,
+            game_id: game_id.clone(),
+            tournament_id: tournament_id.clone(),
+        }],
+    );
+    env.as_contract(&contract_id, || {
+        let instance = env.storage().instance();
+        instance.set(&V1Key::Tournament(tournament_id.clone()), &v1_tournament);
+        instance.set(&V1Key::TournamentList, &Vec::from_array(&env, [tournament_id.clone()]));
+        instance.set(
+            &V1Key::GlobalLeaderboard(game_id.clone()),
+            &Vec::from_array(&env, [entry(0, 70), entry(1, 50), entry(2, 40)]),
+        );
+        instance.set(&V1Key::PlayerScores(players[0].clone()), &history);
+    });
+
+    // Upgraded contracts are initialized first, which leaves their data on version 1
+    client.initialize(&admin);
+    assert_eq!(client.try_get_tournament_info(&tournament_id), Err(Ok(Error::TournamentNotFound)));
+    assert_eq!(client.try_migrate_v1_to_v2(&0), Err(Ok(Error::InvalidParameters)));
+
+    // The global board and the tournament's entries move a few at a time
+    assert_eq!(client.migrate_v1_to_v2(&2), 1);
+    assert_eq!(client.get_all_time_leaderboard(&game_id, &0, &10).len(), 2);
+    assert_eq!(client.migrate_v1_to_v2(&2), 1);
+    assert_eq!(client.try_get_tournament_info(&tournament_id), Err(Ok(Error::TournamentNotFound)));
+    assert_eq!(client.migrate_v1_to_v2(&2), 0);
+
+    let tournament = Tournament {
+        id: tournament_id.clone(),
+        game_id: game_id.clone(),
+        creator: admin.clone(),
+        start_time: 0,
+        end_time: 1000,
+        max_entries: 10,
+        scoring_mode: ScoringMode::Highest,
+        tie_break: TieBreak::EarliestFirst,
+        server_key: None,
+        cancelled: false,
+        max_submissions_per_player: 0,
+        min_interval_secs: 0,
+        max_valid_score: 0,
+    };
+    assert_eq!(client.get_tournament_info(&tournament_id), tournament);
+    assert_eq!(client.get_tournaments(), Vec::from_array(&env, [tournament_id.clone()]));
+    let board = client.get_leaderboard(&tournament_id, &0, &10);
+    assert_eq!(board.len(), 2);
+    assert_eq!((board.get(1).unwrap().player_id, board.get(1).unwrap().score), (players[1].clone(), 50));
+    let global = client.get_all_time_leaderboard(&game_id, &0, &10);
+    assert_eq!(global.len(), 3);
+    assert_eq!((global.get(2).unwrap().player_id, global.get(2).unwrap().rank), (players[2].clone(), 3));
+    let upgraded = client.get_player_history(&players[0]);
+    assert_eq!(upgraded.len(), 1);
+    assert_eq!((upgraded.get(0).unwrap().score, upgraded.get(0).unwrap().proof), (70, None));
+    env.as_contract(&contract_id, || {
+        let instance = env.storage().instance();
+        assert!(!instance.has(&V1Key::Tournament(tournament_id.clone())));
+        assert!(!instance.has(&V1Key::TournamentList));
+        assert!(!instance.has(&V1Key::GlobalLeaderboard(game_id.clone())));
+    });
+    assert_eq!(client.try_migrate_v1_to_v2(&10), Err(Ok(Error::AlreadyMigrated)));
+
+    // Migrated players are registered and keep playing
+    env.ledger().set_timestamp(500);
+    assert_eq!(client.submit_score(&tournament_id, &players[1], &90, &None, &0, &None), 1);
+
+    // Contracts initialized on the current layout have nothing to migrate
+    let fresh = GameLeaderboardContractClient::new(&env, &env.register(GameLeaderboardContract, ()));
+    fresh.initialize(&admin);
+    assert_eq!(fresh.try_migrate_v1_to_v2(&10), Err(Ok(Error::AlreadyMigrated)));
+}
+
+#[test]
+fn test_migrate_keeps_history_submitted_after_upgrade() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(GameLeaderboardContract, ());
+    let client = GameLeaderboardContractClient::new(&env, &contract_id);
+    let game_id = String::from_str(&env, "game");
+    let legacy_id = String::from_str(&env, "legacy");
+    let player = Address::generate(&env);
+    let v1_tournament = V1Tournament {
+        id: legacy_id.clone(),
+        game_id: game_id.clone(),
+        start_time: 0,
+        end_time: 1000,
+        max_entries: 10,
+        entries: Vec::from_array(&env, [V1Entry { player_id: player.clone(), score: 70, rank: 1 }]),
+    };
+    let mut history = Vec::new(&env);
+    for i in 0..scores::MAX_PLAYER_HISTORY as u64 {
+        history.push_back(V1PlayerScore {
+            score: i,
+            timestamp: i,
+            game_id: game_id.clone(),
+            tournament_id: legacy_id.clone(),
+        });
+    }
+    env.as_contract(&contract_id, || {
+        let instance = env.storage().instance();
+        instance.set(&V1Key::Tournament(legacy_id.clone()), &v1_tournament);
+        instance.set(&V1Key::TournamentList, &Vec::from_array(&env, [legacy_id.clone()]));
+        instance.set(&V1Key::PlayerScores(player.clone()), &history);
+    });
+    client.initialize(&Address::generate(&env));
+
+    // The player keeps playing between the upgrade and the migration
+    let tournament_id = String::from_str(&env, "t1");
+    client.create_tournament(
+        &approved_creator(&env, &client),
+        &tournament_id,
+        &game_id,
+        &0,
+        &1000,
+        &100,
+        &ScoringMode::Highest,
+        &TieBreak::EarliestFirst,
+        &None,
+        &None,
+    );
+    client.register_for_tournament(&player, &tournament_id);
+    env.ledger().set_timestamp(500);
+    client.submit_score(&tournament_id, &player, &900, &None, &0, &None);
+    assert_eq!(client.migrate_v1_to_v2(&10), 0);
+
+    // The oldest version 1 score makes room for the new one
+    let merged = client.get_player_history(&player);
+    assert_eq!(merged.len(), scores::MAX_PLAYER_HISTORY);
+    assert_eq!(merged.get(0).unwrap().score, 1);
+    let latest = merged.last().unwrap();
+    assert_eq!((latest.score, latest.timestamp, latest.tournament_id), (900, 500, tournament_id));
+}
+
+/// Sign a score submission the way the game server does
+fn sign_score(
+    env: &Env,
+    server: &ed25519_dalek::SigningKey,
+    player: &Address,
+    tournament_id: &String,
+    score: u64,
+    nonce: u64,
+) -> Signature {
+    use ed25519_dalek::Signer;
+    use soroban_sdk::xdr::ToXdr;
+
+    let message = (player.clone(), tournament_id.clone(), score, nonce).to_xdr(env);
+    let mut buffer = [0u8; 256];
+    let bytes = &mut buffer[..message.len() as usize];
+    message.copy_into_slice(bytes);
+    BytesN::from_array(env, &server.sign(bytes).to_bytes())
+}
+
+#[test]
+fn test_signed_score_submission() {
+    let env = Env::default();
+    let (client, creator) = setup_with_creator(&env);
+    let server = ed25519_dalek::SigningKey::from_bytes(&[7; 32]);
+    let tournament_id = String::from_str(&env, "t1");
+    client.create_tournament(
+        &creator,
+        &tournament_id,
+        &String::from_str(&env, "game"),
+        &0,
+        &1000,
+        &100,
+        &ScoringMode::Highest,
+        &TieBreak::EarliestFirst,
+        &None,
+        &Some(BytesN::from_array(&env, &server.verifying_key().to_bytes())),
+    );
+    let player = Address::generate(&env);
+    client.register_for_tournament(&player, &tournament_id);
+    env.ledger().set_timestamp(10);
+
+    let signature = sign_score(&env, &server, &player, &tournament_id, 50, 1);
+    assert_eq!(client.submit_score(&tournament_id, &player, &50, &None, &1, &Some(signature.clone())), 1);
+
+    // The same signed submission can't be replayed, nor an older nonce used
+    assert_eq!(
+        client.try_submit_score(&tournament_id, &player, &50, &None, &1, &Some(signature)),
+        Err(Ok(Error::NonceUsed))
+    );
+    assert_eq!(
+        client.try_submit_score(&tournament_id, &player, &60, &None, &0, &None),
+        Err(Ok(Error::MissingSignature))
+    );
+
+    // A signature over another score doesn't verify
+    let signature = sign_score(&env, &server, &player, &tournament_id, 60, 2);
+    assert!(client
+        .try_submit_score(&tournament_id, &player, &90, &None, &2, &Some(signature.clone()))
+        .is_err());
+    assert_eq!(client.get_player_rank(&tournament_id, &player), Some((1, 50)));
+    client.submit_score(&tournament_id, &player, &60, &None, &2, &Some(signature));
+    assert_eq!(client.get_player_rank(&tournament_id, &player), Some((1, 60)));
+}
+
+#[test]
+fn test_submission_limits() {
+    let env = Env::default();
+    let (client, creator) = setup_with_creator(&env);
+    let tournament_id = String::from_str(&env, "limited");
+    client.create_tournament(
+        &creator,
+        &tournament_id,
+        &String::from_str(&env, "game"),
+        &10,
+        &1000,
+        &100,
+        &ScoringMode::Highest,
+        &TieBreak::EarliestFirst,
+        &None,
+        &None,
+    );
+    let player = Address::generate(&env);
+    client.register_for_tournament(&player, &tournament_id);
+    client.set_submission_limits(&tournament_id, &2, &60, &500);
+    assert_eq!(client.get_tournament_info(&tournament_id).max_valid_score, 500);
+
+    env.ledger().set_timestamp(100);
+    assert_eq!(
+        client.try_set_submission_limits(&tournament_id, &0, &0, &0),
+        Err(Ok(Error::TournamentNotActive))
+    );
+    assert_eq!(
+        client.try_submit_score(&tournament_id, &player, &501, &None, &0, &None),
+        Err(Ok(Error::ScoreOutOfBounds))
+    );
+    client.submit_score(&tournament_id, &player, &500, &None, &0, &None);
+    env.ledger().set_timestamp(159);
+    assert_eq!(
+        client.try_submit_score(&tournament_id, &player, &10, &None, &0, &None),
+        Err(Ok(Error::SubmissionTooSoon))
+    );
+    env.ledger().set_timestamp(160);
+    client.submit_score(&tournament_id, &player, &10, &None, &0, &None);
+    env.ledger().set_timestamp(500);
+    assert_eq!(
+        client.try_submit_score(&tournament_id, &player, &20, &None, &0, &None),
+        Err(Ok(Error::TooManySubmissions))
+    );
+}
+
+#[test]
+fn test_player_stats() {
+    let env = Env::default();
+    let (client, tournament_id) = setup_tournament(&env, TieBreak::EarliestFirst);
+    let game_id = String::from_str(&env, "game");
+    let player = Address::generate(&env);
+    let rival = Address::generate(&env);
+    client.register_for_tournament(&player, &tournament_id);
+    client.submit_score(&tournament_id, &player, &40, &None, &0, &None);
+    client.submit_score(&tournament_id, &player, &80, &None, &0, &None);
+
+    // A second tournament of the same game with a prize pool to finalize
+    let funder = Address::generate(&env);
+    let token = env.register_stellar_asset_contract_v2(Address::generate(&env)).address();
+    soroban_sdk::token::StellarAssetClient::new(&env, &token).mint(&funder, &1000);
+    let cup_id = String::from_str(&env, "cup");
+    client.create_tournament(
+        &approved_creator(&env, &client),
+        &cup_id,
+        &game_id,
+        &0,
+        &1000,
+        &100,
+        &ScoringMode::Highest,
+        &TieBreak::EarliestFirst,
+        &Some(PrizePool {
+            token,
+            funder,
+            amount: 1000,
+            split_bps: Vec::from_array(&env, [10_000]),
+            entry_fee: 0,
+        }),
+        &None,
+    );
+    client.register_for_tournament(&player, &cup_id);
+    client.register_for_tournament(&rival, &cup_id);
+    client.submit_score(&cup_id, &player, &100, &None, &0, &None);
+    client.submit_score(&cup_id, &rival, &90, &None, &0, &None);
+    env.ledger().set_timestamp(1001);
+    client.finalize_tournament(&cup_id);
+
+    let stats = client.get_player_stats(&player, &game_id);
+    assert_eq!(stats.total_games, 3);
+    assert_eq!(stats.best_score, 100);
+    assert_eq!(stats.total_score, 220);
+    assert_eq!(stats.average_score, 73);
+    assert_eq!(stats.tournaments_won, 1);
+
+    let stats = client.get_player_stats(&rival, &game_id);
+    assert_eq!((stats.total_games, stats.best_score, stats.tournaments_won), (1, 90, 0));
+
+    // Tournaments without a prize pool count once their final standings are frozen
+    client.snapshot_leaderboard(&tournament_id);
+    assert_eq!(client.get_player_stats(&player, &game_id).tournaments_won, 2);
+    assert_eq!(client.get_player_stats(&rival, &game_id).tournaments_won, 0);
+
+    // Players who never played a game have empty stats for it
+    let stats = client.get_player_stats(&player, &String::from_str(&env, "other"));
+    assert_eq!((stats.total_games, stats.best_score, stats.average_score), (0, 0, 0));
+}
+
+#[test]
+fn test_creator_approval() {
+    let env = Env::default();
+    let (client, _) = setup_with_creator(&env);
+    let game_id = String::from_str(&env, "game");
+    let creator = Address::generate(&env);
+    let create = |tournament_id: &str| {
+        client.try_create_tournament(
+            &creator,
+            &String::from_str(&env, tournament_id),
+            &game_id,
+            &0,
+            &1000,
+            &100,
+            &ScoringMode::Highest,
+            &TieBreak::EarliestFirst,
+            &None,
+            &None,
+        )
+    };
+    assert_eq!(create("t1"), Err(Ok(Error::Unauthorized)));
+
+    client.approve_creator(&game_id, &creator);
+    assert!(client.is_approved_creator(&game_id, &creator));
+    assert!(!client.is_approved_creator(&String::from_str(&env, "other"), &creator));
+    assert_eq!(create("t1"), Ok(Ok(())));
+
+    // Revoking stops new tournaments and recurring instances, not running ones
+    let template = TournamentTemplate {
+        id: String::from_str(&env, "daily"),
+        game_id: game_id.clone(),
+        creator: creator.clone(),
+        max_entries: 100,
+        scoring_mode: ScoringMode::Highest,
+        tie_break: TieBreak::EarliestFirst,
+        server_key: None,
+        max_submissions_per_player: 0,
+        min_interval_secs: 0,
+        max_valid_score: 0,
+    };
+    client.create_recurring_tournament(&template, &100);
+    client.revoke_creator(&game_id, &creator);
+    assert!(!client.is_approved_creator(&game_id, &creator));
+    assert_eq!(create("t2"), Err(Ok(Error::Unauthorized)));
+    assert_eq!(client.try_get_current_instance(&template.id), Err(Ok(Error::Unauthorized)));
+    assert_eq!(
+        client.try_create_recurring_tournament(&template, &100),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(client.get_tournament_info(&String::from_str(&env, "t1")).creator, creator);
+
+    // Without an admin nobody is approved
+    let fresh = GameLeaderboardContractClient::new(&env, &env.register(GameLeaderboardContract, ()));
+    assert_eq!(fresh.try_approve_creator(&game_id, &creator), Err(Ok(Error::Unauthorized)));
+    assert!(!fresh.is_approved_creator(&game_id, &creator));
+}
+
+#[test]
+fn test_submit_score_from_game() {
+    let env = Env::default();
+    let (client, _) = setup_with_creator(&env);
+    let game_id = String::from_str(&env, "game");
+    let game_contract = Address::generate(&env);
+    let player = Address::generate(&env);
+    let rival = Address::generate(&env);
+
+    // Unregistered contracts can't push scores
+    assert_eq!(
+        client.try_submit_score_from_game(&game_contract, &player, &50),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    client.register_game_contract(&game_id, &game_contract);
+    assert_eq!(client.get_registered_game(&game_contract), Some(game_id.clone()));
+    assert_eq!(client.submit_score_from_game(&game_contract, &player, &50), 1);
+    assert_eq!(client.submit_score_from_game(&game_contract, &rival, &70), 1);
+    assert_eq!(client.get_global_rank(&game_id, &player), Some((2, 50)));
+
+    let history = client.get_player_history(&player);
+    assert_eq!(history.len(), 1);
+    assert_eq!(history.get(0).unwrap().tournament_id, String::from_str(&env, ""));
+    let stats = client.get_player_stats(&player, &game_id);
+    assert_eq!((stats.total_games, stats.best_score), (1, 50));
+
+    client.unregister_game_contract(&game_contract);
+    assert_eq!(client.get_registered_game(&game_contract), None);
+    assert_eq!(
+        client.try_submit_score_from_game(&game_contract, &player, &90),
+        Err(Ok(Error::Unauthorized))
+    );
+}
+
+#[test]
+fn test_leaderboard_snapshot() {
+    let env = Env::default();
+    let (client, tournament_id) = setup_tournament(&env, TieBreak::EarliestFirst);
+    submit_scores(&env, &client, &tournament_id, &[30, 50, 40]);
+    assert_eq!(
+        client.try_snapshot_leaderboard(&tournament_id),
+        Err(Ok(Error::TournamentNotActive))
+    );
+
+    env.ledger().set_timestamp(1001);
+    let hash = client.snapshot_leaderboard(&tournament_id);
+    let snapshot = client.get_leaderboard_snapshot(&tournament_id);
+    assert_eq!(snapshot.hash, hash);
+    assert_eq!(snapshot.locked_at, 1001);
+    assert_eq!(
+        client.try_snapshot_leaderboard(&tournament_id),
+        Err(Ok(Error::SnapshotLocked))
+    );
+
+    let mut standings = client.get_tournament_results(&tournament_id);
+    assert_eq!(standings, snapshot.entries);
+    assert!(client.verify_snapshot(&tournament_id, &standings));
+
+    let mut first = standings.get(0).unwrap();
+    first.score += 1;
+    standings.set(0, first);
+    assert!(!client.verify_snapshot(&tournament_id, &standings));
+    assert!(!client.verify_snapshot(&String::from_str(&env, "missing"), &snapshot.entries));
+}
+
+#[test]
+fn test_lock_predictions_snapshot() {
+    let env = Env::default();
+    let (client, tournament_id) = setup_tournament(&env, TieBreak::EarliestFirst);
+    let creator = client.get_tournament_info(&tournament_id).creator;
+    submit_scores(&env, &client, &tournament_id, &[30, 50]);
+    assert_eq!(
+        client.try_get_predictions_snapshot(&tournament_id),
+        Err(Ok(Error::SnapshotNotFound))
+    );
+
+    // Only the creator or the admin can lock the standings
+    env.ledger().set_timestamp(500);
+    assert_eq!(
+        client.try_lock_predictions_snapshot(&Address::generate(&env), &tournament_id),
+        Err(Ok(Error::Unauthorized))
+    );
+    let hash = client.lock_predictions_snapshot(&creator, &tournament_id);
+    let snapshot = client.get_predictions_snapshot(&tournament_id);
+    assert_eq!((snapshot.hash.clone(), snapshot.locked_at, snapshot.total_entries), (hash, 500, 2));
+    assert_eq!(snapshot.entries, client.get_leaderboard(&tournament_id, &0, &10));
+    assert_eq!(
+        client.try_lock_predictions_snapshot(&creator, &tournament_id),
+        Err(Ok(Error::SnapshotLocked))
+    );
+
+    // Later scores don't move the locked snapshot
+    submit_scores(&env, &client, &tournament_id, &[90]);
+    assert_eq!(client.get_predictions_snapshot(&tournament_id), snapshot);
+}
+
+#[test]
+fn test_snapshot_keeps_top_of_large_board() {
+    let env = Env::default();
+    let (client, tournament_id, token, _funder) =
+        setup_prize_tournament(&env, TieBreak::EarliestFirst, 1000, &[10_000], 0);
+    let mut scores = [0u64; snapshots::MAX_SNAPSHOT_ENTRIES as usize + 5];
+    for (i, score) in scores.iter_mut().enumerate() {
+        *score = i as u64 + 1;
+    }
+    client.update_tournament(&tournament_id, &1000, &(scores.len() as u32));
+    let (players, _) = submit_scores(&env, &client, &tournament_id, &scores);
+
+    // Only the top of the board is stored, and prizes are still paid from it
+    env.ledger().set_timestamp(1001);
+    let payouts = client.finalize_tournament(&tournament_id);
+    let snapshot = client.get_leaderboard_snapshot(&tournament_id);
+    assert_eq!(snapshot.entries.len(), snapshots::MAX_SNAPSHOT_ENTRIES);
+    assert_eq!(snapshot.total_entries, snapshots::MAX_SNAPSHOT_ENTRIES + 5);
+    let winner = players.get(players.len() - 1).unwrap();
+    assert_eq!(snapshot.entries.get(0).unwrap().player_id, winner);
+    assert_eq!(payouts, Vec::from_array(&env, [(winner.clone(), 1000)]));
+    assert_eq!(token.balance(&winner), 1000);
+    assert!(client.verify_snapshot(&tournament_id, &snapshot.entries));
+}
+
+#[test]
+fn test_prize_pool_pays_at_most_snapshot_ranks() {
+    let env = Env::default();
+    let (client, creator) = setup_with_creator(&env);
+    // One rank more than the final snapshot keeps, adding up to the whole pool
+    let mut split_bps = Vec::from_array(&env, [10_000 - snapshots::MAX_SNAPSHOT_ENTRIES]);
+    for _ in 0..snapshots::MAX_SNAPSHOT_ENTRIES {
+        split_bps.push_back(1);
+    }
+    let pool = PrizePool {
+        token: Address::generate(&env),
+        funder: Address::generate(&env),
+        amount: 0,
+        split_bps,
+        entry_fee: 10,
+    };
+    assert_eq!(
+        client.try_create_tournament(
+            &creator,
+            &String::from_str(&env, "t1"),
+            &String::from_str(&env, "game"),
+            &0,
+            &1000,
+            &1000,
+            &ScoringMode::Highest,
+            &TieBreak::EarliestFirst,
+            &Some(pool),
+            &None,
+        ),
+        Err(Ok(Error::InvalidParameters))
+    );
+}
+
+#[test]
+fn test_elo_ratings() {
+    let env = Env::default();
+    let (client, reporter) = setup_with_creator(&env);
+    let game_id = String::from_str(&env, "game");
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let carol = Address::generate(&env);
+    assert_eq!(client.get_rating(&game_id, &alice), 1200);
+
+    // Even players swap half the K-factor
+    assert_eq!(client.report_head_to_head(&reporter, &game_id, &alice, &bob), (1216, 1184));
+    // The favourite gains less than the underdog would
+    assert_eq!(client.report_head_to_head(&reporter, &game_id, &alice, &bob), (1231, 1169));
+    client.set_k_factor(&game_id, &16);
+    assert_eq!(client.report_head_to_head(&reporter, &game_id, &bob, &carol), (1178, 1191));
+
+    let board = client.get_rating_leaderboard(&game_id, &0, &10);
+    assert_eq!(board.len(), 3);
+    assert_eq!((board.get(0).unwrap().player_id, board.get(0).unwrap().score), (alice.clone(), 1231));
+    assert_eq!((board.get(2).unwrap().player_id, board.get(2).unwrap().rank), (bob.clone(), 3));
+
+    // Only approved creators and the game's own contract may report
+    assert_eq!(
+        client.try_report_head_to_head(&Address::generate(&env), &game_id, &alice, &bob),
+        Err(Ok(Error::Unauthorized))
+    );
+    let game_contract = Address::generate(&env);
+    client.register_game_contract(&game_id, &game_contract);
+    client.report_head_to_head(&game_contract, &game_id, &bob, &alice);
+    assert_eq!(
+        client.try_report_head_to_head(&reporter, &game_id, &alice, &alice),
+        Err(Ok(Error::InvalidParameters))
+    );
+    assert_eq!(client.try_set_k_factor(&game_id, &0), Err(Ok(Error::InvalidParameters)));
+    assert_eq!(client.try_set_k_factor(&game_id, &401), Err(Ok(Error::InvalidParameters)));
+
+    // The largest K-factor moves at most its full value
+    client.set_k_factor(&game_id, &400);
+    assert_eq!(client.get_k_factor(&game_id), 400);
+    let rookie = Address::generate(&env);
+    let (carol_rating, rookie_rating) = client.report_head_to_head(&reporter, &game_id, &carol, &rookie);
+    assert!(carol_rating > 1191 && carol_rating - 1191 <= 400);
+    assert_eq!(1200 - rookie_rating, carol_rating - 1191);
+}
+
+/// Create tournament "t1" with a prize pool of `amount` tokens split by
+/// `split_bps`, minting the pool to its funder first
+fn setup_prize_tournament<'a>(
+    env: &'a Env,
+    tie_break: TieBreak,
+    amount: i128,
+    split_bps: &[u32],
+    entry_fee: i128,
+) -> (GameLeaderboardContractClient<'a>, String, soroban_sdk::token::Client<'a>, Address) {
+    let (client, creator) = setup_with_creator(env);
+    let token_id = env.register_stellar_asset_contract_v2(Address::generate(env)).address();
+    let funder = Address::generate(env);
+    soroban_sdk::token::StellarAssetClient::new(env, &token_id).mint(&funder, &amount);
+
+    let tournament_id = String::from_str(env, "t1");
+    let pool = PrizePool {
+        token: token_id.clone(),
+        funder: funder.clone(),
+        amount,
+        split_bps: Vec::from_slice(env, split_bps),
+        entry_fee,
+    };
+    client.create_tournament(
+        &creator,
+        &tournament_id,
+        &String::from_str(env, "game"),
+        &0,
+        &1000,
+        &100,
+        &ScoringMode::Highest,
+        &tie_break,
+        &Some(pool),
+        &None,
+    );
+    (client, tournament_id, soroban_sdk::token::Client::new(env, &token_id), funder)
+}
+
+#[test]
+fn test_registration() {
+    let env = Env::default();
+    let (client, tournament_id) = setup_tournament(&env, TieBreak::EarliestFirst);
+    client.update_tournament(&tournament_id, &1000, &3);
+    let players = [Address::generate(&env), Address::generate(&env), Address::generate(&env)];
+    for player in players.iter() {
+        client.register_for_tournament(player, &tournament_id);
+    }
+    assert_eq!(
+        client.try_register_for_tournament(&players[0], &tournament_id),
+        Err(Ok(Error::AlreadyRegistered))
+    );
+    assert_eq!(
+        client.try_register_for_tournament(&Address::generate(&env), &tournament_id),
+        Err(Ok(Error::TournamentFull))
+    );
+
+    // Players are listed in registration order, a page at a time
+    assert_eq!(
+        client.get_registered_players(&tournament_id, &0, &2),
+        Vec::from_array(&env, [players[0].clone(), players[1].clone()])
+    );
+    assert_eq!(
+        client.get_registered_players(&tournament_id, &1, &2),
+        Vec::from_array(&env, [players[2].clone()])
+    );
+    assert!(client.get_registered_players(&tournament_id, &2, &2).is_empty());
+    assert_eq!(
+        client.try_get_registered_players(&String::from_str(&env, "missing"), &0, &2),
+        Err(Ok(Error::TournamentNotFound))
+    );
+
+    // Only registered players may submit, and only until the tournament ends
+    env.ledger().set_timestamp(10);
+    assert_eq!(
+        client.try_submit_score(&tournament_id, &Address::generate(&env), &10, &None, &0, &None),
+        Err(Ok(Error::NotRegistered))
+    );
+    env.ledger().set_timestamp(1001);
+    assert_eq!(
+        client.try_register_for_tournament(&Address::generate(&env), &tournament_id),
+        Err(Ok(Error::TournamentNotActive))
+    );
+}
+
+#[test]
+fn test_registration_entry_fee() {
+    let env = Env::default();
+    let (client, tournament_id, token, _funder) =
+        setup_prize_tournament(&env, TieBreak::EarliestFirst, 0, &[10_000], 25);
+    let asset = soroban_sdk::token::StellarAssetClient::new(&env, &token.address);
+    let player = Address::generate(&env);
+    asset.mint(&player, &30);
+
+    // The fee moves from the player into the pool
+    client.register_for_tournament(&player, &tournament_id);
+    assert_eq!(token.balance(&player), 5);
+    assert_eq!(token.balance(&client.address), 25);
+    assert_eq!(client.get_prize_pool(&tournament_id).amount, 25);
+
+    // A player who can't pay the fee isn't registered
+    let broke = Address::generate(&env);
+    assert!(client.try_register_for_tournament(&broke, &tournament_id).is_err());
+    assert_eq!(client.get_registered_players(&tournament_id, &0, &10).len(), 1);
+
+    // The winner of an entry-fee-only pool takes the fees
+    env.ledger().set_timestamp(10);
+    client.submit_score(&tournament_id, &player, &50, &None, &0, &None);
+    env.ledger().set_timestamp(1001);
+    client.finalize_tournament(&tournament_id);
+    assert_eq!(token.balance(&player), 30);
+}
+
+#[test]
+fn test_prize_pool_locked_on_create() {
+    let env = Env::default();
+    let (client, tournament_id, token, funder) =
+        setup_prize_tournament(&env, TieBreak::EarliestFirst, 1000, &[6000, 4000], 0);
+
+    assert_eq!(token.balance(&funder), 0);
+    assert_eq!(token.balance(&client.address), 1000);
+    let pool = client.get_prize_pool(&tournament_id);
+    assert_eq!((pool.funder, pool.amount), (funder, 1000));
+    assert_eq!(
+        client.try_get_prize_pool(&String::from_str(&env, "missing")),
+        Err(Ok(Error::NoPrizePool))
+    );
+}
+
+#[test]
+fn test_finalize_tournament_payouts() {
+    let env = Env::default();
+    let (client, tournament_id, token, funder) =
+        setup_prize_tournament(&env, TieBreak::EarliestFirst, 1001, &[5000, 3000, 2000], 0);
+    let (players, _) = submit_scores(&env, &client, &tournament_id, &[50, 80]);
+
+    // Nothing is paid before the tournament ends
+    assert_eq!(
+        client.try_finalize_tournament(&tournament_id),
+        Err(Ok(Error::TournamentNotActive))
+    );
+
+    // The rounding dust goes to first place and the unreached third place is refunded
+    env.ledger().set_timestamp(1001);
+    let payouts = client.finalize_tournament(&tournament_id);
+    let first = players.get(1).unwrap();
+    let second = players.get(0).unwrap();
+    assert_eq!(payouts, Vec::from_array(&env, [(first.clone(), 501), (second.clone(), 300)]));
+    assert_eq!(token.balance(&first), 501);
+    assert_eq!(token.balance(&second), 300);
+    assert_eq!(token.balance(&funder), 200);
+    assert_eq!(token.balance(&client.address), 0);
+
+    assert_eq!(
+        client.try_finalize_tournament(&tournament_id),
+        Err(Ok(Error::AlreadyFinalized))
+    );
+}
+
+#[test]
+fn test_finalize_tournament_shared_ranks_split_evenly() {
+    let env = Env::default();
+    let (client, tournament_id, token, funder) =
+        setup_prize_tournament(&env, TieBreak::Shared, 1000, &[6000, 4000], 0);
+    let (players, _) = submit_scores(&env, &client, &tournament_id, &[50, 80, 50, 50]);
+
+    // The three players tied for second share the second-place prize, the
+    // first of them listed taking the dust
+    env.ledger().set_timestamp(1001);
+    let payouts = client.finalize_tournament(&tournament_id);
+    let expected = [(1, 600), (0, 134), (2, 133), (3, 133)];
+    assert_eq!(payouts.len(), 4);
+    for (i, (player, share)) in expected.iter().enumerate() {
+        let player = players.get(*player).unwrap();
+        assert_eq!(payouts.get(i as u32).unwrap(), (player.clone(), *share));
+        assert_eq!(token.balance(&player), *share);
+    }
+    assert_eq!(token.balance(&funder), 0);
+    assert_eq!(token.balance(&client.address), 0);
+}
+
+#[test]
+fn test_finalize_cancelled_tournament() {
+    let env = Env::default();
+    let (client, tournament_id, token, funder) =
+        setup_prize_tournament(&env, TieBreak::EarliestFirst, 1000, &[10_000], 0);
+    submit_scores(&env, &client, &tournament_id, &[50]);
+
+    let creator = client.get_tournament_info(&tournament_id).creator;
+    client.cancel_tournament(&creator, &tournament_id);
+    assert_eq!(token.balance(&funder), 1000);
+
+    env.ledger().set_timestamp(1001);
+    assert_eq!(
+        client.try_finalize_tournament(&tournament_id),
+        Err(Ok(Error::TournamentCancelled))
+    );
+    assert_eq!(token.balance(&client.address), 0);
+}
+
+#[test]
+fn test_claim_refund_after_cancel() {
+    let env = Env::default();
+    let (client, tournament_id, token, funder) =
+        setup_prize_tournament(&env, TieBreak::EarliestFirst, 1000, &[10_000], 50);
+    let asset = soroban_sdk::token::StellarAssetClient::new(&env, &token.address);
+    let players = [Address::generate(&env), Address::generate(&env)];
+    for player in players.iter() {
+        asset.mint(player, &50);
+        client.register_for_tournament(player, &tournament_id);
+        assert_eq!(token.balance(player), 0);
+    }
+    assert_eq!(client.get_prize_pool(&tournament_id).amount, 1100);
+    assert_eq!(
+        client.try_claim_refund(&players[0], &tournament_id),
+        Err(Ok(Error::NoRefund))
+    );
+
+    // Cancelling returns the pool to the funder and keeps the fees for claims
+    let creator = client.get_tournament_info(&tournament_id).creator;
+    client.cancel_tournament(&creator, &tournament_id);
+    assert_eq!(token.balance(&funder), 1000);
+    assert_eq!(token.balance(&client.address), 100);
+
+    assert_eq!(client.claim_refund(&players[0], &tournament_id), 50);
+    assert_eq!(token.balance(&players[0]), 50);
+    assert_eq!(
+        client.try_claim_refund(&players[0], &tournament_id),
+        Err(Ok(Error::NoRefund))
+    );
+    assert_eq!(
+        client.try_claim_refund(&Address::generate(&env), &tournament_id),
+        Err(Ok(Error::NoRefund))
+    );
+    client.claim_refund(&players[1], &tournament_id);
+    assert_eq!(token.balance(&client.address), 0);
+    assert_eq!(client.get_prize_pool(&tournament_id).amount, 0);
+}
+
+#[test]
+fn test_cancel_tournament() {
+    let env = Env::default();
+    let (client, tournament_id) = setup_tournament(&env, TieBreak::EarliestFirst);
+    let creator = client.get_tournament_info(&tournament_id).creator;
+
+    assert_eq!(
+        client.try_cancel_tournament(&Address::generate(&env), &tournament_id),
+        Err(Ok(Error::Unauthorized))
+    );
+    client.cancel_tournament(&creator, &tournament_id);
+    assert!(client.get_tournament_info(&tournament_id).cancelled);
+    assert_eq!(
+        client.try_cancel_tournament(&creator, &tournament_id),
+        Err(Ok(Error::TournamentCancelled))
+    );
+    assert_eq!(
+        client.try_register_for_tournament(&Address::generate(&env), &tournament_id),
+        Err(Ok(Error::TournamentCancelled))
+    );
+
+    // An ended tournament can no longer be cancelled
+    let other_id = String::from_str(&env, "t2");
+    client.create_tournament(
+        &creator,
+        &other_id,
+        &String::from_str(&env, "game"),
+        &0,
+        &1000,
+        &100,
+        &ScoringMode::Highest,
+        &TieBreak::EarliestFirst,
+        &None,
+        &None,
+    );
+    env.ledger().set_timestamp(1001);
+    assert_eq!(
+        client.try_cancel_tournament(&creator, &other_id),
+        Err(Ok(Error::TournamentNotActive))
+    );
+}
+
+#[test]
+fn test_update_tournament() {
+    let env = Env::default();
+    let (client, tournament_id, _token, _funder) =
+        setup_prize_tournament(&env, TieBreak::EarliestFirst, 1000, &[5000, 3000, 2000], 0);
+    submit_scores(&env, &client, &tournament_id, &[50, 80]);
+
+    client.update_tournament(&tournament_id, &2000, &10);
+    let tournament = client.get_tournament_info(&tournament_id);
+    assert_eq!((tournament.end_time, tournament.max_entries), (2000, 10));
+
+    // The end time must follow the start, and the cap must cover the
+    // registered players and the paid ranks
+    assert_eq!(
+        client.try_update_tournament(&tournament_id, &0, &10),
+        Err(Ok(Error::InvalidParameters))
+    );
+    assert_eq!(
+        client.try_update_tournament(&tournament_id, &2000, &1),
+        Err(Ok(Error::InvalidParameters))
+    );
+    assert_eq!(
+        client.try_update_tournament(&tournament_id, &2000, &2),
+        Err(Ok(Error::InvalidParameters))
+    );
+
+    env.ledger().set_timestamp(2001);
+    assert_eq!(
+        client.try_update_tournament(&tournament_id, &3000, &10),
+        Err(Ok(Error::TournamentNotActive))
+    );
+}
@@ -1,23 +1,120 @@
-use soroban_sdk::{contracttype, Env, String, Symbol, Vec};
+use soroban_sdk::{contracttype, Address, BytesN, Env, String, Symbol, Vec};
+use crate::audit;
+use crate::migration;
+use crate::leaderboard;
+use crate::prizes;
+use crate::registration;
 use crate::errors::Error;
-use crate::types::{LeaderboardEntry, Tournament};
+use crate::types::{AuditAction, LeaderboardEntry, PrizePool, ScoringMode, TieBreak, Tournament};
+use crate::{TTL_EXTEND_TO, TTL_THRESHOLD};
 
 #[contracttype]
 #[derive(Clone)]
 enum DataKey {
+    Admin,
+    /// Approval of an address to create tournaments for a game
+    Creator(String, Address),
     Tournament(String),
     TournamentList,
 }
 
-/// Create a new tournament
+/// Set the contract admin, who approves tournament creators and may cancel any tournament
+pub fn initialize(env: &Env, admin: Address) -> Result<(), Error> {
+    if env.storage().instance().has(&DataKey::Admin) {
+        return Err(Error::AlreadyInitialized);
+    }
+    admin.require_auth();
+    env.storage().instance().set(&DataKey::Admin, &admin);
+    migration::init_storage_version(env);
+    Ok(())
+}
+
+/// Get the contract admin, if one has been set
+pub fn get_admin(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::Admin)
+}
+
+/// Let an address create tournaments for a game, admin only
+pub fn approve_creator(env: &Env, game_id: String, creator: Address) -> Result<(), Error> {
+    let admin = get_admin(env).ok_or(Error::Unauthorized)?;
+    admin.require_auth();
+
+    let key = DataKey::Creator(game_id.clone(), creator.clone());
+    env.storage().persistent().set(&key, &true);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD, TTL_EXTEND_TO);
+
+    // Emit event
+    env.events().publish(
+        (Symbol::new(env, "creator_approved"), game_id),
+        creator,
+    );
+
+    Ok(())
+}
+
+/// Take back an address's approval to create tournaments for a game, admin only
+///
+/// Tournaments it already created are left running.
+pub fn revoke_creator(env: &Env, game_id: String, creator: Address) -> Result<(), Error> {
+    let admin = get_admin(env).ok_or(Error::Unauthorized)?;
+    admin.require_auth();
+
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Creator(game_id.clone(), creator.clone()));
+
+    // Emit event
+    env.events().publish(
+        (Symbol::new(env, "creator_revoked"), game_id),
+        creator,
+    );
+
+    Ok(())
+}
+
+/// Check whether an address may create tournaments for a game
+///
+/// The admin may create tournaments for every game.
+pub fn is_approved_creator(env: &Env, game_id: String, creator: Address) -> bool {
+    if get_admin(env).as_ref() == Some(&creator) {
+        return true;
+    }
+    let key = DataKey::Creator(game_id, creator);
+    if !env.storage().persistent().has(&key) {
+        return false;
+    }
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD, TTL_EXTEND_TO);
+    true
+}
+
+/// Create a new tournament, optionally escrowing a prize pool from its funder
+///
+/// Only the admin and the creators approved for `game_id` may create
+/// tournaments. `scoring_mode` decides how each player's submissions are
+/// ranked and `tie_break` how equal scores are ordered. With a
+/// `server_key`, every score must be signed by that game server.
 pub fn create_tournament(
     env: &Env,
+    creator: Address,
     tournament_id: String,
     game_id: String,
     start_time: u64,
     end_time: u64,
     max_entries: u32,
+    scoring_mode: ScoringMode,
+    tie_break: TieBreak,
+    prize_pool: Option<PrizePool>,
+    server_key: Option<BytesN<32>>,
 ) -> Result<(), Error> {
+    creator.require_auth();
+    if !is_approved_creator(env, game_id.clone(), creator.clone()) {
+        return Err(Error::Unauthorized);
+    }
+    
     // Check if tournament already exists
     if get_tournament_internal(env, tournament_id.clone()).is_some() {
         return Err(Error::TournamentExists);
@@ -31,23 +128,174 @@ pub fn create_tournament(
     if max_entries == 0 || max_entries > 10000 {
         return Err(Error::InvalidParameters);
     }
+
+    if let Some(pool) = &prize_pool {
+        prizes::validate_prize_pool(pool, max_entries)?;
+    }
     
     let tournament = Tournament {
         id: tournament_id.clone(),
-        game_id: game_id.clone(),
+        game_id,
+        creator,
         start_time,
         end_time,
         max_entries,
-        entries: Vec::new(env),
+        scoring_mode,
+        tie_break,
+        server_key,
+        cancelled: false,
+        max_submissions_per_player: 0,
+        min_interval_secs: 0,
+        max_valid_score: 0,
     };
     
+    add_tournament(env, &tournament);
+    if let Some(pool) = &prize_pool {
+        prizes::lock_prize_pool(env, tournament_id.clone(), pool);
+    }
+    
+    Ok(())
+}
+
+/// Store a new tournament and list it, without checking its creator's authorization
+pub fn add_tournament(env: &Env, tournament: &Tournament) {
+    save_tournament(env, tournament.id.clone(), tournament);
+    add_to_tournament_list(env, tournament.id.clone());
+    audit::record(env, tournament.id.clone(), Some(tournament.creator.clone()), AuditAction::Created);
+
+    // Emit event
+    env.events().publish(
+        (Symbol::new(env, "tournament_created"), tournament.id.clone()),
+        tournament.game_id.clone(),
+    );
+}
+
+/// Change the end time and entry cap of a tournament that has not ended
+///
+/// Only the creator may edit a tournament. The new end time must be after
+/// both the start time and now, and the entry cap can't drop below the
+/// players already registered or the ranks the prize pool pays.
+pub fn update_tournament(
+    env: &Env,
+    tournament_id: String,
+    new_end_time: u64,
+    new_max_entries: u32,
+) -> Result<(), Error> {
+    let mut tournament = get_tournament_internal(env, tournament_id.clone()).ok_or(Error::TournamentNotFound)?;
+    tournament.creator.require_auth();
+    
+    if tournament.cancelled {
+        return Err(Error::TournamentCancelled);
+    }
+    let current_time = env.ledger().timestamp();
+    if current_time > tournament.end_time {
+        return Err(Error::TournamentNotActive);
+    }
+    
+    if new_end_time <= tournament.start_time || new_end_time < current_time {
+        return Err(Error::InvalidParameters);
+    }
+    if new_max_entries == 0 || new_max_entries > 10000 {
+        return Err(Error::InvalidParameters);
+    }
+    if new_max_entries < registration::get_registration_count(env, tournament_id.clone()) {
+        return Err(Error::InvalidParameters);
+    }
+    if let Ok(pool) = prizes::get_prize_pool(env, tournament_id.clone()) {
+        if new_max_entries < pool.split_bps.len() {
+            return Err(Error::InvalidParameters);
+        }
+    }
+    
+    tournament.end_time = new_end_time;
+    tournament.max_entries = new_max_entries;
     save_tournament(env, tournament_id.clone(), &tournament);
-    add_to_tournament_list(env, tournament_id.clone());
+    audit::record(
+        env,
+        tournament_id.clone(),
+        Some(tournament.creator),
+        AuditAction::Updated(new_end_time, new_max_entries),
+    );
     
     // Emit event
     env.events().publish(
-        (Symbol::new(env, "tournament_created"), tournament_id.clone()),
-        game_id,
+        (Symbol::new(env, "tournament_updated"), tournament_id),
+        (new_end_time, new_max_entries),
+    );
+    
+    Ok(())
+}
+
+/// Limit how often and how high players may score in a tournament that has not started
+///
+/// Only the creator may set limits; 0 lifts a limit. Submissions beyond
+/// `max_submissions_per_player`, sooner than `min_interval_secs` after the
+/// player's previous one or above `max_valid_score` are rejected.
+pub fn set_submission_limits(
+    env: &Env,
+    tournament_id: String,
+    max_submissions_per_player: u32,
+    min_interval_secs: u64,
+    max_valid_score: u64,
+) -> Result<(), Error> {
+    let mut tournament = get_tournament_internal(env, tournament_id.clone()).ok_or(Error::TournamentNotFound)?;
+    tournament.creator.require_auth();
+
+    if tournament.cancelled {
+        return Err(Error::TournamentCancelled);
+    }
+    if env.ledger().timestamp() >= tournament.start_time {
+        return Err(Error::TournamentNotActive);
+    }
+
+    tournament.max_submissions_per_player = max_submissions_per_player;
+    tournament.min_interval_secs = min_interval_secs;
+    tournament.max_valid_score = max_valid_score;
+    save_tournament(env, tournament_id.clone(), &tournament);
+    audit::record(
+        env,
+        tournament_id.clone(),
+        Some(tournament.creator),
+        AuditAction::LimitsSet(max_submissions_per_player, min_interval_secs, max_valid_score),
+    );
+
+    // Emit event
+    env.events().publish(
+        (Symbol::new(env, "limits_set"), tournament_id),
+        (max_submissions_per_player, min_interval_secs, max_valid_score),
+    );
+
+    Ok(())
+}
+
+/// Cancel a tournament that has not ended, refunding its escrow
+///
+/// The contract admin or the tournament's creator may cancel. The funder
+/// gets the prize pool back at once and registered players reclaim their
+/// entry fees with `claim_refund`.
+pub fn cancel_tournament(env: &Env, caller: Address, tournament_id: String) -> Result<(), Error> {
+    caller.require_auth();
+    
+    let mut tournament = get_tournament_internal(env, tournament_id.clone()).ok_or(Error::TournamentNotFound)?;
+    if caller != tournament.creator && get_admin(env).as_ref() != Some(&caller) {
+        return Err(Error::Unauthorized);
+    }
+    if tournament.cancelled {
+        return Err(Error::TournamentCancelled);
+    }
+    if env.ledger().timestamp() > tournament.end_time {
+        return Err(Error::TournamentNotActive);
+    }
+    
+    tournament.cancelled = true;
+    save_tournament(env, tournament_id.clone(), &tournament);
+    audit::record(env, tournament_id.clone(), Some(caller.clone()), AuditAction::Cancelled);
+    prizes::refund_prize_pool(env, tournament_id.clone());
+    
+    // Emit event
+    env.events().publish(
+        (Symbol::new(env, "tournament_cancelled"), tournament_id),
+        caller,
     );
     
     Ok(())
@@ -61,6 +309,9 @@ pub fn get_tournament_info(env: &Env, tournament_id: String) -> Result<Tournamen
 /// Get tournament results (final leaderboard)
 pub fn get_tournament_results(env: &Env, tournament_id: String) -> Result<Vec<LeaderboardEntry>, Error> {
     let tournament = get_tournament_internal(env, tournament_id).ok_or(Error::TournamentNotFound)?;
+    if tournament.cancelled {
+        return Err(Error::TournamentCancelled);
+    }
     
     // Check if tournament has ended
     let current_time = env.ledger().timestamp();
@@ -68,7 +319,7 @@ pub fn get_tournament_results(env: &Env, tournament_id: String) -> Result<Vec<Le
         return Err(Error::TournamentNotActive);
     }
     
-    Ok(tournament.entries)
+    Ok(leaderboard::get_tournament_entries(env, &tournament, 0, u32::MAX))
 }
 
 /// Get list of all tournaments
@@ -86,7 +337,7 @@ pub fn get_active_tournaments(env: &Env, game_id: Option<String>) -> Vec<Tournam
         if let Some(tournament_id) = all_tournaments.get(i) {
             if let Some(tournament) = get_tournament_internal(env, tournament_id) {
                 // Check if tournament is currently active
-                if current_time >= tournament.start_time && current_time <= tournament.end_time {
+                if !tournament.cancelled && current_time >= tournament.start_time && current_time <= tournament.end_time {
                     // Filter by game_id if specified
                     if let Some(ref filter_game_id) = game_id {
                         if tournament.game_id == *filter_game_id {
@@ -108,7 +359,7 @@ pub fn is_tournament_active(env: &Env, tournament_id: String) -> Result<bool, Er
     let tournament = get_tournament_internal(env, tournament_id).ok_or(Error::TournamentNotFound)?;
     let current_time = env.ledger().timestamp();
     
-    Ok(current_time >= tournament.start_time && current_time <= tournament.end_time)
+    Ok(!tournament.cancelled && current_time >= tournament.start_time && current_time <= tournament.end_time)
 }
 
 /// Get tournament status
@@ -116,7 +367,9 @@ pub fn get_tournament_status(env: &Env, tournament_id: String) -> Result<String,
     let tournament = get_tournament_internal(env, tournament_id).ok_or(Error::TournamentNotFound)?;
     let current_time = env.ledger().timestamp();
     
-    let status = if current_time < tournament.start_time {
+    let status = if tournament.cancelled {
+        String::from_str(env, "cancelled")
+    } else if current_time < tournament.start_time {
         String::from_str(env, "upcoming")
     } else if current_time <= tournament.end_time {
         String::from_str(env, "active")
@@ -129,26 +382,38 @@ pub fn get_tournament_status(env: &Env, tournament_id: String) -> Result<String,
 
 // Helper functions
 fn get_tournament_internal(env: &Env, tournament_id: String) -> Option<Tournament> {
+    let key = DataKey::Tournament(tournament_id);
+    let tournament = env.storage().persistent().get(&key)?;
     env.storage()
-        .instance()
-        .get(&DataKey::Tournament(tournament_id))
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD, TTL_EXTEND_TO);
+    Some(tournament)
 }
 
 fn save_tournament(env: &Env, tournament_id: String, tournament: &Tournament) {
+    let key = DataKey::Tournament(tournament_id);
+    env.storage().persistent().set(&key, tournament);
     env.storage()
-        .instance()
-        .set(&DataKey::Tournament(tournament_id), tournament);
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD, TTL_EXTEND_TO);
 }
 
 fn get_tournament_list(env: &Env) -> Vec<String> {
+    let key = DataKey::TournamentList;
+    let Some(list) = env.storage().persistent().get(&key) else {
+        return Vec::new(env);
+    };
     env.storage()
-        .instance()
-        .get(&DataKey::TournamentList)
-        .unwrap_or(Vec::new(env))
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD, TTL_EXTEND_TO);
+    list
 }
 
 fn add_to_tournament_list(env: &Env, tournament_id: String) {
     let mut list = get_tournament_list(env);
     list.push_back(tournament_id);
-    env.storage().instance().set(&DataKey::TournamentList, &list);
+    env.storage().persistent().set(&DataKey::TournamentList, &list);
+    env.storage()
+        .persistent()
+        .extend_ttl(&DataKey::TournamentList, TTL_THRESHOLD, TTL_EXTEND_TO);
 } 
\ No newline at end of file
@@ -11,7 +11,10 @@ pub struct PlayerScore {
     pub score: u64,
     pub timestamp: u64,
     pub game_id: String,
+    /// Empty for scores a game contract pushed outside any tournament
     pub tournament_id: String,
+    /// Hash of the replay or proof that produced the score
+    pub proof: Option<BytesN<32>>,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
@@ -20,6 +23,40 @@ pub struct LeaderboardEntry {
     pub player_id: Address,
     pub score: u64,
     pub rank: u32,
+    /// Hash of the replay or proof behind `score`
+    pub proof: Option<BytesN<32>>,
+    /// Ledger timestamp at which the entry reached `score`
+    pub submitted_at: u64,
+}
+
+/// A player's move on a leaderboard, published with every change to their entry
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct RankChange {
+    pub player_id: Address,
+    pub score: u64,
+    /// Rank before the update, `None` if the player had no entry
+    pub previous_rank: Option<u32>,
+    /// Rank after the update, `None` if the entry fell off the board
+    pub new_rank: Option<u32>,
+    pub entered_top_10: bool,
+    pub entered_top_100: bool,
+}
+
+/// How a player's submissions combine into their tournament score
+///
+/// Only `Highest` tournaments feed the game's global and season leaderboards.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum ScoringMode {
+    /// Best single score counts, highest first
+    Highest,
+    /// Best single score counts, lowest first, as for race times
+    Lowest,
+    /// Scores add up across submissions, highest total first
+    Cumulative,
+    /// Most recent score replaces the previous one, highest first
+    Latest,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -27,17 +64,225 @@ pub struct LeaderboardEntry {
 pub struct Tournament {
     pub id: String,
     pub game_id: String,
+    /// Account that created the tournament and may edit or cancel it
+    pub creator: Address,
     pub start_time: u64,
     pub end_time: u64,
     pub max_entries: u32,
-    pub entries: Vec<LeaderboardEntry>,
+    pub scoring_mode: ScoringMode,
+    pub tie_break: TieBreak,
+    /// Ed25519 key of the game server that must sign submitted scores, if any
+    pub server_key: Option<BytesN<32>>,
+    /// Set once the tournament is cancelled and its escrow refunded
+    pub cancelled: bool,
+    /// Most scores one player may submit, 0 for no cap
+    pub max_submissions_per_player: u32,
+    /// Seconds a player must wait between submissions, 0 for none
+    pub min_interval_secs: u64,
+    /// Highest score accepted, 0 for no bound
+    pub max_valid_score: u64,
+}
+
+/// How entries with equal scores are ordered and ranked
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum TieBreak {
+    /// Earlier submission ranks first
+    EarliestFirst,
+    /// Later submission ranks first
+    LatestFirst,
+    /// Equal scores share the rank of the first of them, listed earliest first
+    Shared,
+}
+
+/// Organizer action recorded in a tournament's audit log
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum AuditAction {
+    Created,
+    /// New end time and entry cap
+    Updated(u64, u32),
+    /// Team scoring rule and team size cap
+    TeamsEnabled(TeamScoring, u32),
+    BracketGenerated,
+    Cancelled,
+    /// Submission cap per player, seconds between submissions and highest valid score
+    LimitsSet(u32, u64, u64),
+    /// Total prizes paid out
+    Finalized(i128),
+}
+
+/// One entry of a tournament's audit log
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct AuditEntry {
+    pub action: AuditAction,
+    /// Account that took the action, `None` for permissionless calls
+    pub actor: Option<Address>,
+    pub timestamp: u64,
 }
 
+/// Settings every instance of a recurring tournament is created with
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct TournamentTemplate {
+    pub id: String,
+    pub game_id: String,
+    pub creator: Address,
+    pub max_entries: u32,
+    pub scoring_mode: ScoringMode,
+    pub tie_break: TieBreak,
+    pub server_key: Option<BytesN<32>>,
+    /// Most scores one player may submit, 0 for no cap
+    pub max_submissions_per_player: u32,
+    /// Seconds a player must wait between submissions, 0 for none
+    pub min_interval_secs: u64,
+    /// Highest score accepted, 0 for no bound
+    pub max_valid_score: u64,
+}
+
+/// A tournament that starts over with a fresh leaderboard every period
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct RecurringTournament {
+    pub template: TournamentTemplate,
+    pub period_secs: u64,
+    /// Start of the first instance; instance n runs from `start_time + n * period_secs`
+    pub start_time: u64,
+}
+
+/// A timed run of a game's global leaderboard
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Season {
+    pub game_id: String,
+    pub season_id: u32,
+    pub start_time: u64,
+    pub end_time: u64,
+    /// Set once the season has ended and its board is kept for history only
+    pub archived: bool,
+}
+
+/// How the members' tournament scores combine into a team score
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum TeamScoring {
+    /// Members' scores add up
+    Sum,
+    /// Best member's score counts
+    Best,
+}
+
+/// Team rules of a tournament
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct TeamConfig {
+    pub scoring: TeamScoring,
+    /// Most members per team, captain included
+    pub max_size: u32,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Team {
+    pub id: String,
+    pub tournament_id: String,
+    pub captain: Address,
+    /// Members in joining order, captain first
+    pub members: Vec<Address>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[contracttype]
+pub struct TeamEntry {
+    pub team_id: String,
+    pub score: u64,
+    pub rank: u32,
+    /// Ledger timestamp at which the team reached `score`
+    pub updated_at: u64,
+}
+
+/// Single-elimination bracket, seeded from a tournament's players or a game's global leaderboard
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Bracket {
+    pub id: String,
+    pub game_id: String,
+    /// Account that reports match results
+    pub organizer: Address,
+    /// Players by seed, first seed first
+    pub seeds: Vec<Address>,
+    /// Every match, round by round, each round ordered by the best seed that can reach it
+    pub matches: Vec<BracketMatch>,
+    /// Winner of the final, once it is played
+    pub champion: Option<Address>,
+    pub created_at: u64,
+}
+
+/// Match between two bracket slots, seeds numbered from 1
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct BracketMatch {
+    pub id: u64,
+    /// Round of the match, 1 for the first
+    pub round: u32,
+    /// Seed of the higher slot's player, 0 while the slot is empty
+    pub high_seed: u32,
+    pub high: Option<Address>,
+    /// Seed of the lower slot's player, 0 while the slot is empty
+    pub low_seed: u32,
+    /// Lower slot's player, `None` until decided or when the higher seed has a first-round bye
+    pub low: Option<Address>,
+    pub winner: Option<Address>,
+}
+
+/// Leaderboard entry with the replay its player attached, if any
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct TopEntry {
+    pub entry: LeaderboardEntry,
+    /// Content hash locating the off-chain replay of `entry.score`
+    pub replay: Option<BytesN<32>>,
+}
+
+/// A player's statistics in one game, across every tournament of it
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[contracttype]
 pub struct PlayerHistory {
     pub player_id: Address,
-    pub scores: Vec<PlayerScore>,
+    pub game_id: String,
+    /// Scores submitted
     pub total_games: u32,
     pub best_score: u64,
+    /// Sum of every submitted score
+    pub total_score: u64,
+    pub average_score: u64,
+    /// Finalized tournaments the player finished first in
+    pub tournaments_won: u32,
+}
+
+/// Token prize pool escrowed when a tournament is created
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct PrizePool {
+    pub token: Address,
+    pub funder: Address,
+    pub amount: i128,
+    /// Share of the pool per rank in basis points, first place first
+    pub split_bps: Vec<u32>,
+    /// Tokens each player pays into the pool to register, 0 for free entry
+    pub entry_fee: i128,
+}
+
+/// Immutable tournament standings, locked mid-tournament for prediction contracts or at its end for prize audits, anchored by `hash`
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct StandingsSnapshot {
+    pub tournament_id: String,
+    pub locked_at: u64,
+    /// Top of the standings, at most `MAX_SNAPSHOT_ENTRIES` entries
+    pub entries: Vec<LeaderboardEntry>,
+    /// Entries on the board when the snapshot was taken
+    pub total_entries: u32,
+    pub hash: BytesN<32>,
 }
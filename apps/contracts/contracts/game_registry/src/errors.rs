@@ -0,0 +1,17 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    /// Contract admin already set
+    AlreadyInitialized = 1,
+    /// Unauthorized operation
+    Unauthorized = 2,
+    /// Invalid parameters
+    InvalidParameters = 3,
+    /// Game already registered
+    GameExists = 4,
+    /// Game not found
+    GameNotFound = 5,
+}
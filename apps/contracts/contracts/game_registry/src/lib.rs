@@ -0,0 +1,69 @@
+#![no_std]
+
+use soroban_sdk::{contract, contractimpl, Address, Env, String, Symbol, Vec};
+
+pub mod errors;
+pub mod types;
+pub mod registry;
+
+use errors::Error;
+use types::*;
+
+#[contract]
+pub struct GameRegistryContract;
+
+#[contractimpl]
+impl GameRegistryContract {
+    /// Set the contract admin, who verifies games
+    pub fn initialize(env: Env, admin: Address) -> Result<(), Error> {
+        registry::initialize(&env, admin)
+    }
+
+    /// Register a new game under `game_id` as its developer
+    pub fn register_game(
+        env: Env,
+        developer: Address,
+        game_id: String,
+        metadata: GameMetadata,
+    ) -> Result<GameInfo, Error> {
+        registry::register_game(&env, developer, game_id, metadata)
+    }
+
+    /// Replace a game's metadata as its developer
+    pub fn update_game(env: Env, game_id: String, metadata: GameMetadata) -> Result<GameInfo, Error> {
+        registry::update_game(&env, game_id, metadata)
+    }
+
+    /// Set or clear a game's verification flag, admin only
+    pub fn set_verified(env: Env, game_id: String, verified: bool) -> Result<(), Error> {
+        registry::set_verified(&env, game_id, verified)
+    }
+
+    /// Get a game's metadata
+    pub fn get_game(env: Env, game_id: String) -> Result<GameInfo, Error> {
+        registry::get_game(&env, game_id)
+    }
+
+    /// Check whether a game_id is registered
+    pub fn is_registered(env: Env, game_id: String) -> bool {
+        registry::is_registered(&env, game_id)
+    }
+
+    /// Get registered games with pagination, oldest first, or only verified ones in verification order
+    pub fn list_games(env: Env, verified_only: bool, page: u32, page_size: u32) -> Vec<GameInfo> {
+        registry::list_games(&env, verified_only, page, page_size)
+    }
+
+    /// Get the games tagged with a genre with pagination, oldest first
+    pub fn get_games_by_genre(env: Env, genre: Symbol, page: u32, page_size: u32) -> Vec<GameInfo> {
+        registry::get_games_by_genre(&env, genre, page, page_size)
+    }
+
+    /// Get the games a developer registered with pagination, oldest first
+    pub fn get_games_by_developer(env: Env, developer: Address, page: u32, page_size: u32) -> Vec<GameInfo> {
+        registry::get_games_by_developer(&env, developer, page, page_size)
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,288 @@
+use soroban_sdk::{contracttype, Address, Env, IntoVal, String, Symbol, Val, Vec};
+use crate::errors::Error;
+use crate::types::{GameInfo, GameMetadata};
+
+/// Most genre tags one game may carry
+///
+/// Swapping every tag in one update re-indexes the game in each genre, so
+/// this keeps the update within a transaction's write limit.
+pub const MAX_GENRES: u32 = 4;
+/// Longest game_id, in bytes, a game may be registered under
+pub const MAX_GAME_ID_LEN: u32 = 64;
+/// Remaining ledgers below which a game or index entry is extended when read
+/// or written (~30 days)
+const TTL_THRESHOLD: u32 = 518_400;
+/// Ledgers a game or index entry is extended to (~180 days)
+///
+/// Catalog entries are rarely updated once published, so they rely on
+/// browsing to stay alive and are kept well past a quiet season.
+const TTL_EXTEND_TO: u32 = 3_110_400;
+
+#[contracttype]
+#[derive(Clone)]
+enum DataKey {
+    Admin,
+    Game(String),
+    /// Number of game IDs in an index
+    IndexCount(Index),
+    /// Game ID at a position of an index
+    IndexEntry(Index, u32),
+    /// Position of a game ID in an index
+    IndexPosition(Index, String),
+}
+
+/// Lists of game IDs kept for discovery
+#[contracttype]
+#[derive(Clone)]
+enum Index {
+    /// Every game ID in registration order
+    All,
+    /// Verified game IDs in verification order
+    Verified,
+    Genre(Symbol),
+    Developer(Address),
+}
+
+/// Set the contract admin, who verifies games
+pub fn initialize(env: &Env, admin: Address) -> Result<(), Error> {
+    if env.storage().instance().has(&DataKey::Admin) {
+        return Err(Error::AlreadyInitialized);
+    }
+    admin.require_auth();
+    env.storage().instance().set(&DataKey::Admin, &admin);
+    Ok(())
+}
+
+pub fn get_admin(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::Admin)
+}
+
+/// Register a new game under `game_id` as its developer
+pub fn register_game(
+    env: &Env,
+    developer: Address,
+    game_id: String,
+    metadata: GameMetadata,
+) -> Result<GameInfo, Error> {
+    developer.require_auth();
+    validate_metadata(&game_id, &metadata)?;
+    if env.storage().persistent().has(&DataKey::Game(game_id.clone())) {
+        return Err(Error::GameExists);
+    }
+
+    let now = env.ledger().timestamp();
+    let game = GameInfo {
+        id: game_id.clone(),
+        title_hash: metadata.title_hash,
+        developer: developer.clone(),
+        genres: metadata.genres,
+        release_time: metadata.release_time,
+        content_uri: metadata.content_uri,
+        verified: false,
+        registered_at: now,
+        updated_at: now,
+    };
+    save_game(env, &game);
+    add_to_index(env, Index::All, &game_id);
+    add_to_index(env, Index::Developer(developer.clone()), &game_id);
+    for genre in game.genres.iter() {
+        add_to_index(env, Index::Genre(genre), &game_id);
+    }
+
+    // Emit event
+    env.events().publish(
+        (Symbol::new(env, "game_registered"), game_id),
+        developer,
+    );
+
+    Ok(game)
+}
+
+/// Replace a game's metadata as its developer
+///
+/// The verification flag is kept, so the admin should revoke it if the
+/// new metadata no longer holds up.
+pub fn update_game(env: &Env, game_id: String, metadata: GameMetadata) -> Result<GameInfo, Error> {
+    let mut game = get_game(env, game_id.clone())?;
+    game.developer.require_auth();
+    validate_metadata(&game_id, &metadata)?;
+
+    for genre in game.genres.iter() {
+        if !metadata.genres.contains(&genre) {
+            remove_from_index(env, Index::Genre(genre), &game_id);
+        }
+    }
+    for genre in metadata.genres.iter() {
+        if !game.genres.contains(&genre) {
+            add_to_index(env, Index::Genre(genre), &game_id);
+        }
+    }
+
+    game.title_hash = metadata.title_hash;
+    game.genres = metadata.genres;
+    game.release_time = metadata.release_time;
+    game.content_uri = metadata.content_uri;
+    game.updated_at = env.ledger().timestamp();
+    save_game(env, &game);
+
+    // Emit event
+    env.events().publish(
+        (Symbol::new(env, "game_updated"), game_id),
+        game.updated_at,
+    );
+
+    Ok(game)
+}
+
+/// Set or clear a game's verification flag, admin only
+pub fn set_verified(env: &Env, game_id: String, verified: bool) -> Result<(), Error> {
+    let admin = get_admin(env).ok_or(Error::Unauthorized)?;
+    admin.require_auth();
+
+    let mut game = get_game(env, game_id.clone())?;
+    if game.verified != verified {
+        if verified {
+            add_to_index(env, Index::Verified, &game_id);
+        } else {
+            remove_from_index(env, Index::Verified, &game_id);
+        }
+    }
+    game.verified = verified;
+    save_game(env, &game);
+
+    // Emit event
+    env.events().publish(
+        (Symbol::new(env, "game_verified"), game_id),
+        verified,
+    );
+
+    Ok(())
+}
+
+/// Get a game's metadata
+pub fn get_game(env: &Env, game_id: String) -> Result<GameInfo, Error> {
+    let key = DataKey::Game(game_id);
+    let game = env.storage().persistent().get(&key).ok_or(Error::GameNotFound)?;
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD, TTL_EXTEND_TO);
+    Ok(game)
+}
+
+/// Check whether a game_id is registered, for contracts that validate against the catalog
+pub fn is_registered(env: &Env, game_id: String) -> bool {
+    env.storage().persistent().has(&DataKey::Game(game_id))
+}
+
+/// Get registered games with pagination, oldest first, or only verified ones in verification order
+///
+/// Unverifying a game moves the most recently verified one into its place.
+pub fn list_games(env: &Env, verified_only: bool, page: u32, page_size: u32) -> Vec<GameInfo> {
+    let index = if verified_only { Index::Verified } else { Index::All };
+    load_page(env, index, page, page_size)
+}
+
+/// Get the games tagged with a genre with pagination, oldest first
+///
+/// Removing the tag from a game moves the most recently tagged one into its place.
+pub fn get_games_by_genre(env: &Env, genre: Symbol, page: u32, page_size: u32) -> Vec<GameInfo> {
+    load_page(env, Index::Genre(genre), page, page_size)
+}
+
+/// Get the games a developer registered with pagination, oldest first
+pub fn get_games_by_developer(env: &Env, developer: Address, page: u32, page_size: u32) -> Vec<GameInfo> {
+    load_page(env, Index::Developer(developer), page, page_size)
+}
+
+// Helper functions
+fn validate_metadata(game_id: &String, metadata: &GameMetadata) -> Result<(), Error> {
+    if game_id.is_empty()
+        || game_id.len() > MAX_GAME_ID_LEN
+        || metadata.content_uri.is_empty()
+        || metadata.genres.len() > MAX_GENRES
+    {
+        return Err(Error::InvalidParameters);
+    }
+    for (index, genre) in metadata.genres.iter().enumerate() {
+        if metadata.genres.first_index_of(&genre) != Some(index as u32) {
+            return Err(Error::InvalidParameters);
+        }
+    }
+    Ok(())
+}
+
+fn save_game(env: &Env, game: &GameInfo) {
+    let key = DataKey::Game(game.id.clone());
+    env.storage().persistent().set(&key, game);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+fn get_index_count(env: &Env, index: &Index) -> u32 {
+    let key = DataKey::IndexCount(index.clone());
+    let Some(count) = env.storage().persistent().get(&key) else {
+        return 0;
+    };
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD, TTL_EXTEND_TO);
+    count
+}
+
+fn add_to_index(env: &Env, index: Index, game_id: &String) {
+    let position = get_index_count(env, &index);
+    persist(env, &DataKey::IndexEntry(index.clone(), position), game_id);
+    persist(env, &DataKey::IndexPosition(index.clone(), game_id.clone()), &position);
+    persist(env, &DataKey::IndexCount(index), &(position + 1));
+}
+
+/// Remove a game ID from an index, moving the last entry into its position
+fn remove_from_index(env: &Env, index: Index, game_id: &String) {
+    let position_key = DataKey::IndexPosition(index.clone(), game_id.clone());
+    let Some(position) = env.storage().persistent().get::<_, u32>(&position_key) else {
+        return;
+    };
+    env.storage().persistent().remove(&position_key);
+
+    let last = get_index_count(env, &index) - 1;
+    let last_key = DataKey::IndexEntry(index.clone(), last);
+    if position != last {
+        let moved: String = env.storage().persistent().get(&last_key).unwrap();
+        persist(env, &DataKey::IndexEntry(index.clone(), position), &moved);
+        persist(env, &DataKey::IndexPosition(index.clone(), moved), &position);
+    }
+    env.storage().persistent().remove(&last_key);
+    if last == 0 {
+        env.storage().persistent().remove(&DataKey::IndexCount(index));
+    } else {
+        persist(env, &DataKey::IndexCount(index), &last);
+    }
+}
+
+fn load_page(env: &Env, index: Index, page: u32, page_size: u32) -> Vec<GameInfo> {
+    let count = get_index_count(env, &index);
+    let start = page.saturating_mul(page_size).min(count);
+    let end = start.saturating_add(page_size).min(count);
+    let mut games = Vec::new(env);
+    for position in start..end {
+        let key = DataKey::IndexEntry(index.clone(), position);
+        let Some(game_id) = env.storage().persistent().get::<_, String>(&key) else {
+            continue;
+        };
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, TTL_THRESHOLD, TTL_EXTEND_TO);
+        if let Ok(game) = get_game(env, game_id) {
+            games.push_back(game);
+        }
+    }
+    games
+}
+
+fn persist<V: IntoVal<Env, Val>>(env: &Env, key: &DataKey, value: &V) {
+    env.storage().persistent().set(key, value);
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
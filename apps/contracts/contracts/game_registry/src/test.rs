@@ -0,0 +1,224 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::testutils::{Address as _, Ledger, MockAuth, MockAuthInvoke};
+use soroban_sdk::{symbol_short, Address, BytesN, Env, IntoVal, String, Vec};
+
+/// Register the contract and generate the developer publishing the tests' games
+///
+/// Auths are mocked so the admin can verify games without signing.
+fn setup_registry(env: &Env) -> (GameRegistryContractClient<'_>, Address) {
+    env.mock_all_auths();
+    let client = GameRegistryContractClient::new(env, &env.register(GameRegistryContract, ()));
+    client.initialize(&Address::generate(env));
+    (client, Address::generate(env))
+}
+
+fn metadata(env: &Env, genres: &[Symbol]) -> GameMetadata {
+    GameMetadata {
+        title_hash: BytesN::from_array(env, &[7; 32]),
+        genres: Vec::from_slice(env, genres),
+        release_time: 1_000,
+        content_uri: String::from_str(env, "ipfs://game"),
+    }
+}
+
+#[test]
+fn test_register_and_update_game() {
+    let env = Env::default();
+    let (client, developer) = setup_registry(&env);
+    let game_id = String::from_str(&env, "space-race");
+    env.ledger().set_timestamp(10);
+
+    let game = client.register_game(&developer, &game_id, &metadata(&env, &[symbol_short!("racing")]));
+    assert_eq!(game.developer, developer);
+    assert!(!game.verified);
+    assert!(client.is_registered(&game_id));
+    assert!(!client.is_registered(&String::from_str(&env, "missing")));
+    assert_eq!(
+        client.try_register_game(&Address::generate(&env), &game_id, &metadata(&env, &[])),
+        Err(Ok(Error::GameExists))
+    );
+
+    env.ledger().set_timestamp(20);
+    let update = metadata(&env, &[symbol_short!("arcade"), symbol_short!("shooter")]);
+    let game = client.update_game(&game_id, &update);
+    assert_eq!((game.registered_at, game.updated_at), (10, 20));
+    assert_eq!(client.get_game(&game_id).genres, update.genres);
+    assert!(client.get_games_by_genre(&symbol_short!("racing"), &0, &10).is_empty());
+    assert_eq!(client.get_games_by_genre(&symbol_short!("shooter"), &0, &10).len(), 1);
+    assert_eq!(client.get_games_by_developer(&developer, &0, &10).len(), 1);
+
+    assert_eq!(
+        client.try_update_game(&game_id, &metadata(&env, &[symbol_short!("arcade"), symbol_short!("arcade")])),
+        Err(Ok(Error::InvalidParameters))
+    );
+    let mut no_uri = metadata(&env, &[]);
+    no_uri.content_uri = String::from_str(&env, "");
+    assert_eq!(client.try_update_game(&game_id, &no_uri), Err(Ok(Error::InvalidParameters)));
+    assert_eq!(
+        client.try_get_game(&String::from_str(&env, "missing")),
+        Err(Ok(Error::GameNotFound))
+    );
+}
+
+#[test]
+fn test_discovery_and_verification() {
+    let env = Env::default();
+    let (client, developer) = setup_registry(&env);
+    for id in ["a", "b", "c"] {
+        client.register_game(&developer, &String::from_str(&env, id), &metadata(&env, &[symbol_short!("puzzle")]));
+    }
+    client.set_verified(&String::from_str(&env, "b"), &true);
+
+    let page = client.list_games(&false, &1, &2);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap().id, String::from_str(&env, "c"));
+    let verified = client.list_games(&true, &0, &10);
+    assert_eq!(verified.len(), 1);
+    assert_eq!(verified.get(0).unwrap().id, String::from_str(&env, "b"));
+    assert_eq!(client.get_games_by_genre(&symbol_short!("puzzle"), &0, &2).len(), 2);
+    assert!(client.get_games_by_developer(&Address::generate(&env), &0, &10).is_empty());
+
+    // Verified games page in verification order, and re-verifying lists a game once
+    client.set_verified(&String::from_str(&env, "a"), &true);
+    client.set_verified(&String::from_str(&env, "b"), &true);
+    let verified = client.list_games(&true, &1, &1);
+    assert_eq!(verified.len(), 1);
+    assert_eq!(verified.get(0).unwrap().id, String::from_str(&env, "a"));
+    assert_eq!(client.list_games(&true, &0, &10).len(), 2);
+
+    client.set_verified(&String::from_str(&env, "b"), &false);
+    client.set_verified(&String::from_str(&env, "b"), &false);
+    let verified = client.list_games(&true, &0, &10);
+    assert_eq!(verified.len(), 1);
+    assert_eq!(verified.get(0).unwrap().id, String::from_str(&env, "a"));
+    client.set_verified(&String::from_str(&env, "a"), &false);
+    assert!(client.list_games(&true, &0, &10).is_empty());
+}
+
+#[test]
+fn test_update_requires_developer() {
+    let env = Env::default();
+    let (client, developer) = setup_registry(&env);
+    let game_id = String::from_str(&env, "space-race");
+    client.register_game(&developer, &game_id, &metadata(&env, &[symbol_short!("racing")]));
+
+    let intruder = Address::generate(&env);
+    let update = metadata(&env, &[symbol_short!("puzzle")]);
+    let result = client
+        .mock_auths(&[MockAuth {
+            address: &intruder,
+            invoke: &MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "update_game",
+                args: (&game_id, &update).into_val(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .try_update_game(&game_id, &update);
+    assert!(result.is_err());
+    assert_eq!(client.get_game(&game_id).genres, Vec::from_slice(&env, &[symbol_short!("racing")]));
+    assert!(client.get_games_by_genre(&symbol_short!("puzzle"), &0, &10).is_empty());
+}
+
+#[test]
+fn test_game_id_length_cap() {
+    let env = Env::default();
+    let (client, developer) = setup_registry(&env);
+    let longest = String::from_bytes(&env, &[b'g'; registry::MAX_GAME_ID_LEN as usize]);
+    let too_long = String::from_bytes(&env, &[b'g'; registry::MAX_GAME_ID_LEN as usize + 1]);
+
+    client.register_game(&developer, &longest, &metadata(&env, &[]));
+    assert_eq!(
+        client.try_register_game(&developer, &too_long, &metadata(&env, &[])),
+        Err(Ok(Error::InvalidParameters))
+    );
+    assert!(!client.is_registered(&too_long));
+}
+
+#[test]
+fn test_genre_reindexing() {
+    let env = Env::default();
+    let (client, developer) = setup_registry(&env);
+    let ids = ["a", "b", "c"].map(|id| String::from_str(&env, id));
+    for game_id in ids.iter() {
+        client.register_game(&developer, game_id, &metadata(&env, &[symbol_short!("puzzle")]));
+    }
+
+    // Untagging the first game moves the last one into its place
+    client.update_game(&ids[0], &metadata(&env, &[symbol_short!("racing")]));
+    let puzzles = client.get_games_by_genre(&symbol_short!("puzzle"), &0, &10);
+    assert_eq!(puzzles.len(), 2);
+    assert_eq!(puzzles.get(0).unwrap().id, ids[2]);
+    assert_eq!(puzzles.get(1).unwrap().id, ids[1]);
+    assert_eq!(client.get_games_by_genre(&symbol_short!("racing"), &0, &10).len(), 1);
+
+    // Re-tagging appends, and keeping a tag leaves its position alone
+    client.update_game(&ids[0], &metadata(&env, &[symbol_short!("racing"), symbol_short!("puzzle")]));
+    let puzzles = client.get_games_by_genre(&symbol_short!("puzzle"), &2, &1);
+    assert_eq!(puzzles.len(), 1);
+    assert_eq!(puzzles.get(0).unwrap().id, ids[0]);
+    assert_eq!(client.get_games_by_genre(&symbol_short!("racing"), &0, &10).len(), 1);
+
+    for game_id in ids.iter() {
+        client.update_game(game_id, &metadata(&env, &[]));
+    }
+    assert!(client.get_games_by_genre(&symbol_short!("puzzle"), &0, &10).is_empty());
+    assert!(client.get_games_by_genre(&symbol_short!("racing"), &0, &10).is_empty());
+}
+
+#[test]
+fn test_verified_list_pagination() {
+    let env = Env::default();
+    let (client, developer) = setup_registry(&env);
+    let ids = ["a", "b", "c", "d", "e"].map(|id| String::from_str(&env, id));
+    for game_id in ids.iter() {
+        client.register_game(&developer, game_id, &metadata(&env, &[]));
+        client.set_verified(game_id, &true);
+    }
+
+    let page_ids = |page: u32| {
+        let mut ids = Vec::new(&env);
+        for game in client.list_games(&true, &page, &2).iter() {
+            ids.push_back(game.id);
+        }
+        ids
+    };
+    assert_eq!(page_ids(0), Vec::from_slice(&env, &[ids[0].clone(), ids[1].clone()]));
+    assert_eq!(page_ids(2), Vec::from_slice(&env, &[ids[4].clone()]));
+    assert!(page_ids(3).is_empty());
+
+    // Unverifying a game moves the most recently verified one into its place
+    client.set_verified(&ids[1], &false);
+    assert_eq!(page_ids(0), Vec::from_slice(&env, &[ids[0].clone(), ids[4].clone()]));
+    assert_eq!(page_ids(1), Vec::from_slice(&env, &[ids[2].clone(), ids[3].clone()]));
+    assert!(page_ids(2).is_empty());
+    assert_eq!(client.list_games(&false, &0, &10).len(), 5);
+}
+
+#[test]
+fn test_swap_every_genre_in_one_update() {
+    let env = Env::default();
+    let (client, developer) = setup_registry(&env);
+    let old = ["arcade", "puzzle", "racing", "sports"].map(|genre| Symbol::new(&env, genre));
+    let new = ["horror", "rpg", "shooter", "strategy"].map(|genre| Symbol::new(&env, genre));
+    assert_eq!(old.len() as u32, registry::MAX_GENRES);
+    let first = String::from_str(&env, "first");
+    client.register_game(&developer, &first, &metadata(&env, &old));
+    client.register_game(&developer, &String::from_str(&env, "second"), &metadata(&env, &old));
+
+    client.update_game(&first, &metadata(&env, &new));
+    for genre in old.iter() {
+        assert_eq!(client.get_games_by_genre(genre, &0, &10).len(), 1);
+    }
+    for genre in new.iter() {
+        assert_eq!(client.get_games_by_genre(genre, &0, &10).get(0).unwrap().id, first);
+    }
+
+    let mut too_many = Vec::from_slice(&env, &new);
+    too_many.push_back(symbol_short!("extra"));
+    let mut update = metadata(&env, &[]);
+    update.genres = too_many;
+    assert_eq!(client.try_update_game(&first, &update), Err(Ok(Error::InvalidParameters)));
+}
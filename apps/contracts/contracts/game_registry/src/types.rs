@@ -0,0 +1,31 @@
+use soroban_sdk::{contracttype, Address, BytesN, String, Symbol, Vec};
+
+/// Canonical metadata of a game in the Arcadis catalog
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct GameInfo {
+    /// The game_id other contracts key their data by
+    pub id: String,
+    /// Hash of the game's title, which is stored off chain
+    pub title_hash: BytesN<32>,
+    /// Account that registered the game and may update it
+    pub developer: Address,
+    pub genres: Vec<Symbol>,
+    pub release_time: u64,
+    /// Where the game's full metadata and assets are published
+    pub content_uri: String,
+    /// Set by the admin once the game and its developer are vetted
+    pub verified: bool,
+    pub registered_at: u64,
+    pub updated_at: u64,
+}
+
+/// Developer-editable part of a game's metadata
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct GameMetadata {
+    pub title_hash: BytesN<32>,
+    pub genres: Vec<Symbol>,
+    pub release_time: u64,
+    pub content_uri: String,
+}
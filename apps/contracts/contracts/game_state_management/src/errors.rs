@@ -0,0 +1,17 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    /// An admin has already been set
+    AlreadyInitialized = 1,
+    /// No admin has been set yet
+    NotInitialized = 2,
+    /// A parameter is out of range
+    InvalidParameters = 3,
+    /// No maintenance bounty has been configured
+    MaintenanceNotConfigured = 4,
+    /// The caller is neither the admin nor a trusted reporter
+    Unauthorized = 5,
+}
@@ -14,6 +14,11 @@ pub struct GameEvent {
 
 pub fn log_event(env: &Env, player: Address, event_type: Symbol, metadata: BytesN<64>) -> String {
     player.require_auth();
+    push_event(env, player, event_type, metadata)
+}
+
+/// Appends an event to the log without checking who reported it
+pub fn push_event(env: &Env, player: Address, event_type: Symbol, metadata: BytesN<64>) -> String {
     let timestamp = env.ledger().timestamp();
     let event_key: Symbol = Symbol::new(&env, "EVENT");
 
@@ -66,6 +71,29 @@ pub fn get_event_log(
     filtered
 }
 
+/// Drops logged events older than `before`, returning how many were removed
+pub fn prune_events(env: &Env, before: u64) -> u32 {
+    let event_key: Symbol = Symbol::new(env, "EVENT");
+    let events: Vec<GameEvent> = env
+        .storage()
+        .persistent()
+        .get(&event_key)
+        .unwrap_or(Vec::new(env));
+
+    let mut kept = Vec::new(env);
+    for e in events.iter() {
+        if e.timestamp >= before {
+            kept.push_back(e);
+        }
+    }
+
+    let pruned = events.len() - kept.len();
+    if pruned > 0 {
+        env.storage().persistent().set(&event_key, &kept);
+    }
+    pruned
+}
+
 // Helper: check byte containment using only Soroban types
 fn contains_bytes(haystack: &Bytes, needle: &Bytes) -> bool {
     let haystack_len = haystack.len();
@@ -0,0 +1,145 @@
+use soroban_sdk::{contractclient, contracttype, Address, BytesN, Env, String, Symbol};
+
+use crate::errors::Error;
+use crate::events;
+
+/// How one event type feeds a player's leaderstat
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LeaderstatRule {
+    /// Name of the stat the event counts towards, e.g. `coins`
+    pub stat: Symbol,
+    /// Amount added to the stat for each logged event
+    pub increment: u64,
+}
+
+/// Interface the contract expects from the leaderboards contract
+#[contractclient(name = "LeaderboardClient")]
+pub trait LeaderboardInterface {
+    /// Submits `score` for `player_id` to a tournament and returns the player's rank
+    fn submit_score(
+        env: Env,
+        tournament_id: String,
+        player_id: Address,
+        score: u64,
+        proof: Option<BytesN<32>>,
+        nonce: u64,
+        signature: Option<BytesN<64>>,
+    ) -> u32;
+}
+
+#[contracttype]
+#[derive(Clone)]
+enum DataKey {
+    Admin,
+    Rule(Symbol),
+    /// Game contracts trusted to report leaderstat events
+    Reporter(Address),
+    Leaderstat(Address, Symbol),
+}
+
+pub fn initialize(env: &Env, admin: Address) -> Result<(), Error> {
+    if env.storage().instance().has(&DataKey::Admin) {
+        return Err(Error::AlreadyInitialized);
+    }
+    admin.require_auth();
+    env.storage().instance().set(&DataKey::Admin, &admin);
+    Ok(())
+}
+
+/// Returns the admin set by `initialize`
+pub fn get_admin(env: &Env) -> Result<Address, Error> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Admin)
+        .ok_or(Error::NotInitialized)
+}
+
+/// Configures which leaderstat an event type maintains, or stops tracking it with `None`
+pub fn set_leaderstat_rule(env: &Env, event_type: Symbol, rule: Option<LeaderstatRule>) -> Result<(), Error> {
+    get_admin(env)?.require_auth();
+
+    let key = DataKey::Rule(event_type);
+    match rule {
+        Some(rule) => env.storage().persistent().set(&key, &rule),
+        None => env.storage().persistent().remove(&key),
+    }
+    Ok(())
+}
+
+pub fn get_leaderstat_rule(env: &Env, event_type: Symbol) -> Option<LeaderstatRule> {
+    env.storage().persistent().get(&DataKey::Rule(event_type))
+}
+
+/// Trusts a game contract to report leaderstat events, or stops trusting it
+pub fn set_reporter(env: &Env, reporter: Address, trusted: bool) -> Result<(), Error> {
+    get_admin(env)?.require_auth();
+
+    let key = DataKey::Reporter(reporter);
+    if trusted {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+    Ok(())
+}
+
+pub fn is_reporter(env: &Env, reporter: Address) -> bool {
+    env.storage().persistent().has(&DataKey::Reporter(reporter))
+}
+
+/// Logs an event on the player's behalf and adds it to their leaderstat
+///
+/// Players log their own events with `log_event`, but only events reported
+/// by the admin or a trusted game contract count towards leaderstats, so a
+/// player can't raise a stat they later export as a score.
+pub fn report_event(
+    env: &Env,
+    reporter: Address,
+    player: Address,
+    event_type: Symbol,
+    metadata: BytesN<64>,
+) -> Result<String, Error> {
+    reporter.require_auth();
+    if reporter != get_admin(env)? && !is_reporter(env, reporter) {
+        return Err(Error::Unauthorized);
+    }
+
+    record_event(env, &player, &event_type);
+    Ok(events::push_event(env, player, event_type, metadata))
+}
+
+/// Adds an event to the player's leaderstat if its type is configured
+fn record_event(env: &Env, player: &Address, event_type: &Symbol) {
+    let Some(rule) = get_leaderstat_rule(env, event_type.clone()) else {
+        return;
+    };
+    let key = DataKey::Leaderstat(player.clone(), rule.stat);
+    let value: u64 = env.storage().persistent().get(&key).unwrap_or(0);
+    env.storage().persistent().set(&key, &value.saturating_add(rule.increment));
+}
+
+pub fn get_leaderstat(env: &Env, player: Address, stat: Symbol) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Leaderstat(player, stat))
+        .unwrap_or(0)
+}
+
+/// Submits the player's current leaderstat as a score to a leaderboards tournament
+///
+/// The stat only counts reported events, so the player can't inflate it.
+/// The player must have registered for the tournament first. The score is
+/// submitted unsigned, so tournaments that require a game-server signature
+/// reject it.
+pub fn export_leaderstat(
+    env: &Env,
+    player: Address,
+    stat: Symbol,
+    leaderboard: Address,
+    tournament_id: String,
+) -> u32 {
+    player.require_auth();
+    let score = get_leaderstat(env, player.clone(), stat);
+    LeaderboardClient::new(env, &leaderboard).submit_score(&tournament_id, &player, &score, &None, &0, &None)
+}
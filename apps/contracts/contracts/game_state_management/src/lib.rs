@@ -1,9 +1,16 @@
 #![no_std]
 use soroban_sdk::{contract, contractimpl, Env, Address, BytesN, String, Vec,Symbol};
 
+use errors::Error;
+pub use leaderstats::{LeaderboardClient, LeaderboardInterface, LeaderstatRule};
+pub use maintenance::MaintenanceConfig;
+
 mod save;
 mod world;
 mod events;
+mod errors;
+mod leaderstats;
+mod maintenance;
 
 #[contract]
 pub struct GameStateContract;
@@ -29,4 +36,69 @@ impl GameStateContract {
     pub fn get_event_log(env: Env, player: Option<Address>, region_id: Option<String>) -> Vec<events::GameEvent> {
         events::get_event_log(&env, player, region_id)
     }
-}
\ No newline at end of file
+
+    pub fn initialize(env: Env, admin: Address) -> Result<(), Error> {
+        leaderstats::initialize(&env, admin)
+    }
+
+    pub fn set_leaderstat_rule(env: Env, event_type: Symbol, rule: Option<LeaderstatRule>) -> Result<(), Error> {
+        leaderstats::set_leaderstat_rule(&env, event_type, rule)
+    }
+
+    pub fn get_leaderstat_rule(env: Env, event_type: Symbol) -> Option<LeaderstatRule> {
+        leaderstats::get_leaderstat_rule(&env, event_type)
+    }
+
+    pub fn set_reporter(env: Env, reporter: Address, trusted: bool) -> Result<(), Error> {
+        leaderstats::set_reporter(&env, reporter, trusted)
+    }
+
+    pub fn is_reporter(env: Env, reporter: Address) -> bool {
+        leaderstats::is_reporter(&env, reporter)
+    }
+
+    pub fn report_event(
+        env: Env,
+        reporter: Address,
+        player: Address,
+        event_type: Symbol,
+        metadata: BytesN<64>,
+    ) -> Result<String, Error> {
+        leaderstats::report_event(&env, reporter, player, event_type, metadata)
+    }
+
+    pub fn get_leaderstat(env: Env, player: Address, stat: Symbol) -> u64 {
+        leaderstats::get_leaderstat(&env, player, stat)
+    }
+
+    pub fn export_leaderstat(env: Env, player: Address, stat: Symbol, leaderboard: Address, tournament_id: String) -> u32 {
+        leaderstats::export_leaderstat(&env, player, stat, leaderboard, tournament_id)
+    }
+
+    pub fn set_maintenance_config(env: Env, config: MaintenanceConfig) -> Result<(), Error> {
+        maintenance::set_maintenance_config(&env, config)
+    }
+
+    pub fn get_maintenance_config(env: Env) -> Option<MaintenanceConfig> {
+        maintenance::get_maintenance_config(&env)
+    }
+
+    pub fn fund_maintenance(env: Env, funder: Address, amount: i128) -> Result<i128, Error> {
+        maintenance::fund_maintenance(&env, funder, amount)
+    }
+
+    pub fn get_maintenance_fund(env: Env) -> i128 {
+        maintenance::get_maintenance_fund(&env)
+    }
+
+    pub fn bump_ttl(env: Env, keeper: Address) -> i128 {
+        maintenance::bump_ttl(&env, keeper)
+    }
+
+    pub fn prune_events(env: Env, keeper: Address) -> Result<i128, Error> {
+        maintenance::prune_events(&env, keeper)
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,150 @@
+use soroban_sdk::{contracttype, symbol_short, token, Address, Env, Symbol};
+
+use crate::errors::Error;
+use crate::events;
+use crate::leaderstats;
+
+/// Remaining ledgers below which `bump_ttl` extends an entry (~7 days)
+pub const TTL_THRESHOLD: u32 = 120_960;
+/// Ledgers `bump_ttl` extends an entry's TTL to (~30 days)
+pub const TTL_EXTEND_TO: u32 = 518_400;
+
+/// Bounty paid to keepers for running maintenance entrypoints
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MaintenanceConfig {
+    /// Token the maintenance fund holds and bounties are paid in
+    pub token: Address,
+    /// Amount paid per successful maintenance call
+    pub bounty: i128,
+    /// Seconds before the same task pays a bounty again
+    pub cooldown: u64,
+    /// Age in seconds after which logged events may be pruned, 0 to keep them
+    pub event_retention: u64,
+}
+
+#[contracttype]
+#[derive(Clone)]
+enum DataKey {
+    Config,
+    Fund,
+    LastPaid(Symbol),
+    /// Ledger sequence at which `bump_ttl` last extended the instance
+    InstanceExtendedAt,
+}
+
+/// Sets the keeper bounty, admin only
+///
+/// The token can't change while the fund still holds a balance in the old one.
+pub fn set_maintenance_config(env: &Env, config: MaintenanceConfig) -> Result<(), Error> {
+    leaderstats::get_admin(env)?.require_auth();
+
+    if config.bounty < 0 {
+        return Err(Error::InvalidParameters);
+    }
+    if let Some(current) = get_maintenance_config(env) {
+        if current.token != config.token && get_maintenance_fund(env) > 0 {
+            return Err(Error::InvalidParameters);
+        }
+    }
+    env.storage().instance().set(&DataKey::Config, &config);
+    Ok(())
+}
+
+pub fn get_maintenance_config(env: &Env) -> Option<MaintenanceConfig> {
+    env.storage().instance().get(&DataKey::Config)
+}
+
+/// Moves `amount` of the bounty token from `funder` into the maintenance fund, returning the new balance
+pub fn fund_maintenance(env: &Env, funder: Address, amount: i128) -> Result<i128, Error> {
+    funder.require_auth();
+    let config = get_maintenance_config(env).ok_or(Error::MaintenanceNotConfigured)?;
+    if amount <= 0 {
+        return Err(Error::InvalidParameters);
+    }
+
+    token::Client::new(env, &config.token).transfer(&funder, env.current_contract_address(), &amount);
+    let balance = get_maintenance_fund(env).saturating_add(amount);
+    env.storage().instance().set(&DataKey::Fund, &balance);
+    Ok(balance)
+}
+
+/// Tokens left in the maintenance fund
+pub fn get_maintenance_fund(env: &Env) -> i128 {
+    env.storage().instance().get(&DataKey::Fund).unwrap_or(0)
+}
+
+/// Extends the TTL of the contract instance and its shared save, event and world logs
+///
+/// Returns the bounty paid to `keeper`, which is nothing unless the instance
+/// was within `TTL_THRESHOLD` ledgers of archival. Contracts can't read a
+/// TTL, so the ledger of each extension is recorded instead and the first
+/// call counts as due.
+pub fn bump_ttl(env: &Env, keeper: Address) -> i128 {
+    keeper.require_auth();
+
+    let ledger = env.ledger().sequence();
+    let extended_at: Option<u32> = env.storage().instance().get(&DataKey::InstanceExtendedAt);
+    let due = extended_at.is_none_or(|extended_at| {
+        extended_at.saturating_add(TTL_EXTEND_TO) < ledger.saturating_add(TTL_THRESHOLD)
+    });
+    if due {
+        env.storage().instance().extend_ttl(TTL_EXTEND_TO, TTL_EXTEND_TO);
+        env.storage().instance().set(&DataKey::InstanceExtendedAt, &ledger);
+    }
+    for name in ["SAVES", "EVENT", "WORLD"] {
+        let key = Symbol::new(env, name);
+        if env.storage().persistent().has(&key) {
+            env.storage()
+                .persistent()
+                .extend_ttl(&key, TTL_THRESHOLD, TTL_EXTEND_TO);
+        }
+    }
+
+    if !due {
+        return 0;
+    }
+    pay_bounty(env, &keeper, symbol_short!("bump_ttl"))
+}
+
+/// Drops logged events older than the configured retention
+///
+/// Returns the bounty paid to `keeper`, which is nothing when no event was old enough.
+pub fn prune_events(env: &Env, keeper: Address) -> Result<i128, Error> {
+    keeper.require_auth();
+    let config = get_maintenance_config(env).ok_or(Error::MaintenanceNotConfigured)?;
+    if config.event_retention == 0 {
+        return Ok(0);
+    }
+
+    let before = env.ledger().timestamp().saturating_sub(config.event_retention);
+    if events::prune_events(env, before) == 0 {
+        return Ok(0);
+    }
+    Ok(pay_bounty(env, &keeper, symbol_short!("prune")))
+}
+
+// Helper: pays the bounty for `task` once per cooldown, as far as the fund allows
+fn pay_bounty(env: &Env, keeper: &Address, task: Symbol) -> i128 {
+    let Some(config) = get_maintenance_config(env) else {
+        return 0;
+    };
+    let now = env.ledger().timestamp();
+    let last_paid_key = DataKey::LastPaid(task);
+    let last_paid: Option<u64> = env.storage().instance().get(&last_paid_key);
+    if last_paid.is_some_and(|last_paid| now < last_paid.saturating_add(config.cooldown)) {
+        return 0;
+    }
+
+    let fund = get_maintenance_fund(env);
+    let bounty = config.bounty.min(fund);
+    if bounty <= 0 {
+        return 0;
+    }
+
+    // Settle the books before the transfer so a reentrant call can't pay twice
+    env.storage().instance().set(&DataKey::Fund, &(fund - bounty));
+    env.storage().instance().set(&last_paid_key, &now);
+    token::Client::new(env, &config.token).transfer(&env.current_contract_address(), keeper, &bounty);
+    bounty
+}
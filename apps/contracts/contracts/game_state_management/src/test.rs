@@ -0,0 +1,337 @@
+#![cfg(test)]
+
+use super::*;
+use crate::maintenance::{TTL_EXTEND_TO, TTL_THRESHOLD};
+use soroban_sdk::testutils::storage::Instance as _;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{contract, contractimpl, token, Address, BytesN, Env, String, Symbol};
+
+#[contract]
+pub struct MockLeaderboard;
+
+#[contractimpl]
+impl MockLeaderboard {
+    pub fn submit_score(
+        env: Env,
+        tournament_id: String,
+        player_id: Address,
+        score: u64,
+        proof: Option<BytesN<32>>,
+        _nonce: u64,
+        signature: Option<BytesN<64>>,
+    ) -> u32 {
+        player_id.require_auth();
+        assert!(proof.is_none() && signature.is_none());
+        env.storage().instance().set(&(tournament_id, player_id), &score);
+        1
+    }
+
+    pub fn get_score(env: Env, tournament_id: String, player_id: Address) -> Option<u64> {
+        env.storage().instance().get(&(tournament_id, player_id))
+    }
+}
+
+/// Ledgers after a `bump_ttl` extension at which the instance is due again
+const DUE_AFTER: u32 = TTL_EXTEND_TO - TTL_THRESHOLD + 1;
+
+/// Register the contract, returning its admin
+///
+/// The admin configures leaderstat rules and maintenance, and its reports
+/// always count towards leaderstats.
+fn setup_with_admin(env: &Env) -> (GameStateContractClient<'_>, Address) {
+    env.mock_all_auths();
+    let client = GameStateContractClient::new(env, &env.register(GameStateContract, ()));
+    let admin = Address::generate(env);
+    client.initialize(&admin);
+    (client, admin)
+}
+
+/// Configure a keeper bounty and fund it with `fund` tokens
+///
+/// Entries are created with a TTL long enough to survive the ledgers tests
+/// skip between instance bumps.
+fn setup_maintenance<'a>(
+    env: &'a Env,
+    bounty: i128,
+    cooldown: u64,
+    event_retention: u64,
+    fund: i128,
+) -> (GameStateContractClient<'a>, token::Client<'a>) {
+    env.ledger().set_max_entry_ttl(4 * TTL_EXTEND_TO);
+    env.ledger().set_min_persistent_entry_ttl(3 * TTL_EXTEND_TO);
+    let (client, _) = setup_with_admin(env);
+
+    let token_admin = Address::generate(env);
+    let token_id = env.register_stellar_asset_contract_v2(token_admin).address();
+    client.set_maintenance_config(&MaintenanceConfig {
+        token: token_id.clone(),
+        bounty,
+        cooldown,
+        event_retention,
+    });
+    let funder = Address::generate(env);
+    token::StellarAssetClient::new(env, &token_id).mint(&funder, &fund);
+    client.fund_maintenance(&funder, &fund);
+    (client, token::Client::new(env, &token_id))
+}
+
+fn log_event(env: &Env, client: &GameStateContractClient, player: &Address, event_type: &str) {
+    client.log_event(player, &Symbol::new(env, event_type), &BytesN::from_array(env, &[0; 64]));
+}
+
+fn report_event(
+    env: &Env,
+    client: &GameStateContractClient,
+    reporter: &Address,
+    player: &Address,
+    event_type: &str,
+) {
+    client.report_event(
+        reporter,
+        player,
+        &Symbol::new(env, event_type),
+        &BytesN::from_array(env, &[0; 64]),
+    );
+}
+
+#[test]
+fn test_leaderstat_rules() {
+    let env = Env::default();
+    let (client, _) = setup_with_admin(&env);
+    let game = Address::generate(&env);
+    let player = Address::generate(&env);
+    let rival = Address::generate(&env);
+    let coin = Symbol::new(&env, "coin");
+    let coins = Symbol::new(&env, "coins");
+    assert_eq!(client.try_initialize(&player), Err(Ok(Error::AlreadyInitialized)));
+
+    let rule = LeaderstatRule {
+        stat: coins.clone(),
+        increment: 5,
+    };
+    client.set_leaderstat_rule(&coin, &Some(rule.clone()));
+    assert_eq!(client.get_leaderstat_rule(&coin), Some(rule));
+    client.set_reporter(&game, &true);
+    assert!(client.is_reporter(&game));
+
+    // Only configured event types count, per player
+    report_event(&env, &client, &game, &player, "coin");
+    report_event(&env, &client, &game, &player, "coin");
+    report_event(&env, &client, &game, &player, "jump");
+    report_event(&env, &client, &game, &rival, "coin");
+    assert_eq!(client.get_leaderstat(&player, &coins), 10);
+    assert_eq!(client.get_leaderstat(&rival, &coins), 5);
+    assert_eq!(client.get_leaderstat(&player, &Symbol::new(&env, "jump")), 0);
+    assert_eq!(client.get_event_log(&Some(player.clone()), &None).len(), 3);
+
+    // Removing the rule stops tracking but keeps the stat
+    client.set_leaderstat_rule(&coin, &None);
+    assert_eq!(client.get_leaderstat_rule(&coin), None);
+    report_event(&env, &client, &game, &player, "coin");
+    assert_eq!(client.get_leaderstat(&player, &coins), 10);
+}
+
+#[test]
+fn test_leaderstats_count_only_trusted_reports() {
+    let env = Env::default();
+    let (client, admin) = setup_with_admin(&env);
+    let game = Address::generate(&env);
+    let player = Address::generate(&env);
+    let coin = Symbol::new(&env, "coin");
+    let coins = Symbol::new(&env, "coins");
+    client.set_leaderstat_rule(
+        &coin,
+        &Some(LeaderstatRule {
+            stat: coins.clone(),
+            increment: 5,
+        }),
+    );
+
+    // Players can log their own events, but they don't count
+    log_event(&env, &client, &player, "coin");
+    assert_eq!(client.get_leaderstat(&player, &coins), 0);
+    let metadata = BytesN::from_array(&env, &[0; 64]);
+    assert_eq!(
+        client.try_report_event(&player, &player, &coin, &metadata),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_report_event(&game, &player, &coin, &metadata),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    // The admin always counts, game contracts while they are trusted
+    report_event(&env, &client, &admin, &player, "coin");
+    client.set_reporter(&game, &true);
+    report_event(&env, &client, &game, &player, "coin");
+    client.set_reporter(&game, &false);
+    assert!(!client.is_reporter(&game));
+    assert_eq!(
+        client.try_report_event(&game, &player, &coin, &metadata),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(client.get_leaderstat(&player, &coins), 10);
+}
+
+#[test]
+fn test_leaderstat_rule_requires_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(GameStateContract, ());
+    let client = GameStateContractClient::new(&env, &contract_id);
+    assert_eq!(
+        client.try_set_leaderstat_rule(&Symbol::new(&env, "coin"), &None),
+        Err(Ok(Error::NotInitialized))
+    );
+}
+
+#[test]
+fn test_export_leaderstat() {
+    let env = Env::default();
+    let (client, _) = setup_with_admin(&env);
+    let player = Address::generate(&env);
+    let coin = Symbol::new(&env, "coin");
+    let coins = Symbol::new(&env, "coins");
+    client.set_leaderstat_rule(
+        &coin,
+        &Some(LeaderstatRule {
+            stat: coins.clone(),
+            increment: 7,
+        }),
+    );
+    let game = Address::generate(&env);
+    client.set_reporter(&game, &true);
+    report_event(&env, &client, &game, &player, "coin");
+    report_event(&env, &client, &game, &player, "coin");
+    log_event(&env, &client, &player, "coin");
+
+    let leaderboard_id = env.register(MockLeaderboard, ());
+    let leaderboard = MockLeaderboardClient::new(&env, &leaderboard_id);
+    let tournament_id = String::from_str(&env, "t1");
+    assert_eq!(client.export_leaderstat(&player, &coins, &leaderboard_id, &tournament_id), 1);
+    assert_eq!(leaderboard.get_score(&tournament_id, &player), Some(14));
+}
+
+#[test]
+fn test_maintenance_requires_config() {
+    let env = Env::default();
+    let (client, _) = setup_with_admin(&env);
+    let keeper = Address::generate(&env);
+
+    // Bumping still works, but pays nothing
+    assert_eq!(client.bump_ttl(&keeper), 0);
+    let ttl = env.as_contract(&client.address, || env.storage().instance().get_ttl());
+    assert_eq!(ttl, TTL_EXTEND_TO);
+
+    assert_eq!(
+        client.try_fund_maintenance(&keeper, &10),
+        Err(Ok(Error::MaintenanceNotConfigured))
+    );
+    assert_eq!(
+        client.try_prune_events(&keeper),
+        Err(Ok(Error::MaintenanceNotConfigured))
+    );
+}
+
+#[test]
+fn test_bump_ttl_pays_only_when_due() {
+    let env = Env::default();
+    let (client, token) = setup_maintenance(&env, 30, 0, 0, 100);
+    let keeper = Address::generate(&env);
+    env.ledger().set_sequence_number(100);
+
+    // Nothing is recorded yet, so the first bump is due
+    assert_eq!(client.bump_ttl(&keeper), 30);
+    assert_eq!(client.bump_ttl(&keeper), 0);
+    env.ledger().set_sequence_number(100 + DUE_AFTER - 1);
+    assert_eq!(client.bump_ttl(&keeper), 0);
+
+    env.ledger().set_sequence_number(100 + DUE_AFTER);
+    assert_eq!(client.bump_ttl(&keeper), 30);
+    assert_eq!(client.bump_ttl(&keeper), 0);
+    assert_eq!(token.balance(&keeper), 60);
+    assert_eq!(client.get_maintenance_fund(), 40);
+}
+
+#[test]
+fn test_bounty_cooldown() {
+    let env = Env::default();
+    let (client, token) = setup_maintenance(&env, 30, 100, 50, 100);
+    let keeper = Address::generate(&env);
+    let player = Address::generate(&env);
+    env.ledger().set_timestamp(1_000);
+    env.ledger().set_sequence_number(100);
+    assert_eq!(client.bump_ttl(&keeper), 30);
+
+    // A due bump inside the cooldown extends the instance without paying
+    env.ledger().set_timestamp(1_050);
+    env.ledger().set_sequence_number(100 + DUE_AFTER);
+    assert_eq!(client.bump_ttl(&keeper), 0);
+    env.ledger().set_timestamp(1_100);
+    assert_eq!(client.bump_ttl(&keeper), 0);
+
+    // Each task has its own cooldown, and pruning pays only when an event was old enough
+    assert_eq!(client.prune_events(&keeper), 0);
+    log_event(&env, &client, &player, "jump");
+    env.ledger().set_timestamp(1_160);
+    assert_eq!(client.prune_events(&keeper), 30);
+    log_event(&env, &client, &player, "jump");
+    env.ledger().set_timestamp(1_220);
+    assert_eq!(client.prune_events(&keeper), 0);
+    assert!(client.get_event_log(&None, &None).is_empty());
+
+    assert_eq!(token.balance(&keeper), 60);
+    assert_eq!(client.get_maintenance_fund(), 40);
+}
+
+#[test]
+fn test_fund_exhaustion() {
+    let env = Env::default();
+    let (client, token) = setup_maintenance(&env, 30, 0, 0, 40);
+    let keeper = Address::generate(&env);
+    assert_eq!(client.get_maintenance_fund(), 40);
+    assert_eq!(token.balance(&client.address), 40);
+
+    // The last bounty is cut to what the fund holds, then nothing is paid
+    env.ledger().set_sequence_number(100);
+    assert_eq!(client.bump_ttl(&keeper), 30);
+    env.ledger().set_sequence_number(100 + DUE_AFTER);
+    assert_eq!(client.bump_ttl(&keeper), 10);
+    env.ledger().set_sequence_number(100 + 2 * DUE_AFTER);
+    assert_eq!(client.bump_ttl(&keeper), 0);
+    assert_eq!(token.balance(&keeper), 40);
+    assert_eq!(token.balance(&client.address), 0);
+    assert_eq!(client.get_maintenance_fund(), 0);
+
+    assert_eq!(
+        client.try_fund_maintenance(&keeper, &0),
+        Err(Ok(Error::InvalidParameters))
+    );
+}
+
+#[test]
+fn test_maintenance_config_validation() {
+    let env = Env::default();
+    let (client, token) = setup_maintenance(&env, 30, 0, 0, 5);
+    let config = client.get_maintenance_config().unwrap();
+
+    // The token can't change while the fund holds a balance
+    let other = MaintenanceConfig {
+        token: Address::generate(&env),
+        ..config.clone()
+    };
+    assert_eq!(
+        client.try_set_maintenance_config(&other),
+        Err(Ok(Error::InvalidParameters))
+    );
+    let negative = MaintenanceConfig {
+        bounty: -1,
+        ..config.clone()
+    };
+    assert_eq!(
+        client.try_set_maintenance_config(&negative),
+        Err(Ok(Error::InvalidParameters))
+    );
+    assert_eq!(client.get_maintenance_config(), Some(config));
+    assert_eq!(token.balance(&client.address), 5);
+}
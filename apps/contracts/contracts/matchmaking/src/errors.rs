@@ -0,0 +1,23 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    /// Contract admin already set
+    AlreadyInitialized = 1,
+    /// No admin has been set yet
+    NotInitialized = 2,
+    /// Player is already waiting in this game's queue
+    AlreadyQueued = 3,
+    /// Player is not waiting in this game's queue
+    NotQueued = 4,
+    /// Match not found
+    MatchNotFound = 5,
+    /// No leaderboards contract configured to read ratings from
+    NoLeaderboard = 6,
+    /// Ratings come from the leaderboards contract, so players cannot report their own
+    RankedOnly = 7,
+    /// The game's queue holds as many players as it can
+    QueueFull = 8,
+}
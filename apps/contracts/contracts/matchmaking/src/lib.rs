@@ -0,0 +1,88 @@
+#![no_std]
+
+use soroban_sdk::{contract, contractimpl, Address, Env, String, Vec};
+
+pub mod errors;
+pub mod types;
+pub mod queue;
+pub mod ratings;
+
+use errors::Error;
+pub use ratings::{LeaderboardClient, LeaderboardInterface};
+use types::*;
+
+#[contract]
+pub struct MatchmakingContract;
+
+#[contractimpl]
+impl MatchmakingContract {
+    /// Set the contract admin, who configures rating windows and the leaderboards contract
+    pub fn initialize(env: Env, admin: Address) -> Result<(), Error> {
+        queue::initialize(&env, admin)
+    }
+
+    /// Set the widest rating gap a game pairs players across, admin only
+    pub fn set_rating_window(env: Env, game_id: String, window: u64) -> Result<(), Error> {
+        queue::set_rating_window(&env, game_id, window)
+    }
+
+    /// Get the widest rating gap a game pairs players across
+    pub fn get_rating_window(env: Env, game_id: String) -> u64 {
+        queue::get_rating_window(&env, game_id)
+    }
+
+    /// Set how many seconds a game's players stay queued before they are dropped, admin only
+    pub fn set_max_wait(env: Env, game_id: String, max_wait: u64) -> Result<(), Error> {
+        queue::set_max_wait(&env, game_id, max_wait)
+    }
+
+    /// Get how many seconds a game's players stay queued
+    pub fn get_max_wait(env: Env, game_id: String) -> u64 {
+        queue::get_max_wait(&env, game_id)
+    }
+
+    /// Set the leaderboards contract whose Elo ratings rate players, admin only
+    pub fn set_leaderboard_contract(env: Env, leaderboard: Address) -> Result<(), Error> {
+        ratings::set_leaderboard_contract(&env, leaderboard)
+    }
+
+    /// Get the leaderboards contract ratings are read from, if any
+    pub fn get_leaderboard_contract(env: Env) -> Option<Address> {
+        ratings::get_leaderboard_contract(&env)
+    }
+
+    /// Queue for a game with a self-reported rating, returning the ID of the match formed if an opponent was waiting
+    ///
+    /// Only available until a leaderboards contract is configured; from then
+    /// on players queue with `enqueue_ranked`.
+    pub fn enqueue(env: Env, player: Address, game_id: String, rating: u64) -> Result<Option<u64>, Error> {
+        if ratings::get_leaderboard_contract(&env).is_some() {
+            return Err(Error::RankedOnly);
+        }
+        queue::enqueue(&env, player, game_id, rating)
+    }
+
+    /// Queue for a game rated by the player's Elo rating on the leaderboards contract, returning the ID of the match formed if any
+    pub fn enqueue_ranked(env: Env, player: Address, game_id: String) -> Result<Option<u64>, Error> {
+        let rating = ratings::get_rating(&env, &game_id, &player)?;
+        queue::enqueue(&env, player, game_id, rating)
+    }
+
+    /// Leave a game's queue
+    pub fn dequeue(env: Env, player: Address, game_id: String) -> Result<(), Error> {
+        queue::dequeue(&env, player, game_id)
+    }
+
+    /// Get the players waiting in a game's queue, longest waiting first, leaving out those past the max wait
+    pub fn get_queue(env: Env, game_id: String) -> Vec<QueueEntry> {
+        queue::get_queue(&env, game_id)
+    }
+
+    /// Get a match by ID
+    pub fn get_match(env: Env, match_id: u64) -> Result<Match, Error> {
+        queue::get_match(&env, match_id)
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,223 @@
+use soroban_sdk::{contracttype, Address, Env, String, Symbol, Vec};
+use crate::errors::Error;
+use crate::types::{Match, QueueEntry};
+
+/// Widest rating gap two players may be paired across when a game sets none
+pub const DEFAULT_RATING_WINDOW: u64 = 100;
+/// Seconds a player stays queued when a game sets no limit
+pub const DEFAULT_MAX_WAIT: u64 = 600;
+/// Most players one game's queue holds
+pub const MAX_QUEUE_SIZE: u32 = 50;
+/// Remaining ledgers below which a queue, match or game setting is extended (~7 days)
+const TTL_THRESHOLD: u32 = 120_960;
+/// Ledgers those entries are extended to (~30 days)
+///
+/// Queued players expire within minutes, but a game's rating window and max
+/// wait must outlive quiet spells between matches.
+const TTL_EXTEND_TO: u32 = 518_400;
+
+#[contracttype]
+#[derive(Clone)]
+enum DataKey {
+    Admin,
+    NextMatchId,
+    RatingWindow(String),
+    MaxWait(String),
+    Queue(String),
+    Match(u64),
+}
+
+/// Set the contract admin, who configures rating windows and the leaderboards contract
+pub fn initialize(env: &Env, admin: Address) -> Result<(), Error> {
+    if env.storage().instance().has(&DataKey::Admin) {
+        return Err(Error::AlreadyInitialized);
+    }
+    admin.require_auth();
+    env.storage().instance().set(&DataKey::Admin, &admin);
+    Ok(())
+}
+
+/// Get the admin set by `initialize`
+pub fn get_admin(env: &Env) -> Result<Address, Error> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Admin)
+        .ok_or(Error::NotInitialized)
+}
+
+/// Set the widest rating gap a game pairs players across, admin only
+pub fn set_rating_window(env: &Env, game_id: String, window: u64) -> Result<(), Error> {
+    get_admin(env)?.require_auth();
+    let key = DataKey::RatingWindow(game_id);
+    env.storage().persistent().set(&key, &window);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD, TTL_EXTEND_TO);
+    Ok(())
+}
+
+/// Get the widest rating gap a game pairs players across
+pub fn get_rating_window(env: &Env, game_id: String) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::RatingWindow(game_id))
+        .unwrap_or(DEFAULT_RATING_WINDOW)
+}
+
+/// Set how many seconds a game's players stay queued before they are dropped, admin only
+pub fn set_max_wait(env: &Env, game_id: String, max_wait: u64) -> Result<(), Error> {
+    get_admin(env)?.require_auth();
+    let key = DataKey::MaxWait(game_id);
+    env.storage().persistent().set(&key, &max_wait);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD, TTL_EXTEND_TO);
+    Ok(())
+}
+
+/// Get how many seconds a game's players stay queued
+pub fn get_max_wait(env: &Env, game_id: String) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::MaxWait(game_id))
+        .unwrap_or(DEFAULT_MAX_WAIT)
+}
+
+/// Queue a player for a game, pairing them right away if someone is waiting within the rating window
+///
+/// Players who waited longer than the game's max wait are dropped first.
+/// The waiting player with the closest rating is picked, the one who waited
+/// longest among equally close ones. Returns the ID of the match formed, or
+/// `None` if the player stays queued.
+pub fn enqueue(env: &Env, player: Address, game_id: String, rating: u64) -> Result<Option<u64>, Error> {
+    player.require_auth();
+
+    let mut queue = get_queue(env, game_id.clone());
+    if queue.iter().any(|entry| entry.player == player) {
+        return Err(Error::AlreadyQueued);
+    }
+
+    let window = get_rating_window(env, game_id.clone());
+    let mut opponent: Option<(u32, u64)> = None;
+    for (index, entry) in queue.iter().enumerate() {
+        let gap = entry.rating.abs_diff(rating);
+        if gap <= window && opponent.is_none_or(|(_, best)| gap < best) {
+            opponent = Some((index as u32, gap));
+        }
+    }
+
+    let now = env.ledger().timestamp();
+    let Some((index, _)) = opponent else {
+        if queue.len() >= MAX_QUEUE_SIZE {
+            return Err(Error::QueueFull);
+        }
+        queue.push_back(QueueEntry {
+            player: player.clone(),
+            rating,
+            queued_at: now,
+        });
+        save_queue(env, &game_id, &queue);
+
+        // Emit event
+        env.events().publish(
+            (Symbol::new(env, "player_queued"), game_id),
+            (player, rating),
+        );
+        return Ok(None);
+    };
+
+    let waiting = queue.get(index).unwrap();
+    queue.remove(index);
+    save_queue(env, &game_id, &queue);
+
+    let match_id: u64 = env.storage().instance().get(&DataKey::NextMatchId).unwrap_or(1);
+    env.storage().instance().set(&DataKey::NextMatchId, &(match_id + 1));
+    let formed = Match {
+        id: match_id,
+        game_id: game_id.clone(),
+        player_one: waiting.player,
+        player_one_rating: waiting.rating,
+        player_two: player,
+        player_two_rating: rating,
+        created_at: now,
+    };
+    let key = DataKey::Match(match_id);
+    env.storage().persistent().set(&key, &formed);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD, TTL_EXTEND_TO);
+
+    // Emit event
+    env.events().publish(
+        (Symbol::new(env, "match_created"), match_id),
+        (game_id, formed.player_one, formed.player_two),
+    );
+
+    Ok(Some(match_id))
+}
+
+/// Take a waiting player out of a game's queue
+pub fn dequeue(env: &Env, player: Address, game_id: String) -> Result<(), Error> {
+    player.require_auth();
+
+    let mut queue = load_queue(env, &game_id);
+    let index = queue
+        .iter()
+        .position(|entry| entry.player == player)
+        .ok_or(Error::NotQueued)?;
+    queue.remove(index as u32);
+    save_queue(env, &game_id, &queue);
+
+    // Emit event
+    env.events().publish(
+        (Symbol::new(env, "player_dequeued"), game_id),
+        player,
+    );
+
+    Ok(())
+}
+
+/// Get the players waiting in a game's queue, longest waiting first
+///
+/// Players who waited longer than the game's max wait are left out.
+pub fn get_queue(env: &Env, game_id: String) -> Vec<QueueEntry> {
+    let max_wait = get_max_wait(env, game_id.clone());
+    let now = env.ledger().timestamp();
+    let mut queue = Vec::new(env);
+    for entry in load_queue(env, &game_id).iter() {
+        if now.saturating_sub(entry.queued_at) <= max_wait {
+            queue.push_back(entry);
+        }
+    }
+    queue
+}
+
+/// Get a match by ID
+pub fn get_match(env: &Env, match_id: u64) -> Result<Match, Error> {
+    let key = DataKey::Match(match_id);
+    let formed = env.storage().persistent().get(&key).ok_or(Error::MatchNotFound)?;
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD, TTL_EXTEND_TO);
+    Ok(formed)
+}
+
+// Helper functions
+fn load_queue(env: &Env, game_id: &String) -> Vec<QueueEntry> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Queue(game_id.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+fn save_queue(env: &Env, game_id: &String, queue: &Vec<QueueEntry>) {
+    let key = DataKey::Queue(game_id.clone());
+    if queue.is_empty() {
+        env.storage().persistent().remove(&key);
+        return;
+    }
+    env.storage().persistent().set(&key, queue);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
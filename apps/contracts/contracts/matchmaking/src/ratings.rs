@@ -0,0 +1,38 @@
+use soroban_sdk::{contractclient, contracttype, Address, Env, String, Symbol};
+use crate::errors::Error;
+use crate::queue;
+
+/// Interface the contract expects from the leaderboards contract
+#[contractclient(name = "LeaderboardClient")]
+pub trait LeaderboardInterface {
+    /// Returns a player's Elo rating in a game, the default rating until they played a rated match
+    fn get_rating(env: Env, game_id: String, player_id: Address) -> u32;
+}
+
+#[contracttype]
+#[derive(Clone)]
+enum DataKey {
+    Leaderboard,
+}
+
+/// Set the leaderboards contract whose Elo ratings rate players, admin only
+pub fn set_leaderboard_contract(env: &Env, leaderboard: Address) -> Result<(), Error> {
+    queue::get_admin(env)?.require_auth();
+    env.storage().instance().set(&DataKey::Leaderboard, &leaderboard);
+
+    // Emit event
+    env.events().publish((Symbol::new(env, "leaderboard_set"),), leaderboard);
+
+    Ok(())
+}
+
+/// Get the leaderboards contract ratings are read from, if any
+pub fn get_leaderboard_contract(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::Leaderboard)
+}
+
+/// Get a player's rating in a game: their Elo rating on the leaderboards contract
+pub fn get_rating(env: &Env, game_id: &String, player: &Address) -> Result<u64, Error> {
+    let leaderboard = get_leaderboard_contract(env).ok_or(Error::NoLeaderboard)?;
+    Ok(LeaderboardClient::new(env, &leaderboard).get_rating(game_id, player).into())
+}
@@ -0,0 +1,183 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{contract, contractimpl, Address, Env, String};
+
+#[contract]
+pub struct MockLeaderboard;
+
+#[contractimpl]
+impl MockLeaderboard {
+    pub fn set_rating(env: Env, game_id: String, player_id: Address, rating: u32) {
+        env.storage().instance().set(&(game_id, player_id), &rating);
+    }
+
+    pub fn get_rating(env: Env, game_id: String, player_id: Address) -> u32 {
+        env.storage().instance().get(&(game_id, player_id)).unwrap_or(1200)
+    }
+}
+
+/// Register an initialized contract and name the game whose queue the test uses
+fn setup_queue(env: &Env) -> (MatchmakingContractClient<'_>, String) {
+    env.mock_all_auths();
+    let client = MatchmakingContractClient::new(env, &env.register(MatchmakingContract, ()));
+    client.initialize(&Address::generate(env));
+    (client, String::from_str(env, "game"))
+}
+
+#[test]
+fn test_pair_within_rating_window() {
+    let env = Env::default();
+    let (client, game_id) = setup_queue(&env);
+    let low = Address::generate(&env);
+    let mid = Address::generate(&env);
+    let high = Address::generate(&env);
+
+    env.ledger().set_timestamp(10);
+    assert_eq!(client.enqueue(&low, &game_id, &1000), None);
+    assert_eq!(client.enqueue(&high, &game_id, &1300), None);
+    assert_eq!(
+        client.try_enqueue(&low, &game_id, &1000),
+        Err(Ok(Error::AlreadyQueued))
+    );
+
+    // 1250 is within the default window of 1300 only
+    env.ledger().set_timestamp(20);
+    let match_id = client.enqueue(&mid, &game_id, &1250).unwrap();
+    let formed = client.get_match(&match_id);
+    assert_eq!((formed.player_one, formed.player_two), (high, mid));
+    assert_eq!((formed.player_one_rating, formed.player_two_rating), (1300, 1250));
+    assert_eq!(formed.created_at, 20);
+
+    let queue = client.get_queue(&game_id);
+    assert_eq!(queue.len(), 1);
+    assert_eq!(queue.get(0).unwrap().player, low);
+    assert_eq!(client.try_get_match(&(match_id + 1)), Err(Ok(Error::MatchNotFound)));
+
+    // Queues of other games are separate
+    let other = Address::generate(&env);
+    assert_eq!(client.enqueue(&other, &String::from_str(&env, "other"), &1000), None);
+}
+
+#[test]
+fn test_rating_window_and_dequeue() {
+    let env = Env::default();
+    let (client, game_id) = setup_queue(&env);
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+
+    client.set_rating_window(&game_id, &10);
+    assert_eq!(client.get_rating_window(&game_id), 10);
+    client.enqueue(&first, &game_id, &500);
+    assert_eq!(client.enqueue(&second, &game_id, &520), None);
+
+    client.dequeue(&first, &game_id);
+    assert_eq!(client.try_dequeue(&first, &game_id), Err(Ok(Error::NotQueued)));
+    assert_eq!(client.get_queue(&game_id).get(0).unwrap().player, second);
+}
+
+#[test]
+fn test_enqueue_ranked() {
+    let env = Env::default();
+    let (client, game_id) = setup_queue(&env);
+    let player = Address::generate(&env);
+    let rival = Address::generate(&env);
+    assert_eq!(
+        client.try_enqueue_ranked(&player, &game_id),
+        Err(Ok(Error::NoLeaderboard))
+    );
+
+    let leaderboard_id = env.register(MockLeaderboard, ());
+    let leaderboard = MockLeaderboardClient::new(&env, &leaderboard_id);
+    leaderboard.set_rating(&game_id, &player, &900);
+    client.set_leaderboard_contract(&leaderboard_id);
+
+    client.enqueue_ranked(&player, &game_id);
+    assert_eq!(client.get_queue(&game_id).get(0).unwrap().rating, 900);
+
+    // Unrated players get the default rating, too far from 900 to be paired
+    assert_eq!(client.enqueue_ranked(&rival, &game_id), None);
+    assert_eq!(client.get_queue(&game_id).get(1).unwrap().rating, 1200);
+
+    // A rival rated close to 1200 is paired with the waiting default-rated player
+    let challenger = Address::generate(&env);
+    leaderboard.set_rating(&game_id, &challenger, &1250);
+    let match_id = client.enqueue_ranked(&challenger, &game_id).unwrap();
+    assert_eq!(client.get_match(&match_id).player_one, rival);
+
+    // Self-reported ratings are refused once ratings come from the leaderboards contract
+    assert_eq!(
+        client.try_enqueue(&Address::generate(&env), &game_id, &5000),
+        Err(Ok(Error::RankedOnly))
+    );
+}
+
+#[test]
+fn test_queue_entries_expire() {
+    let env = Env::default();
+    let (client, game_id) = setup_queue(&env);
+    let stale = Address::generate(&env);
+    let fresh = Address::generate(&env);
+    assert_eq!(client.get_max_wait(&game_id), queue::DEFAULT_MAX_WAIT);
+    client.set_max_wait(&game_id, &60);
+
+    env.ledger().set_timestamp(100);
+    client.enqueue(&stale, &game_id, &1000);
+    env.ledger().set_timestamp(160);
+    assert_eq!(client.get_queue(&game_id).len(), 1);
+
+    // Past the max wait the player is no longer listed nor paired
+    env.ledger().set_timestamp(161);
+    assert!(client.get_queue(&game_id).is_empty());
+    assert_eq!(client.enqueue(&fresh, &game_id, &1000), None);
+    let queue = client.get_queue(&game_id);
+    assert_eq!(queue.len(), 1);
+    assert_eq!(queue.get(0).unwrap().player, fresh);
+
+    // A dropped player may queue again and is paired as usual
+    let match_id = client.enqueue(&stale, &game_id, &1010).unwrap();
+    assert_eq!(client.get_match(&match_id).player_one, fresh);
+    assert!(client.get_queue(&game_id).is_empty());
+}
+
+#[test]
+fn test_queue_size_is_bounded() {
+    let env = Env::default();
+    let (client, game_id) = setup_queue(&env);
+    client.set_rating_window(&game_id, &0);
+
+    env.ledger().set_timestamp(100);
+    for rating in 0..queue::MAX_QUEUE_SIZE as u64 {
+        client.enqueue(&Address::generate(&env), &game_id, &(rating * 10));
+    }
+    assert_eq!(
+        client.try_enqueue(&Address::generate(&env), &game_id, &5),
+        Err(Ok(Error::QueueFull))
+    );
+
+    // A full queue still pairs players, and expired entries free their places
+    let match_id = client.enqueue(&Address::generate(&env), &game_id, &0).unwrap();
+    assert_eq!(client.get_match(&match_id).player_one_rating, 0);
+    env.ledger().set_timestamp(100 + queue::DEFAULT_MAX_WAIT + 1);
+    assert_eq!(client.enqueue(&Address::generate(&env), &game_id, &5), None);
+    assert_eq!(client.get_queue(&game_id).len(), 1);
+}
+
+#[test]
+fn test_set_max_wait_requires_admin() {
+    let env = Env::default();
+    let contract_id = env.register(MatchmakingContract, ());
+    let client = MatchmakingContractClient::new(&env, &contract_id);
+    let game_id = String::from_str(&env, "game");
+    assert_eq!(
+        client.try_set_max_wait(&game_id, &60),
+        Err(Ok(Error::NotInitialized))
+    );
+
+    env.mock_all_auths();
+    client.initialize(&Address::generate(&env));
+    env.set_auths(&[]);
+    assert!(client.try_set_max_wait(&game_id, &60).is_err());
+    assert_eq!(client.get_max_wait(&game_id), queue::DEFAULT_MAX_WAIT);
+}
@@ -0,0 +1,26 @@
+use soroban_sdk::{contracttype, Address, String};
+
+/// A player waiting in a game's queue
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct QueueEntry {
+    pub player: Address,
+    pub rating: u64,
+    /// Ledger timestamp at which the player joined the queue
+    pub queued_at: u64,
+}
+
+/// Two players paired from a game's queue
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Match {
+    pub id: u64,
+    pub game_id: String,
+    /// Player who was waiting in the queue
+    pub player_one: Address,
+    pub player_one_rating: u64,
+    /// Player whose enqueue formed the match
+    pub player_two: Address,
+    pub player_two_rating: u64,
+    pub created_at: u64,
+}
@@ -0,0 +1,70 @@
+//! Export every `#[contracttype]` and `#[contracterror]` definition in the
+//! contract crates as a JSON schema and TypeScript types, so the webapp and
+//! indexer stay in sync with the Rust contracts.
+//!
+//! ```text
+//! schema-export [--json <path>] [--ts <path>] [<dir>...]
+//! ```
+//!
+//! Every crate under the given directories is scanned, `contracts` by
+//! default. Without `--json` or `--ts` the JSON schema goes to stdout.
+
+mod schema;
+mod typescript;
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::process;
+
+const USAGE: &str = "usage: schema-export [--json <path>] [--ts <path>] [<dir>...]";
+
+fn main() {
+    if let Err(message) = run(env::args().skip(1)) {
+        eprintln!("schema-export: {message}");
+        process::exit(1);
+    }
+}
+
+fn run(mut args: impl Iterator<Item = String>) -> Result<(), String> {
+    let mut json_path: Option<PathBuf> = None;
+    let mut ts_path: Option<PathBuf> = None;
+    let mut roots = Vec::new();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--json" => json_path = Some(args.next().ok_or(USAGE)?.into()),
+            "--ts" => ts_path = Some(args.next().ok_or(USAGE)?.into()),
+            "-h" | "--help" => {
+                println!("{USAGE}");
+                return Ok(());
+            }
+            _ if arg.starts_with('-') => return Err(USAGE.into()),
+            _ => roots.push(PathBuf::from(arg)),
+        }
+    }
+    if roots.is_empty() {
+        roots.push(PathBuf::from("contracts"));
+    }
+
+    let bundle = schema::collect(&roots)?;
+    let json = serde_json::to_string_pretty(&bundle).map_err(|e| e.to_string())? + "\n";
+    match (&json_path, &ts_path) {
+        (None, None) => print!("{json}"),
+        _ => {
+            if let Some(path) = &json_path {
+                fs::write(path, json).map_err(|e| format!("{}: {e}", path.display()))?;
+            }
+            if let Some(path) = &ts_path {
+                fs::write(path, typescript::render(&bundle))
+                    .map_err(|e| format!("{}: {e}", path.display()))?;
+            }
+        }
+    }
+
+    let count: usize = bundle.crates.iter().map(|schema| schema.types.len()).sum();
+    eprintln!(
+        "schema-export: {count} types from {} crates",
+        bundle.crates.len()
+    );
+    Ok(())
+}
@@ -0,0 +1,607 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+use syn::{Attribute, Expr, Fields, GenericArgument, Item, Lit, PathArguments, Visibility};
+
+/// Every exported type in the scanned crates
+#[derive(Debug, Serialize)]
+pub struct Bundle {
+    pub crates: Vec<CrateSchema>,
+}
+
+/// Exported types of one contract crate, in source order
+#[derive(Debug, Serialize)]
+pub struct CrateSchema {
+    pub name: String,
+    pub aliases: Vec<Alias>,
+    pub types: Vec<TypeDef>,
+}
+
+/// A public `type` alias, kept so clients can use the same names
+#[derive(Debug, Serialize)]
+pub struct Alias {
+    pub name: String,
+    pub target: Type,
+}
+
+/// A `#[contracttype]` or `#[contracterror]` definition
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum TypeDef {
+    /// Struct with named fields, encoded as a map
+    Struct {
+        name: String,
+        doc: Option<String>,
+        fields: Vec<Field>,
+    },
+    /// Struct with unnamed fields, encoded as a vector
+    Tuple {
+        name: String,
+        doc: Option<String>,
+        items: Vec<Type>,
+    },
+    /// Enum whose variants may carry values, encoded as a tagged vector
+    Union {
+        name: String,
+        doc: Option<String>,
+        variants: Vec<Variant>,
+    },
+    /// Enum of unit variants with explicit discriminants, encoded as a u32
+    Enum {
+        name: String,
+        doc: Option<String>,
+        variants: Vec<Discriminant>,
+    },
+    /// Contract error codes
+    Error {
+        name: String,
+        doc: Option<String>,
+        variants: Vec<Discriminant>,
+    },
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+pub struct Field {
+    pub name: String,
+    pub doc: Option<String>,
+    #[serde(rename = "type")]
+    pub ty: Type,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+pub struct Variant {
+    pub name: String,
+    pub doc: Option<String>,
+    pub values: Vec<Type>,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+pub struct Discriminant {
+    pub name: String,
+    pub doc: Option<String>,
+    pub value: u32,
+}
+
+/// A field or value type, as the contract spec sees it
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Type {
+    /// Built-in Soroban value such as `u32`, `Address` or `Symbol`
+    Primitive {
+        name: String,
+    },
+    BytesN {
+        n: u32,
+    },
+    Vec {
+        element: Box<Type>,
+    },
+    Map {
+        key: Box<Type>,
+        value: Box<Type>,
+    },
+    Option {
+        value: Box<Type>,
+    },
+    Tuple {
+        items: Vec<Type>,
+    },
+    /// Another exported type or alias of the same crate
+    Ref {
+        name: String,
+    },
+}
+
+/// Soroban built-ins that map straight onto a client type
+pub const PRIMITIVES: [&str; 16] = [
+    "bool",
+    "u32",
+    "i32",
+    "u64",
+    "i64",
+    "u128",
+    "i128",
+    "U256",
+    "I256",
+    "Timepoint",
+    "Duration",
+    "String",
+    "Symbol",
+    "Address",
+    "MuxedAddress",
+    "Bytes",
+];
+
+impl TypeDef {
+    pub fn name(&self) -> &str {
+        match self {
+            TypeDef::Struct { name, .. }
+            | TypeDef::Tuple { name, .. }
+            | TypeDef::Union { name, .. }
+            | TypeDef::Enum { name, .. }
+            | TypeDef::Error { name, .. } => name,
+        }
+    }
+}
+
+/// Collect the exported types of every crate found under `roots`
+///
+/// A crate is any directory holding a `Cargo.toml` next to a `src`
+/// directory. Crates without exported types are left out.
+pub fn collect(roots: &[PathBuf]) -> Result<Bundle, String> {
+    let mut crate_dirs = Vec::new();
+    for root in roots {
+        find_crates(root, &mut crate_dirs)?;
+    }
+    crate_dirs.sort();
+
+    let mut crates = Vec::new();
+    for dir in crate_dirs {
+        let schema = parse_crate(&dir)?;
+        if !schema.types.is_empty() {
+            crates.push(schema);
+        }
+    }
+    Ok(Bundle { crates })
+}
+
+/// Parse the exported types of the crate at `dir`
+pub fn parse_crate(dir: &Path) -> Result<CrateSchema, String> {
+    let name = crate_name(dir)?;
+    let mut files = Vec::new();
+    find_sources(&dir.join("src"), &mut files)?;
+    files.sort();
+
+    let mut schema = CrateSchema {
+        name,
+        aliases: Vec::new(),
+        types: Vec::new(),
+    };
+    for file in files {
+        let source = fs::read_to_string(&file).map_err(|e| format!("{}: {e}", file.display()))?;
+        parse_source(&source, &mut schema).map_err(|e| format!("{}: {e}", file.display()))?;
+    }
+
+    for (index, def) in schema.types.iter().enumerate() {
+        if schema.types[..index]
+            .iter()
+            .any(|other| other.name() == def.name())
+        {
+            return Err(format!(
+                "{}: type {} is defined twice",
+                schema.name,
+                def.name()
+            ));
+        }
+    }
+    Ok(schema)
+}
+
+/// Add the exported types and aliases of one source file to `schema`
+///
+/// Private types, like per-module storage keys, never cross the contract
+/// boundary and are skipped along with `#[cfg(test)]` modules.
+pub fn parse_source(source: &str, schema: &mut CrateSchema) -> Result<(), String> {
+    let file = syn::parse_file(source).map_err(|e| e.to_string())?;
+    parse_items(&file.items, schema)
+}
+
+// Helper functions
+fn parse_items(items: &[Item], schema: &mut CrateSchema) -> Result<(), String> {
+    for item in items {
+        match item {
+            Item::Mod(module) if !is_test(&module.attrs) => {
+                if let Some((_, items)) = &module.content {
+                    parse_items(items, schema)?;
+                }
+            }
+            Item::Type(alias) if is_pub(&alias.vis) => schema.aliases.push(Alias {
+                name: alias.ident.to_string(),
+                target: parse_type(&alias.ty)?,
+            }),
+            Item::Struct(item) if is_pub(&item.vis) && has_attr(&item.attrs, "contracttype") => {
+                let name = item.ident.to_string();
+                let doc = doc(&item.attrs);
+                schema.types.push(match &item.fields {
+                    Fields::Named(fields) => TypeDef::Struct {
+                        name,
+                        doc,
+                        fields: fields
+                            .named
+                            .iter()
+                            .map(|field| {
+                                Ok(Field {
+                                    name: field.ident.as_ref().unwrap().to_string(),
+                                    doc: self::doc(&field.attrs),
+                                    ty: parse_type(&field.ty)?,
+                                })
+                            })
+                            .collect::<Result<_, String>>()?,
+                    },
+                    Fields::Unnamed(fields) => TypeDef::Tuple {
+                        name,
+                        doc,
+                        items: fields
+                            .unnamed
+                            .iter()
+                            .map(|field| parse_type(&field.ty))
+                            .collect::<Result<_, _>>()?,
+                    },
+                    Fields::Unit => {
+                        return Err(format!("unit struct {name} has no contract encoding"))
+                    }
+                });
+            }
+            Item::Enum(item) if is_pub(&item.vis) && has_attr(&item.attrs, "contracterror") => {
+                schema.types.push(TypeDef::Error {
+                    name: item.ident.to_string(),
+                    doc: doc(&item.attrs),
+                    variants: discriminants(item)?,
+                });
+            }
+            Item::Enum(item) if is_pub(&item.vis) && has_attr(&item.attrs, "contracttype") => {
+                let name = item.ident.to_string();
+                let doc = doc(&item.attrs);
+                if item
+                    .variants
+                    .iter()
+                    .all(|variant| variant.discriminant.is_some())
+                {
+                    schema.types.push(TypeDef::Enum {
+                        name,
+                        doc,
+                        variants: discriminants(item)?,
+                    });
+                    continue;
+                }
+
+                let mut variants = Vec::new();
+                for variant in &item.variants {
+                    let values = match &variant.fields {
+                        Fields::Unit => Vec::new(),
+                        Fields::Unnamed(fields) => fields
+                            .unnamed
+                            .iter()
+                            .map(|field| parse_type(&field.ty))
+                            .collect::<Result<_, _>>()?,
+                        Fields::Named(_) => {
+                            return Err(format!("{name}::{} has named fields", variant.ident));
+                        }
+                    };
+                    variants.push(Variant {
+                        name: variant.ident.to_string(),
+                        doc: self::doc(&variant.attrs),
+                        values,
+                    });
+                }
+                schema.types.push(TypeDef::Union {
+                    name,
+                    doc,
+                    variants,
+                });
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+fn parse_type(ty: &syn::Type) -> Result<Type, String> {
+    match ty {
+        syn::Type::Tuple(tuple) => Ok(Type::Tuple {
+            items: tuple
+                .elems
+                .iter()
+                .map(parse_type)
+                .collect::<Result<_, _>>()?,
+        }),
+        syn::Type::Reference(reference) => parse_type(&reference.elem),
+        syn::Type::Path(path) => {
+            let segment = path.path.segments.last().ok_or("empty type path")?;
+            let name = segment.ident.to_string();
+            let args: Vec<&GenericArgument> = match &segment.arguments {
+                PathArguments::AngleBracketed(args) => args.args.iter().collect(),
+                _ => Vec::new(),
+            };
+            let arg = |index: usize| -> Result<Box<Type>, String> {
+                match args.get(index) {
+                    Some(GenericArgument::Type(ty)) => Ok(Box::new(parse_type(ty)?)),
+                    _ => Err(format!("{name} is missing a type argument")),
+                }
+            };
+
+            match name.as_str() {
+                "Vec" => Ok(Type::Vec { element: arg(0)? }),
+                "Option" => Ok(Type::Option { value: arg(0)? }),
+                "Map" => Ok(Type::Map {
+                    key: arg(0)?,
+                    value: arg(1)?,
+                }),
+                "BytesN" => match args.first() {
+                    Some(GenericArgument::Const(Expr::Lit(expr))) => match &expr.lit {
+                        Lit::Int(n) => Ok(Type::BytesN {
+                            n: n.base10_parse().map_err(|e| e.to_string())?,
+                        }),
+                        _ => Err("BytesN length must be an integer".into()),
+                    },
+                    _ => Err("BytesN length must be a literal".into()),
+                },
+                _ if PRIMITIVES.contains(&name.as_str()) => Ok(Type::Primitive { name }),
+                _ => Ok(Type::Ref { name }),
+            }
+        }
+        _ => Err("unsupported type".into()),
+    }
+}
+
+fn discriminants(item: &syn::ItemEnum) -> Result<Vec<Discriminant>, String> {
+    item.variants
+        .iter()
+        .map(|variant| {
+            let value = match &variant.discriminant {
+                Some((_, Expr::Lit(expr))) => match &expr.lit {
+                    Lit::Int(value) => value.base10_parse().map_err(|e| e.to_string())?,
+                    _ => {
+                        return Err(format!(
+                            "{}::{} needs an integer value",
+                            item.ident, variant.ident
+                        ))
+                    }
+                },
+                _ => {
+                    return Err(format!(
+                        "{}::{} needs an integer value",
+                        item.ident, variant.ident
+                    ))
+                }
+            };
+            Ok(Discriminant {
+                name: variant.ident.to_string(),
+                doc: doc(&variant.attrs),
+                value,
+            })
+        })
+        .collect()
+}
+
+fn doc(attrs: &[Attribute]) -> Option<String> {
+    let lines: Vec<String> = attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("doc"))
+        .filter_map(|attr| match &attr.meta {
+            syn::Meta::NameValue(meta) => match &meta.value {
+                Expr::Lit(expr) => match &expr.lit {
+                    Lit::Str(line) => Some(line.value().trim().to_string()),
+                    _ => None,
+                },
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect();
+    (!lines.is_empty()).then(|| lines.join("\n").trim().to_string())
+}
+
+fn has_attr(attrs: &[Attribute], name: &str) -> bool {
+    attrs.iter().any(|attr| {
+        attr.path()
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == name)
+    })
+}
+
+fn is_pub(vis: &Visibility) -> bool {
+    matches!(vis, Visibility::Public(_))
+}
+
+fn is_test(attrs: &[Attribute]) -> bool {
+    attrs.iter().any(|attr| {
+        attr.path().is_ident("cfg")
+            && attr
+                .parse_args::<syn::Ident>()
+                .is_ok_and(|ident| ident == "test")
+    })
+}
+
+fn crate_name(dir: &Path) -> Result<String, String> {
+    let manifest = manifest_path(dir).ok_or(format!("{}: no Cargo.toml", dir.display()))?;
+    let contents =
+        fs::read_to_string(&manifest).map_err(|e| format!("{}: {e}", manifest.display()))?;
+    contents
+        .lines()
+        .map(str::trim)
+        .find_map(|line| {
+            let value = line.strip_prefix("name")?.trim_start().strip_prefix('=')?;
+            Some(value.trim().trim_matches('"').to_string())
+        })
+        .ok_or(format!("{}: no package name", manifest.display()))
+}
+
+fn manifest_path(dir: &Path) -> Option<PathBuf> {
+    // Some crates in the tree spell it `cargo.toml`
+    ["Cargo.toml", "cargo.toml"]
+        .iter()
+        .map(|name| dir.join(name))
+        .find(|path| path.is_file())
+}
+
+fn find_crates(dir: &Path, crates: &mut Vec<PathBuf>) -> Result<(), String> {
+    if manifest_path(dir).is_some() && dir.join("src").is_dir() {
+        crates.push(dir.to_path_buf());
+    }
+    for entry in fs::read_dir(dir).map_err(|e| format!("{}: {e}", dir.display()))? {
+        let path = entry.map_err(|e| e.to_string())?.path();
+        let name = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or("");
+        if path.is_dir()
+            && !name.starts_with('.')
+            && !matches!(name, "src" | "target" | "node_modules")
+        {
+            find_crates(&path, crates)?;
+        }
+    }
+    Ok(())
+}
+
+fn find_sources(dir: &Path, files: &mut Vec<PathBuf>) -> Result<(), String> {
+    for entry in fs::read_dir(dir).map_err(|e| format!("{}: {e}", dir.display()))? {
+        let path = entry.map_err(|e| e.to_string())?.path();
+        let name = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or("");
+        if path.is_dir() {
+            if !matches!(name, "test" | "tests") {
+                find_sources(&path, files)?;
+            }
+        } else if name.ends_with(".rs") && !matches!(name, "test.rs" | "tests.rs") {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(source: &str) -> CrateSchema {
+        let mut schema = CrateSchema {
+            name: "demo".into(),
+            aliases: Vec::new(),
+            types: Vec::new(),
+        };
+        parse_source(source, &mut schema).unwrap();
+        schema
+    }
+
+    #[test]
+    fn test_parse_struct_and_alias() {
+        let schema = parse(
+            r#"
+            pub type GameId = String;
+
+            /// A score
+            #[contracttype]
+            pub struct Entry {
+                pub player: Address,
+                /// Proof hash
+                pub proof: Option<BytesN<32>>,
+                pub scores: Map<GameId, Vec<(u32, u64)>>,
+            }
+            "#,
+        );
+
+        assert_eq!(schema.aliases.len(), 1);
+        assert_eq!(
+            schema.aliases[0].target,
+            Type::Primitive {
+                name: "String".into()
+            }
+        );
+        let TypeDef::Struct { name, doc, fields } = &schema.types[0] else {
+            panic!("expected a struct");
+        };
+        assert_eq!(name, "Entry");
+        assert_eq!(doc.as_deref(), Some("A score"));
+        assert_eq!(fields[1].doc.as_deref(), Some("Proof hash"));
+        assert_eq!(
+            fields[1].ty,
+            Type::Option {
+                value: Box::new(Type::BytesN { n: 32 })
+            }
+        );
+        assert_eq!(
+            fields[2].ty,
+            Type::Map {
+                key: Box::new(Type::Ref {
+                    name: "GameId".into()
+                }),
+                value: Box::new(Type::Vec {
+                    element: Box::new(Type::Tuple {
+                        items: vec![
+                            Type::Primitive { name: "u32".into() },
+                            Type::Primitive { name: "u64".into() },
+                        ]
+                    })
+                }),
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_enums() {
+        let schema = parse(
+            r#"
+            #[contracttype]
+            pub enum Kind { Melee = 0, Ranged = 1 }
+
+            #[contracttype]
+            pub enum Key { Admin, Game(u32, Address) }
+
+            #[contracterror]
+            pub enum Error { NotFound = 1, Full = 4 }
+            "#,
+        );
+
+        assert!(
+            matches!(&schema.types[0], TypeDef::Enum { variants, .. } if variants[1].value == 1)
+        );
+        let TypeDef::Union { variants, .. } = &schema.types[1] else {
+            panic!("expected a union");
+        };
+        assert!(variants[0].values.is_empty());
+        assert_eq!(variants[1].values.len(), 2);
+        assert!(
+            matches!(&schema.types[2], TypeDef::Error { variants, .. } if variants[1].value == 4)
+        );
+    }
+
+    #[test]
+    fn test_skip_private_and_test_items() {
+        let schema = parse(
+            r#"
+            #[contracttype]
+            enum DataKey { Admin }
+
+            #[cfg(test)]
+            mod test {
+                #[contracttype]
+                pub struct Mock { pub value: u32 }
+            }
+
+            mod inner {
+                #[contracttype]
+                pub struct Visible(pub u32, pub u32);
+            }
+            "#,
+        );
+
+        assert_eq!(schema.types.len(), 1);
+        assert_eq!(schema.types[0].name(), "Visible");
+    }
+}
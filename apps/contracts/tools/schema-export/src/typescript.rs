@@ -0,0 +1,186 @@
+use std::fmt::Write;
+
+use crate::schema::{Bundle, CrateSchema, Type, TypeDef};
+
+/// Render the bundle as TypeScript, one namespace per crate
+///
+/// Values follow the encoding `@stellar/stellar-sdk` uses when converting
+/// contract values: 64-bit and wider integers become `bigint`, bytes become
+/// `Buffer`, and union enums become `{ tag, values }` objects.
+pub fn render(bundle: &Bundle) -> String {
+    let mut out = String::new();
+    out.push_str("// Generated by schema-export from the contract sources. Do not edit.\n\n");
+    out.push_str("import type { Buffer } from \"buffer\";\n");
+    for schema in &bundle.crates {
+        out.push('\n');
+        render_crate(schema, &mut out);
+    }
+    out
+}
+
+// Helper functions
+fn render_crate(schema: &CrateSchema, out: &mut String) {
+    writeln!(out, "export namespace {} {{", pascal_case(&schema.name)).unwrap();
+    for alias in &schema.aliases {
+        writeln!(
+            out,
+            "  export type {} = {};",
+            alias.name,
+            type_name(&alias.target)
+        )
+        .unwrap();
+    }
+
+    for (index, def) in schema.types.iter().enumerate() {
+        if index > 0 || !schema.aliases.is_empty() {
+            out.push('\n');
+        }
+        match def {
+            TypeDef::Struct { name, doc, fields } => {
+                write_doc(out, "  ", doc);
+                writeln!(out, "  export interface {name} {{").unwrap();
+                for field in fields {
+                    write_doc(out, "    ", &field.doc);
+                    writeln!(out, "    {}: {};", field.name, type_name(&field.ty)).unwrap();
+                }
+                out.push_str("  }\n");
+            }
+            TypeDef::Tuple { name, doc, items } => {
+                write_doc(out, "  ", doc);
+                writeln!(out, "  export type {name} = {};", tuple_name(items)).unwrap();
+            }
+            TypeDef::Union {
+                name,
+                doc,
+                variants,
+            } => {
+                write_doc(out, "  ", doc);
+                writeln!(out, "  export type {name} =").unwrap();
+                for (index, variant) in variants.iter().enumerate() {
+                    let values = if variant.values.is_empty() {
+                        "void".to_string()
+                    } else {
+                        tuple_name(&variant.values)
+                    };
+                    let end = if index + 1 == variants.len() { ";" } else { "" };
+                    writeln!(
+                        out,
+                        "    | {{ tag: \"{}\"; values: {values} }}{end}",
+                        variant.name
+                    )
+                    .unwrap();
+                }
+            }
+            TypeDef::Enum {
+                name,
+                doc,
+                variants,
+            }
+            | TypeDef::Error {
+                name,
+                doc,
+                variants,
+            } => {
+                write_doc(out, "  ", doc);
+                writeln!(out, "  export enum {name} {{").unwrap();
+                for variant in variants {
+                    write_doc(out, "    ", &variant.doc);
+                    writeln!(out, "    {} = {},", variant.name, variant.value).unwrap();
+                }
+                out.push_str("  }\n");
+            }
+        }
+    }
+    out.push_str("}\n");
+}
+
+fn type_name(ty: &Type) -> String {
+    match ty {
+        Type::Primitive { name } => match name.as_str() {
+            "bool" => "boolean",
+            "u32" | "i32" => "number",
+            "String" | "Symbol" | "Address" | "MuxedAddress" => "string",
+            "Bytes" => "Buffer",
+            _ => "bigint",
+        }
+        .to_string(),
+        Type::BytesN { .. } => "Buffer".to_string(),
+        Type::Vec { element } => format!("Array<{}>", type_name(element)),
+        Type::Map { key, value } => format!("Map<{}, {}>", type_name(key), type_name(value)),
+        Type::Option { value } => format!("{} | undefined", type_name(value)),
+        Type::Tuple { items } if items.is_empty() => "void".to_string(),
+        Type::Tuple { items } => tuple_name(items),
+        Type::Ref { name } => name.clone(),
+    }
+}
+
+fn tuple_name(items: &[Type]) -> String {
+    let items: Vec<String> = items.iter().map(type_name).collect();
+    format!("readonly [{}]", items.join(", "))
+}
+
+fn write_doc(out: &mut String, indent: &str, doc: &Option<String>) {
+    let Some(doc) = doc else {
+        return;
+    };
+    writeln!(out, "{indent}/**").unwrap();
+    for line in doc.lines() {
+        if line.is_empty() {
+            writeln!(out, "{indent} *").unwrap();
+        } else {
+            writeln!(out, "{indent} * {line}").unwrap();
+        }
+    }
+    writeln!(out, "{indent} */").unwrap();
+}
+
+fn pascal_case(name: &str) -> String {
+    name.split(['-', '_'])
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            let first = chars.next().unwrap().to_ascii_uppercase();
+            first.to_string() + chars.as_str()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::parse_source;
+
+    #[test]
+    fn test_render_crate() {
+        let mut schema = CrateSchema {
+            name: "game-leaderboards_contract".into(),
+            aliases: Vec::new(),
+            types: Vec::new(),
+        };
+        parse_source(
+            r#"
+            pub type Signature = BytesN<64>;
+
+            #[contracttype]
+            pub struct Entry {
+                pub score: u64,
+                pub proof: Option<BytesN<32>>,
+            }
+
+            #[contracttype]
+            pub enum Board { Tournament(String), Global(String) }
+            "#,
+            &mut schema,
+        )
+        .unwrap();
+
+        let ts = render(&Bundle {
+            crates: vec![schema],
+        });
+        assert!(ts.contains("export namespace GameLeaderboardsContract {"));
+        assert!(ts.contains("  export type Signature = Buffer;"));
+        assert!(ts.contains("    score: bigint;"));
+        assert!(ts.contains("    proof: Buffer | undefined;"));
+        assert!(ts.contains("    | { tag: \"Global\"; values: readonly [string] };"));
+    }
+}
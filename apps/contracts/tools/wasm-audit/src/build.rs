@@ -0,0 +1,159 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+const TARGET: &str = "wasm32-unknown-unknown";
+
+/// A contract crate, built as a `cdylib`
+#[derive(Debug, PartialEq)]
+pub struct Contract {
+    /// Package name, as the limits file refers to it
+    pub name: String,
+    /// Name of the library target, which names the `.wasm` file
+    pub lib_name: String,
+    pub manifest: PathBuf,
+}
+
+/// Find every contract crate under `roots`
+///
+/// A contract is any directory holding a `Cargo.toml` next to a `src`
+/// directory whose library builds as a `cdylib`. Crates whose manifest is
+/// spelled `cargo.toml` can't be built by cargo and are reported as skipped.
+pub fn find_contracts(roots: &[PathBuf]) -> Result<Vec<Contract>, String> {
+    let mut dirs = Vec::new();
+    for root in roots {
+        find_crates(root, &mut dirs)?;
+    }
+    dirs.sort();
+
+    let mut contracts = Vec::new();
+    for dir in dirs {
+        let manifest = dir.join("Cargo.toml");
+        if !manifest.is_file() {
+            eprintln!("wasm-audit: skipping {}: no Cargo.toml", dir.display());
+            continue;
+        }
+        let contents =
+            fs::read_to_string(&manifest).map_err(|e| format!("{}: {e}", manifest.display()))?;
+        if let Some(contract) = parse_manifest(&contents, manifest.clone())? {
+            contracts.push(contract);
+        }
+    }
+    Ok(contracts)
+}
+
+/// Read a contract from a manifest, `None` when the crate isn't a `cdylib`
+pub fn parse_manifest(contents: &str, manifest: PathBuf) -> Result<Option<Contract>, String> {
+    let mut section = "";
+    let mut name = None;
+    let mut lib_name = None;
+    let mut cdylib = false;
+    for line in contents.lines().map(str::trim) {
+        if line.starts_with('[') {
+            section = line;
+            continue;
+        }
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+        let value = value.trim().trim_matches('"');
+        match (section, key.trim()) {
+            ("[package]", "name") => name = Some(value.to_string()),
+            ("[lib]", "name") => lib_name = Some(value.to_string()),
+            ("[lib]", "crate-type") => cdylib = value.contains("\"cdylib\""),
+            _ => {}
+        }
+    }
+
+    let name = name.ok_or(format!("{}: no package name", manifest.display()))?;
+    if !cdylib {
+        return Ok(None);
+    }
+    Ok(Some(Contract {
+        lib_name: lib_name.unwrap_or_else(|| name.replace('-', "_")),
+        name,
+        manifest,
+    }))
+}
+
+/// Build a contract for wasm32 with its release profile and return the binary's path
+pub fn build(contract: &Contract, target_dir: &Path) -> Result<PathBuf, String> {
+    let status = Command::new(std::env::var("CARGO").unwrap_or("cargo".into()))
+        .args(["build", "--release", "--lib", "--target", TARGET])
+        .arg("--manifest-path")
+        .arg(&contract.manifest)
+        .arg("--target-dir")
+        .arg(target_dir)
+        .status()
+        .map_err(|e| format!("cargo: {e}"))?;
+    if !status.success() {
+        return Err(format!("{}: build failed", contract.name));
+    }
+
+    Ok(target_dir
+        .join(TARGET)
+        .join("release")
+        .join(format!("{}.wasm", contract.lib_name)))
+}
+
+// Helper functions
+fn find_crates(dir: &Path, crates: &mut Vec<PathBuf>) -> Result<(), String> {
+    let has_manifest = ["Cargo.toml", "cargo.toml"]
+        .iter()
+        .any(|name| dir.join(name).is_file());
+    if has_manifest && dir.join("src").is_dir() {
+        crates.push(dir.to_path_buf());
+    }
+    for entry in fs::read_dir(dir).map_err(|e| format!("{}: {e}", dir.display()))? {
+        let path = entry.map_err(|e| e.to_string())?.path();
+        let name = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or("");
+        if path.is_dir()
+            && !name.starts_with('.')
+            && !matches!(name, "src" | "target" | "node_modules")
+        {
+            find_crates(&path, crates)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_manifest() {
+        let manifest = r#"
+[package]
+name = "soroban-ecs"
+
+[lib]
+name = "soroban_ecs"
+crate-type = ["rlib", "cdylib"]
+
+[dependencies]
+soroban-sdk = "23.0.1"
+"#;
+        let contract = parse_manifest(manifest, "Cargo.toml".into())
+            .unwrap()
+            .unwrap();
+        assert_eq!(contract.name, "soroban-ecs");
+        assert_eq!(contract.lib_name, "soroban_ecs");
+
+        let manifest = "[package]\nname = \"game-leaderboards-contract\"\n\n[lib]\ncrate-type = [\"cdylib\"]\n";
+        let contract = parse_manifest(manifest, "Cargo.toml".into())
+            .unwrap()
+            .unwrap();
+        assert_eq!(contract.lib_name, "game_leaderboards_contract");
+    }
+
+    #[test]
+    fn test_skip_non_contracts() {
+        let manifest = "[package]\nname = \"schema-export\"\n\n[dependencies]\nserde = \"1\"\n";
+        assert_eq!(parse_manifest(manifest, "Cargo.toml".into()), Ok(None));
+        assert!(parse_manifest("[lib]\ncrate-type = [\"cdylib\"]\n", "Cargo.toml".into()).is_err());
+    }
+}
@@ -0,0 +1,145 @@
+//! Build every contract crate for wasm32 and check that its binary stays
+//! within the configured size and export limits, so std or dependency bloat
+//! is caught before a deployment fails on it.
+//!
+//! ```text
+//! wasm-audit [--limits <path>] [--target-dir <path>] [<dir>...]
+//! ```
+//!
+//! Every `cdylib` crate under the given directories is built, `contracts` by
+//! default. Limits are read from `wasm-limits.json` when it exists.
+
+mod build;
+mod wasm;
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process;
+
+use serde::Deserialize;
+
+const USAGE: &str = "usage: wasm-audit [--limits <path>] [--target-dir <path>] [<dir>...]";
+const DEFAULT_LIMITS: &str = "wasm-limits.json";
+const DEFAULT_TARGET_DIR: &str = "target/wasm-audit";
+
+/// Largest binary in bytes when the limits file doesn't say otherwise
+const DEFAULT_MAX_SIZE: usize = 64 * 1024;
+
+/// Size and export limits, as read from the limits file
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Limits {
+    #[serde(default)]
+    default: Limit,
+    /// Overrides by package name
+    #[serde(default)]
+    crates: BTreeMap<String, Limit>,
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Limit {
+    max_size: Option<usize>,
+    max_exports: Option<usize>,
+}
+
+impl Limits {
+    fn for_crate(&self, name: &str) -> Limit {
+        let own = self.crates.get(name).copied().unwrap_or_default();
+        Limit {
+            max_size: own.max_size.or(self.default.max_size),
+            max_exports: own.max_exports.or(self.default.max_exports),
+        }
+    }
+}
+
+fn main() {
+    match run(env::args().skip(1)) {
+        Ok(true) => {}
+        Ok(false) => process::exit(1),
+        Err(message) => {
+            eprintln!("wasm-audit: {message}");
+            process::exit(1);
+        }
+    }
+}
+
+/// Returns whether every contract stayed within its limits
+fn run(mut args: impl Iterator<Item = String>) -> Result<bool, String> {
+    let mut limits_path: Option<PathBuf> = None;
+    let mut target_dir = PathBuf::from(DEFAULT_TARGET_DIR);
+    let mut roots = Vec::new();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--limits" => limits_path = Some(args.next().ok_or(USAGE)?.into()),
+            "--target-dir" => target_dir = args.next().ok_or(USAGE)?.into(),
+            "-h" | "--help" => {
+                println!("{USAGE}");
+                return Ok(true);
+            }
+            _ if arg.starts_with('-') => return Err(USAGE.into()),
+            _ => roots.push(PathBuf::from(arg)),
+        }
+    }
+    if roots.is_empty() {
+        roots.push(PathBuf::from("contracts"));
+    }
+
+    let limits = read_limits(limits_path.as_deref())?;
+    let contracts = build::find_contracts(&roots)?;
+    if contracts.is_empty() {
+        return Err("no contract crates found".into());
+    }
+
+    let mut passed = true;
+    let mut report = Vec::new();
+    for contract in &contracts {
+        let path = build::build(contract, &target_dir)?;
+        let bytes = fs::read(&path).map_err(|e| format!("{}: {e}", path.display()))?;
+        let module = wasm::inspect(&bytes).map_err(|e| format!("{}: {e}", path.display()))?;
+
+        let limit = limits.for_crate(&contract.name);
+        let mut problems = Vec::new();
+        let max_size = limit.max_size.unwrap_or(DEFAULT_MAX_SIZE);
+        if module.size > max_size {
+            problems.push(format!("size {} > {max_size}", module.size));
+        }
+        if let Some(max_exports) = limit.max_exports {
+            if module.functions.len() > max_exports {
+                problems.push(format!(
+                    "exports {} > {max_exports}",
+                    module.functions.len()
+                ));
+            }
+        }
+        passed &= problems.is_empty();
+        report.push((contract.name.clone(), module, problems));
+    }
+
+    println!("{:<32} {:>10} {:>8}", "contract", "bytes", "exports");
+    for (name, module, problems) in &report {
+        let status = if problems.is_empty() {
+            "ok".to_string()
+        } else {
+            problems.join(", ")
+        };
+        println!(
+            "{name:<32} {:>10} {:>8}  {status}",
+            module.size,
+            module.functions.len()
+        );
+    }
+    Ok(passed)
+}
+
+fn read_limits(path: Option<&Path>) -> Result<Limits, String> {
+    let path = match path {
+        Some(path) => path,
+        None if Path::new(DEFAULT_LIMITS).is_file() => Path::new(DEFAULT_LIMITS),
+        None => return Ok(Limits::default()),
+    };
+    let contents = fs::read_to_string(path).map_err(|e| format!("{}: {e}", path.display()))?;
+    serde_json::from_str(&contents).map_err(|e| format!("{}: {e}", path.display()))
+}
@@ -0,0 +1,142 @@
+/// What the audit measures in a compiled contract
+#[derive(Debug, PartialEq)]
+pub struct Module {
+    /// Size of the binary in bytes
+    pub size: usize,
+    /// Names of the exported functions, in export order
+    pub functions: Vec<String>,
+}
+
+const MAGIC: &[u8] = b"\0asm";
+const EXPORT_SECTION: u8 = 7;
+const FUNCTION_EXPORT: u8 = 0;
+
+/// Read the size and exported functions of a WebAssembly binary
+///
+/// Only the section headers and the export section are decoded, which is
+/// enough to measure a contract without pulling in a full wasm parser.
+pub fn inspect(bytes: &[u8]) -> Result<Module, String> {
+    let mut reader = Reader { bytes, pos: 0 };
+    if reader.take(4)? != MAGIC {
+        return Err("not a wasm binary".into());
+    }
+    reader.take(4)?;
+
+    let mut functions = Vec::new();
+    while !reader.is_empty() {
+        let id = reader.byte()?;
+        let len = reader.leb()? as usize;
+        let payload = reader.take(len)?;
+        if id == EXPORT_SECTION {
+            read_exports(payload, &mut functions)?;
+        }
+    }
+
+    Ok(Module {
+        size: bytes.len(),
+        functions,
+    })
+}
+
+// Helper functions
+fn read_exports(payload: &[u8], functions: &mut Vec<String>) -> Result<(), String> {
+    let mut reader = Reader {
+        bytes: payload,
+        pos: 0,
+    };
+    for _ in 0..reader.leb()? {
+        let len = reader.leb()? as usize;
+        let name = String::from_utf8_lossy(reader.take(len)?).into_owned();
+        let kind = reader.byte()?;
+        reader.leb()?;
+        if kind == FUNCTION_EXPORT {
+            functions.push(name);
+        }
+    }
+    Ok(())
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn is_empty(&self) -> bool {
+        self.pos >= self.bytes.len()
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'a [u8], String> {
+        let end = self
+            .pos
+            .checked_add(len)
+            .filter(|end| *end <= self.bytes.len())
+            .ok_or("unexpected end of wasm binary")?;
+        let slice = &self.bytes[self.pos..end];
+        self.pos = end;
+        Ok(slice)
+    }
+
+    fn byte(&mut self) -> Result<u8, String> {
+        Ok(self.take(1)?[0])
+    }
+
+    /// Unsigned LEB128, as wasm encodes every length and index
+    fn leb(&mut self) -> Result<u32, String> {
+        let mut value: u32 = 0;
+        for shift in (0..35).step_by(7) {
+            let byte = self.byte()?;
+            value |= u32::from(byte & 0x7f) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err("malformed integer in wasm binary".into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn module(sections: &[(u8, Vec<u8>)]) -> Vec<u8> {
+        let mut bytes = b"\0asm\x01\0\0\0".to_vec();
+        for (id, payload) in sections {
+            bytes.push(*id);
+            bytes.push(payload.len() as u8);
+            bytes.extend(payload);
+        }
+        bytes
+    }
+
+    fn export(name: &str, kind: u8, index: u8) -> Vec<u8> {
+        let mut bytes = vec![name.len() as u8];
+        bytes.extend(name.as_bytes());
+        bytes.extend([kind, index]);
+        bytes
+    }
+
+    #[test]
+    fn test_count_function_exports() {
+        let mut exports = vec![3];
+        exports.extend(export("memory", 2, 0));
+        exports.extend(export("init", FUNCTION_EXPORT, 4));
+        exports.extend(export("get_score", FUNCTION_EXPORT, 5));
+        let bytes = module(&[(1, vec![0]), (EXPORT_SECTION, exports), (10, vec![0])]);
+
+        let module = inspect(&bytes).unwrap();
+        assert_eq!(module.size, bytes.len());
+        assert_eq!(module.functions, vec!["init", "get_score"]);
+    }
+
+    #[test]
+    fn test_reject_bad_binaries() {
+        assert!(inspect(b"\x7fELF\x02\x01\x01\0").is_err());
+
+        let mut exports = vec![1];
+        exports.extend(export("init", FUNCTION_EXPORT, 0));
+        let mut truncated = module(&[(EXPORT_SECTION, exports)]);
+        truncated.pop();
+        assert!(inspect(&truncated).is_err());
+    }
+}
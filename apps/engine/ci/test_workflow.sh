@@ -44,6 +44,12 @@ if rustup target list --installed | grep -q "wasm32-unknown-unknown"; then
         echo -e "${RED}❌ Wasm32 build failed${NC}"
         exit 1
     fi
+    if (cd ../contracts && cargo run -q -p wasm-audit -- contracts ../engine/contracts); then
+        echo -e "${GREEN}✅ Wasm size audit passed${NC}"
+    else
+        echo -e "${RED}❌ Wasm size audit failed${NC}"
+        exit 1
+    fi
 else
     echo -e "${YELLOW}⚠️ wasm32-unknown-unknown target not installed, skipping wasm build test${NC}"
     echo -e "${YELLOW}  To install: rustup target add wasm32-unknown-unknown${NC}"
@@ -7,6 +7,11 @@
 //! 
 //! - `Position`: Represents the 2D coordinates of an entity in the game world
 //! - `Health`: Represents the health points of an entity
+//! - `Speed`: Movement multiplier applied when an entity carries a rider
+//! - `Parent`: Links a rider to the mount entity carrying it
+//! - `Poisoned`, `Shielded`, `SpeedBoost`: Timed status effects
+//! - `Energy`, `HealingPower`, `Shield`: Support actions and the damage pool they grant
+//! - `Boss`: Phase-based boss behavior and damage attribution
 //!
 //! # Usage Example
 //!
@@ -27,7 +32,8 @@
 //! - Serialization/deserialization for storage
 //! - Integration with the ECS World
 
-use soroban_sdk::{contracttype, symbol_short, Env, Symbol, Bytes};
+use soroban_sdk::xdr::{FromXdr, ToXdr};
+use soroban_sdk::{contracttype, symbol_short, Bytes, Env, Map, Symbol, Vec};
 use soroban_ecs::ComponentTrait;
 
 /// Position component for entities in 2D space
@@ -163,6 +169,871 @@ impl ComponentTrait for Health {
     }
 }
 
+/// Speed component for mounts and other fast-moving entities
+///
+/// Movement deltas applied to an entity carrying a rider are multiplied by
+/// this value, so a mount with `Speed(3)` covers three tiles per step.
+///
+/// # Fields
+///
+/// - `0`: Movement multiplier (1 = normal speed)
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let speed = Speed(2);
+/// // Entity moves twice as far per step
+/// ```
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Speed(pub u32);
+
+impl ComponentTrait for Speed {
+    /// Returns the unique identifier for this component type
+    fn component_type() -> Symbol {
+        symbol_short!("speed")
+    }
+
+    /// Serializes the Speed component to bytes for storage
+    ///
+    /// Format: 4 bytes (big-endian u32)
+    fn serialize(&self, env: &Env) -> Bytes {
+        let mut bytes = Bytes::new(env);
+        bytes.append(&Bytes::from_slice(env, &self.0.to_be_bytes()));
+        bytes
+    }
+
+    /// Deserializes bytes into a Speed component
+    ///
+    /// Returns `None` if the data is not exactly 4 bytes
+    fn deserialize(env: &Env, data: &Bytes) -> Option<Self> {
+        if data.len() != 4 {
+            return None;
+        }
+        let value = u32::from_be_bytes([
+            data.get(0).unwrap(),
+            data.get(1).unwrap(),
+            data.get(2).unwrap(),
+            data.get(3).unwrap(),
+        ]);
+        Some(Self(value))
+    }
+}
+
+/// Parent component forming the entity hierarchy
+///
+/// Attached to a rider while it is mounted. The rider's position follows the
+/// parent entity until the link is removed by dismounting or by either
+/// entity leaving the world.
+///
+/// # Fields
+///
+/// - `0`: ID of the parent (mount) entity
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let parent = Parent(7);
+/// // Entity is riding entity 7
+/// ```
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Parent(pub u32);
+
+impl ComponentTrait for Parent {
+    /// Returns the unique identifier for this component type
+    fn component_type() -> Symbol {
+        symbol_short!("parent")
+    }
+
+    /// Serializes the Parent component to bytes for storage
+    ///
+    /// Format: 4 bytes (big-endian u32)
+    fn serialize(&self, env: &Env) -> Bytes {
+        let mut bytes = Bytes::new(env);
+        bytes.append(&Bytes::from_slice(env, &self.0.to_be_bytes()));
+        bytes
+    }
+
+    /// Deserializes bytes into a Parent component
+    ///
+    /// Returns `None` if the data is not exactly 4 bytes
+    fn deserialize(env: &Env, data: &Bytes) -> Option<Self> {
+        if data.len() != 4 {
+            return None;
+        }
+        let value = u32::from_be_bytes([
+            data.get(0).unwrap(),
+            data.get(1).unwrap(),
+            data.get(2).unwrap(),
+            data.get(3).unwrap(),
+        ]);
+        Some(Self(value))
+    }
+}
+
+/// Team (faction) component
+///
+/// Entities on the same team are allies: with friendly fire disabled,
+/// CombatSystem rejects attacks between them.
+///
+/// # Fields
+///
+/// - `0`: Team identifier
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let team = Team(2);
+/// // Entity fights for team 2
+/// ```
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Team(pub u32);
+
+impl ComponentTrait for Team {
+    /// Returns the unique identifier for this component type
+    fn component_type() -> Symbol {
+        symbol_short!("team")
+    }
+
+    /// Serializes the Team component to bytes for storage
+    ///
+    /// Format: 4 bytes (big-endian u32)
+    fn serialize(&self, env: &Env) -> Bytes {
+        let mut bytes = Bytes::new(env);
+        bytes.append(&Bytes::from_slice(env, &self.0.to_be_bytes()));
+        bytes
+    }
+
+    /// Deserializes bytes into a Team component
+    ///
+    /// Returns `None` if the data is not exactly 4 bytes
+    fn deserialize(_env: &Env, data: &Bytes) -> Option<Self> {
+        if data.len() != 4 {
+            return None;
+        }
+        Some(Self(read_u32(data, 0)))
+    }
+}
+
+/// Projectile component
+///
+/// Marks an entity as a projectile that travels `(dx, dy)` every game tick,
+/// damages the first entity it lands on and disappears after `ttl` ticks.
+///
+/// # Fields
+///
+/// - `dx`, `dy`: Movement per tick
+/// - `damage`: Damage dealt on impact
+/// - `ttl`: Remaining ticks before the projectile despawns
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Projectile {
+    pub dx: i32,
+    pub dy: i32,
+    pub damage: u32,
+    pub ttl: u32,
+}
+
+impl ComponentTrait for Projectile {
+    /// Returns the unique identifier for this component type
+    fn component_type() -> Symbol {
+        symbol_short!("project")
+    }
+
+    /// Serializes the Projectile component to bytes for storage
+    ///
+    /// Format: 16 bytes (i32 dx, i32 dy, u32 damage, u32 ttl), big-endian
+    fn serialize(&self, env: &Env) -> Bytes {
+        let mut bytes = Bytes::new(env);
+        bytes.append(&Bytes::from_slice(env, &self.dx.to_be_bytes()));
+        bytes.append(&Bytes::from_slice(env, &self.dy.to_be_bytes()));
+        bytes.append(&Bytes::from_slice(env, &self.damage.to_be_bytes()));
+        bytes.append(&Bytes::from_slice(env, &self.ttl.to_be_bytes()));
+        bytes
+    }
+
+    /// Deserializes bytes into a Projectile component
+    ///
+    /// Returns `None` if the data is not exactly 16 bytes
+    fn deserialize(_env: &Env, data: &Bytes) -> Option<Self> {
+        if data.len() != 16 {
+            return None;
+        }
+        Some(Self {
+            dx: read_u32(data, 0) as i32,
+            dy: read_u32(data, 4) as i32,
+            damage: read_u32(data, 8),
+            ttl: read_u32(data, 12),
+        })
+    }
+}
+
+/// Maximum health component
+///
+/// Caps how far regeneration can restore an entity's health.
+///
+/// # Fields
+///
+/// - `0`: Health ceiling
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MaxHealth(pub u32);
+
+impl ComponentTrait for MaxHealth {
+    /// Returns the unique identifier for this component type
+    fn component_type() -> Symbol {
+        symbol_short!("max_hp")
+    }
+
+    /// Serializes the MaxHealth component to bytes for storage
+    ///
+    /// Format: 4 bytes (big-endian u32)
+    fn serialize(&self, env: &Env) -> Bytes {
+        let mut bytes = Bytes::new(env);
+        bytes.append(&Bytes::from_slice(env, &self.0.to_be_bytes()));
+        bytes
+    }
+
+    /// Deserializes bytes into a MaxHealth component
+    ///
+    /// Returns `None` if the data is not exactly 4 bytes
+    fn deserialize(_env: &Env, data: &Bytes) -> Option<Self> {
+        if data.len() != 4 {
+            return None;
+        }
+        Some(Self(read_u32(data, 0)))
+    }
+}
+
+/// Health regeneration component
+///
+/// Restores `rate_per_minute` health for every full minute of ledger time
+/// since `last_update`. Applied lazily by `RegenSystem` whenever the entity
+/// is read or ticked.
+///
+/// # Fields
+///
+/// - `rate_per_minute`: Health restored per minute
+/// - `last_update`: Ledger timestamp up to which regeneration has been applied
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Regeneration {
+    pub rate_per_minute: u32,
+    pub last_update: u64,
+}
+
+impl ComponentTrait for Regeneration {
+    /// Returns the unique identifier for this component type
+    fn component_type() -> Symbol {
+        symbol_short!("regen")
+    }
+
+    /// Serializes the Regeneration component to bytes for storage
+    ///
+    /// Format: 12 bytes (u32 rate_per_minute, u64 last_update), big-endian
+    fn serialize(&self, env: &Env) -> Bytes {
+        let mut bytes = Bytes::new(env);
+        bytes.append(&Bytes::from_slice(env, &self.rate_per_minute.to_be_bytes()));
+        bytes.append(&Bytes::from_slice(env, &self.last_update.to_be_bytes()));
+        bytes
+    }
+
+    /// Deserializes bytes into a Regeneration component
+    ///
+    /// Returns `None` if the data is not exactly 12 bytes
+    fn deserialize(_env: &Env, data: &Bytes) -> Option<Self> {
+        if data.len() != 12 {
+            return None;
+        }
+        Some(Self {
+            rate_per_minute: read_u32(data, 0),
+            last_update: read_u64(data, 4),
+        })
+    }
+}
+
+/// Loot component
+///
+/// Carried by the loot entity dropped where another entity died, until an
+/// adjacent entity picks it up.
+///
+/// # Fields
+///
+/// - `currency`: Currency granted to the collector
+/// - `item_id`: Item granted to the collector
+/// - `quantity`: Number of `item_id` items granted
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Loot {
+    pub currency: u32,
+    pub item_id: u32,
+    pub quantity: u32,
+}
+
+impl ComponentTrait for Loot {
+    /// Returns the unique identifier for this component type
+    fn component_type() -> Symbol {
+        symbol_short!("loot")
+    }
+
+    /// Serializes the Loot component to bytes for storage
+    ///
+    /// Format: 12 bytes (u32 currency, u32 item_id, u32 quantity), big-endian
+    fn serialize(&self, env: &Env) -> Bytes {
+        let mut bytes = Bytes::new(env);
+        bytes.append(&Bytes::from_slice(env, &self.currency.to_be_bytes()));
+        bytes.append(&Bytes::from_slice(env, &self.item_id.to_be_bytes()));
+        bytes.append(&Bytes::from_slice(env, &self.quantity.to_be_bytes()));
+        bytes
+    }
+
+    /// Deserializes bytes into a Loot component
+    ///
+    /// Returns `None` if the data is not exactly 12 bytes
+    fn deserialize(_env: &Env, data: &Bytes) -> Option<Self> {
+        if data.len() != 12 {
+            return None;
+        }
+        Some(Self {
+            currency: read_u32(data, 0),
+            item_id: read_u32(data, 4),
+            quantity: read_u32(data, 8),
+        })
+    }
+}
+
+/// Inventory component
+///
+/// Holds the currency and items an entity has collected.
+///
+/// # Fields
+///
+/// - `currency`: Collected currency
+/// - `items`: Quantity held per item ID
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Inventory {
+    pub currency: u32,
+    pub items: Map<u32, u32>,
+}
+
+impl Inventory {
+    /// Creates an empty inventory
+    pub fn new(env: &Env) -> Self {
+        Self {
+            currency: 0,
+            items: Map::new(env),
+        }
+    }
+}
+
+impl ComponentTrait for Inventory {
+    /// Returns the unique identifier for this component type
+    fn component_type() -> Symbol {
+        symbol_short!("inventory")
+    }
+
+    /// Serializes the Inventory component to bytes for storage
+    ///
+    /// The item map has no fixed size, so the component is stored as XDR.
+    fn serialize(&self, env: &Env) -> Bytes {
+        self.clone().to_xdr(env)
+    }
+
+    /// Deserializes XDR bytes into an Inventory component
+    ///
+    /// Returns `None` if the bytes decode to a value other than an Inventory
+    fn deserialize(env: &Env, data: &Bytes) -> Option<Self> {
+        Self::from_xdr(env, data).ok()
+    }
+}
+
+/// The kind of object an `Interactable` entity represents
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InteractableKind {
+    Door,
+    Switch,
+    Chest,
+}
+
+/// Interactable component
+///
+/// Marks doors, switches and chests that adjacent entities can use.
+///
+/// # Fields
+///
+/// - `kind`: What the entity is
+/// - `active`: Whether a door is open, a switch is on or a chest is open
+/// - `key_item`: Item the interacting entity must hold, if any
+/// - `linked`: Interactable entity a switch toggles along with itself
+/// - `contents`: Loot a chest hands out the first time it is opened, empty for doors and switches
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Interactable {
+    pub kind: InteractableKind,
+    pub active: bool,
+    pub key_item: Option<u32>,
+    pub linked: Option<u32>,
+    pub contents: Vec<Loot>,
+}
+
+impl ComponentTrait for Interactable {
+    /// Returns the unique identifier for this component type
+    fn component_type() -> Symbol {
+        symbol_short!("interact")
+    }
+
+    /// Serializes the Interactable component to bytes for storage
+    ///
+    /// The optional fields have no fixed size, so the component is stored as XDR.
+    fn serialize(&self, env: &Env) -> Bytes {
+        self.clone().to_xdr(env)
+    }
+
+    /// Deserializes XDR bytes into an Interactable component
+    ///
+    /// Returns `None` if the bytes decode to a value other than an Interactable
+    fn deserialize(env: &Env, data: &Bytes) -> Option<Self> {
+        Self::from_xdr(env, data).ok()
+    }
+}
+
+/// How a boss picks its targets during a phase
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BossBehavior {
+    /// Strikes one adjacent entity
+    Melee,
+    /// Strikes one entity within ranged attack range and line of sight
+    Ranged,
+    /// Strikes every entity within its aggro radius
+    Enraged,
+}
+
+/// One phase of a boss fight
+///
+/// # Fields
+///
+/// - `threshold_percent`: The phase starts once health drops to this share of max health
+/// - `behavior`: How the boss attacks during the phase
+/// - `damage`: Damage of each boss attack during the phase
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BossPhase {
+    pub threshold_percent: u32,
+    pub behavior: BossBehavior,
+    pub damage: u32,
+}
+
+/// Boss component
+///
+/// Marks an entity as a boss that attacks on every game tick and splits a
+/// reward among the entities that damaged it when it dies.
+///
+/// # Fields
+///
+/// - `max_health`: Health the phase thresholds are measured against
+/// - `phases`: Phases in order of descending threshold, the first at 100%
+/// - `phase`: Index of the current phase, which only moves forward
+/// - `reward_currency`: Currency shared among contributors on death
+/// - `damage_by`: Damage dealt to the boss by each attacking entity
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Boss {
+    pub max_health: u32,
+    pub phases: Vec<BossPhase>,
+    pub phase: u32,
+    pub reward_currency: u32,
+    pub damage_by: Map<u32, u32>,
+}
+
+impl ComponentTrait for Boss {
+    /// Returns the unique identifier for this component type
+    fn component_type() -> Symbol {
+        symbol_short!("boss")
+    }
+
+    /// Serializes the Boss component to bytes for storage
+    ///
+    /// The phase list and damage map have no fixed size, so the component is stored as XDR.
+    fn serialize(&self, env: &Env) -> Bytes {
+        self.clone().to_xdr(env)
+    }
+
+    /// Deserializes XDR bytes into a Boss component
+    ///
+    /// Returns `None` if the bytes decode to a value other than a Boss
+    fn deserialize(env: &Env, data: &Bytes) -> Option<Self> {
+        Self::from_xdr(env, data).ok()
+    }
+}
+
+/// Reads a big-endian u32 from `data` starting at `offset`
+fn read_u32(data: &Bytes, offset: u32) -> u32 {
+    u32::from_be_bytes([
+        data.get(offset).unwrap(),
+        data.get(offset + 1).unwrap(),
+        data.get(offset + 2).unwrap(),
+        data.get(offset + 3).unwrap(),
+    ])
+}
+
+/// Reads a big-endian u64 from `data` starting at `offset`
+fn read_u64(data: &Bytes, offset: u32) -> u64 {
+    ((read_u32(data, offset) as u64) << 32) | read_u32(data, offset + 4) as u64
+}
+
+/// Poison status effect
+///
+/// Deals `damage` every `EffectSystem::POISON_INTERVAL_SECS` seconds of
+/// ledger time until `expires_at`.
+///
+/// # Fields
+///
+/// - `damage`: Damage dealt per poison interval
+/// - `last_tick`: Ledger timestamp up to which damage has been applied
+/// - `expires_at`: Ledger timestamp at which the poison wears off
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Poisoned {
+    pub damage: u32,
+    pub last_tick: u64,
+    pub expires_at: u64,
+}
+
+impl ComponentTrait for Poisoned {
+    /// Returns the unique identifier for this component type
+    fn component_type() -> Symbol {
+        symbol_short!("poisoned")
+    }
+
+    /// Serializes the Poisoned component to bytes for storage
+    ///
+    /// Format: 20 bytes (u32 damage, u64 last_tick, u64 expires_at), big-endian
+    fn serialize(&self, env: &Env) -> Bytes {
+        let mut bytes = Bytes::new(env);
+        bytes.append(&Bytes::from_slice(env, &self.damage.to_be_bytes()));
+        bytes.append(&Bytes::from_slice(env, &self.last_tick.to_be_bytes()));
+        bytes.append(&Bytes::from_slice(env, &self.expires_at.to_be_bytes()));
+        bytes
+    }
+
+    /// Deserializes bytes into a Poisoned component
+    ///
+    /// Returns `None` if the data is not exactly 20 bytes
+    fn deserialize(_env: &Env, data: &Bytes) -> Option<Self> {
+        if data.len() != 20 {
+            return None;
+        }
+        Some(Self {
+            damage: read_u32(data, 0),
+            last_tick: read_u64(data, 4),
+            expires_at: read_u64(data, 12),
+        })
+    }
+}
+
+/// Shield status effect
+///
+/// Absorbs up to `reduction` points of every incoming hit until `expires_at`.
+///
+/// # Fields
+///
+/// - `reduction`: Flat damage reduction per hit
+/// - `expires_at`: Ledger timestamp at which the shield fades
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Shielded {
+    pub reduction: u32,
+    pub expires_at: u64,
+}
+
+impl ComponentTrait for Shielded {
+    /// Returns the unique identifier for this component type
+    fn component_type() -> Symbol {
+        symbol_short!("shielded")
+    }
+
+    /// Serializes the Shielded component to bytes for storage
+    ///
+    /// Format: 12 bytes (u32 reduction, u64 expires_at), big-endian
+    fn serialize(&self, env: &Env) -> Bytes {
+        let mut bytes = Bytes::new(env);
+        bytes.append(&Bytes::from_slice(env, &self.reduction.to_be_bytes()));
+        bytes.append(&Bytes::from_slice(env, &self.expires_at.to_be_bytes()));
+        bytes
+    }
+
+    /// Deserializes bytes into a Shielded component
+    ///
+    /// Returns `None` if the data is not exactly 12 bytes
+    fn deserialize(_env: &Env, data: &Bytes) -> Option<Self> {
+        if data.len() != 12 {
+            return None;
+        }
+        Some(Self {
+            reduction: read_u32(data, 0),
+            expires_at: read_u64(data, 4),
+        })
+    }
+}
+
+/// Speed boost status effect
+///
+/// Adds `bonus` to the entity's movement multiplier until `expires_at`.
+///
+/// # Fields
+///
+/// - `bonus`: Extra movement multiplier
+/// - `expires_at`: Ledger timestamp at which the boost ends
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SpeedBoost {
+    pub bonus: u32,
+    pub expires_at: u64,
+}
+
+impl ComponentTrait for SpeedBoost {
+    /// Returns the unique identifier for this component type
+    fn component_type() -> Symbol {
+        symbol_short!("spd_boost")
+    }
+
+    /// Serializes the SpeedBoost component to bytes for storage
+    ///
+    /// Format: 12 bytes (u32 bonus, u64 expires_at), big-endian
+    fn serialize(&self, env: &Env) -> Bytes {
+        let mut bytes = Bytes::new(env);
+        bytes.append(&Bytes::from_slice(env, &self.bonus.to_be_bytes()));
+        bytes.append(&Bytes::from_slice(env, &self.expires_at.to_be_bytes()));
+        bytes
+    }
+
+    /// Deserializes bytes into a SpeedBoost component
+    ///
+    /// Returns `None` if the data is not exactly 12 bytes
+    fn deserialize(_env: &Env, data: &Bytes) -> Option<Self> {
+        if data.len() != 12 {
+            return None;
+        }
+        Some(Self {
+            bonus: read_u32(data, 0),
+            expires_at: read_u64(data, 4),
+        })
+    }
+}
+
+/// Energy component
+///
+/// Spent by support actions such as healing an ally or casting a shield.
+///
+/// # Fields
+///
+/// - `0`: Energy left
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Energy(pub u32);
+
+impl ComponentTrait for Energy {
+    /// Returns the unique identifier for this component type
+    fn component_type() -> Symbol {
+        symbol_short!("energy")
+    }
+
+    /// Serializes the Energy component to bytes for storage
+    ///
+    /// Format: 4 bytes (big-endian u32)
+    fn serialize(&self, env: &Env) -> Bytes {
+        let mut bytes = Bytes::new(env);
+        bytes.append(&Bytes::from_slice(env, &self.0.to_be_bytes()));
+        bytes
+    }
+
+    /// Deserializes bytes into an Energy component
+    ///
+    /// Returns `None` if the data is not exactly 4 bytes
+    fn deserialize(_env: &Env, data: &Bytes) -> Option<Self> {
+        if data.len() != 4 {
+            return None;
+        }
+        Some(Self(read_u32(data, 0)))
+    }
+}
+
+/// Healing stat component
+///
+/// Caps the health one heal restores and the damage one cast shield absorbs.
+///
+/// # Fields
+///
+/// - `0`: Most health or shield granted per support action
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HealingPower(pub u32);
+
+impl ComponentTrait for HealingPower {
+    /// Returns the unique identifier for this component type
+    fn component_type() -> Symbol {
+        symbol_short!("heal_pwr")
+    }
+
+    /// Serializes the HealingPower component to bytes for storage
+    ///
+    /// Format: 4 bytes (big-endian u32)
+    fn serialize(&self, env: &Env) -> Bytes {
+        let mut bytes = Bytes::new(env);
+        bytes.append(&Bytes::from_slice(env, &self.0.to_be_bytes()));
+        bytes
+    }
+
+    /// Deserializes bytes into a HealingPower component
+    ///
+    /// Returns `None` if the data is not exactly 4 bytes
+    fn deserialize(_env: &Env, data: &Bytes) -> Option<Self> {
+        if data.len() != 4 {
+            return None;
+        }
+        Some(Self(read_u32(data, 0)))
+    }
+}
+
+/// Cast shield component
+///
+/// Unlike `Shielded`, which blunts every hit by a flat amount, a shield is
+/// a pool of `amount` damage that is used up before Health until
+/// `expires_at`.
+///
+/// # Fields
+///
+/// - `amount`: Damage the shield can still absorb
+/// - `expires_at`: Ledger timestamp at which the shield fades
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Shield {
+    pub amount: u32,
+    pub expires_at: u64,
+}
+
+impl ComponentTrait for Shield {
+    /// Returns the unique identifier for this component type
+    fn component_type() -> Symbol {
+        symbol_short!("barrier")
+    }
+
+    /// Serializes the Shield component to bytes for storage
+    ///
+    /// Format: 12 bytes (u32 amount, u64 expires_at), big-endian
+    fn serialize(&self, env: &Env) -> Bytes {
+        let mut bytes = Bytes::new(env);
+        bytes.append(&Bytes::from_slice(env, &self.amount.to_be_bytes()));
+        bytes.append(&Bytes::from_slice(env, &self.expires_at.to_be_bytes()));
+        bytes
+    }
+
+    /// Deserializes bytes into a Shield component
+    ///
+    /// Returns `None` if the data is not exactly 12 bytes
+    fn deserialize(_env: &Env, data: &Bytes) -> Option<Self> {
+        if data.len() != 12 {
+            return None;
+        }
+        Some(Self {
+            amount: read_u32(data, 0),
+            expires_at: read_u64(data, 4),
+        })
+    }
+}
+
+/// Kinds of status effect that can be applied to an entity
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EffectKind {
+    Poisoned,
+    Shielded,
+    SpeedBoost,
+}
+
+/// A status effect as reported to clients
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ActiveEffect {
+    pub kind: EffectKind,
+    /// Poison damage per interval, shield reduction per hit, or speed bonus
+    pub magnitude: u32,
+    pub expires_at: u64,
+}
+
+/// World-level weather that modifies how the systems behave
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Weather {
+    Clear,
+    Storm,
+    Night,
+    Fog,
+}
+
+/// Percentage modifiers a weather applies, where 100 leaves a value unchanged
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WeatherModifiers {
+    pub movement_percent: u32,
+    pub damage_percent: u32,
+    pub aggro_percent: u32,
+}
+
+/// The world's weather resource and its rotation schedule
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WorldWeather {
+    pub weather: Weather,
+    /// Seconds between automatic changes during ticks, or 0 to hold the weather
+    pub rotation_period: u64,
+    /// Ledger timestamp of the last change
+    pub changed_at: u64,
+}
+
+/// Kind of an admin-scripted world event and the parameters it takes
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WorldEventKind {
+    /// `[x, y, width, height, count, damage]`: drops `count` meteors in the area
+    MeteorShower,
+    /// `[duration, percent]`: scales XP by `percent` for `duration` ledgers
+    DoubleXp,
+    /// `[x, y, health]`: spawns a boss entity
+    BossSpawn,
+}
+
+/// A world event waiting for `game_tick` to reach its start ledger
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WorldEvent {
+    pub kind: WorldEventKind,
+    /// Ledger sequence from which the next tick runs the event
+    pub start_seq: u32,
+    pub params: Vec<u32>,
+}
+
+/// The XP multiplier opened by a double-XP world event
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct XpWindow {
+    pub percent: u32,
+    /// Ledger sequence at which the multiplier ends
+    pub ends_seq: u32,
+}
+
+/// All status effects attached to an entity, grouped for the EffectSystem
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StatusEffects {
+    pub poisoned: Option<Poisoned>,
+    pub shielded: Option<Shielded>,
+    pub speed_boost: Option<SpeedBoost>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -219,4 +1090,134 @@ mod tests {
         let result = Health::deserialize(&env, &bytes);
         assert!(result.is_none());
     }
+
+    #[test]
+    fn test_speed_serialization() {
+        let env = Env::default();
+        let speed = Speed(3);
+
+        let bytes = speed.serialize(&env);
+        assert_eq!(bytes.len(), 4);
+
+        let deserialized = Speed::deserialize(&env, &bytes).unwrap();
+        assert_eq!(deserialized, speed);
+    }
+
+    #[test]
+    fn test_parent_serialization() {
+        let env = Env::default();
+        let parent = Parent(42);
+
+        let bytes = parent.serialize(&env);
+        assert_eq!(bytes.len(), 4);
+
+        let deserialized = Parent::deserialize(&env, &bytes).unwrap();
+        assert_eq!(deserialized, parent);
+        assert_eq!(Parent::component_type(), symbol_short!("parent"));
+    }
+
+    #[test]
+    fn test_team_serialization() {
+        let env = Env::default();
+        let team = Team(9);
+
+        let bytes = team.serialize(&env);
+        assert_eq!(bytes.len(), 4);
+        assert_eq!(Team::deserialize(&env, &bytes).unwrap(), team);
+        assert!(Team::deserialize(&env, &Bytes::from_slice(&env, &[9])).is_none());
+    }
+
+    #[test]
+    fn test_projectile_serialization() {
+        let env = Env::default();
+        let projectile = Projectile { dx: -2, dy: 1, damage: 25, ttl: 3 };
+
+        let bytes = projectile.serialize(&env);
+        assert_eq!(bytes.len(), 16);
+        assert_eq!(Projectile::deserialize(&env, &bytes).unwrap(), projectile);
+    }
+
+    #[test]
+    fn test_regeneration_serialization() {
+        let env = Env::default();
+
+        let max_health = MaxHealth(150);
+        let bytes = max_health.serialize(&env);
+        assert_eq!(bytes.len(), 4);
+        assert_eq!(MaxHealth::deserialize(&env, &bytes).unwrap(), max_health);
+
+        let regen = Regeneration { rate_per_minute: 6, last_update: 1_700_000_000 };
+        let bytes = regen.serialize(&env);
+        assert_eq!(bytes.len(), 12);
+        assert_eq!(Regeneration::deserialize(&env, &bytes).unwrap(), regen);
+    }
+
+    #[test]
+    fn test_loot_and_inventory_serialization() {
+        let env = Env::default();
+
+        let loot = Loot { currency: 12, item_id: 3, quantity: 2 };
+        let bytes = loot.serialize(&env);
+        assert_eq!(bytes.len(), 12);
+        assert_eq!(Loot::deserialize(&env, &bytes).unwrap(), loot);
+
+        let mut inventory = Inventory::new(&env);
+        inventory.currency = 40;
+        inventory.items.set(3, 2);
+        let bytes = inventory.serialize(&env);
+        assert_eq!(Inventory::deserialize(&env, &bytes).unwrap(), inventory);
+        assert!(Inventory::deserialize(&env, &loot.serialize(&env).len().to_xdr(&env)).is_none());
+    }
+
+    #[test]
+    fn test_status_effect_serialization() {
+        let env = Env::default();
+
+        let poison = Poisoned { damage: 5, last_tick: 1_000, expires_at: u64::MAX };
+        let bytes = poison.serialize(&env);
+        assert_eq!(bytes.len(), 20);
+        assert_eq!(Poisoned::deserialize(&env, &bytes).unwrap(), poison);
+
+        let shield = Shielded { reduction: 7, expires_at: 2_000 };
+        let bytes = shield.serialize(&env);
+        assert_eq!(bytes.len(), 12);
+        assert_eq!(Shielded::deserialize(&env, &bytes).unwrap(), shield);
+
+        let boost = SpeedBoost { bonus: 2, expires_at: 3_000 };
+        let bytes = boost.serialize(&env);
+        assert_eq!(SpeedBoost::deserialize(&env, &bytes).unwrap(), boost);
+        assert!(SpeedBoost::deserialize(&env, &Bytes::from_slice(&env, &[1, 2])).is_none());
+    }
+
+    #[test]
+    fn test_support_serialization() {
+        let env = Env::default();
+
+        let bytes = Energy(40).serialize(&env);
+        assert_eq!(bytes.len(), 4);
+        assert_eq!(Energy::deserialize(&env, &bytes).unwrap(), Energy(40));
+        assert_eq!(HealingPower::deserialize(&env, &HealingPower(15).serialize(&env)).unwrap(), HealingPower(15));
+
+        let shield = Shield { amount: 25, expires_at: 600 };
+        let bytes = shield.serialize(&env);
+        assert_eq!(bytes.len(), 12);
+        assert_eq!(Shield::deserialize(&env, &bytes).unwrap(), shield);
+        assert!(Shield::deserialize(&env, &Bytes::from_slice(&env, &[1, 2])).is_none());
+    }
+
+    #[test]
+    fn test_interactable_serialization() {
+        let env = Env::default();
+
+        let chest = Interactable {
+            kind: InteractableKind::Chest,
+            active: false,
+            key_item: Some(4),
+            linked: None,
+            contents: Vec::from_array(&env, [Loot { currency: 5, item_id: 1, quantity: 1 }]),
+        };
+        let bytes = chest.serialize(&env);
+        assert_eq!(Interactable::deserialize(&env, &bytes).unwrap(), chest);
+        assert_eq!(Interactable::component_type(), symbol_short!("interact"));
+    }
 }
@@ -0,0 +1,33 @@
+//! Crafting Station Integration
+//!
+//! Crafting stations are world entities that route crafting requests to an
+//! external crafting contract. The game contract only enforces world rules
+//! (ownership, adjacency); recipes and item minting live in the crafting
+//! contract.
+//!
+//! # Usage Example
+//!
+//! ```rust,ignore
+//! // Place an anvil next to the player and craft recipe 3 there
+//! let station_id = client.spawn_station(&owner, &11, &10, &ANVIL, &crafting_contract);
+//! client.craft_at_station(&player_id, &station_id, &3);
+//! ```
+
+use soroban_sdk::{contractclient, contracttype, Address, Env};
+
+/// Configuration attached to a crafting station entity
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CraftingStation {
+    /// Kind of station (forge, alchemy table, ...) interpreted by the crafting contract
+    pub station_type: u32,
+    /// Crafting contract that executes recipes for this station
+    pub crafting_contract: Address,
+}
+
+/// Interface the game contract expects from a crafting contract
+#[contractclient(name = "CraftingClient")]
+pub trait CraftingInterface {
+    /// Crafts `recipe_id` for `crafter` at a station of `station_type`
+    fn craft(env: Env, crafter: Address, recipe_id: u32, station_type: u32);
+}
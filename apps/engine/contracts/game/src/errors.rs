@@ -0,0 +1,80 @@
+use soroban_sdk::contracterror;
+
+/// Errors returned by the game world contract's mutating endpoints
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum GameError {
+    /// No entity with the given ID exists in the world
+    EntityNotFound = 1,
+    /// The entity was killed and removed from the world
+    EntityDead = 2,
+    /// Caller is not allowed to perform this operation
+    Unauthorized = 3,
+    /// Stored entity data could not be decoded
+    InvalidEntityData = 4,
+    /// Entities must be on neighbouring tiles for this operation
+    NotAdjacent = 5,
+    /// The rider is already mounted or the mount already carries a rider
+    AlreadyMounted = 6,
+    /// The entity is not currently mounted
+    NotMounted = 7,
+    /// The target entity cannot be ridden
+    NotAMount = 8,
+    /// An entity cannot target itself with this operation
+    InvalidTarget = 9,
+    /// The target entity is not a crafting station
+    NotAStation = 10,
+    /// The crafting contract rejected the recipe
+    CraftingFailed = 11,
+    /// Status effect magnitude or duration is zero or above its cap
+    InvalidEffect = 12,
+    /// An admin has already been set for this contract
+    AlreadyInitialized = 13,
+    /// The world is paused and rejects state changes
+    Paused = 14,
+    /// The target is on the attacker's team and friendly fire is disabled
+    FriendlyFire = 15,
+    /// Projectile damage or lifetime is zero
+    InvalidProjectile = 16,
+    /// The target entity is not a loot drop
+    NotLoot = 17,
+    /// No path to the destination was found within the node budget
+    NoPath = 18,
+    /// No checkpoint has been saved under this name
+    CheckpointNotFound = 19,
+    /// The checkpoint was written with a layout this contract cannot read
+    UnsupportedCheckpoint = 20,
+    /// The target entity is not a door, switch or chest
+    NotInteractable = 21,
+    /// The interactable needs a key item the entity does not hold
+    MissingKey = 22,
+    /// The target is further away than the attack can reach
+    OutOfRange = 23,
+    /// A wall or closed door stands between the attacker and the target
+    NoLineOfSight = 24,
+    /// The world event's parameters don't fit its kind or its start has passed
+    InvalidWorldEvent = 25,
+    /// The boss has no health or its phases are out of order or deal no damage
+    InvalidBoss = 26,
+    /// No inventory contract has been configured for tokenizing entities
+    NoInventoryContract = 27,
+    /// The inventory contract rejected the mint, lookup or burn
+    InventoryFailed = 28,
+    /// The item does not hold a tokenized entity
+    ItemNotFound = 29,
+    /// The tile is blocked by a closed door
+    TileBlocked = 30,
+    /// The target is not on the caster's team
+    NotAllies = 31,
+    /// The caster has too little energy for this action
+    NotEnoughEnergy = 32,
+    /// The heal or shield amount is zero or above the caster's healing power
+    InvalidSupportAmount = 33,
+    /// Bosses, doors, loot, projectiles and crafting stations can't become items
+    NotTokenizable = 34,
+    /// The region spans more grid cells than one query may read
+    RegionTooLarge = 35,
+    /// The world holds more live entities than a checkpoint may cover
+    WorldTooLarge = 36,
+}
@@ -0,0 +1,315 @@
+use soroban_sdk::{contractevent, Address, Env, Symbol};
+
+use crate::components::{BossBehavior, Position, Weather, WorldEventKind};
+
+/// Emitted when a new entity enters the world
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EntitySpawned {
+    #[topic]
+    pub entity_id: u32,
+    pub x: u32,
+    pub y: u32,
+}
+
+/// Emitted whenever an entity's position changes
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EntityMoved {
+    #[topic]
+    pub entity_id: u32,
+    pub x: u32,
+    pub y: u32,
+}
+
+/// Emitted when an entity takes damage, including the final lethal hit
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EntityDamaged {
+    #[topic]
+    pub entity_id: u32,
+    pub damage: u32,
+    pub health: u32,
+}
+
+/// Emitted when an entity's health reaches zero
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EntityDied {
+    #[topic]
+    pub entity_id: u32,
+    pub x: u32,
+    pub y: u32,
+}
+
+/// Emitted when an entity is removed from the world without dying
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EntityDespawned {
+    #[topic]
+    pub entity_id: u32,
+}
+
+/// Emitted when the admin role is handed over
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminChanged {
+    pub old_admin: Address,
+    pub new_admin: Address,
+}
+
+/// Emitted when the world is rolled back to a named checkpoint
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CheckpointRestored {
+    #[topic]
+    pub name: Symbol,
+    pub version: u32,
+    pub entity_count: u32,
+}
+
+/// Emitted when the world weather changes, by the admin or by rotation
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WeatherChanged {
+    pub weather: Weather,
+}
+
+/// Emitted when an entity uses a door, switch or chest
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Interacted {
+    #[topic]
+    pub target_id: u32,
+    pub entity_id: u32,
+    pub active: bool,
+}
+
+/// Emitted when the admin schedules a world event
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WorldEventScheduled {
+    #[topic]
+    pub event_id: u32,
+    pub kind: WorldEventKind,
+    pub start_seq: u32,
+}
+
+/// Emitted when a tick runs a scheduled world event
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WorldEventStarted {
+    #[topic]
+    pub event_id: u32,
+    pub kind: WorldEventKind,
+}
+
+/// Emitted when a boss's health crosses a phase threshold
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BossPhaseChanged {
+    #[topic]
+    pub boss_id: u32,
+    pub phase: u32,
+    pub behavior: BossBehavior,
+}
+
+/// Emitted for each contributor rewarded when a boss dies
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BossRewarded {
+    #[topic]
+    pub boss_id: u32,
+    pub entity_id: u32,
+    pub damage: u32,
+    pub currency: u32,
+}
+
+/// Emitted when an entity leaves the world as an inventory item
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EntityTokenized {
+    #[topic]
+    pub entity_id: u32,
+    pub item_id: u64,
+    pub owner: Address,
+}
+
+/// Emitted when an inventory item is redeemed back into its entity
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EntityRedeemed {
+    #[topic]
+    pub entity_id: u32,
+    pub item_id: u64,
+    pub holder: Address,
+}
+
+/// Emitted when an entity heals an ally
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EntityHealed {
+    #[topic]
+    pub entity_id: u32,
+    pub healer_id: u32,
+    pub amount: u32,
+    pub health: u32,
+}
+
+/// Emitted when an entity casts a shield on itself or an ally
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ShieldCast {
+    #[topic]
+    pub entity_id: u32,
+    pub caster_id: u32,
+    pub amount: u32,
+    pub expires_at: u64,
+}
+
+/// Contract event emissions
+pub struct GameEvents;
+
+impl GameEvents {
+    /// Emits event when an entity is spawned at `position`
+    pub fn emit_entity_spawned(env: &Env, entity_id: u32, position: &Position) {
+        EntitySpawned {
+            entity_id,
+            x: position.0,
+            y: position.1,
+        }
+        .publish(env);
+    }
+
+    /// Emits event when an entity arrives at `position`
+    pub fn emit_entity_moved(env: &Env, entity_id: u32, position: &Position) {
+        EntityMoved {
+            entity_id,
+            x: position.0,
+            y: position.1,
+        }
+        .publish(env);
+    }
+
+    /// Emits event when an entity loses `damage` health, leaving `health`
+    pub fn emit_entity_damaged(env: &Env, entity_id: u32, damage: u32, health: u32) {
+        EntityDamaged {
+            entity_id,
+            damage,
+            health,
+        }
+        .publish(env);
+    }
+
+    /// Emits event when an entity dies at `position`
+    pub fn emit_entity_died(env: &Env, entity_id: u32, position: &Position) {
+        EntityDied {
+            entity_id,
+            x: position.0,
+            y: position.1,
+        }
+        .publish(env);
+    }
+
+    /// Emits event when an entity is despawned
+    pub fn emit_entity_despawned(env: &Env, entity_id: u32) {
+        EntityDespawned { entity_id }.publish(env);
+    }
+
+    /// Emits event when the admin role changes hands
+    pub fn emit_admin_changed(env: &Env, old_admin: &Address, new_admin: &Address) {
+        AdminChanged {
+            old_admin: old_admin.clone(),
+            new_admin: new_admin.clone(),
+        }
+        .publish(env);
+    }
+
+    /// Emits event when the world is restored from checkpoint `name`
+    pub fn emit_checkpoint_restored(env: &Env, name: &Symbol, version: u32, entity_count: u32) {
+        CheckpointRestored {
+            name: name.clone(),
+            version,
+            entity_count,
+        }
+        .publish(env);
+    }
+
+    /// Emits event when the world weather changes
+    pub fn emit_weather_changed(env: &Env, weather: Weather) {
+        WeatherChanged { weather }.publish(env);
+    }
+
+    /// Emits event when `entity_id` leaves interactable `target_id` in state `active`
+    pub fn emit_interacted(env: &Env, entity_id: u32, target_id: u32, active: bool) {
+        Interacted { target_id, entity_id, active }.publish(env);
+    }
+
+    /// Emits event when world event `event_id` is scheduled for `start_seq`
+    pub fn emit_world_event_scheduled(env: &Env, event_id: u32, kind: WorldEventKind, start_seq: u32) {
+        WorldEventScheduled { event_id, kind, start_seq }.publish(env);
+    }
+
+    /// Emits event when world event `event_id` runs
+    pub fn emit_world_event_started(env: &Env, event_id: u32, kind: WorldEventKind) {
+        WorldEventStarted { event_id, kind }.publish(env);
+    }
+
+    /// Emits event when boss `boss_id` enters `phase`
+    pub fn emit_boss_phase_changed(env: &Env, boss_id: u32, phase: u32, behavior: BossBehavior) {
+        BossPhaseChanged { boss_id, phase, behavior }.publish(env);
+    }
+
+    /// Emits event when `entity_id` receives `currency` for dealing `damage` to boss `boss_id`
+    pub fn emit_boss_rewarded(env: &Env, boss_id: u32, entity_id: u32, damage: u32, currency: u32) {
+        BossRewarded {
+            boss_id,
+            entity_id,
+            damage,
+            currency,
+        }
+        .publish(env);
+    }
+
+    /// Emits event when `owner` tokenizes `entity_id` into item `item_id`
+    pub fn emit_entity_tokenized(env: &Env, entity_id: u32, item_id: u64, owner: &Address) {
+        EntityTokenized {
+            entity_id,
+            item_id,
+            owner: owner.clone(),
+        }
+        .publish(env);
+    }
+
+    /// Emits event when `holder` redeems item `item_id` back into `entity_id`
+    pub fn emit_entity_redeemed(env: &Env, entity_id: u32, item_id: u64, holder: &Address) {
+        EntityRedeemed {
+            entity_id,
+            item_id,
+            holder: holder.clone(),
+        }
+        .publish(env);
+    }
+
+    /// Emits event when `healer_id` restores `amount` health to `entity_id`, leaving it at `health`
+    pub fn emit_entity_healed(env: &Env, entity_id: u32, healer_id: u32, amount: u32, health: u32) {
+        EntityHealed {
+            entity_id,
+            healer_id,
+            amount,
+            health,
+        }
+        .publish(env);
+    }
+
+    /// Emits event when `caster_id` shields `entity_id` against `amount` damage until `expires_at`
+    pub fn emit_shield_cast(env: &Env, entity_id: u32, caster_id: u32, amount: u32, expires_at: u64) {
+        ShieldCast {
+            entity_id,
+            caster_id,
+            amount,
+            expires_at,
+        }
+        .publish(env);
+    }
+}
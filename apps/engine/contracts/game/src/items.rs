@@ -0,0 +1,41 @@
+//! Entity Items
+//!
+//! A living entity can be tokenized into an item of an external inventory
+//! contract, where it can be traded like any other item, for example
+//! through the marketplace. The entity leaves the world while tokenized and
+//! its components are kept by the game contract, so whoever holds the item
+//! can redeem it back into the world with the same ID and stats.
+//!
+//! # Usage Example
+//!
+//! ```rust,ignore
+//! client.set_inventory_contract(&inventory_contract);
+//! let item_id = client.tokenize_entity(&hero_id);
+//! // ... the item changes hands in the inventory contract ...
+//! let hero_id = client.redeem_entity(&item_id, &12, &4);
+//! ```
+
+use soroban_sdk::{contractclient, contracttype, Address, Env};
+
+use crate::storage::EntitySnapshot;
+
+/// A tokenized entity waiting to be redeemed
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TokenizedEntity {
+    /// Inventory contract holding the item
+    pub inventory_contract: Address,
+    /// The entity's `(id, x, y, health)` tuple and components when it was tokenized
+    pub snapshot: EntitySnapshot,
+}
+
+/// Interface the game contract expects from an inventory contract
+#[contractclient(name = "InventoryClient")]
+pub trait InventoryInterface {
+    /// Mints an item standing for `entity_id` to `owner` and returns its ID
+    fn mint_entity_item(env: Env, owner: Address, entity_id: u32) -> u64;
+    /// Returns the current holder of an item
+    fn owner_of(env: Env, item_id: u64) -> Address;
+    /// Burns `holder`'s item
+    fn burn_item(env: Env, holder: Address, item_id: u64);
+}
@@ -2,22 +2,90 @@
 
 extern crate alloc;
 
-use soroban_sdk::{contract, contracttype, contractimpl, symbol_short, Env, Symbol, Bytes, vec, Val, IntoVal, TryFromVal};
-use soroban_ecs::{World, EntityId, Component, ComponentTrait, System, SystemParam};
-use soroban_ecs::prelude::*;
+use soroban_sdk::{contract, contracttype, contractimpl, symbol_short, Address, Env, Symbol, BytesN, Map, Val, IntoVal, TryFromVal, Vec};
+use soroban_ecs::World;
 
 mod storage;
 mod components;
+mod crafting;
+mod errors;
+mod events;
+mod items;
+mod pathfinding;
 mod systems;
 
 use storage::*;
-pub use components::{Position, Health};
-pub use systems::{MovementSystem, CombatSystem};
+pub use storage::{Checkpoint, EntitySnapshot, TtlInfo, WorldStats, CHECKPOINT_VERSION, REGION_CELL_SIZE, TTL_EXTEND_TO, TTL_THRESHOLD};
+pub use components::{
+    ActiveEffect, Boss, BossBehavior, BossPhase, EffectKind, Energy, HealingPower, Health, Interactable, InteractableKind, Inventory, Loot, MaxHealth, Parent,
+    Poisoned, Position, Projectile, Regeneration, Shield, Shielded, Speed, SpeedBoost, StatusEffects, Team, Weather, WeatherModifiers, WorldEvent,
+    WorldEventKind, WorldWeather, XpWindow,
+};
+pub use crafting::{CraftingClient, CraftingInterface, CraftingStation};
+pub use errors::GameError;
+pub use events::{
+    AdminChanged, BossPhaseChanged, BossRewarded, CheckpointRestored, EntityDamaged, EntityDespawned, EntityDied, EntityHealed, EntityMoved, EntityRedeemed,
+    EntitySpawned, EntityTokenized, GameEvents,
+    Interacted, ShieldCast, WeatherChanged, WorldEventScheduled, WorldEventStarted,
+};
+pub use items::{InventoryClient, InventoryInterface, TokenizedEntity};
+pub use pathfinding::{Grid, LineOfSight, Pathfinder};
+pub use systems::{
+    MovementSystem, CombatSystem, EffectSystem, ProjectileSystem, RegenSystem, LootSystem, WeatherSystem, InteractionSystem,
+    WorldEventSystem, BossSystem, SupportSystem,
+};
 
 // Re-export Position as GamePosition for backward compatibility
 // This allows existing code to use GamePosition(x, y) syntax
 pub use components::Position as GamePosition;
 
+/// Most entities scanned by a single `get_entities_in_region` call
+///
+/// Keeps a region query well inside the per-transaction footprint limit.
+pub const MAX_REGION_PAGE_SIZE: u32 = 50;
+
+/// Most grid cells of the spatial index one `get_entities_in_region` call may span
+pub const MAX_REGION_CELLS: u32 = 25;
+
+/// Most entity IDs a single `extend_world_ttl` call visits
+///
+/// Each entity found adds its own entry, its grid cell and every component
+/// slot it may have to the footprint.
+pub const MAX_TTL_SCAN: u32 = 3;
+
+/// Most entity IDs a single `game_tick` call visits
+///
+/// Each entity reads about a dozen component entries, and projectiles and
+/// bosses also read the entities around them.
+pub const MAX_TICK_SCAN: u32 = 2;
+
+/// Most entities a single checkpoint call may touch
+///
+/// Saving, removing or restoring an entity touches every component slot it
+/// may have. A checkpoint holds at most this many entities, and restoring
+/// it is limited to this many live and saved entities combined.
+pub const MAX_CHECKPOINT_ENTITIES: u32 = 3;
+
+/// One page of a `game_tick` pass
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TickPage {
+    /// Live entities found in the page's ID range
+    pub processed: u32,
+    /// ID to resume the pass from, or `None` once the last entity was reached
+    pub next_id: Option<u32>,
+}
+
+/// One page of a `get_entities_in_region` query
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RegionPage {
+    /// Matching entity IDs with their positions, in spatial index order
+    pub entities: Vec<(u32, Position)>,
+    /// Cursor for the next page, or `None` once the scan is complete
+    pub next_cursor: Option<u32>,
+}
+
 // GameWorldContract defines the Soroban smart contract
 #[contract]
 pub struct GameWorldContract;
@@ -47,33 +115,191 @@ impl GameWorldContract {
         data
     }
 
+    /// Sets the contract admin, initializing the world if needed
+    ///
+    /// Existing entity storage is left untouched, so this can be called on a
+    /// world that was set up with `init` before admin support existed.
+    ///
+    /// # Errors
+    ///
+    /// - `GameError::AlreadyInitialized` if an admin has already been set
+    pub fn initialize(env: &Env, admin: Address) -> Result<(), GameError> {
+        if storage::get_admin(env).is_some() {
+            return Err(GameError::AlreadyInitialized);
+        }
+        admin.require_auth();
+        if !storage::get_contract_data(env).is_initialized {
+            Self::init(env);
+        }
+        storage::set_admin(env, &admin);
+        Ok(())
+    }
+
+    /// Returns the contract admin, if one has been set
+    pub fn get_admin(env: &Env) -> Option<Address> {
+        storage::get_admin(env)
+    }
+
+    /// Hands the admin role to a new address
+    ///
+    /// # Errors
+    ///
+    /// - `GameError::Unauthorized` if no admin has been set
+    pub fn set_admin(env: &Env, new_admin: Address) -> Result<(), GameError> {
+        let admin = Self::require_admin(env)?;
+        storage::set_admin(env, &new_admin);
+        GameEvents::emit_admin_changed(env, &admin, &new_admin);
+        Ok(())
+    }
+
+    /// Replaces the contract code with an already uploaded WASM
+    ///
+    /// Entity storage belongs to the contract instance and survives the
+    /// upgrade, so the world carries over to the new code.
+    ///
+    /// # Errors
+    ///
+    /// - `GameError::Unauthorized` if no admin has been set
+    pub fn upgrade(env: &Env, new_wasm_hash: BytesN<32>) -> Result<(), GameError> {
+        Self::require_admin(env)?;
+        env.deployer().update_current_contract_wasm(new_wasm_hash);
+        Ok(())
+    }
+
+    /// Freezes the world, rejecting every mutating endpoint with `GameError::Paused`
+    ///
+    /// Admin endpoints, including `upgrade`, keep working while paused.
+    ///
+    /// # Errors
+    ///
+    /// - `GameError::Unauthorized` if no admin has been set
+    pub fn pause(env: &Env) -> Result<(), GameError> {
+        Self::require_admin(env)?;
+        storage::set_paused(env, true);
+        Ok(())
+    }
+
+    /// Lifts a pause set with `pause`
+    ///
+    /// # Errors
+    ///
+    /// - `GameError::Unauthorized` if no admin has been set
+    pub fn unpause(env: &Env) -> Result<(), GameError> {
+        Self::require_admin(env)?;
+        storage::set_paused(env, false);
+        Ok(())
+    }
+
+    /// Returns whether the world is currently paused
+    pub fn is_paused(env: &Env) -> bool {
+        storage::is_paused(env)
+    }
+
+    /// Extends the world's hot storage entries to the full TTL
+    ///
+    /// Anyone may call this to keep an idle world from being archived. Each
+    /// call extends the counters, then visits at most `limit` entity IDs
+    /// (capped at `MAX_TTL_SCAN`) from `start_id`, extending their index
+    /// buckets and the entities found with their spatial index cells and
+    /// components. Returns the ID to resume from, or `None` once the last
+    /// entity was reached; a pass started at ID 0 and run to the end is
+    /// reported by `get_ttl_info`.
+    pub fn extend_world_ttl(env: &Env, start_id: u32, limit: u32) -> Option<u32> {
+        let next_id = storage::get_next_entity_id(env);
+        let end_id = start_id
+            .saturating_add(limit.min(MAX_TTL_SCAN))
+            .min(next_id)
+            .max(start_id);
+        storage::extend_world_ttl(env, start_id, end_id);
+        if end_id >= next_id {
+            return None;
+        }
+        Some(end_id)
+    }
+
+    /// Moves entities written by older contract versions to per-entity keys
+    ///
+    /// Earlier versions kept every entity in one map entry. Run once after
+    /// upgrading; returns the number of entities migrated.
+    ///
+    /// # Errors
+    ///
+    /// - `GameError::Unauthorized` if no admin has been set
+    pub fn migrate_entity_storage(env: &Env) -> Result<u32, GameError> {
+        Self::require_admin(env)?;
+        Ok(storage::migrate_entity_storage(env))
+    }
+
+    /// Reports when the world's hot storage entries were last fully extended
+    pub fn get_ttl_info(env: &Env) -> TtlInfo {
+        storage::get_ttl_info(env)
+    }
+
+    /// Saves every entity, its components and the world counters under `name`
+    ///
+    /// Replaces any checkpoint already saved under that name. The snapshot
+    /// reads every component slot of every entity, so the world may hold at
+    /// most `MAX_CHECKPOINT_ENTITIES` live entities.
+    ///
+    /// # Errors
+    ///
+    /// - `GameError::Unauthorized` if no admin has been set
+    /// - `GameError::WorldTooLarge` if the world holds too many live entities
+    pub fn create_checkpoint(env: &Env, name: Symbol) -> Result<(), GameError> {
+        Self::require_admin(env)?;
+        if storage::get_entity_count(env) > MAX_CHECKPOINT_ENTITIES {
+            return Err(GameError::WorldTooLarge);
+        }
+        storage::set_checkpoint(env, &name, &storage::snapshot_world(env));
+        Ok(())
+    }
+
+    /// Rolls the world back to the checkpoint saved under `name`
+    ///
+    /// Entities created since the checkpoint are removed and entities killed
+    /// or changed since are restored. Entity IDs issued after the checkpoint
+    /// are not reused. Entities sitting in inventory items are left there,
+    /// and entities redeemed from an item since the checkpoint stay in the
+    /// world. Like `create_checkpoint`, this touches every component slot of
+    /// every entity involved, so the live entities and the checkpoint's may
+    /// add up to at most `MAX_CHECKPOINT_ENTITIES`.
+    ///
+    /// # Errors
+    ///
+    /// - `GameError::Unauthorized` if no admin has been set
+    /// - `GameError::CheckpointNotFound` if no checkpoint has this name
+    /// - `GameError::UnsupportedCheckpoint` if the checkpoint layout is unknown
+    /// - `GameError::WorldTooLarge` if the restore would touch too many entities
+    pub fn restore_checkpoint(env: &Env, name: Symbol) -> Result<(), GameError> {
+        Self::require_admin(env)?;
+        let checkpoint = storage::get_checkpoint(env, &name).ok_or(GameError::CheckpointNotFound)?;
+        if checkpoint.version != CHECKPOINT_VERSION {
+            return Err(GameError::UnsupportedCheckpoint);
+        }
+        if storage::get_entity_count(env).saturating_add(checkpoint.entities.len()) > MAX_CHECKPOINT_ENTITIES {
+            return Err(GameError::WorldTooLarge);
+        }
+        storage::restore_world(env, &checkpoint);
+        GameEvents::emit_checkpoint_restored(env, &name, checkpoint.version, checkpoint.entity_count);
+        Ok(())
+    }
+
+    /// Returns the checkpoint saved under `name`, if any
+    pub fn get_checkpoint(env: &Env, name: Symbol) -> Option<Checkpoint> {
+        storage::get_checkpoint(env, &name)
+    }
+
     /// Spawns a new entity with optimized storage and ECS integration
     /// 
     /// Uses efficient storage patterns:
     /// - Persistent storage for entity data with proper TTL
     /// - Atomic counter updates for entity management
     /// - Optimized data serialization for minimal storage footprint
-    pub fn spawn_entity(env: &Env, x: u32, y: u32) -> u32 {
-        let entity_count = storage::get_entity_count(env);
-        let entity_id = entity_count;
-        
-        // Create entity components using the modular components
-        let position = Position(x, y);
-        let health = Health(100);
-        
-        // Store entity data as optimized tuple
-        let entity_data: (u32, u32, u32, u32) = (entity_id, position.0, position.1, health.0);
-        let val: Val = entity_data.into_val(env);
-        
-        // Use optimized storage functions
-        storage::set_entity_data(env, entity_id, val);
-        storage::set_entity_count(env, entity_count + 1);
-        
-        entity_id
+    pub fn spawn_entity(env: &Env, x: u32, y: u32) -> Result<u32, GameError> {
+        Self::require_not_paused(env)?;
+        Ok(Self::create_entity(env, x, y))
     }
 
-    
-
     /// Moves an entity by dx, dy using MovementSystem
     ///
     /// # Arguments
@@ -83,9 +309,12 @@ impl GameWorldContract {
     /// * `dx` - Change in x-coordinate (can be negative)
     /// * `dy` - Change in y-coordinate (can be negative)
     ///
-    /// # Returns
+    /// # Errors
     ///
-    /// `true` if the entity was successfully moved, `false` if entity not found
+    /// - `GameError::EntityNotFound` if no entity with this ID exists
+    /// - `GameError::EntityDead` if the entity was killed
+    ///
+    /// Requires authorization from the entity's owner, if it has one.
     ///
     /// # Example
     ///
@@ -93,21 +322,29 @@ impl GameWorldContract {
     /// // Move entity 5 units right and 3 units up
     /// client.move_entity(&entity_id, 5, 3);
     /// ```
-    pub fn move_entity(env: &Env, entity_id: u32, dx: i32, dy: i32) -> bool {
-        if let Some(entity_data) = storage::get_entity_data(env, entity_id) { 
-            if let Ok((id, x, y, health)) = <(u32, u32, u32, u32)>::try_from_val(env, &entity_data) {
-                if id == entity_id { 
-                    let current_position = Position(x, y); 
-                    // Use the MovementSystem to calculate new position
-                    let new_position = MovementSystem::update_position(&current_position, dx, dy); 
-                    let updated_entity_data: (u32, u32, u32, u32) = (id, new_position.0, new_position.1, health); 
-                    let val: soroban_sdk::Val = updated_entity_data.into_val(env);
-                    storage::set_entity_data(env, entity_id, val); 
-                    return true;
-                }
-            }
+    pub fn move_entity(env: &Env, entity_id: u32, dx: i32, dy: i32) -> Result<(), GameError> {
+        Self::require_not_paused(env)?;
+        let (id, x, y, health) = Self::load_entity(env, entity_id)?;
+        Self::require_owner_if_set(env, entity_id);
+
+        // A mounted rider steers its mount, and a mount always carries its rider along
+        if let Some(Parent(mount_id)) = storage::get_entity_parent(env, entity_id) {
+            return Self::move_mount(env, mount_id, dx, dy);
+        }
+        if storage::get_entity_rider(env, entity_id).is_some() {
+            return Self::move_mount(env, entity_id, dx, dy);
         }
-        false 
+
+        let current_position = Position(x, y);
+        // Use the MovementSystem to calculate new position, boosted by any active SpeedBoost
+        let effects = storage::get_status_effects(env, entity_id);
+        let speed = EffectSystem::boosted_speed(effects.speed_boost.as_ref(), &Speed(1), env.ledger().timestamp());
+        let (dx, dy) = MovementSystem::apply_speed(&speed, dx, dy);
+        let (dx, dy) = WeatherSystem::scale_movement(storage::get_world_weather(env).weather, dx, dy);
+        let new_position = MovementSystem::update_position(&current_position, dx, dy);
+        Self::save_entity(env, id, &new_position, health);
+        Self::entity_moved(env, id, &new_position);
+        Ok(())
     }
 
     /// Attacks an entity, reducing its health using CombatSystem
@@ -117,13 +354,16 @@ impl GameWorldContract {
     /// * `env` - The Soroban environment
     /// * `entity_id` - The ID of the entity to attack
     ///
-    /// # Returns
+    /// # Errors
     ///
-    /// `true` if the attack was successful, `false` if entity not found
+    /// - `GameError::EntityNotFound` if no entity with this ID exists
+    /// - `GameError::EntityDead` if the entity was already killed
     ///
     /// # Behavior
     ///
-    /// - Applies 10 damage to the entity's health
+    /// - Requires authorization from the entity's owner, if it has one; other
+    ///   players hit owned entities through `attack_with`
+    /// - Applies 10 damage to the entity's health, scaled by the weather and reduced by an active shield
     /// - If health reaches 0, the entity is marked as dead and removed
     /// - Dead entity counter is incremented when an entity dies
     ///
@@ -133,36 +373,161 @@ impl GameWorldContract {
     /// // Attack an entity
     /// client.attack_entity(&entity_id);
     /// ```
-    pub fn attack_entity(env: &Env, entity_id: u32) -> bool {
-        if let Some(entity_data) = storage::get_entity_data(env, entity_id) {
-            if let Ok((id, x, y, health)) = <(u32, u32, u32, u32)>::try_from_val(env, &entity_data) {
-                if id == entity_id {
-                    let current_health = Health(health);
-                    // Use the CombatSystem to apply attack damage
-                    let new_health = CombatSystem::attack(&current_health);
-
-                    if new_health.0 > 0 {
-                        // Just update the entity's health
-                        let updated: (u32, u32, u32, u32) = (id, x, y, new_health.0);
-                        let val: Val = updated.into_val(env);
-                        storage::set_entity_data(env, entity_id, val);
-                    } else {
-                        // Entity dies - increment dead count and decrement live count
-                        let current_dead = storage::get_dead_entity_count(env);
-                        storage::set_dead_entity_count(env, current_dead + 1);
-                        
-                        let current_live = storage::get_entity_count(env);
-                        if current_live > 0 {
-                            storage::set_entity_count(env, current_live - 1);
-                        }
+    pub fn attack_entity(env: &Env, entity_id: u32) -> Result<(), GameError> {
+        Self::require_not_paused(env)?;
+        Self::require_owner_if_set(env, entity_id);
+        Self::damage_entity(env, entity_id, CombatSystem::BASE_ATTACK_DAMAGE, None)
+    }
 
-                        storage::remove_entity_data(env, entity_id);
-                    }
-                    return true;
-                }
-            }
+    /// Attacks a target on behalf of an attacking entity, honouring team rules
+    ///
+    /// Requires authorization from the attacker's owner. Damage dealt to a
+    /// boss counts towards the attacker's share of its reward.
+    ///
+    /// # Errors
+    ///
+    /// - `GameError::Unauthorized` if the attacker has no owner
+    /// - `GameError::InvalidTarget` if the attacker targets itself
+    /// - `GameError::FriendlyFire` if both entities share a team and friendly fire is disabled
+    pub fn attack_with(env: &Env, attacker_id: u32, target_id: u32) -> Result<(), GameError> {
+        Self::require_not_paused(env)?;
+        Self::check_attack(env, attacker_id, target_id)?;
+        Self::damage_entity(env, target_id, CombatSystem::BASE_ATTACK_DAMAGE, Some(attacker_id))
+    }
+
+    /// Attacks a distant target on behalf of an attacking entity
+    ///
+    /// Follows the rules of `attack_with`, and additionally requires the
+    /// target to be within `CombatSystem::RANGED_ATTACK_RANGE` tiles with no
+    /// wall or closed door on the line between the two entities.
+    ///
+    /// # Errors
+    ///
+    /// - `GameError::Unauthorized` if the attacker has no owner
+    /// - `GameError::InvalidTarget` if the attacker targets itself
+    /// - `GameError::FriendlyFire` if both entities share a team and friendly fire is disabled
+    /// - `GameError::OutOfRange` if the target is too far away
+    /// - `GameError::NoLineOfSight` if a blocked tile lies between the entities
+    pub fn ranged_attack(env: &Env, attacker_id: u32, target_id: u32) -> Result<(), GameError> {
+        Self::require_not_paused(env)?;
+        let (attacker, target) = Self::check_attack(env, attacker_id, target_id)?;
+        if !CombatSystem::in_range(&attacker, &target) {
+            return Err(GameError::OutOfRange);
+        }
+        if !LineOfSight::is_clear(env, &Self::blocked_tiles(env), &attacker, &target) {
+            return Err(GameError::NoLineOfSight);
+        }
+        Self::damage_entity(env, target_id, CombatSystem::BASE_ATTACK_DAMAGE, Some(attacker_id))
+    }
+
+    /// Assigns an entity to a team, or removes it from its team with `None`
+    ///
+    /// # Errors
+    ///
+    /// - `GameError::Unauthorized` if the entity has no owner
+    pub fn set_entity_team(env: &Env, entity_id: u32, team_id: Option<u32>) -> Result<(), GameError> {
+        Self::require_not_paused(env)?;
+        Self::load_entity(env, entity_id)?;
+        let owner = storage::get_entity_owner(env, entity_id).ok_or(GameError::Unauthorized)?;
+        owner.require_auth();
+        storage::set_entity_team(env, entity_id, team_id.map(Team).as_ref());
+        Ok(())
+    }
+
+    /// Returns the team of an entity, if any
+    pub fn get_entity_team(env: &Env, entity_id: u32) -> Option<Team> {
+        storage::get_entity_team(env, entity_id)
+    }
+
+    /// Returns the IDs of all entities on a team
+    pub fn get_team_members(env: &Env, team_id: u32) -> Vec<u32> {
+        storage::get_team_members(env, team_id)
+    }
+
+    /// Allows or forbids attacks between teammates
+    ///
+    /// # Errors
+    ///
+    /// - `GameError::Unauthorized` if no admin has been set
+    pub fn set_friendly_fire(env: &Env, enabled: bool) -> Result<(), GameError> {
+        Self::require_admin(env)?;
+        storage::set_friendly_fire_enabled(env, enabled);
+        Ok(())
+    }
+
+    /// Sets the world weather, restarting the rotation period from now
+    ///
+    /// # Errors
+    ///
+    /// - `GameError::Unauthorized` if the caller is not the admin
+    pub fn set_weather(env: &Env, weather: Weather) -> Result<(), GameError> {
+        Self::require_admin(env)?;
+        let mut world = storage::get_world_weather(env);
+        world.weather = weather;
+        world.changed_at = env.ledger().timestamp();
+        storage::set_world_weather(env, &world);
+        GameEvents::emit_weather_changed(env, weather);
+        Ok(())
+    }
+
+    /// Sets how many seconds each weather lasts before `game_tick` rotates it
+    ///
+    /// A period of 0 holds the current weather until the admin changes it.
+    ///
+    /// # Errors
+    ///
+    /// - `GameError::Unauthorized` if the caller is not the admin
+    pub fn set_weather_rotation(env: &Env, period_secs: u64) -> Result<(), GameError> {
+        Self::require_admin(env)?;
+        let mut world = storage::get_world_weather(env);
+        world.rotation_period = period_secs;
+        world.changed_at = env.ledger().timestamp();
+        storage::set_world_weather(env, &world);
+        Ok(())
+    }
+
+    /// Returns the current world weather and its rotation schedule
+    pub fn get_weather(env: &Env) -> WorldWeather {
+        storage::get_world_weather(env)
+    }
+
+    /// Schedules a world event that `game_tick` runs once the ledger reaches `start_seq`
+    ///
+    /// See `WorldEventKind` for the parameters each kind takes. Returns the
+    /// event ID.
+    ///
+    /// # Errors
+    ///
+    /// - `GameError::Unauthorized` if the caller is not the admin
+    /// - `GameError::InvalidWorldEvent` if the parameters don't fit the kind
+    ///   or `start_seq` has already passed
+    pub fn schedule_world_event(
+        env: &Env,
+        kind: WorldEventKind,
+        start_seq: u32,
+        params: Vec<u32>,
+    ) -> Result<u32, GameError> {
+        Self::require_admin(env)?;
+        let event = WorldEvent { kind, start_seq, params };
+        if start_seq < env.ledger().sequence() || !WorldEventSystem::is_valid(&event) {
+            return Err(GameError::InvalidWorldEvent);
         }
-        false
+        let event_id = storage::next_world_event_id(env);
+        let mut events = storage::get_world_events(env);
+        events.set(event_id, event);
+        storage::set_world_events(env, &events);
+        GameEvents::emit_world_event_scheduled(env, event_id, kind, start_seq);
+        Ok(event_id)
+    }
+
+    /// Returns the world events that have not run yet, by event ID
+    pub fn get_world_events(env: &Env) -> Map<u32, WorldEvent> {
+        storage::get_world_events(env)
+    }
+
+    /// Returns the XP percentage currently in effect, 100 outside a double-XP window
+    pub fn get_xp_multiplier(env: &Env) -> u32 {
+        WorldEventSystem::xp_percent(storage::get_xp_window(env).as_ref(), env.ledger().sequence())
     }
 
     // Retrieves the position of an entity
@@ -178,17 +543,100 @@ impl GameWorldContract {
     }
 
     // Retrieves the health of an entity
+    //
+    // Includes regeneration accrued since the last update without persisting it
     pub fn get_entity_health(env: &Env, entity_id: u32) -> Option<Health> {
         if let Some(entity_data) = storage::get_entity_data(env, entity_id) { 
             if let Ok((id, x, y, health)) = <(u32, u32, u32, u32)>::try_from_val(env, &entity_data) {
                 if id == entity_id { 
-                    return Some(Health(health)); 
+                    let health = match storage::get_regeneration(env, entity_id) {
+                        Some(regen) => {
+                            let max_health = storage::get_max_health(env, entity_id).unwrap_or(MaxHealth(health));
+                            RegenSystem::regenerate(&Health(health), &regen, &max_health, env.ledger().timestamp()).0
+                        }
+                        None => Health(health),
+                    };
+                    return Some(health); 
                 }
             }
         }
         None 
     }
 
+    /// Returns the entities inside a rectangular region with their positions
+    ///
+    /// The region spans `x0..=x1` by `y0..=y1`; the corners may be given in
+    /// either order. Only the spatial index cells of `REGION_CELL_SIZE` tiles
+    /// the region overlaps are read, at most `MAX_REGION_CELLS` of them, row
+    /// by row and in arrival order within each cell. Each call scans at most
+    /// `limit` indexed entities (capped at `MAX_REGION_PAGE_SIZE`) starting
+    /// at position `cursor` of that order, and returns the ones inside the
+    /// region. A page may therefore hold fewer matches than `limit`; keep
+    /// passing `next_cursor` until it is `None`. Entities that move between
+    /// pages may be missed or returned twice.
+    pub fn get_entities_in_region(
+        env: &Env,
+        x0: u32,
+        y0: u32,
+        x1: u32,
+        y1: u32,
+        cursor: u32,
+        limit: u32,
+    ) -> Result<RegionPage, GameError> {
+        let (min_x, max_x) = (x0.min(x1), x0.max(x1));
+        let (min_y, max_y) = (y0.min(y1), y0.max(y1));
+        let (min_cell, max_cell) = (
+            storage::cell_of(&Position(min_x, min_y)),
+            storage::cell_of(&Position(max_x, max_y)),
+        );
+        let cells = u64::from(max_cell.0 - min_cell.0 + 1) * u64::from(max_cell.1 - min_cell.1 + 1);
+        if cells > u64::from(MAX_REGION_CELLS) {
+            return Err(GameError::RegionTooLarge);
+        }
+
+        let limit = limit.min(MAX_REGION_PAGE_SIZE);
+        let mut page = RegionPage {
+            entities: Vec::new(env),
+            next_cursor: None,
+        };
+
+        // Position in the scan order of the current cell's first entity
+        let mut cell_start = 0u32;
+        let mut scanned = 0;
+        for cell_y in min_cell.1..=max_cell.1 {
+            for cell_x in min_cell.0..=max_cell.0 {
+                let entity_ids = storage::get_cell_entities(env, (cell_x, cell_y));
+                for (offset, entity_id) in entity_ids.iter().enumerate() {
+                    let position = cell_start + offset as u32;
+                    if position < cursor {
+                        continue;
+                    }
+                    if scanned == limit {
+                        page.next_cursor = Some(position);
+                        return Ok(page);
+                    }
+                    scanned += 1;
+                    let Some(Position(x, y)) = Self::get_entity_position(env, entity_id) else {
+                        continue;
+                    };
+                    if (min_x..=max_x).contains(&x) && (min_y..=max_y).contains(&y) {
+                        page.entities.push_back((entity_id, Position(x, y)));
+                    }
+                }
+                cell_start += entity_ids.len();
+            }
+        }
+        Ok(page)
+    }
+
+    /// Returns aggregate world statistics for dashboards
+    ///
+    /// Counters are kept up to date by the systems as they run, so no event
+    /// replay is needed.
+    pub fn get_world_stats(env: &Env) -> WorldStats {
+        storage::get_world_stats(env)
+    }
+
     // Returns the total number of entities in the world
     pub fn entity_count(env: &Env) -> u32 {
         storage::get_entity_count(env)
@@ -199,18 +647,1439 @@ impl GameWorldContract {
         storage::get_dead_entity_count(env)
     }
 
-    // Removes an entity from the world
-    pub fn despawn_entity(env: &Env, entity_id: u32) -> bool {
-        if let Some(entity_data) = storage::get_entity_data(env, entity_id) { 
-            if let Ok((id, _, _, _)) = <(u32, u32, u32, u32)>::try_from_val(env, &entity_data) {
-                if id == entity_id {
-                    storage::remove_entity_data(env, entity_id);
-                    let current_count = storage::get_entity_count(env);
-                    storage::set_entity_count(env, current_count.saturating_sub(1));
-                    return true;
-                }
+    /// Removes an entity from the world
+    ///
+    /// Requires authorization from the entity's owner, if it has one.
+    ///
+    /// # Errors
+    ///
+    /// - `GameError::EntityNotFound` if no entity with this ID exists
+    /// - `GameError::EntityDead` if the entity was already killed
+    pub fn despawn_entity(env: &Env, entity_id: u32) -> Result<(), GameError> {
+        Self::require_not_paused(env)?;
+        Self::load_entity(env, entity_id)?;
+        Self::require_owner_if_set(env, entity_id);
+        Self::remove_entity(env, entity_id);
+        Ok(())
+    }
+
+    /// Assigns an owner to an entity
+    ///
+    /// Only the admin can give an unowned entity its first owner; an owned
+    /// entity can only be handed over by its current owner.
+    ///
+    /// # Errors
+    ///
+    /// - `GameError::EntityNotFound` / `GameError::EntityDead` if the entity is gone
+    /// - `GameError::Unauthorized` if the entity is unowned and no admin has been set
+    pub fn set_entity_owner(env: &Env, entity_id: u32, owner: Address) -> Result<(), GameError> {
+        Self::require_not_paused(env)?;
+        Self::load_entity(env, entity_id)?;
+        match storage::get_entity_owner(env, entity_id) {
+            Some(current) => current.require_auth(),
+            None => {
+                Self::require_admin(env)?;
+            }
+        }
+        storage::set_entity_owner(env, entity_id, &owner);
+        Ok(())
+    }
+
+    /// Returns the owner of an entity, if any
+    pub fn get_entity_owner(env: &Env, entity_id: u32) -> Option<Address> {
+        storage::get_entity_owner(env, entity_id)
+    }
+
+    /// Spawns a rideable mount owned by `owner`
+    ///
+    /// Mounts are regular entities with an additional `Speed` component that
+    /// multiplies the distance covered while carrying a rider.
+    pub fn spawn_mount(env: &Env, owner: Address, x: u32, y: u32, speed: u32) -> Result<u32, GameError> {
+        Self::require_not_paused(env)?;
+        owner.require_auth();
+        let mount_id = Self::spawn_entity(env, x, y)?;
+        storage::set_entity_owner(env, mount_id, &owner);
+        storage::set_entity_speed(env, mount_id, &Speed(speed.max(1)));
+        Ok(mount_id)
+    }
+
+    /// Mounts a rider on an adjacent mount
+    ///
+    /// Parents the rider to the mount and snaps the rider onto the mount's
+    /// tile. From then on moving either entity moves both.
+    ///
+    /// # Errors
+    ///
+    /// - `GameError::Unauthorized` if the rider is unowned or the mount has a different owner
+    /// - `GameError::NotAMount` if the target has no `Speed` component
+    /// - `GameError::NotAdjacent` if the entities are not on neighbouring tiles
+    /// - `GameError::AlreadyMounted` if either entity is already part of a pair
+    /// - `GameError::InvalidTarget` if the rider tries to mount itself
+    pub fn mount(env: &Env, rider_id: u32, mount_id: u32) -> Result<(), GameError> {
+        Self::require_not_paused(env)?;
+        if rider_id == mount_id {
+            return Err(GameError::InvalidTarget);
+        }
+        let (_, rider_x, rider_y, rider_health) = Self::load_entity(env, rider_id)?;
+        let (_, mount_x, mount_y, _) = Self::load_entity(env, mount_id)?;
+
+        let owner = storage::get_entity_owner(env, rider_id).ok_or(GameError::Unauthorized)?;
+        owner.require_auth();
+        if storage::get_entity_owner(env, mount_id) != Some(owner) {
+            return Err(GameError::Unauthorized);
+        }
+        if storage::get_entity_speed(env, mount_id).is_none() {
+            return Err(GameError::NotAMount);
+        }
+
+        let rider_position = Position(rider_x, rider_y);
+        let mount_position = Position(mount_x, mount_y);
+        if !MovementSystem::is_adjacent(&rider_position, &mount_position) {
+            return Err(GameError::NotAdjacent);
+        }
+        if storage::get_entity_parent(env, rider_id).is_some()
+            || storage::get_entity_rider(env, rider_id).is_some()
+            || storage::get_entity_parent(env, mount_id).is_some()
+            || storage::get_entity_rider(env, mount_id).is_some()
+        {
+            return Err(GameError::AlreadyMounted);
+        }
+
+        storage::set_entity_parent(env, rider_id, &Parent(mount_id));
+        storage::set_entity_rider(env, mount_id, rider_id);
+        Self::save_entity(env, rider_id, &mount_position, rider_health);
+        Self::entity_moved(env, rider_id, &mount_position);
+        Ok(())
+    }
+
+    /// Dismounts a rider, leaving it on the mount's current tile
+    ///
+    /// # Errors
+    ///
+    /// - `GameError::NotMounted` if the entity is not riding anything
+    /// - `GameError::Unauthorized` if the rider has no owner
+    pub fn dismount(env: &Env, rider_id: u32) -> Result<(), GameError> {
+        Self::require_not_paused(env)?;
+        Self::load_entity(env, rider_id)?;
+        let Parent(mount_id) = storage::get_entity_parent(env, rider_id).ok_or(GameError::NotMounted)?;
+        let owner = storage::get_entity_owner(env, rider_id).ok_or(GameError::Unauthorized)?;
+        owner.require_auth();
+
+        storage::remove_entity_parent(env, rider_id);
+        storage::remove_entity_rider(env, mount_id);
+        Ok(())
+    }
+
+    /// Returns the mount a rider is currently parented to, if any
+    pub fn get_mount(env: &Env, rider_id: u32) -> Option<u32> {
+        storage::get_entity_parent(env, rider_id).map(|parent| parent.0)
+    }
+
+    /// Places a crafting station in the world
+    ///
+    /// The station is an ordinary entity owned by `owner` whose crafting
+    /// requests are forwarded to `crafting_contract`.
+    pub fn spawn_station(
+        env: &Env,
+        owner: Address,
+        x: u32,
+        y: u32,
+        station_type: u32,
+        crafting_contract: Address,
+    ) -> Result<u32, GameError> {
+        Self::require_not_paused(env)?;
+        owner.require_auth();
+        let station_id = Self::spawn_entity(env, x, y)?;
+        storage::set_entity_owner(env, station_id, &owner);
+        storage::set_crafting_station(
+            env,
+            station_id,
+            &CraftingStation {
+                station_type,
+                crafting_contract,
+            },
+        );
+        Ok(station_id)
+    }
+
+    /// Returns the crafting configuration of a station entity, if any
+    pub fn get_station(env: &Env, station_id: u32) -> Option<CraftingStation> {
+        storage::get_crafting_station(env, station_id)
+    }
+
+    /// Crafts a recipe at a nearby station on behalf of an entity's owner
+    ///
+    /// Requires the crafting entity to stand next to the station, then calls
+    /// the station's crafting contract with the entity owner as crafter.
+    ///
+    /// # Errors
+    ///
+    /// - `GameError::Unauthorized` if the crafting entity has no owner
+    /// - `GameError::NotAStation` if the target is not a crafting station
+    /// - `GameError::NotAdjacent` if the entity is not next to the station
+    /// - `GameError::CraftingFailed` if the crafting contract rejects the call
+    pub fn craft_at_station(env: &Env, entity_id: u32, station_id: u32, recipe_id: u32) -> Result<(), GameError> {
+        Self::require_not_paused(env)?;
+        let (_, x, y, _) = Self::load_entity(env, entity_id)?;
+        let (_, station_x, station_y, _) = Self::load_entity(env, station_id)?;
+
+        let crafter = storage::get_entity_owner(env, entity_id).ok_or(GameError::Unauthorized)?;
+        crafter.require_auth();
+
+        let station = storage::get_crafting_station(env, station_id).ok_or(GameError::NotAStation)?;
+        if !MovementSystem::is_adjacent(&Position(x, y), &Position(station_x, station_y)) {
+            return Err(GameError::NotAdjacent);
+        }
+
+        let crafting = CraftingClient::new(env, &station.crafting_contract);
+        match crafting.try_craft(&crafter, &recipe_id, &station.station_type) {
+            Ok(Ok(())) => Ok(()),
+            _ => Err(GameError::CraftingFailed),
+        }
+    }
+
+    /// Sets the inventory contract that entities are tokenized into (admin only)
+    pub fn set_inventory_contract(env: &Env, contract: Address) -> Result<(), GameError> {
+        Self::require_admin(env)?;
+        storage::set_inventory_contract(env, &contract);
+        Ok(())
+    }
+
+    /// Returns the inventory contract that entities are tokenized into, if any
+    pub fn get_inventory_contract(env: &Env) -> Option<Address> {
+        storage::get_inventory_contract(env)
+    }
+
+    /// Takes an entity out of the world as an item minted to its owner
+    ///
+    /// The entity's data and components are kept until the item is redeemed.
+    /// Status effects, paths and mount links don't survive tokenizing.
+    ///
+    /// # Errors
+    ///
+    /// - `GameError::NotTokenizable` if the entity is a boss, interactable, loot, projectile or crafting station
+    /// - `GameError::Unauthorized` if the entity has no owner
+    /// - `GameError::NoInventoryContract` if no inventory contract is configured
+    /// - `GameError::InventoryFailed` if the inventory contract rejects the mint
+    pub fn tokenize_entity(env: &Env, entity_id: u32) -> Result<u64, GameError> {
+        Self::require_not_paused(env)?;
+        Self::load_entity(env, entity_id)?;
+        if storage::get_boss(env, entity_id).is_some()
+            || storage::get_interactable(env, entity_id).is_some()
+            || storage::get_loot(env, entity_id).is_some()
+            || storage::get_projectile(env, entity_id).is_some()
+            || storage::get_crafting_station(env, entity_id).is_some()
+        {
+            return Err(GameError::NotTokenizable);
+        }
+        let owner = storage::get_entity_owner(env, entity_id).ok_or(GameError::Unauthorized)?;
+        owner.require_auth();
+        let inventory_contract = storage::get_inventory_contract(env).ok_or(GameError::NoInventoryContract)?;
+
+        let mut snapshot = storage::snapshot_entity(env, entity_id).ok_or(GameError::InvalidEntityData)?;
+        for transient in [
+            symbol_short!("poison"),
+            symbol_short!("shield"),
+            symbol_short!("boost"),
+            symbol_short!("path"),
+            symbol_short!("parent"),
+            symbol_short!("rider"),
+        ] {
+            snapshot.components.remove(transient);
+        }
+
+        let inventory = InventoryClient::new(env, &inventory_contract);
+        let item_id = match inventory.try_mint_entity_item(&owner, &entity_id) {
+            Ok(Ok(item_id)) => item_id,
+            _ => return Err(GameError::InventoryFailed),
+        };
+        if storage::get_tokenized_entity(env, item_id).is_some() {
+            return Err(GameError::InventoryFailed);
+        }
+
+        storage::set_tokenized_entity(
+            env,
+            item_id,
+            Some(&TokenizedEntity {
+                inventory_contract,
+                snapshot,
+            }),
+        );
+        storage::set_entity_tokenized(env, entity_id, true);
+        Self::remove_entity(env, entity_id);
+        GameEvents::emit_entity_tokenized(env, entity_id, item_id, &owner);
+        Ok(item_id)
+    }
+
+    /// Burns an item and brings its entity back into the world at `(x, y)`
+    ///
+    /// Whoever holds the item now redeems it and becomes the entity's owner.
+    /// The entity keeps its ID, health and components.
+    ///
+    /// # Errors
+    ///
+    /// - `GameError::ItemNotFound` if the item does not hold a tokenized entity
+    /// - `GameError::TileBlocked` if a wall or closed door stands on `(x, y)`
+    /// - `GameError::InventoryFailed` if the inventory contract can't look up or burn the item
+    pub fn redeem_entity(env: &Env, item_id: u64, x: u32, y: u32) -> Result<u32, GameError> {
+        Self::require_not_paused(env)?;
+        let tokenized = storage::get_tokenized_entity(env, item_id).ok_or(GameError::ItemNotFound)?;
+        let position = Position(x, y);
+        if Self::blocked_tiles(env).contains(&position) {
+            return Err(GameError::TileBlocked);
+        }
+
+        let inventory = InventoryClient::new(env, &tokenized.inventory_contract);
+        let holder = match inventory.try_owner_of(&item_id) {
+            Ok(Ok(holder)) => holder,
+            _ => return Err(GameError::InventoryFailed),
+        };
+        holder.require_auth();
+        if !matches!(inventory.try_burn_item(&holder, &item_id), Ok(Ok(()))) {
+            return Err(GameError::InventoryFailed);
+        }
+        storage::set_tokenized_entity(env, item_id, None);
+
+        let mut snapshot = tokenized.snapshot;
+        let (entity_id, _, _, health) = snapshot.entity_data;
+        snapshot.entity_data = (entity_id, x, y, health);
+        storage::set_entity_data(env, entity_id, snapshot.entity_data.into_val(env));
+        storage::restore_entity_components(env, entity_id, &snapshot);
+        storage::set_entity_owner(env, entity_id, &holder);
+        storage::set_entity_tokenized(env, entity_id, false);
+        storage::set_entity_redeemed(env, entity_id, env.ledger().sequence());
+        if let Some(mut regen) = storage::get_regeneration(env, entity_id) {
+            regen.last_update = env.ledger().timestamp();
+            storage::set_regeneration(env, entity_id, &regen);
+        }
+        storage::set_entity_count(env, storage::get_entity_count(env) + 1);
+
+        GameEvents::emit_entity_spawned(env, entity_id, &position);
+        GameEvents::emit_entity_redeemed(env, entity_id, item_id, &holder);
+        Ok(entity_id)
+    }
+
+    /// Returns the tokenized entity an item holds, if any
+    pub fn get_tokenized_entity(env: &Env, item_id: u64) -> Option<TokenizedEntity> {
+        storage::get_tokenized_entity(env, item_id)
+    }
+
+    /// Applies a timed status effect to an entity, admin only
+    ///
+    /// Re-applying an effect of the same kind replaces the previous one.
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - Which effect to apply
+    /// * `magnitude` - Poison damage per interval, shield reduction per hit, or speed bonus
+    /// * `duration_secs` - Ledger seconds until the effect expires
+    ///
+    /// # Errors
+    ///
+    /// - `GameError::Unauthorized` if no admin has been set
+    /// - `GameError::InvalidEffect` if magnitude or duration is zero or above
+    ///   `EffectSystem::MAX_MAGNITUDE` / `EffectSystem::MAX_DURATION_SECS`
+    pub fn apply_effect(
+        env: &Env,
+        entity_id: u32,
+        kind: EffectKind,
+        magnitude: u32,
+        duration_secs: u64,
+    ) -> Result<(), GameError> {
+        Self::require_not_paused(env)?;
+        Self::require_admin(env)?;
+        Self::load_entity(env, entity_id)?;
+        if magnitude == 0
+            || magnitude > EffectSystem::MAX_MAGNITUDE
+            || duration_secs == 0
+            || duration_secs > EffectSystem::MAX_DURATION_SECS
+        {
+            return Err(GameError::InvalidEffect);
+        }
+
+        let now = env.ledger().timestamp();
+        let expires_at = now.saturating_add(duration_secs);
+        let mut effects = storage::get_status_effects(env, entity_id);
+        match kind {
+            EffectKind::Poisoned => {
+                effects.poisoned = Some(Poisoned {
+                    damage: magnitude,
+                    last_tick: now,
+                    expires_at,
+                })
+            }
+            EffectKind::Shielded => {
+                effects.shielded = Some(Shielded {
+                    reduction: magnitude,
+                    expires_at,
+                })
+            }
+            EffectKind::SpeedBoost => {
+                effects.speed_boost = Some(SpeedBoost {
+                    bonus: magnitude,
+                    expires_at,
+                })
+            }
+        }
+        storage::set_status_effects(env, entity_id, &effects);
+        Ok(())
+    }
+
+    /// Returns the status effects currently active on an entity
+    pub fn get_effects(env: &Env, entity_id: u32) -> Vec<ActiveEffect> {
+        let now = env.ledger().timestamp();
+        let effects = storage::get_status_effects(env, entity_id);
+        let mut active = Vec::new(env);
+        if let Some(poison) = effects.poisoned {
+            active.push_back(ActiveEffect {
+                kind: EffectKind::Poisoned,
+                magnitude: poison.damage,
+                expires_at: poison.expires_at,
+            });
+        }
+        if let Some(shield) = effects.shielded {
+            active.push_back(ActiveEffect {
+                kind: EffectKind::Shielded,
+                magnitude: shield.reduction,
+                expires_at: shield.expires_at,
+            });
+        }
+        if let Some(boost) = effects.speed_boost {
+            active.push_back(ActiveEffect {
+                kind: EffectKind::SpeedBoost,
+                magnitude: boost.bonus,
+                expires_at: boost.expires_at,
+            });
+        }
+        // Effects are only cleared from storage when ticked, so filter by time here
+        let mut result = Vec::new(env);
+        for effect in active.iter() {
+            if EffectSystem::is_active(effect.expires_at, now) {
+                result.push_back(effect);
             }
         }
-        false
+        result
+    }
+
+    /// Gives an entity health regeneration up to `max_health`
+    ///
+    /// Regeneration starts counting from the current ledger time.
+    ///
+    /// # Errors
+    ///
+    /// - `GameError::Unauthorized` if no admin has been set
+    pub fn set_regeneration(env: &Env, entity_id: u32, rate_per_minute: u32, max_health: u32) -> Result<(), GameError> {
+        Self::require_not_paused(env)?;
+        Self::require_admin(env)?;
+        Self::load_entity(env, entity_id)?;
+        storage::set_max_health(env, entity_id, &MaxHealth(max_health));
+        storage::set_regeneration(
+            env,
+            entity_id,
+            &Regeneration {
+                rate_per_minute,
+                last_update: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    /// Returns the Regeneration component of an entity, if any
+    pub fn get_regeneration(env: &Env, entity_id: u32) -> Option<Regeneration> {
+        storage::get_regeneration(env, entity_id)
+    }
+
+    /// Gives an entity the energy and healing power its support actions draw on
+    ///
+    /// # Errors
+    ///
+    /// - `GameError::Unauthorized` if no admin has been set
+    pub fn set_support_stats(env: &Env, entity_id: u32, energy: u32, healing_power: u32) -> Result<(), GameError> {
+        Self::require_not_paused(env)?;
+        Self::require_admin(env)?;
+        Self::load_entity(env, entity_id)?;
+        storage::set_energy(env, entity_id, &Energy(energy));
+        storage::set_healing_power(env, entity_id, &HealingPower(healing_power));
+        Ok(())
+    }
+
+    /// Returns the Energy component of an entity, if any
+    pub fn get_energy(env: &Env, entity_id: u32) -> Option<Energy> {
+        storage::get_energy(env, entity_id)
+    }
+
+    /// Heals a teammate on behalf of a healing entity
+    ///
+    /// Requires authorization from the healer's owner and costs the healer
+    /// `SupportSystem::HEAL_ENERGY_COST` energy. Health never rises above
+    /// the target's MaxHealth, if it has one.
+    ///
+    /// # Errors
+    ///
+    /// - `GameError::InvalidTarget` if the healer targets itself
+    /// - `GameError::Unauthorized` if the healer has no owner
+    /// - `GameError::NotAllies` if the entities are not on the same team
+    /// - `GameError::OutOfRange` if the target is further than `SupportSystem::SUPPORT_RANGE` tiles
+    /// - `GameError::InvalidSupportAmount` if `amount` is zero or above the healer's HealingPower
+    /// - `GameError::NotEnoughEnergy` if the healer can't pay the energy cost
+    pub fn heal_ally(env: &Env, healer_id: u32, target_id: u32, amount: u32) -> Result<(), GameError> {
+        Self::require_not_paused(env)?;
+        if healer_id == target_id {
+            return Err(GameError::InvalidTarget);
+        }
+        Self::spend_support(env, healer_id, target_id, amount, SupportSystem::HEAL_ENERGY_COST)?;
+
+        let (id, x, y, health) = Self::load_entity(env, target_id)?;
+        let max_health = storage::get_max_health(env, target_id);
+        let healed = SupportSystem::heal(&Health(health), amount, max_health.as_ref());
+        Self::save_entity(env, id, &Position(x, y), healed.0);
+        GameEvents::emit_entity_healed(env, id, healer_id, healed.0 - health, healed.0);
+        Ok(())
+    }
+
+    /// Shields the caster or a teammate against `amount` damage
+    ///
+    /// Requires authorization from the caster's owner and costs the caster
+    /// `SupportSystem::SHIELD_ENERGY_COST` energy. The shield absorbs damage
+    /// before Health for `SupportSystem::SHIELD_DURATION_SECS` seconds and
+    /// replaces any shield the target already had.
+    ///
+    /// # Errors
+    ///
+    /// - `GameError::Unauthorized` if the caster has no owner
+    /// - `GameError::NotAllies` if the entities are not on the same team
+    /// - `GameError::OutOfRange` if the target is further than `SupportSystem::SUPPORT_RANGE` tiles
+    /// - `GameError::InvalidSupportAmount` if `amount` is zero or above the caster's HealingPower
+    /// - `GameError::NotEnoughEnergy` if the caster can't pay the energy cost
+    pub fn cast_shield(env: &Env, caster_id: u32, target_id: u32, amount: u32) -> Result<(), GameError> {
+        Self::require_not_paused(env)?;
+        Self::spend_support(env, caster_id, target_id, amount, SupportSystem::SHIELD_ENERGY_COST)?;
+
+        let expires_at = env.ledger().timestamp().saturating_add(SupportSystem::SHIELD_DURATION_SECS);
+        storage::set_shield(env, target_id, Some(&Shield { amount, expires_at }));
+        GameEvents::emit_shield_cast(env, target_id, caster_id, amount, expires_at);
+        Ok(())
+    }
+
+    /// Returns the shield cast on an entity while it still absorbs damage
+    pub fn get_shield(env: &Env, entity_id: u32) -> Option<Shield> {
+        storage::get_shield(env, entity_id)
+            .filter(|shield| EffectSystem::is_active(shield.expires_at, env.ledger().timestamp()))
+    }
+
+    /// Moves the contents of an adjacent loot drop into an entity's inventory
+    ///
+    /// Requires authorization from the collecting entity's owner. The loot
+    /// entity is despawned once collected.
+    ///
+    /// # Errors
+    ///
+    /// - `GameError::Unauthorized` if the collector has no owner
+    /// - `GameError::NotLoot` if the target is not a loot drop
+    /// - `GameError::NotAdjacent` if the collector is not next to the loot
+    pub fn pick_up_loot(env: &Env, entity_id: u32, loot_id: u32) -> Result<(), GameError> {
+        Self::require_not_paused(env)?;
+        let (_, x, y, _) = Self::load_entity(env, entity_id)?;
+        let (_, loot_x, loot_y, _) = Self::load_entity(env, loot_id)?;
+
+        let owner = storage::get_entity_owner(env, entity_id).ok_or(GameError::Unauthorized)?;
+        owner.require_auth();
+
+        let loot = storage::get_loot(env, loot_id).ok_or(GameError::NotLoot)?;
+        if !MovementSystem::is_adjacent(&Position(x, y), &Position(loot_x, loot_y)) {
+            return Err(GameError::NotAdjacent);
+        }
+
+        let inventory = storage::get_inventory(env, entity_id).unwrap_or_else(|| Inventory::new(env));
+        storage::set_inventory(env, entity_id, &LootSystem::collect(&inventory, &loot));
+        Self::remove_entity(env, loot_id);
+        Ok(())
+    }
+
+    /// Returns the contents of a loot drop, if the entity is one
+    pub fn get_loot(env: &Env, loot_id: u32) -> Option<Loot> {
+        storage::get_loot(env, loot_id)
+    }
+
+    /// Returns the inventory of an entity, if it has collected anything
+    pub fn get_inventory(env: &Env, entity_id: u32) -> Option<Inventory> {
+        storage::get_inventory(env, entity_id)
+    }
+
+    /// Places a door, switch or chest in the world
+    ///
+    /// A closed door blocks its tile for pathfinding until it is opened.
+    ///
+    /// # Errors
+    ///
+    /// - `GameError::Unauthorized` if the caller is not the admin
+    pub fn spawn_interactable(env: &Env, x: u32, y: u32, interactable: Interactable) -> Result<u32, GameError> {
+        Self::require_not_paused(env)?;
+        Self::require_admin(env)?;
+        let entity_id = Self::create_entity(env, x, y);
+        Self::update_interactable(env, entity_id, &Position(x, y), &interactable);
+        Ok(entity_id)
+    }
+
+    /// Uses an adjacent door, switch or chest on behalf of an entity's owner
+    ///
+    /// Toggles the target and returns its new state. A switch also toggles
+    /// its linked interactable, and a chest hands its contents to the
+    /// entity's inventory the first time it is opened.
+    ///
+    /// # Errors
+    ///
+    /// - `GameError::InvalidTarget` if the entity targets itself
+    /// - `GameError::Unauthorized` if the entity has no owner
+    /// - `GameError::NotInteractable` if the target is not a door, switch or chest
+    /// - `GameError::NotAdjacent` if the entity is not next to the target
+    /// - `GameError::MissingKey` if the target needs a key item the entity does not hold
+    pub fn interact(env: &Env, entity_id: u32, target_id: u32) -> Result<bool, GameError> {
+        Self::require_not_paused(env)?;
+        if entity_id == target_id {
+            return Err(GameError::InvalidTarget);
+        }
+        let (_, x, y, _) = Self::load_entity(env, entity_id)?;
+        let (_, target_x, target_y, _) = Self::load_entity(env, target_id)?;
+
+        let owner = storage::get_entity_owner(env, entity_id).ok_or(GameError::Unauthorized)?;
+        owner.require_auth();
+
+        let interactable = storage::get_interactable(env, target_id).ok_or(GameError::NotInteractable)?;
+        if !MovementSystem::is_adjacent(&Position(x, y), &Position(target_x, target_y)) {
+            return Err(GameError::NotAdjacent);
+        }
+        let inventory = storage::get_inventory(env, entity_id);
+        if !InteractionSystem::has_key(&interactable, inventory.as_ref()) {
+            return Err(GameError::MissingKey);
+        }
+
+        let mut updated = InteractionSystem::toggle(&interactable);
+        let contents = InteractionSystem::take_contents(env, &mut updated);
+        if !contents.is_empty() {
+            let mut inventory = inventory.unwrap_or_else(|| Inventory::new(env));
+            for loot in contents.iter() {
+                inventory = LootSystem::collect(&inventory, &loot);
+            }
+            storage::set_inventory(env, entity_id, &inventory);
+        }
+        Self::update_interactable(env, target_id, &Position(target_x, target_y), &updated);
+        GameEvents::emit_interacted(env, entity_id, target_id, updated.active);
+
+        if updated.kind == InteractableKind::Switch {
+            if let Some(linked_id) = updated.linked.filter(|linked_id| *linked_id != target_id) {
+                if let (Ok((_, linked_x, linked_y, _)), Some(linked)) =
+                    (Self::load_entity(env, linked_id), storage::get_interactable(env, linked_id))
+                {
+                    let linked = InteractionSystem::toggle(&linked);
+                    Self::update_interactable(env, linked_id, &Position(linked_x, linked_y), &linked);
+                    GameEvents::emit_interacted(env, entity_id, linked_id, linked.active);
+                }
+            }
+        }
+        Ok(updated.active)
+    }
+
+    /// Returns the Interactable component of an entity, if any
+    pub fn get_interactable(env: &Env, entity_id: u32) -> Option<Interactable> {
+        storage::get_interactable(env, entity_id)
+    }
+
+    /// Spawns a boss with `max_health` health at `(x, y)`
+    ///
+    /// The boss strikes owned entities on every game tick in the manner of
+    /// its current phase, moving to the next phase once its health drops to
+    /// that phase's threshold. When it dies, `reward_currency` is split among
+    /// the entities that damaged it in proportion to their damage.
+    ///
+    /// # Errors
+    ///
+    /// - `GameError::Unauthorized` if the caller is not the admin
+    /// - `GameError::InvalidBoss` if `max_health` is zero or the phases don't
+    ///   start at 100% with strictly descending thresholds and non-zero damage
+    pub fn spawn_boss(
+        env: &Env,
+        x: u32,
+        y: u32,
+        max_health: u32,
+        phases: Vec<BossPhase>,
+        reward_currency: u32,
+    ) -> Result<u32, GameError> {
+        Self::require_not_paused(env)?;
+        Self::require_admin(env)?;
+        let boss = Boss { max_health, phases, phase: 0, reward_currency, damage_by: Map::new(env) };
+        if !BossSystem::is_valid(&boss) {
+            return Err(GameError::InvalidBoss);
+        }
+        Ok(Self::create_boss(env, &Position(x, y), &boss))
+    }
+
+    /// Returns the Boss component of an entity, if any
+    pub fn get_boss(env: &Env, entity_id: u32) -> Option<Boss> {
+        storage::get_boss(env, entity_id)
+    }
+
+    /// Places a wall on a tile, or removes it with `blocked` set to false
+    ///
+    /// Walls block pathfinding, ranged attacks and projectiles.
+    ///
+    /// # Errors
+    ///
+    /// - `GameError::Unauthorized` if the caller is not the admin
+    pub fn set_wall(env: &Env, x: u32, y: u32, blocked: bool) -> Result<(), GameError> {
+        Self::require_admin(env)?;
+        storage::set_wall(env, &Position(x, y), blocked);
+        Ok(())
+    }
+
+    /// Returns the tiles currently blocked by walls and closed doors
+    pub fn get_blocked_tiles(env: &Env) -> Vec<Position> {
+        Self::blocked_tiles(env)
+    }
+
+    /// Finds a shortest path on `grid` with the A* Pathfinder
+    ///
+    /// Walls and closed doors are blocked in addition to `grid.blocked`.
+    /// Returns the tiles from `start` to `goal`, excluding `start`, or `None`
+    /// if `goal` cannot be reached within `max_nodes` expanded tiles.
+    pub fn find_path(env: &Env, grid: Grid, start: Position, goal: Position, max_nodes: u32) -> Option<Vec<Position>> {
+        Pathfinder::find_path(env, &Self::with_blocked_tiles(env, grid), &start, &goal, max_nodes)
+    }
+
+    /// Sends an entity towards `goal`, one tile per `game_tick`
+    ///
+    /// Plans a path from the entity's position with the A* Pathfinder and
+    /// stores it on the entity, replacing any path it was following. Walls
+    /// and closed doors block their tiles. A mounted rider steers its mount.
+    /// Returns the number of steps planned.
+    ///
+    /// # Errors
+    ///
+    /// - `GameError::EntityNotFound` if no entity with this ID exists
+    /// - `GameError::NoPath` if `goal` cannot be reached within `max_nodes` expanded tiles
+    pub fn move_along_path(env: &Env, entity_id: u32, grid: Grid, goal: Position, max_nodes: u32) -> Result<u32, GameError> {
+        Self::require_not_paused(env)?;
+        Self::load_entity(env, entity_id)?;
+        let mover = match storage::get_entity_parent(env, entity_id) {
+            Some(Parent(mount_id)) => mount_id,
+            None => entity_id,
+        };
+        let (_, x, y, _) = Self::load_entity(env, mover)?;
+
+        let grid = Self::with_blocked_tiles(env, grid);
+        let path = Pathfinder::find_path(env, &grid, &Position(x, y), &goal, max_nodes).ok_or(GameError::NoPath)?;
+        if path.is_empty() {
+            storage::remove_entity_path(env, mover);
+        } else {
+            storage::set_entity_path(env, mover, &path);
+        }
+        Ok(path.len())
+    }
+
+    /// Returns the steps an entity has left on its path, if it is following one
+    pub fn get_entity_path(env: &Env, entity_id: u32) -> Option<Vec<Position>> {
+        storage::get_entity_path(env, entity_id)
+    }
+
+    /// Fires a projectile owned by `owner`
+    ///
+    /// The projectile travels `(dx, dy)` per `game_tick`, hits the first
+    /// entity of another owner it lands on for `damage`, and despawns after
+    /// `ttl_ticks` ticks.
+    ///
+    /// # Errors
+    ///
+    /// - `GameError::InvalidProjectile` if damage or lifetime is zero
+    #[allow(clippy::too_many_arguments)]
+    pub fn spawn_projectile(
+        env: &Env,
+        owner: Address,
+        x: u32,
+        y: u32,
+        dx: i32,
+        dy: i32,
+        damage: u32,
+        ttl_ticks: u32,
+    ) -> Result<u32, GameError> {
+        Self::require_not_paused(env)?;
+        owner.require_auth();
+        if damage == 0 || ttl_ticks == 0 {
+            return Err(GameError::InvalidProjectile);
+        }
+        let projectile_id = Self::spawn_entity(env, x, y)?;
+        storage::set_entity_owner(env, projectile_id, &owner);
+        storage::set_projectile(env, projectile_id, &Projectile { dx, dy, damage, ttl: ttl_ticks });
+        Ok(projectile_id)
+    }
+
+    /// Returns the Projectile component of an entity, if any
+    pub fn get_projectile(env: &Env, entity_id: u32) -> Option<Projectile> {
+        storage::get_projectile(env, entity_id)
+    }
+
+    /// Runs the EffectSystem for one entity
+    ///
+    /// Applies poison damage accrued since the last tick and clears expired
+    /// effects. An entity poisoned to 0 health dies like any other.
+    pub fn tick_effects(env: &Env, entity_id: u32) -> Result<(), GameError> {
+        Self::require_not_paused(env)?;
+        let (id, x, y, health) = Self::load_entity(env, entity_id)?;
+        let effects = storage::get_status_effects(env, entity_id);
+        let (new_health, remaining) = EffectSystem::tick(&effects, &Health(health), env.ledger().timestamp());
+        if new_health.0 < health {
+            Self::entity_damaged(env, id, health - new_health.0, new_health.0);
+        }
+
+        if CombatSystem::is_dead(&new_health) {
+            Self::kill_entity(env, entity_id, &Position(x, y));
+        } else {
+            storage::set_status_effects(env, entity_id, &remaining);
+            Self::save_entity(env, id, &Position(x, y), new_health.0);
+        }
+        Ok(())
+    }
+
+    /// Advances the world by one tick, a page of entity IDs at a time
+    ///
+    /// Each call visits at most `limit` entity IDs (capped at
+    /// `MAX_TICK_SCAN`) from `start_id`. The entities found regenerate and
+    /// run the EffectSystem; entities killed by their effects are removed as
+    /// in `attack_entity`. Entities in the page following a path then take
+    /// their next step, the ProjectileSystem moves the page's projectiles and
+    /// resolves impacts, and finally the page's bosses strike according to
+    /// their current phase. Only the entities near a projectile or boss are
+    /// read, through the spatial index. A pass starts at ID 0: that call
+    /// first rotates the weather once its rotation period has elapsed and
+    /// runs scheduled world events whose start ledger has been reached. Keep
+    /// passing `next_id` until it is `None` to tick the whole world.
+    ///
+    /// # Errors
+    ///
+    /// - `GameError::Paused` while the world is paused
+    pub fn game_tick(env: &Env, start_id: u32, limit: u32) -> Result<TickPage, GameError> {
+        Self::require_not_paused(env)?;
+        let now = env.ledger().timestamp();
+        if start_id == 0 {
+            storage::record_tick(env, now);
+            Self::rotate_weather(env, now);
+            Self::run_world_events(env);
+        }
+        let next_id = storage::get_next_entity_id(env);
+        let end_id = start_id
+            .saturating_add(limit.min(MAX_TICK_SCAN))
+            .min(next_id)
+            .max(start_id);
+        let entity_ids = storage::get_entity_ids_in_range(env, start_id, end_id);
+        let entities = storage::get_entities_batch(env, &entity_ids);
+        let mut updated: Map<u32, Val> = Map::new(env);
+        let mut processed = 0;
+
+        for (entity_id, entity_data) in entities.iter() {
+            let Ok((id, x, y, stored_health)) = <(u32, u32, u32, u32)>::try_from_val(env, &entity_data) else {
+                continue;
+            };
+            if id != entity_id {
+                continue;
+            }
+            processed += 1;
+
+            let health = Self::regenerate(env, id, stored_health, now);
+            let effects = storage::get_status_effects(env, entity_id);
+            let (new_health, remaining) = EffectSystem::tick(&effects, &Health(health), now);
+            if new_health.0 < health {
+                Self::entity_damaged(env, id, health - new_health.0, new_health.0);
+            }
+
+            if CombatSystem::is_dead(&new_health) {
+                Self::kill_entity(env, entity_id, &Position(x, y));
+                continue;
+            }
+            if remaining != effects {
+                storage::set_status_effects(env, entity_id, &remaining);
+            }
+            if new_health.0 != stored_health {
+                let entity_data: (u32, u32, u32, u32) = (id, x, y, new_health.0);
+                updated.set(entity_id, entity_data.into_val(env));
+            }
+        }
+
+        if !updated.is_empty() {
+            storage::set_entities_batch(env, &updated);
+        }
+        Self::tick_paths(env, start_id, end_id)?;
+        Self::tick_projectiles(env, start_id, end_id)?;
+        Self::tick_bosses(env, start_id, end_id)?;
+        Ok(TickPage {
+            processed,
+            next_id: (end_id < next_id).then_some(end_id),
+        })
+    }
+}
+
+impl GameWorldContract {
+    /// Counts an entity move in the world statistics and emits entity_moved
+    fn entity_moved(env: &Env, entity_id: u32, position: &Position) {
+        storage::record_move(env);
+        GameEvents::emit_entity_moved(env, entity_id, position);
+    }
+
+    /// Counts dealt damage in the world statistics and emits entity_damaged
+    fn entity_damaged(env: &Env, entity_id: u32, damage: u32, health: u32) {
+        storage::record_damage(env, damage);
+        GameEvents::emit_entity_damaged(env, entity_id, damage, health);
+    }
+
+    /// Returns the admin after checking its authorization
+    fn require_admin(env: &Env) -> Result<Address, GameError> {
+        let admin = storage::get_admin(env).ok_or(GameError::Unauthorized)?;
+        admin.require_auth();
+        Ok(admin)
+    }
+
+    /// Requires the owner's authorization if the entity has one
+    ///
+    /// Unowned entities stay open to any caller.
+    fn require_owner_if_set(env: &Env, entity_id: u32) {
+        if let Some(owner) = storage::get_entity_owner(env, entity_id) {
+            owner.require_auth();
+        }
+    }
+
+    /// Rejects the call while the world is paused
+    fn require_not_paused(env: &Env) -> Result<(), GameError> {
+        if storage::is_paused(env) {
+            return Err(GameError::Paused);
+        }
+        Ok(())
+    }
+
+    /// Loads and decodes the stored `(id, x, y, health)` tuple for an entity
+    ///
+    /// Distinguishes entities that never existed from entities that were
+    /// killed, so callers can surface the precise failure to clients.
+    fn load_entity(env: &Env, entity_id: u32) -> Result<(u32, u32, u32, u32), GameError> {
+        let entity_data = match storage::get_entity_data(env, entity_id) {
+            Some(data) => data,
+            None if storage::is_entity_dead(env, entity_id) => return Err(GameError::EntityDead),
+            None => return Err(GameError::EntityNotFound),
+        };
+        let (id, x, y, stored_health) = <(u32, u32, u32, u32)>::try_from_val(env, &entity_data)
+            .map_err(|_| GameError::InvalidEntityData)?;
+        if id != entity_id {
+            return Err(GameError::InvalidEntityData);
+        }
+
+        let health = Self::regenerate(env, id, stored_health, env.ledger().timestamp());
+        if health != stored_health {
+            Self::save_entity(env, id, &Position(x, y), health);
+        }
+        Ok((id, x, y, health))
+    }
+
+    /// Applies pending regeneration and returns the entity's new health
+    ///
+    /// Persists the advanced Regeneration component; saving the health is
+    /// left to the caller so batch updates can stay batched.
+    fn regenerate(env: &Env, entity_id: u32, health: u32, now: u64) -> u32 {
+        let Some(regen) = storage::get_regeneration(env, entity_id) else {
+            return health;
+        };
+        let max_health = storage::get_max_health(env, entity_id).unwrap_or(MaxHealth(health));
+        let (new_health, updated) = RegenSystem::regenerate(&Health(health), &regen, &max_health, now);
+        if updated != regen {
+            storage::set_regeneration(env, entity_id, &updated);
+        }
+        new_health.0
+    }
+
+    /// Advances the world weather by any rotation periods elapsed up to `now`
+    fn rotate_weather(env: &Env, now: u64) {
+        let world = storage::get_world_weather(env);
+        let rotated = WeatherSystem::rotate(&world, now);
+        if rotated != world {
+            storage::set_world_weather(env, &rotated);
+            if rotated.weather != world.weather {
+                GameEvents::emit_weather_changed(env, rotated.weather);
+            }
+        }
+    }
+
+    /// Runs every scheduled world event whose start ledger has been reached, in ID order
+    fn run_world_events(env: &Env) {
+        let events = storage::get_world_events(env);
+        let sequence = env.ledger().sequence();
+        let mut pending = events.clone();
+        for (event_id, event) in events.iter() {
+            if !WorldEventSystem::is_due(&event, sequence) {
+                continue;
+            }
+            pending.remove(event_id);
+            Self::start_world_event(env, &event, sequence);
+            GameEvents::emit_world_event_started(env, event_id, event.kind);
+        }
+        if pending.len() != events.len() {
+            storage::set_world_events(env, &pending);
+        }
+    }
+
+    /// Applies a world event to the world
+    ///
+    /// Meteors are owned by the contract itself, so they strike any entity
+    /// that ends up on their tile. A spawned boss uses the default phases and
+    /// rewards a tenth of its health in currency.
+    fn start_world_event(env: &Env, event: &WorldEvent, sequence: u32) {
+        let param = |index: u32| event.params.get(index).unwrap_or(0);
+        match event.kind {
+            WorldEventKind::MeteorShower => {
+                let owner = env.current_contract_address();
+                let meteor = WorldEventSystem::meteor(param(5));
+                for _ in 0..param(4) {
+                    let x = param(0).saturating_add(env.prng().gen_range::<u64>(0..param(2) as u64) as u32);
+                    let y = param(1).saturating_add(env.prng().gen_range::<u64>(0..param(3) as u64) as u32);
+                    let meteor_id = Self::create_entity(env, x, y);
+                    storage::set_entity_owner(env, meteor_id, &owner);
+                    storage::set_projectile(env, meteor_id, &meteor);
+                }
+            }
+            WorldEventKind::DoubleXp => {
+                let window = XpWindow { percent: param(1), ends_seq: sequence.saturating_add(param(0)) };
+                storage::set_xp_window(env, &window);
+            }
+            WorldEventKind::BossSpawn => {
+                let boss = Boss {
+                    max_health: param(2),
+                    phases: BossSystem::default_phases(env, CombatSystem::BASE_ATTACK_DAMAGE),
+                    phase: 0,
+                    reward_currency: param(2) / 10,
+                    damage_by: Map::new(env),
+                };
+                Self::create_boss(env, &Position(param(0), param(1)), &boss);
+            }
+        }
+    }
+
+    /// Stores an interactable and blocks or frees its tile to match its state
+    fn update_interactable(env: &Env, entity_id: u32, position: &Position, interactable: &Interactable) {
+        storage::set_interactable(env, entity_id, interactable);
+        let tile = InteractionSystem::blocks_tile(interactable).then_some(position);
+        storage::set_tile_blocked(env, entity_id, tile);
+    }
+
+    /// Collects the tiles blocked by walls and closed doors
+    fn blocked_tiles(env: &Env) -> Vec<Position> {
+        let mut blocked = storage::get_walls(env);
+        blocked.append(&storage::get_blocked_tiles(env).values());
+        blocked
+    }
+
+    /// Adds the tiles blocked by walls and closed doors to a caller-supplied grid
+    fn with_blocked_tiles(env: &Env, mut grid: Grid) -> Grid {
+        grid.blocked.append(&Self::blocked_tiles(env));
+        grid
+    }
+
+    /// Validates an attack and returns the attacker and target positions
+    ///
+    /// Requires authorization from the attacker's owner and enforces the
+    /// friendly fire rule.
+    fn check_attack(env: &Env, attacker_id: u32, target_id: u32) -> Result<(Position, Position), GameError> {
+        if attacker_id == target_id {
+            return Err(GameError::InvalidTarget);
+        }
+        let (_, attacker_x, attacker_y, _) = Self::load_entity(env, attacker_id)?;
+        let (_, target_x, target_y, _) = Self::load_entity(env, target_id)?;
+        let owner = storage::get_entity_owner(env, attacker_id).ok_or(GameError::Unauthorized)?;
+        owner.require_auth();
+
+        let attacker_team = storage::get_entity_team(env, attacker_id);
+        let target_team = storage::get_entity_team(env, target_id);
+        if CombatSystem::is_friendly_fire(attacker_team.as_ref(), target_team.as_ref())
+            && !storage::is_friendly_fire_enabled(env)
+        {
+            return Err(GameError::FriendlyFire);
+        }
+        Ok((Position(attacker_x, attacker_y), Position(target_x, target_y)))
+    }
+
+    /// Validates a support action and charges the caster its energy cost
+    ///
+    /// Requires authorization from the caster's owner. A target other than
+    /// the caster must be on its team and within support range.
+    fn spend_support(env: &Env, caster_id: u32, target_id: u32, amount: u32, cost: u32) -> Result<(), GameError> {
+        let (_, caster_x, caster_y, _) = Self::load_entity(env, caster_id)?;
+        let (_, target_x, target_y, _) = Self::load_entity(env, target_id)?;
+        let owner = storage::get_entity_owner(env, caster_id).ok_or(GameError::Unauthorized)?;
+        owner.require_auth();
+
+        if caster_id != target_id {
+            let caster_team = storage::get_entity_team(env, caster_id);
+            let target_team = storage::get_entity_team(env, target_id);
+            if !SupportSystem::are_allies(caster_team.as_ref(), target_team.as_ref()) {
+                return Err(GameError::NotAllies);
+            }
+            if !SupportSystem::in_range(&Position(caster_x, caster_y), &Position(target_x, target_y)) {
+                return Err(GameError::OutOfRange);
+            }
+        }
+        let power = storage::get_healing_power(env, caster_id).unwrap_or(HealingPower(0));
+        if amount == 0 || amount > power.0 {
+            return Err(GameError::InvalidSupportAmount);
+        }
+        let energy = storage::get_energy(env, caster_id).unwrap_or(Energy(0));
+        let energy = SupportSystem::spend_energy(&energy, cost).ok_or(GameError::NotEnoughEnergy)?;
+        storage::set_energy(env, caster_id, &energy);
+        Ok(())
+    }
+
+    /// Writes an entity's `(id, x, y, health)` tuple back to storage
+    fn save_entity(env: &Env, entity_id: u32, position: &Position, health: u32) {
+        let entity_data: (u32, u32, u32, u32) = (entity_id, position.0, position.1, health);
+        let val: Val = entity_data.into_val(env);
+        storage::set_entity_data(env, entity_id, val);
+    }
+
+    /// Deals `base_damage` to an entity, scaled by the weather and absorbed in part by any active shield
+    ///
+    /// A `Shielded` effect blunts the hit first, then a cast `Shield` soaks
+    /// up what it can before Health. Damage to a boss is attributed to
+    /// `attacker_id`, if given.
+    fn damage_entity(env: &Env, entity_id: u32, base_damage: u32, attacker_id: Option<u32>) -> Result<(), GameError> {
+        let (id, x, y, health) = Self::load_entity(env, entity_id)?;
+        let current_health = Health(health);
+        // Use the CombatSystem to apply attack damage, absorbed in part by any active shield
+        let now = env.ledger().timestamp();
+        let base_damage = WeatherSystem::scale_damage(storage::get_world_weather(env).weather, base_damage);
+        let effects = storage::get_status_effects(env, entity_id);
+        let damage = EffectSystem::reduce_damage(effects.shielded.as_ref(), base_damage, now);
+        let shield = storage::get_shield(env, entity_id);
+        let (damage, remaining) = SupportSystem::absorb(shield.as_ref(), damage, now);
+        if remaining != shield {
+            storage::set_shield(env, entity_id, remaining.as_ref());
+        }
+        let new_health = CombatSystem::apply_damage(&current_health, damage);
+        Self::entity_damaged(env, id, health - new_health.0, new_health.0);
+        if let Some(boss) = storage::get_boss(env, entity_id) {
+            Self::update_boss(env, id, &boss, attacker_id, health - new_health.0, new_health.0);
+        }
+
+        if new_health.0 > 0 {
+            // Just update the entity's health
+            Self::save_entity(env, id, &Position(x, y), new_health.0);
+        } else {
+            Self::kill_entity(env, entity_id, &Position(x, y));
+        }
+        Ok(())
+    }
+
+    /// Creates a boss entity at full health and returns its ID
+    fn create_boss(env: &Env, position: &Position, boss: &Boss) -> u32 {
+        let boss_id = Self::create_entity(env, position.0, position.1);
+        Self::save_entity(env, boss_id, position, boss.max_health);
+        storage::set_max_health(env, boss_id, &MaxHealth(boss.max_health));
+        storage::set_boss(env, boss_id, boss);
+        boss_id
+    }
+
+    /// Attributes damage dealt to a boss and advances its phase
+    ///
+    /// A boss left at zero health pays out its reward instead; removing it
+    /// from the world is left to the caller.
+    fn update_boss(env: &Env, boss_id: u32, boss: &Boss, attacker_id: Option<u32>, damage: u32, health: u32) {
+        let mut updated = match attacker_id {
+            Some(attacker_id) if attacker_id != boss_id => BossSystem::record_damage(boss, attacker_id, damage),
+            _ => boss.clone(),
+        };
+        if health == 0 {
+            Self::reward_contributors(env, boss_id, &updated);
+            return;
+        }
+
+        updated.phase = BossSystem::phase_for(&updated, health);
+        if updated.phase != boss.phase {
+            if let Some(phase) = BossSystem::current_phase(&updated) {
+                GameEvents::emit_boss_phase_changed(env, boss_id, updated.phase, phase.behavior);
+            }
+        }
+        if updated != *boss {
+            storage::set_boss(env, boss_id, &updated);
+        }
+    }
+
+    /// Pays each living contributor its share of a dead boss's reward
+    ///
+    /// Shares of contributors that have since left the world are forfeited.
+    fn reward_contributors(env: &Env, boss_id: u32, boss: &Boss) {
+        for (entity_id, damage, currency) in BossSystem::split_rewards(env, boss).iter() {
+            if storage::get_entity_data(env, entity_id).is_none() {
+                continue;
+            }
+            let mut inventory = storage::get_inventory(env, entity_id).unwrap_or_else(|| Inventory::new(env));
+            inventory.currency = inventory.currency.saturating_add(currency);
+            storage::set_inventory(env, entity_id, &inventory);
+            GameEvents::emit_boss_rewarded(env, boss_id, entity_id, damage, currency);
+        }
+    }
+
+    /// Moves a mount scaled by its Speed component and carries its rider along
+    fn move_mount(env: &Env, mount_id: u32, dx: i32, dy: i32) -> Result<(), GameError> {
+        let (_, x, y, health) = Self::load_entity(env, mount_id)?;
+        let base_speed = storage::get_entity_speed(env, mount_id).unwrap_or(Speed(1));
+        let effects = storage::get_status_effects(env, mount_id);
+        let speed = EffectSystem::boosted_speed(effects.speed_boost.as_ref(), &base_speed, env.ledger().timestamp());
+        let (dx, dy) = MovementSystem::apply_speed(&speed, dx, dy);
+        let (dx, dy) = WeatherSystem::scale_movement(storage::get_world_weather(env).weather, dx, dy);
+        let new_position = MovementSystem::update_position(&Position(x, y), dx, dy);
+        Self::save_entity(env, mount_id, &new_position, health);
+        Self::entity_moved(env, mount_id, &new_position);
+
+        if let Some(rider_id) = storage::get_entity_rider(env, mount_id) {
+            let (_, _, _, rider_health) = Self::load_entity(env, rider_id)?;
+            Self::save_entity(env, rider_id, &new_position, rider_health);
+            Self::entity_moved(env, rider_id, &new_position);
+        }
+        Ok(())
+    }
+
+    /// Creates an entity with full health at `(x, y)` and returns its ID
+    fn create_entity(env: &Env, x: u32, y: u32) -> u32 {
+        let entity_count = storage::get_entity_count(env);
+        let entity_id = storage::get_next_entity_id(env);
+        
+        // Create entity components using the modular components
+        let position = Position(x, y);
+        let health = Health(100);
+        
+        // Store entity data as optimized tuple
+        let entity_data: (u32, u32, u32, u32) = (entity_id, position.0, position.1, health.0);
+        let val: Val = entity_data.into_val(env);
+        
+        // Use optimized storage functions
+        storage::set_entity_data(env, entity_id, val);
+        storage::set_entity_count(env, entity_count + 1);
+        storage::set_next_entity_id(env, entity_id + 1);
+        storage::clear_entity_dead(env, entity_id);
+        storage::record_spawn(env);
+
+        GameEvents::emit_entity_spawned(env, entity_id, &position);
+        entity_id
+    }
+
+    /// Removes a despawned entity from the world and updates the live counter
+    fn remove_entity(env: &Env, entity_id: u32) {
+        Self::detach_entity(env, entity_id);
+        storage::remove_entity_data(env, entity_id);
+        let current_count = storage::get_entity_count(env);
+        storage::set_entity_count(env, current_count.saturating_sub(1));
+        GameEvents::emit_entity_despawned(env, entity_id);
+    }
+
+    /// Moves every entity in `start_id..end_id` that is following a path one step along it
+    ///
+    /// An entity that was moved off its path since the last tick, or whose
+    /// next tile was closed off by a door, stops following it. A mount
+    /// carries its rider along.
+    fn tick_paths(env: &Env, start_id: u32, end_id: u32) -> Result<(), GameError> {
+        let blocked = Self::blocked_tiles(env);
+        for entity_id in storage::get_entity_ids_in_range(env, start_id, end_id).iter() {
+            let Some(mut path) = storage::get_entity_path(env, entity_id) else {
+                continue;
+            };
+            let (_, x, y, health) = Self::load_entity(env, entity_id)?;
+            let Some(step) = path.pop_front() else {
+                storage::remove_entity_path(env, entity_id);
+                continue;
+            };
+            if !MovementSystem::is_adjacent(&Position(x, y), &step) || blocked.contains(&step) {
+                storage::remove_entity_path(env, entity_id);
+                continue;
+            }
+
+            Self::save_entity(env, entity_id, &step, health);
+            Self::entity_moved(env, entity_id, &step);
+            if let Some(rider_id) = storage::get_entity_rider(env, entity_id) {
+                let (_, _, _, rider_health) = Self::load_entity(env, rider_id)?;
+                Self::save_entity(env, rider_id, &step, rider_health);
+                Self::entity_moved(env, rider_id, &step);
+            }
+
+            if path.is_empty() {
+                storage::remove_entity_path(env, entity_id);
+            } else {
+                storage::set_entity_path(env, entity_id, &path);
+            }
+        }
+        Ok(())
+    }
+
+    /// Runs the ProjectileSystem over the projectiles in `start_id..end_id`
+    ///
+    /// Each projectile moves one step and damages the live entity of another
+    /// owner with the lowest ID on its new tile, passing over loot drops and
+    /// other projectiles. It despawns on impact or when its lifetime runs
+    /// out. A projectile whose step crosses or ends on a wall or closed door
+    /// is stopped there and despawns.
+    fn tick_projectiles(env: &Env, start_id: u32, end_id: u32) -> Result<(), GameError> {
+        let blocked = Self::blocked_tiles(env);
+        let entity_ids = storage::get_entity_ids_in_range(env, start_id, end_id);
+        let entities = storage::get_entities_batch(env, &entity_ids);
+        let mut moved: Map<u32, Val> = Map::new(env);
+        for (projectile_id, entity_data) in entities.iter() {
+            let Ok((_, x, y, health)) = <(u32, u32, u32, u32)>::try_from_val(env, &entity_data) else {
+                continue;
+            };
+            let Some(projectile) = storage::get_projectile(env, projectile_id) else {
+                continue;
+            };
+            let position = Position(x, y);
+            let (new_position, projectile) = ProjectileSystem::advance(&position, &projectile);
+            if blocked.contains(&new_position) || !LineOfSight::is_clear(env, &blocked, &position, &new_position) {
+                Self::remove_entity(env, projectile_id);
+                continue;
+            }
+            let owner = storage::get_entity_owner(env, projectile_id);
+            let hit = Self::entities_near(env, &new_position, 0).keys().iter().find(|target_id| {
+                *target_id != projectile_id
+                    && storage::get_projectile(env, *target_id).is_none()
+                    && storage::get_entity_owner(env, *target_id) != owner
+                    && storage::get_loot(env, *target_id).is_none()
+            });
+
+            if let Some(target_id) = hit {
+                Self::damage_entity(env, target_id, projectile.damage, None)?;
+                Self::remove_entity(env, projectile_id);
+            } else if ProjectileSystem::is_expired(&projectile) {
+                Self::remove_entity(env, projectile_id);
+            } else {
+                storage::set_projectile(env, projectile_id, &projectile);
+                let entity_data: (u32, u32, u32, u32) = (projectile_id, new_position.0, new_position.1, health);
+                moved.set(projectile_id, entity_data.into_val(env));
+                Self::entity_moved(env, projectile_id, &new_position);
+            }
+        }
+
+        if !moved.is_empty() {
+            storage::set_entities_batch(env, &moved);
+        }
+        Ok(())
+    }
+
+    /// Lets every boss in `start_id..end_id` strike according to its current phase
+    ///
+    /// Bosses only target owned entities, so loot drops, meteors and other
+    /// bosses are left alone. A melee or ranged boss strikes the first target
+    /// it reaches in ID order, a ranged boss only with a clear line of sight.
+    /// An enraged boss strikes the first `BossSystem::MAX_ENRAGED_TARGETS`
+    /// targets within its weather-scaled aggro radius.
+    fn tick_bosses(env: &Env, start_id: u32, end_id: u32) -> Result<(), GameError> {
+        let boss_ids = storage::get_boss_ids(env);
+        if !boss_ids.iter().any(|boss_id| (start_id..end_id).contains(&boss_id)) {
+            return Ok(());
+        }
+        let blocked = Self::blocked_tiles(env);
+        let aggro_radius = WeatherSystem::aggro_radius(storage::get_world_weather(env).weather, BossSystem::AGGRO_RADIUS);
+
+        for boss_id in boss_ids.iter().filter(|boss_id| (start_id..end_id).contains(boss_id)) {
+            let Some(phase) = storage::get_boss(env, boss_id).and_then(|boss| BossSystem::current_phase(&boss)) else {
+                continue;
+            };
+            let (_, x, y, _) = Self::load_entity(env, boss_id)?;
+            let position = Position(x, y);
+            let reach = match phase.behavior {
+                BossBehavior::Melee => 1,
+                BossBehavior::Ranged => CombatSystem::RANGED_ATTACK_RANGE,
+                BossBehavior::Enraged => aggro_radius,
+            };
+            let mut strikes = 0;
+            for (target_id, target) in Self::entities_near(env, &position, reach).iter() {
+                if boss_ids.contains(target_id)
+                    || storage::get_entity_owner(env, target_id).is_none()
+                    || storage::get_projectile(env, target_id).is_some()
+                    || !BossSystem::reaches(phase.behavior, &position, &target, aggro_radius)
+                    || (phase.behavior == BossBehavior::Ranged && !LineOfSight::is_clear(env, &blocked, &position, &target))
+                {
+                    continue;
+                }
+                Self::damage_entity(env, target_id, phase.damage, Some(boss_id))?;
+                strikes += 1;
+                if phase.behavior != BossBehavior::Enraged || strikes == BossSystem::MAX_ENRAGED_TARGETS {
+                    break;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the live entities within `reach` tiles of a position, by ID
+    ///
+    /// Reads only the spatial index cells the square around the position
+    /// overlaps.
+    fn entities_near(env: &Env, position: &Position, reach: u32) -> Map<u32, Position> {
+        let min_cell = storage::cell_of(&Position(position.0.saturating_sub(reach), position.1.saturating_sub(reach)));
+        let max_cell = storage::cell_of(&Position(position.0.saturating_add(reach), position.1.saturating_add(reach)));
+        let mut entities = Map::new(env);
+        for cell_y in min_cell.1..=max_cell.1 {
+            for cell_x in min_cell.0..=max_cell.0 {
+                for entity_id in storage::get_cell_entities(env, (cell_x, cell_y)).iter() {
+                    let Some(Position(x, y)) = Self::get_entity_position(env, entity_id) else {
+                        continue;
+                    };
+                    if x.abs_diff(position.0).max(y.abs_diff(position.1)) <= reach {
+                        entities.set(entity_id, Position(x, y));
+                    }
+                }
+            }
+        }
+        entities
+    }
+
+    /// Removes a killed entity from the world and updates the live/dead counters
+    fn kill_entity(env: &Env, entity_id: u32, position: &Position) {
+        let current_dead = storage::get_dead_entity_count(env);
+        storage::set_dead_entity_count(env, current_dead + 1);
+
+        let current_live = storage::get_entity_count(env);
+        if current_live > 0 {
+            storage::set_entity_count(env, current_live - 1);
+        }
+
+        let drops_loot = Self::drops_loot(env, entity_id);
+
+        Self::detach_entity(env, entity_id);
+        storage::remove_entity_data(env, entity_id);
+        storage::mark_entity_dead(env, entity_id);
+        GameEvents::emit_entity_died(env, entity_id, position);
+
+        if drops_loot {
+            let loot_id = Self::create_entity(env, position.0, position.1);
+            storage::set_loot(env, loot_id, &LootSystem::roll(env.prng().gen::<u64>()));
+        }
+    }
+
+    /// Checks whether an entity leaves a loot drop when it dies
+    ///
+    /// Only combatants do: entities with an owner or a health cap. Projectiles,
+    /// interactables, crafting stations and loot drops never drop loot.
+    fn drops_loot(env: &Env, entity_id: u32) -> bool {
+        let combatant = storage::get_entity_owner(env, entity_id).is_some()
+            || storage::get_max_health(env, entity_id).is_some();
+        combatant
+            && storage::get_projectile(env, entity_id).is_none()
+            && storage::get_interactable(env, entity_id).is_none()
+            && storage::get_crafting_station(env, entity_id).is_none()
+            && storage::get_loot(env, entity_id).is_none()
+    }
+
+    /// Breaks any mount links involving an entity and drops its auxiliary components
+    ///
+    /// Called before an entity leaves the world so its partner is not left
+    /// pointing at a missing entity.
+    fn detach_entity(env: &Env, entity_id: u32) {
+        if let Some(Parent(mount_id)) = storage::get_entity_parent(env, entity_id) {
+            storage::remove_entity_rider(env, mount_id);
+        }
+        if let Some(rider_id) = storage::get_entity_rider(env, entity_id) {
+            storage::remove_entity_parent(env, rider_id);
+        }
+        storage::remove_entity_components(env, entity_id);
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,321 @@
+//! Deterministic A* Pathfinding
+//!
+//! Finds shortest paths on a bounded grid with blocked tiles. Entities move
+//! to any of their eight neighbouring tiles at a cost of one, matching
+//! `MovementSystem::is_adjacent`, so the search uses the Chebyshev distance
+//! as its heuristic.
+//!
+//! The search is fully deterministic: neighbours are expanded in a fixed
+//! order and ties on the estimated cost are broken by the heuristic and then
+//! by insertion order, so every node computes the same path. A node budget
+//! bounds the compute spent on a single query.
+//!
+//! `LineOfSight` walks integer Bresenham lines between tiles so ranged
+//! attacks and projectiles can be stopped by blocked tiles.
+//!
+//! # Usage Example
+//!
+//! ```rust,ignore
+//! // Walk around a wall on a 10x10 map, expanding at most 64 tiles
+//! let grid = Grid { width: 10, height: 10, blocked: vec![&env, Position(1, 0), Position(1, 1)] };
+//! let path = Pathfinder::find_path(&env, &grid, &Position(0, 0), &Position(2, 0), 64);
+//! ```
+
+use soroban_sdk::{contracttype, Env, Map, Vec};
+
+use crate::components::Position;
+
+/// A bounded map for pathfinding
+///
+/// Tiles span `0..width` by `0..height`; `blocked` tiles cannot be entered.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid {
+    pub width: u32,
+    pub height: u32,
+    pub blocked: Vec<Position>,
+}
+
+impl Grid {
+    /// Returns true if the tile lies on the grid
+    pub fn contains(&self, x: u32, y: u32) -> bool {
+        x < self.width && y < self.height
+    }
+}
+
+/// Integer line-of-sight checks between tiles
+pub struct LineOfSight;
+
+impl LineOfSight {
+    /// Returns the tiles on the Bresenham line from `from` to `to`
+    ///
+    /// The line excludes `from` and ends at `to`, so it is empty when both
+    /// are the same tile. Walking it backwards visits the same tiles only
+    /// for straight and exactly diagonal lines.
+    pub fn line(env: &Env, from: &Position, to: &Position) -> Vec<Position> {
+        let mut line = Vec::new(env);
+        let (mut x, mut y) = (from.0 as i64, from.1 as i64);
+        let (goal_x, goal_y) = (to.0 as i64, to.1 as i64);
+        let dx = (goal_x - x).abs();
+        let dy = -(goal_y - y).abs();
+        let step_x = if x < goal_x { 1 } else { -1 };
+        let step_y = if y < goal_y { 1 } else { -1 };
+        let mut error = dx + dy;
+        while (x, y) != (goal_x, goal_y) {
+            let doubled = 2 * error;
+            if doubled >= dy {
+                error += dy;
+                x += step_x;
+            }
+            if doubled <= dx {
+                error += dx;
+                y += step_y;
+            }
+            line.push_back(Position(x as u32, y as u32));
+        }
+        line
+    }
+
+    /// Returns true if no tile strictly between `from` and `to` is blocked
+    ///
+    /// The end tiles themselves are never checked, so an entity standing
+    /// in a doorway can still see and be seen.
+    pub fn is_clear(env: &Env, blocked: &Vec<Position>, from: &Position, to: &Position) -> bool {
+        let line = Self::line(env, from, to);
+        let between = line.len().saturating_sub(1);
+        line.iter().take(between as usize).all(|tile| !blocked.contains(&tile))
+    }
+}
+
+/// A* search over a `Grid`
+pub struct Pathfinder;
+
+impl Pathfinder {
+    /// Offsets of the eight neighbouring tiles, in expansion order
+    const NEIGHBOURS: [(i32, i32); 8] = [(1, 0), (0, 1), (-1, 0), (0, -1), (1, 1), (-1, 1), (-1, -1), (1, -1)];
+
+    /// Finds a shortest path from `start` to `goal`
+    ///
+    /// Returns the tiles to step through in order, excluding `start` and
+    /// ending at `goal`, or an empty path if both are the same tile.
+    /// Returns `None` if either tile is off the grid, `goal` is blocked, no
+    /// path exists, or the search expands more than `max_nodes` tiles.
+    pub fn find_path(env: &Env, grid: &Grid, start: &Position, goal: &Position, max_nodes: u32) -> Option<Vec<Position>> {
+        if !grid.contains(start.0, start.1) || !grid.contains(goal.0, goal.1) {
+            return None;
+        }
+        let mut blocked: Map<(u32, u32), bool> = Map::new(env);
+        for tile in grid.blocked.iter() {
+            blocked.set((tile.0, tile.1), true);
+        }
+        if blocked.contains_key((goal.0, goal.1)) {
+            return None;
+        }
+
+        let start_tile = (start.0, start.1);
+        let goal_tile = (goal.0, goal.1);
+        // Open list entries are (estimated total cost, heuristic, x, y)
+        let mut open: Vec<(u32, u32, u32, u32)> = Vec::new(env);
+        let mut cost: Map<(u32, u32), u32> = Map::new(env);
+        let mut came_from: Map<(u32, u32), (u32, u32)> = Map::new(env);
+        let mut closed: Map<(u32, u32), bool> = Map::new(env);
+        let start_h = Self::heuristic(start_tile, goal_tile);
+        open.push_back((start_h, start_h, start.0, start.1));
+        cost.set(start_tile, 0);
+
+        let mut expanded = 0;
+        while let Some(best) = Self::pop_best(&mut open) {
+            let (_, _, x, y) = best;
+            let tile = (x, y);
+            if closed.contains_key(tile) {
+                continue;
+            }
+            if tile == goal_tile {
+                return Some(Self::reconstruct(env, &came_from, start_tile, goal_tile));
+            }
+            expanded += 1;
+            if expanded > max_nodes {
+                return None;
+            }
+            closed.set(tile, true);
+
+            let next_cost = cost.get(tile).unwrap_or(0) + 1;
+            for (dx, dy) in Self::NEIGHBOURS {
+                let (Some(nx), Some(ny)) = (x.checked_add_signed(dx), y.checked_add_signed(dy)) else {
+                    continue;
+                };
+                let neighbour = (nx, ny);
+                if !grid.contains(nx, ny) || blocked.contains_key(neighbour) || closed.contains_key(neighbour) {
+                    continue;
+                }
+                if cost.get(neighbour).is_some_and(|known| known <= next_cost) {
+                    continue;
+                }
+                cost.set(neighbour, next_cost);
+                came_from.set(neighbour, tile);
+                let h = Self::heuristic(neighbour, goal_tile);
+                open.push_back((next_cost + h, h, nx, ny));
+            }
+        }
+        None
+    }
+
+    /// Chebyshev distance, the exact cost of an unobstructed 8-way walk
+    fn heuristic(from: (u32, u32), to: (u32, u32)) -> u32 {
+        from.0.abs_diff(to.0).max(from.1.abs_diff(to.1))
+    }
+
+    /// Removes the open entry with the lowest estimated cost, then heuristic
+    ///
+    /// On a full tie the earliest inserted entry wins.
+    fn pop_best(open: &mut Vec<(u32, u32, u32, u32)>) -> Option<(u32, u32, u32, u32)> {
+        let mut best_index = 0;
+        let mut best = open.get(0)?;
+        for (index, entry) in open.iter().enumerate().skip(1) {
+            if (entry.0, entry.1) < (best.0, best.1) {
+                best_index = index;
+                best = entry;
+            }
+        }
+        open.remove(best_index as u32);
+        Some(best)
+    }
+
+    /// Walks `came_from` back from `goal` and returns the path in travel order
+    fn reconstruct(env: &Env, came_from: &Map<(u32, u32), (u32, u32)>, start: (u32, u32), goal: (u32, u32)) -> Vec<Position> {
+        let mut path = Vec::new(env);
+        let mut tile = goal;
+        while tile != start {
+            path.push_front(Position(tile.0, tile.1));
+            tile = came_from.get(tile).unwrap_or(start);
+        }
+        path
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::vec;
+
+    fn open_grid(env: &Env, width: u32, height: u32) -> Grid {
+        Grid {
+            width,
+            height,
+            blocked: Vec::new(env),
+        }
+    }
+
+    #[test]
+    fn test_straight_and_diagonal_paths() {
+        let env = Env::default();
+        let grid = open_grid(&env, 10, 10);
+
+        let path = Pathfinder::find_path(&env, &grid, &Position(0, 0), &Position(3, 0), 100).unwrap();
+        assert_eq!(path, vec![&env, Position(1, 0), Position(2, 0), Position(3, 0)]);
+
+        let path = Pathfinder::find_path(&env, &grid, &Position(0, 0), &Position(2, 2), 100).unwrap();
+        assert_eq!(path, vec![&env, Position(1, 1), Position(2, 2)]);
+
+        let path = Pathfinder::find_path(&env, &grid, &Position(4, 4), &Position(4, 4), 100).unwrap();
+        assert!(path.is_empty());
+    }
+
+    #[test]
+    fn test_path_avoids_blocked_tiles() {
+        let env = Env::default();
+        // A wall at x = 2 with a gap at y = 3
+        let grid = Grid {
+            width: 5,
+            height: 5,
+            blocked: vec![&env, Position(2, 0), Position(2, 1), Position(2, 2), Position(2, 4)],
+        };
+
+        let path = Pathfinder::find_path(&env, &grid, &Position(0, 0), &Position(4, 0), 100).unwrap();
+        assert!(path.contains(Position(2, 3)));
+        assert_eq!(path.last().unwrap(), Position(4, 0));
+        for tile in path.iter() {
+            assert!(!grid.blocked.contains(tile));
+        }
+        // Each step moves to a neighbouring tile
+        let mut previous = Position(0, 0);
+        for tile in path.iter() {
+            assert!(previous.0.abs_diff(tile.0) <= 1 && previous.1.abs_diff(tile.1) <= 1);
+            previous = tile;
+        }
+        assert_eq!(path.len(), 6);
+    }
+
+    #[test]
+    fn test_unreachable_or_invalid_goals() {
+        let env = Env::default();
+        let grid = Grid {
+            width: 3,
+            height: 3,
+            blocked: vec![&env, Position(1, 0), Position(1, 1), Position(1, 2)],
+        };
+
+        assert!(Pathfinder::find_path(&env, &grid, &Position(0, 0), &Position(2, 2), 100).is_none());
+        assert!(Pathfinder::find_path(&env, &grid, &Position(0, 0), &Position(1, 1), 100).is_none());
+        assert!(Pathfinder::find_path(&env, &grid, &Position(0, 0), &Position(3, 0), 100).is_none());
+    }
+
+    #[test]
+    fn test_node_budget() {
+        let env = Env::default();
+        let grid = open_grid(&env, 20, 20);
+
+        assert!(Pathfinder::find_path(&env, &grid, &Position(0, 0), &Position(10, 0), 5).is_none());
+        assert!(Pathfinder::find_path(&env, &grid, &Position(0, 0), &Position(10, 0), 10).is_some());
+    }
+
+    #[test]
+    fn test_paths_are_deterministic() {
+        let env = Env::default();
+        let grid = Grid {
+            width: 8,
+            height: 8,
+            blocked: vec![&env, Position(3, 3), Position(4, 3), Position(3, 4)],
+        };
+
+        let first = Pathfinder::find_path(&env, &grid, &Position(0, 0), &Position(7, 7), 100);
+        let second = Pathfinder::find_path(&env, &grid, &Position(0, 0), &Position(7, 7), 100);
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_bresenham_lines() {
+        let env = Env::default();
+
+        let line = LineOfSight::line(&env, &Position(0, 0), &Position(3, 0));
+        assert_eq!(line, vec![&env, Position(1, 0), Position(2, 0), Position(3, 0)]);
+
+        let line = LineOfSight::line(&env, &Position(3, 3), &Position(1, 1));
+        assert_eq!(line, vec![&env, Position(2, 2), Position(1, 1)]);
+
+        // Shallow and steep lines advance one tile along the major axis per step
+        let line = LineOfSight::line(&env, &Position(0, 0), &Position(4, 2));
+        assert_eq!(line, vec![&env, Position(1, 1), Position(2, 1), Position(3, 2), Position(4, 2)]);
+        let line = LineOfSight::line(&env, &Position(0, 4), &Position(1, 0));
+        assert_eq!(line, vec![&env, Position(0, 3), Position(1, 2), Position(1, 1), Position(1, 0)]);
+
+        assert!(LineOfSight::line(&env, &Position(2, 2), &Position(2, 2)).is_empty());
+    }
+
+    #[test]
+    fn test_line_of_sight_over_maps() {
+        let env = Env::default();
+        // A wall at x = 2 with a gap at y = 3
+        let wall = vec![&env, Position(2, 0), Position(2, 1), Position(2, 2), Position(2, 4)];
+
+        assert!(!LineOfSight::is_clear(&env, &wall, &Position(0, 0), &Position(4, 0)));
+        assert!(!LineOfSight::is_clear(&env, &wall, &Position(0, 1), &Position(4, 1)));
+        assert!(LineOfSight::is_clear(&env, &wall, &Position(0, 3), &Position(4, 3)));
+        assert!(LineOfSight::is_clear(&env, &wall, &Position(1, 2), &Position(3, 4)));
+        // Tiles on the same side of the wall see each other
+        assert!(LineOfSight::is_clear(&env, &wall, &Position(0, 0), &Position(1, 4)));
+        // The end tiles are not checked
+        assert!(LineOfSight::is_clear(&env, &wall, &Position(1, 1), &Position(2, 1)));
+        assert!(LineOfSight::is_clear(&env, &Vec::new(&env), &Position(0, 0), &Position(9, 5)));
+    }
+}
@@ -1,6 +1,46 @@
-use soroban_sdk::{symbol_short, Env, Symbol, Val, IntoVal, TryFromVal, Map, Vec};
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol, Val, IntoVal, TryFromVal, Map, Vec};
 use soroban_ecs::World;
 
+use crate::components::{Boss, Energy, HealingPower, Interactable, Inventory, Loot, MaxHealth, Parent, Poisoned, Position, Projectile, Regeneration, Shield, Shielded, Speed, SpeedBoost, StatusEffects, Team, Weather, WorldEvent, WorldWeather, XpWindow};
+use crate::crafting::CraftingStation;
+use crate::items::TokenizedEntity;
+use crate::systems::InteractionSystem;
+
+/// Remaining ledgers below which hot entries are extended (~5 days at 5s per ledger)
+pub const TTL_THRESHOLD: u32 = 86_400;
+/// Ledgers hot entries are extended to (~30 days at 5s per ledger)
+pub const TTL_EXTEND_TO: u32 = 518_400;
+
+/// Archival status of the world's hot storage entries
+///
+/// Soroban contracts cannot read an entry's TTL, so the contract records the
+/// ledger at which the last complete `extend_world_ttl` pass started instead.
+/// Reads and writes may extend entries further, which makes
+/// `live_until_ledger` a lower bound.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TtlInfo {
+    pub current_ledger: u32,
+    pub last_extended_ledger: u32,
+    pub live_until_ledger: u32,
+}
+
+/// Aggregate world statistics for dashboards
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WorldStats {
+    pub live_entities: u32,
+    pub dead_entities: u32,
+    /// Entities ever spawned, including mounts, projectiles and loot drops
+    pub total_spawns: u32,
+    /// Tiles moved by all entities, one per `entity_moved` event
+    pub total_moves: u64,
+    /// Health removed by attacks, projectiles and status effects
+    pub total_damage: u64,
+    /// Ledger timestamp of the last `game_tick`, or 0 if the world never ticked
+    pub last_tick: u64,
+}
+
 /// Optimized contract data structure for efficient ledger storage
 /// 
 /// This schema uses different storage types for optimal performance:
@@ -70,6 +110,65 @@ pub fn save_contract_data(env: &Env, data: &GameWorldData) {
     env.storage().instance().set(&key, &val);
 }
 
+/// Retrieves the contract admin, if one has been set
+pub fn get_admin(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&symbol_short!("admin"))
+}
+
+/// Stores the contract admin in instance storage
+pub fn set_admin(env: &Env, admin: &Address) {
+    env.storage().instance().set(&symbol_short!("admin"), admin);
+}
+
+/// Checks whether the world has been paused by the admin
+pub fn is_paused(env: &Env) -> bool {
+    env.storage().instance().get(&symbol_short!("paused")).unwrap_or(false)
+}
+
+/// Sets or clears the world pause flag
+pub fn set_paused(env: &Env, paused: bool) {
+    env.storage().instance().set(&symbol_short!("paused"), &paused);
+}
+
+/// Retrieves the inventory contract that entities are tokenized into, if any
+pub fn get_inventory_contract(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&symbol_short!("inv_ctr"))
+}
+
+/// Stores the inventory contract that entities are tokenized into
+pub fn set_inventory_contract(env: &Env, contract: &Address) {
+    env.storage().instance().set(&symbol_short!("inv_ctr"), contract);
+}
+
+/// Storage key for a single entity's `(id, x, y, health)` tuple
+fn entity_key(entity_id: u32) -> (Symbol, u32) {
+    (symbol_short!("ent"), entity_id)
+}
+
+/// Consecutive entity IDs tracked by one bucket of the live entity index
+const ENTITY_BUCKET_SIZE: u32 = 64;
+
+/// Storage key for one bucket of the live entity index
+///
+/// Each bucket is a bitmask with bit `id % ENTITY_BUCKET_SIZE` set for every
+/// live entity of IDs `bucket * ENTITY_BUCKET_SIZE..`.
+fn entity_bucket_key(bucket: u32) -> (Symbol, u32) {
+    (symbol_short!("ent_idx"), bucket)
+}
+
+/// Width and height in tiles of the grid cells the spatial index groups entities by
+pub const REGION_CELL_SIZE: u32 = 16;
+
+/// Storage key for the IDs of the entities standing in one grid cell
+fn cell_key(cell: (u32, u32)) -> (Symbol, u32, u32) {
+    (symbol_short!("cell"), cell.0, cell.1)
+}
+
+/// Storage key of the monolithic entity map used before per-entity keys
+fn legacy_entities_key() -> Symbol {
+    symbol_short!("entities")
+}
+
 /// Retrieves entity data from persistent storage using optimized key structure
 /// 
 /// Uses persistent storage for entity data because:
@@ -79,58 +178,63 @@ pub fn save_contract_data(env: &Env, data: &GameWorldData) {
 /// 
 /// Key structure: Individual keys per entity for efficient access
 pub fn get_entity_data(env: &Env, entity_id: u32) -> Option<Val> {
-    // Use a map in persistent storage for entity data
-    let entity_key = symbol_short!("entities");
-    if let Some(map) = env.storage().persistent().get::<Symbol, Map<u32, Val>>(&entity_key) {
-        map.get(entity_id)
-    } else {
-        None
+    let key = entity_key(entity_id);
+    let entity_data = env.storage().persistent().get::<(Symbol, u32), Val>(&key);
+    if entity_data.is_some() {
+        env.storage().persistent().extend_ttl(&key, TTL_THRESHOLD, TTL_EXTEND_TO);
     }
+    entity_data
 }
 
 /// Stores entity data in persistent storage with optimized key structure
 /// 
 /// The entity data is stored as a tuple (id, x, y, health) for efficient
-/// serialization and deserialization. This approach minimizes storage overhead
-/// while maintaining type safety.
+/// serialization and deserialization. Each entity lives under its own key, so
+/// a write only touches that entity, its index bucket when the entity is new
+/// and the spatial index when it crossed into another grid cell.
 pub fn set_entity_data(env: &Env, entity_id: u32, val: Val) {
-    let entity_key = symbol_short!("entities");
-    let mut map = env.storage().persistent()
-        .get::<Symbol, Map<u32, Val>>(&entity_key)
-        .unwrap_or_else(|| Map::new(env));
-    
-    map.set(entity_id, val);
-    env.storage().persistent().set(&entity_key, &map);
-    
-    // Set TTL for persistent storage to optimize ledger efficiency
-    // This prevents indefinite storage growth while maintaining reasonable persistence
-    let ledger_seq = env.ledger().sequence();
-    let ttl_ledgers = 518400; // ~30 days (assuming 5s per ledger)
-    env.storage().persistent().extend_ttl(&entity_key, ledger_seq, ttl_ledgers);
+    write_entity(env, entity_id, val);
 }
 
 /// Removes entity data from persistent storage
 /// 
 /// Properly cleans up storage to prevent ledger bloat and optimize costs
 pub fn remove_entity_data(env: &Env, entity_id: u32) {
-    let entity_key = symbol_short!("entities");
-    if let Some(mut map) = env.storage().persistent().get::<Symbol, Map<u32, Val>>(&entity_key) {
-        map.remove(entity_id);
-        env.storage().persistent().set(&entity_key, &map);
-    }
+    delete_entity(env, entity_id);
 }
 
 /// Retrieves all entity IDs for batch operations
 /// 
 /// This function enables efficient batch processing by providing a way to
-/// enumerate all existing entities without loading their full data.
+/// enumerate all existing entities without loading their full data. IDs come
+/// in ascending order, read from one index bucket per `ENTITY_BUCKET_SIZE`
+/// IDs ever issued.
 pub fn get_all_entity_ids(env: &Env) -> Vec<u32> {
-    let entity_key = symbol_short!("entities");
-    if let Some(map) = env.storage().persistent().get::<Symbol, Map<u32, Val>>(&entity_key) {
-        map.keys()
-    } else {
-        Vec::new(env)
+    get_entity_ids_in_range(env, 0, get_next_entity_id(env))
+}
+
+/// Retrieves the IDs of the live entities in `start_id..end_id`, in ascending order
+///
+/// Only the index buckets covering the range are read.
+pub fn get_entity_ids_in_range(env: &Env, start_id: u32, end_id: u32) -> Vec<u32> {
+    let mut entity_ids = Vec::new(env);
+    if start_id >= end_id {
+        return entity_ids;
     }
+    for bucket in start_id / ENTITY_BUCKET_SIZE..=(end_id - 1) / ENTITY_BUCKET_SIZE {
+        let key = entity_bucket_key(bucket);
+        let Some(live) = env.storage().persistent().get::<_, u64>(&key) else {
+            continue;
+        };
+        env.storage().persistent().extend_ttl(&key, TTL_THRESHOLD, TTL_EXTEND_TO);
+        for bit in 0..ENTITY_BUCKET_SIZE {
+            let entity_id = bucket * ENTITY_BUCKET_SIZE + bit;
+            if live & (1 << bit) != 0 && (start_id..end_id).contains(&entity_id) {
+                entity_ids.push_back(entity_id);
+            }
+        }
+    }
+    entity_ids
 }
 
 /// Batch entity data retrieval for efficient mass operations
@@ -139,46 +243,216 @@ pub fn get_all_entity_ids(env: &Env) -> Vec<u32> {
 /// the number of storage calls and improve performance for bulk operations.
 pub fn get_entities_batch(env: &Env, entity_ids: &Vec<u32>) -> Map<u32, Val> {
     let mut result = Map::new(env);
-    let entity_key = symbol_short!("entities");
-    
-    if let Some(map) = env.storage().persistent().get::<Symbol, Map<u32, Val>>(&entity_key) {
-        for i in 0..entity_ids.len() {
-            if let Some(entity_id) = entity_ids.get(i) {
-                if let Some(entity_data) = map.get(entity_id) {
-                    result.set(entity_id, entity_data);
-                }
-            }
+    for entity_id in entity_ids.iter() {
+        if let Some(entity_data) = get_entity_data(env, entity_id) {
+            result.set(entity_id, entity_data);
         }
     }
-    
     result
 }
 
 /// Batch entity data storage for efficient mass operations
 /// 
-/// Stores multiple entity data in a optimized manner to reduce
-/// the number of storage operations and transaction costs.
+/// Writes each entity under its own key, keeping transaction costs
+/// proportional to the entities touched.
 pub fn set_entities_batch(env: &Env, entities: &Map<u32, Val>) {
-    let entity_key = symbol_short!("entities");
-    let mut map = env.storage().persistent()
-        .get::<Symbol, Map<u32, Val>>(&entity_key)
-        .unwrap_or_else(|| Map::new(env));
-    
-    let keys = entities.keys();
-    for i in 0..keys.len() {
-        if let Some(entity_id) = keys.get(i) {
-            if let Some(entity_data) = entities.get(entity_id) {
-                map.set(entity_id, entity_data);
+    for (entity_id, entity_data) in entities.iter() {
+        write_entity(env, entity_id, entity_data);
+    }
+}
+
+/// Moves entities from the legacy monolithic map to per-entity keys
+///
+/// Returns the number of entities migrated. Running it again after the
+/// legacy map has been removed is a no-op.
+pub fn migrate_entity_storage(env: &Env) -> u32 {
+    let legacy_key = legacy_entities_key();
+    let Some(map) = env.storage().persistent().get::<Symbol, Map<u32, Val>>(&legacy_key) else {
+        return 0;
+    };
+
+    // Keep issuing IDs after the legacy entities once the map is gone
+    set_next_entity_id(env, get_next_entity_id(env));
+    set_entities_batch(env, &map);
+    env.storage().persistent().remove(&legacy_key);
+    map.len()
+}
+
+/// Writes one entity and extends its TTL, adding new IDs to the index
+///
+/// Moves the entity between grid cells when it crossed into another one.
+fn write_entity(env: &Env, entity_id: u32, val: Val) {
+    let key = entity_key(entity_id);
+    let previous = env.storage().persistent().get::<_, Val>(&key);
+    env.storage().persistent().set(&key, &val);
+    env.storage().persistent().extend_ttl(&key, TTL_EXTEND_TO, TTL_EXTEND_TO);
+
+    let from = previous.as_ref().and_then(|data| entity_cell(env, data));
+    let to = entity_cell(env, &val);
+    if from != to {
+        if let Some(cell) = from {
+            set_cell_member(env, cell, entity_id, false);
+        }
+        if let Some(cell) = to {
+            set_cell_member(env, cell, entity_id, true);
+        }
+    }
+
+    if previous.is_none() {
+        set_entity_indexed(env, entity_id, true);
+    }
+}
+
+/// Removes one entity's data and takes it out of the index and its grid cell
+fn delete_entity(env: &Env, entity_id: u32) {
+    let key = entity_key(entity_id);
+    let Some(data) = env.storage().persistent().get::<_, Val>(&key) else {
+        return;
+    };
+    env.storage().persistent().remove(&key);
+    set_entity_indexed(env, entity_id, false);
+    if let Some(cell) = entity_cell(env, &data) {
+        set_cell_member(env, cell, entity_id, false);
+    }
+}
+
+/// Sets or clears an entity's bit in its index bucket, dropping buckets that become empty
+fn set_entity_indexed(env: &Env, entity_id: u32, live: bool) {
+    let key = entity_bucket_key(entity_id / ENTITY_BUCKET_SIZE);
+    let bit = 1u64 << (entity_id % ENTITY_BUCKET_SIZE);
+    let bucket: u64 = env.storage().persistent().get(&key).unwrap_or(0);
+    let bucket = if live { bucket | bit } else { bucket & !bit };
+    if bucket == 0 {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &bucket);
+        env.storage().persistent().extend_ttl(&key, TTL_EXTEND_TO, TTL_EXTEND_TO);
+    }
+}
+
+/// Returns the grid cell holding a tile
+pub fn cell_of(position: &Position) -> (u32, u32) {
+    (position.0 / REGION_CELL_SIZE, position.1 / REGION_CELL_SIZE)
+}
+
+/// Returns the grid cell of an entity from its stored `(id, x, y, health)` tuple
+fn entity_cell(env: &Env, data: &Val) -> Option<(u32, u32)> {
+    let (_, x, y, _) = <(u32, u32, u32, u32)>::try_from_val(env, data).ok()?;
+    Some(cell_of(&Position(x, y)))
+}
+
+/// Retrieves the IDs of the entities in a grid cell, in the order they entered it
+pub fn get_cell_entities(env: &Env, cell: (u32, u32)) -> Vec<u32> {
+    let key = cell_key(cell);
+    let Some(ids) = env.storage().persistent().get(&key) else {
+        return Vec::new(env);
+    };
+    env.storage().persistent().extend_ttl(&key, TTL_THRESHOLD, TTL_EXTEND_TO);
+    ids
+}
+
+/// Adds an entity to a grid cell or takes it out, dropping cells that become empty
+fn set_cell_member(env: &Env, cell: (u32, u32), entity_id: u32, present: bool) {
+    let key = cell_key(cell);
+    let mut ids: Vec<u32> = env.storage().persistent().get(&key).unwrap_or_else(|| Vec::new(env));
+    match (ids.first_index_of(entity_id), present) {
+        (None, true) => ids.push_back(entity_id),
+        (Some(position), false) => {
+            ids.remove(position);
+        }
+        _ => return,
+    }
+    if ids.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &ids);
+        env.storage().persistent().extend_ttl(&key, TTL_EXTEND_TO, TTL_EXTEND_TO);
+    }
+}
+
+/// Extends the instance entry and the entities with IDs in `start_id..end_id` to the full TTL
+///
+/// The index buckets covering the range, each entity's grid cell, its
+/// components and its team's member list are extended along with them, as
+/// are the markers of killed and tokenized entities. A pass starts at ID 0
+/// and continues where the previous call stopped; once it reaches the next
+/// entity ID, the ledger it started at is recorded so `get_ttl_info` can
+/// report it.
+pub fn extend_world_ttl(env: &Env, start_id: u32, end_id: u32) {
+    env.storage().instance().extend_ttl(TTL_EXTEND_TO, TTL_EXTEND_TO);
+    if start_id < end_id {
+        for bucket in start_id / ENTITY_BUCKET_SIZE..=(end_id - 1) / ENTITY_BUCKET_SIZE {
+            let key = entity_bucket_key(bucket);
+            if env.storage().persistent().has(&key) {
+                env.storage().persistent().extend_ttl(&key, TTL_EXTEND_TO, TTL_EXTEND_TO);
             }
         }
     }
-    
-    env.storage().persistent().set(&entity_key, &map);
-    
-    // Set TTL for the batch operation
-    let ledger_seq = env.ledger().sequence();
-    let ttl_ledgers = 518400;
-    env.storage().persistent().extend_ttl(&entity_key, ledger_seq, ttl_ledgers);
+
+    for entity_id in start_id..end_id {
+        let key = entity_key(entity_id);
+        let Some(data) = env.storage().persistent().get::<_, Val>(&key) else {
+            // Killed and tokenized entities keep only their marker
+            extend_if_present(env, &(symbol_short!("dead"), entity_id));
+            extend_if_present(env, &(symbol_short!("tokened"), entity_id));
+            continue;
+        };
+        env.storage().persistent().extend_ttl(&key, TTL_EXTEND_TO, TTL_EXTEND_TO);
+        if let Some(cell) = entity_cell(env, &data) {
+            extend_if_present(env, &cell_key(cell));
+        }
+        for component in checkpoint_component_keys() {
+            extend_if_present(env, &(component, entity_id));
+        }
+        extend_if_present(env, &(symbol_short!("redeemed"), entity_id));
+        if let Some(Team(team_id)) = get_entity_team(env, entity_id) {
+            extend_if_present(env, &(symbol_short!("team_mem"), team_id));
+        }
+    }
+
+    // The running pass as (ledger it started at, ID it resumes from)
+    let pass_key = symbol_short!("ttl_pass");
+    let pass_start = if start_id == 0 {
+        Some(env.ledger().sequence())
+    } else {
+        env.storage()
+            .instance()
+            .get::<_, (u32, u32)>(&pass_key)
+            .filter(|&(_, resume_id)| resume_id == start_id)
+            .map(|(started, _)| started)
+    };
+    let Some(pass_start) = pass_start else {
+        return;
+    };
+    if end_id >= get_next_entity_id(env) {
+        env.storage().instance().remove(&pass_key);
+        env.storage().instance().set(&symbol_short!("ttl_ext"), &pass_start);
+    } else {
+        env.storage().instance().set(&pass_key, &(pass_start, end_id));
+    }
+}
+
+/// Extends a persistent entry to the full TTL if it exists
+fn extend_if_present<K: IntoVal<Env, Val>>(env: &Env, key: &K) {
+    if env.storage().persistent().has(key) {
+        env.storage().persistent().extend_ttl(key, TTL_EXTEND_TO, TTL_EXTEND_TO);
+    }
+}
+
+/// Reports when the whole world was last extended to the full TTL
+pub fn get_ttl_info(env: &Env) -> TtlInfo {
+    let current_ledger = env.ledger().sequence();
+    let last_extended_ledger = env.storage().instance().get(&symbol_short!("ttl_ext")).unwrap_or(0);
+    TtlInfo {
+        current_ledger,
+        last_extended_ledger,
+        live_until_ledger: last_extended_ledger.saturating_add(TTL_EXTEND_TO),
+    }
+}
+
+/// Extends the instance entry holding the counters when it is close to archival
+fn bump_instance_ttl(env: &Env) {
+    env.storage().instance().extend_ttl(TTL_THRESHOLD, TTL_EXTEND_TO);
 }
 
 /// Get entity count efficiently without loading full contract data
@@ -187,6 +461,7 @@ pub fn set_entities_batch(env: &Env, entities: &Map<u32, Val>) {
 /// to avoid deserializing the entire contract state.
 pub fn get_entity_count(env: &Env) -> u32 {
     let key = symbol_short!("ent_cnt");
+    bump_instance_ttl(env);
     env.storage().instance().get(&key).unwrap_or(0)
 }
 
@@ -196,9 +471,71 @@ pub fn set_entity_count(env: &Env, count: u32) {
     env.storage().instance().set(&key, &count);
 }
 
+/// Returns the ID the next spawned entity will receive
+///
+/// IDs are never reused, so a despawned or killed entity can't be
+/// overwritten by a later spawn. Worlds created before this counter existed
+/// continue after the highest ID in the legacy entity map.
+pub fn get_next_entity_id(env: &Env) -> u32 {
+    let key = symbol_short!("next_id");
+    env.storage().instance().get(&key).unwrap_or_else(|| {
+        env.storage()
+            .persistent()
+            .get::<Symbol, Map<u32, Val>>(&legacy_entities_key())
+            .and_then(|map| map.keys().iter().max())
+            .map_or(0, |id| id + 1)
+    })
+}
+
+/// Advances the entity ID counter
+pub fn set_next_entity_id(env: &Env, next_id: u32) {
+    let key = symbol_short!("next_id");
+    env.storage().instance().set(&key, &next_id);
+}
+
+/// Counts a newly spawned entity in the world statistics
+pub fn record_spawn(env: &Env) {
+    let key = symbol_short!("spawns");
+    let spawns: u32 = env.storage().instance().get(&key).unwrap_or(0);
+    env.storage().instance().set(&key, &spawns.saturating_add(1));
+}
+
+/// Counts an entity move in the world statistics
+pub fn record_move(env: &Env) {
+    let key = symbol_short!("moves");
+    let moves: u64 = env.storage().instance().get(&key).unwrap_or(0);
+    env.storage().instance().set(&key, &moves.saturating_add(1));
+}
+
+/// Adds dealt damage to the world statistics
+pub fn record_damage(env: &Env, damage: u32) {
+    let key = symbol_short!("damage");
+    let total: u64 = env.storage().instance().get(&key).unwrap_or(0);
+    env.storage().instance().set(&key, &total.saturating_add(damage as u64));
+}
+
+/// Records the timestamp of the latest world tick
+pub fn record_tick(env: &Env, timestamp: u64) {
+    env.storage().instance().set(&symbol_short!("last_tick"), &timestamp);
+}
+
+/// Collects the world counters into a `WorldStats`
+pub fn get_world_stats(env: &Env) -> WorldStats {
+    let instance = env.storage().instance();
+    WorldStats {
+        live_entities: get_entity_count(env),
+        dead_entities: get_dead_entity_count(env),
+        total_spawns: instance.get(&symbol_short!("spawns")).unwrap_or(0),
+        total_moves: instance.get(&symbol_short!("moves")).unwrap_or(0),
+        total_damage: instance.get(&symbol_short!("damage")).unwrap_or(0),
+        last_tick: instance.get(&symbol_short!("last_tick")).unwrap_or(0),
+    }
+}
+
 /// Get dead entity count efficiently
 pub fn get_dead_entity_count(env: &Env) -> u32 {
     let key = symbol_short!("dead_cnt");
+    bump_instance_ttl(env);
     env.storage().instance().get(&key).unwrap_or(0)
 }
 
@@ -207,3 +544,662 @@ pub fn set_dead_entity_count(env: &Env, count: u32) {
     let key = symbol_short!("dead_cnt");
     env.storage().instance().set(&key, &count);
 }
+
+/// Records that an entity was killed so later lookups can report it as dead
+/// rather than as never having existed
+pub fn mark_entity_dead(env: &Env, entity_id: u32) {
+    let key = (symbol_short!("dead"), entity_id);
+    env.storage().persistent().set(&key, &true);
+}
+
+/// Clears the dead marker when an entity ID is reused by a new spawn
+pub fn clear_entity_dead(env: &Env, entity_id: u32) {
+    let key = (symbol_short!("dead"), entity_id);
+    env.storage().persistent().remove(&key);
+}
+
+/// Checks whether an entity was killed
+pub fn is_entity_dead(env: &Env, entity_id: u32) -> bool {
+    let key = (symbol_short!("dead"), entity_id);
+    env.storage().persistent().has(&key)
+}
+
+/// Retrieves the owner of an entity, if one has been assigned
+pub fn get_entity_owner(env: &Env, entity_id: u32) -> Option<Address> {
+    let key = (symbol_short!("owner"), entity_id);
+    env.storage().persistent().get(&key)
+}
+
+/// Assigns an owner to an entity
+pub fn set_entity_owner(env: &Env, entity_id: u32, owner: &Address) {
+    let key = (symbol_short!("owner"), entity_id);
+    env.storage().persistent().set(&key, owner);
+}
+
+/// Retrieves the Speed component of an entity
+///
+/// Only mounts carry a Speed component; other entities return `None`.
+pub fn get_entity_speed(env: &Env, entity_id: u32) -> Option<Speed> {
+    let key = (symbol_short!("speed"), entity_id);
+    env.storage().persistent().get(&key)
+}
+
+/// Stores the Speed component of an entity
+pub fn set_entity_speed(env: &Env, entity_id: u32, speed: &Speed) {
+    let key = (symbol_short!("speed"), entity_id);
+    env.storage().persistent().set(&key, speed);
+}
+
+/// Retrieves the Parent component of a mounted rider
+pub fn get_entity_parent(env: &Env, entity_id: u32) -> Option<Parent> {
+    let key = (symbol_short!("parent"), entity_id);
+    env.storage().persistent().get(&key)
+}
+
+/// Parents a rider to its mount
+pub fn set_entity_parent(env: &Env, entity_id: u32, parent: &Parent) {
+    let key = (symbol_short!("parent"), entity_id);
+    env.storage().persistent().set(&key, parent);
+}
+
+/// Removes the Parent component from a rider
+pub fn remove_entity_parent(env: &Env, entity_id: u32) {
+    let key = (symbol_short!("parent"), entity_id);
+    env.storage().persistent().remove(&key);
+}
+
+/// Retrieves the rider currently carried by a mount
+///
+/// This is the reverse index of the Parent component, so a mount can move
+/// its rider without scanning all entities.
+pub fn get_entity_rider(env: &Env, mount_id: u32) -> Option<u32> {
+    let key = (symbol_short!("rider"), mount_id);
+    env.storage().persistent().get(&key)
+}
+
+/// Records the rider carried by a mount
+pub fn set_entity_rider(env: &Env, mount_id: u32, rider_id: u32) {
+    let key = (symbol_short!("rider"), mount_id);
+    env.storage().persistent().set(&key, &rider_id);
+}
+
+/// Clears the rider carried by a mount
+pub fn remove_entity_rider(env: &Env, mount_id: u32) {
+    let key = (symbol_short!("rider"), mount_id);
+    env.storage().persistent().remove(&key);
+}
+
+/// Retrieves the crafting station configuration of an entity
+pub fn get_crafting_station(env: &Env, entity_id: u32) -> Option<CraftingStation> {
+    let key = (symbol_short!("station"), entity_id);
+    env.storage().persistent().get(&key)
+}
+
+/// Marks an entity as a crafting station
+pub fn set_crafting_station(env: &Env, entity_id: u32, station: &CraftingStation) {
+    let key = (symbol_short!("station"), entity_id);
+    env.storage().persistent().set(&key, station);
+}
+
+/// Retrieves the tokenized entity behind an inventory item
+pub fn get_tokenized_entity(env: &Env, item_id: u64) -> Option<TokenizedEntity> {
+    let key = (symbol_short!("token"), item_id);
+    env.storage().persistent().get(&key)
+}
+
+/// Stores a tokenized entity under its inventory item, or removes it with `None`
+pub fn set_tokenized_entity(env: &Env, item_id: u64, tokenized: Option<&TokenizedEntity>) {
+    let key = (symbol_short!("token"), item_id);
+    match tokenized {
+        Some(tokenized) => env.storage().persistent().set(&key, tokenized),
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+/// Checks whether an entity is currently held in an inventory item
+pub fn is_entity_tokenized(env: &Env, entity_id: u32) -> bool {
+    let key = (symbol_short!("tokened"), entity_id);
+    env.storage().persistent().has(&key)
+}
+
+/// Marks an entity as held in an inventory item, or clears the mark once redeemed
+pub fn set_entity_tokenized(env: &Env, entity_id: u32, tokenized: bool) {
+    let key = (symbol_short!("tokened"), entity_id);
+    if tokenized {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+/// Records the ledger an entity was redeemed from an inventory item at
+pub fn set_entity_redeemed(env: &Env, entity_id: u32, ledger: u32) {
+    let key = (symbol_short!("redeemed"), entity_id);
+    env.storage().persistent().set(&key, &ledger);
+    env.storage().persistent().extend_ttl(&key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+/// Returns the ledger an entity was last redeemed at, if it ever was
+pub fn get_entity_redeemed(env: &Env, entity_id: u32) -> Option<u32> {
+    env.storage().persistent().get(&(symbol_short!("redeemed"), entity_id))
+}
+
+/// Retrieves the Projectile component of an entity
+pub fn get_projectile(env: &Env, entity_id: u32) -> Option<Projectile> {
+    let key = (symbol_short!("proj"), entity_id);
+    env.storage().persistent().get(&key)
+}
+
+/// Stores the Projectile component of an entity
+pub fn set_projectile(env: &Env, entity_id: u32, projectile: &Projectile) {
+    let key = (symbol_short!("proj"), entity_id);
+    env.storage().persistent().set(&key, projectile);
+}
+
+/// Retrieves the remaining steps of an entity's path, if it is following one
+pub fn get_entity_path(env: &Env, entity_id: u32) -> Option<Vec<Position>> {
+    let key = (symbol_short!("path"), entity_id);
+    env.storage().persistent().get(&key)
+}
+
+/// Stores the remaining steps of an entity's path
+pub fn set_entity_path(env: &Env, entity_id: u32, path: &Vec<Position>) {
+    let key = (symbol_short!("path"), entity_id);
+    env.storage().persistent().set(&key, path);
+}
+
+/// Removes an entity's path once it has arrived or been interrupted
+pub fn remove_entity_path(env: &Env, entity_id: u32) {
+    env.storage().persistent().remove(&(symbol_short!("path"), entity_id));
+}
+
+/// Retrieves the Regeneration component of an entity
+pub fn get_regeneration(env: &Env, entity_id: u32) -> Option<Regeneration> {
+    let key = (symbol_short!("regen"), entity_id);
+    env.storage().persistent().get(&key)
+}
+
+/// Stores the Regeneration component of an entity
+pub fn set_regeneration(env: &Env, entity_id: u32, regen: &Regeneration) {
+    let key = (symbol_short!("regen"), entity_id);
+    env.storage().persistent().set(&key, regen);
+}
+
+/// Retrieves the MaxHealth component of an entity
+pub fn get_max_health(env: &Env, entity_id: u32) -> Option<MaxHealth> {
+    let key = (symbol_short!("max_hp"), entity_id);
+    env.storage().persistent().get(&key)
+}
+
+/// Stores the MaxHealth component of an entity
+pub fn set_max_health(env: &Env, entity_id: u32, max_health: &MaxHealth) {
+    let key = (symbol_short!("max_hp"), entity_id);
+    env.storage().persistent().set(&key, max_health);
+}
+
+/// Retrieves the Energy component of an entity
+pub fn get_energy(env: &Env, entity_id: u32) -> Option<Energy> {
+    let key = (symbol_short!("energy"), entity_id);
+    env.storage().persistent().get(&key)
+}
+
+/// Stores the Energy component of an entity
+pub fn set_energy(env: &Env, entity_id: u32, energy: &Energy) {
+    let key = (symbol_short!("energy"), entity_id);
+    env.storage().persistent().set(&key, energy);
+}
+
+/// Retrieves the HealingPower component of an entity
+pub fn get_healing_power(env: &Env, entity_id: u32) -> Option<HealingPower> {
+    let key = (symbol_short!("heal_pwr"), entity_id);
+    env.storage().persistent().get(&key)
+}
+
+/// Stores the HealingPower component of an entity
+pub fn set_healing_power(env: &Env, entity_id: u32, power: &HealingPower) {
+    let key = (symbol_short!("heal_pwr"), entity_id);
+    env.storage().persistent().set(&key, power);
+}
+
+/// Retrieves the cast Shield component of an entity, even if it has faded
+pub fn get_shield(env: &Env, entity_id: u32) -> Option<Shield> {
+    let key = (symbol_short!("barrier"), entity_id);
+    env.storage().persistent().get(&key)
+}
+
+/// Stores the cast Shield component of an entity, or removes it with `None`
+pub fn set_shield(env: &Env, entity_id: u32, shield: Option<&Shield>) {
+    let key = (symbol_short!("barrier"), entity_id);
+    match shield {
+        Some(shield) => env.storage().persistent().set(&key, shield),
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+/// Retrieves the Loot component of a dropped loot entity
+pub fn get_loot(env: &Env, entity_id: u32) -> Option<Loot> {
+    let key = (symbol_short!("loot"), entity_id);
+    env.storage().persistent().get(&key)
+}
+
+/// Stores the Loot component of a dropped loot entity
+pub fn set_loot(env: &Env, entity_id: u32, loot: &Loot) {
+    let key = (symbol_short!("loot"), entity_id);
+    env.storage().persistent().set(&key, loot);
+}
+
+/// Retrieves the Inventory component of an entity
+pub fn get_inventory(env: &Env, entity_id: u32) -> Option<Inventory> {
+    let key = (symbol_short!("inventory"), entity_id);
+    env.storage().persistent().get(&key)
+}
+
+/// Stores the Inventory component of an entity
+pub fn set_inventory(env: &Env, entity_id: u32, inventory: &Inventory) {
+    let key = (symbol_short!("inventory"), entity_id);
+    env.storage().persistent().set(&key, inventory);
+}
+
+/// Retrieves the Interactable component of an entity
+pub fn get_interactable(env: &Env, entity_id: u32) -> Option<Interactable> {
+    let key = (symbol_short!("interact"), entity_id);
+    env.storage().persistent().get(&key)
+}
+
+/// Stores the Interactable component of an entity
+pub fn set_interactable(env: &Env, entity_id: u32, interactable: &Interactable) {
+    let key = (symbol_short!("interact"), entity_id);
+    env.storage().persistent().set(&key, interactable);
+}
+
+/// Retrieves the Boss component of an entity
+pub fn get_boss(env: &Env, entity_id: u32) -> Option<Boss> {
+    let key = (symbol_short!("boss"), entity_id);
+    env.storage().persistent().get(&key)
+}
+
+/// Stores the Boss component of an entity and lists the entity among the bosses
+pub fn set_boss(env: &Env, entity_id: u32, boss: &Boss) {
+    let key = (symbol_short!("boss"), entity_id);
+    env.storage().persistent().set(&key, boss);
+    set_boss_listed(env, entity_id, true);
+}
+
+/// Retrieves the IDs of every entity with a Boss component, in spawn order
+pub fn get_boss_ids(env: &Env) -> Vec<u32> {
+    env.storage().instance().get(&symbol_short!("bosses")).unwrap_or(Vec::new(env))
+}
+
+/// Adds an entity to or removes it from the boss list
+fn set_boss_listed(env: &Env, entity_id: u32, listed: bool) {
+    let mut bosses = get_boss_ids(env);
+    match (bosses.first_index_of(entity_id), listed) {
+        (None, true) => bosses.push_back(entity_id),
+        (Some(index), false) => {
+            bosses.remove(index);
+        }
+        _ => return,
+    }
+    env.storage().instance().set(&symbol_short!("bosses"), &bosses);
+}
+
+/// Retrieves the tiles blocked by closed doors, keyed by door entity
+pub fn get_blocked_tiles(env: &Env) -> Map<u32, Position> {
+    env.storage().instance().get(&symbol_short!("blocked")).unwrap_or(Map::new(env))
+}
+
+/// Marks the tile held by a door entity as blocked, or frees it with `None`
+pub fn set_tile_blocked(env: &Env, entity_id: u32, tile: Option<&Position>) {
+    let mut blocked = get_blocked_tiles(env);
+    match tile {
+        Some(tile) => blocked.set(entity_id, tile.clone()),
+        None => {
+            if blocked.remove(entity_id).is_none() {
+                return;
+            }
+        }
+    }
+    env.storage().instance().set(&symbol_short!("blocked"), &blocked);
+}
+
+/// Retrieves the wall tiles placed by the admin
+pub fn get_walls(env: &Env) -> Vec<Position> {
+    env.storage().instance().get(&symbol_short!("walls")).unwrap_or(Vec::new(env))
+}
+
+/// Places or removes a wall on a tile
+pub fn set_wall(env: &Env, tile: &Position, blocked: bool) {
+    let mut walls = get_walls(env);
+    match (walls.first_index_of(tile), blocked) {
+        (None, true) => walls.push_back(tile.clone()),
+        (Some(index), false) => {
+            walls.remove(index);
+        }
+        _ => return,
+    }
+    env.storage().instance().set(&symbol_short!("walls"), &walls);
+}
+
+/// Retrieves the Team component of an entity
+pub fn get_entity_team(env: &Env, entity_id: u32) -> Option<Team> {
+    let key = (symbol_short!("team"), entity_id);
+    env.storage().persistent().get(&key)
+}
+
+/// Moves an entity to a team, keeping the per-team member lists in sync
+///
+/// Passing `None` removes the entity from its current team.
+pub fn set_entity_team(env: &Env, entity_id: u32, team: Option<&Team>) {
+    let key = (symbol_short!("team"), entity_id);
+    if let Some(Team(old_team)) = get_entity_team(env, entity_id) {
+        let mut members = get_team_members(env, old_team);
+        if let Some(position) = members.first_index_of(entity_id) {
+            members.remove(position);
+            set_team_members(env, old_team, &members);
+        }
+    }
+    match team {
+        Some(team) => {
+            env.storage().persistent().set(&key, team);
+            let mut members = get_team_members(env, team.0);
+            members.push_back(entity_id);
+            set_team_members(env, team.0, &members);
+        }
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+/// Retrieves the IDs of all entities on a team
+pub fn get_team_members(env: &Env, team_id: u32) -> Vec<u32> {
+    let key = (symbol_short!("team_mem"), team_id);
+    env.storage().persistent().get(&key).unwrap_or_else(|| Vec::new(env))
+}
+
+/// Stores the member list of a team, removing it once empty
+fn set_team_members(env: &Env, team_id: u32, members: &Vec<u32>) {
+    let key = (symbol_short!("team_mem"), team_id);
+    if members.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, members);
+    }
+}
+
+/// Checks whether attacks between teammates are allowed
+pub fn is_friendly_fire_enabled(env: &Env) -> bool {
+    env.storage().instance().get(&symbol_short!("ff")).unwrap_or(false)
+}
+
+/// Enables or disables attacks between teammates
+pub fn set_friendly_fire_enabled(env: &Env, enabled: bool) {
+    env.storage().instance().set(&symbol_short!("ff"), &enabled);
+}
+
+/// Retrieves the world weather, which starts out clear with no rotation
+pub fn get_world_weather(env: &Env) -> WorldWeather {
+    env.storage().instance().get(&symbol_short!("weather")).unwrap_or(WorldWeather {
+        weather: Weather::Clear,
+        rotation_period: 0,
+        changed_at: 0,
+    })
+}
+
+/// Stores the world weather
+pub fn set_world_weather(env: &Env, weather: &WorldWeather) {
+    env.storage().instance().set(&symbol_short!("weather"), weather);
+}
+
+/// Retrieves the world events waiting to run, by event ID
+pub fn get_world_events(env: &Env) -> Map<u32, WorldEvent> {
+    env.storage().instance().get(&symbol_short!("wevents")).unwrap_or(Map::new(env))
+}
+
+/// Stores the world events waiting to run
+pub fn set_world_events(env: &Env, events: &Map<u32, WorldEvent>) {
+    env.storage().instance().set(&symbol_short!("wevents"), events);
+}
+
+/// Allocates the ID of the next scheduled world event, starting from 1
+pub fn next_world_event_id(env: &Env) -> u32 {
+    let key = symbol_short!("wevent_id");
+    let event_id = env.storage().instance().get(&key).unwrap_or(0) + 1;
+    env.storage().instance().set(&key, &event_id);
+    event_id
+}
+
+/// Retrieves the last XP multiplier window, which may have ended
+pub fn get_xp_window(env: &Env) -> Option<XpWindow> {
+    env.storage().instance().get(&symbol_short!("xp_win"))
+}
+
+/// Stores the XP multiplier window
+pub fn set_xp_window(env: &Env, window: &XpWindow) {
+    env.storage().instance().set(&symbol_short!("xp_win"), window);
+}
+
+/// Retrieves all status effect components of an entity
+///
+/// Expired effects are returned as stored; callers filter them with
+/// `EffectSystem` against the current ledger time.
+pub fn get_status_effects(env: &Env, entity_id: u32) -> StatusEffects {
+    let storage = env.storage().persistent();
+    StatusEffects {
+        poisoned: storage.get::<_, Poisoned>(&(symbol_short!("poison"), entity_id)),
+        shielded: storage.get::<_, Shielded>(&(symbol_short!("shield"), entity_id)),
+        speed_boost: storage.get::<_, SpeedBoost>(&(symbol_short!("boost"), entity_id)),
+    }
+}
+
+/// Stores the status effect components of an entity
+///
+/// Effects set to `None` are removed from storage.
+pub fn set_status_effects(env: &Env, entity_id: u32, effects: &StatusEffects) {
+    let storage = env.storage().persistent();
+    let poison_key = (symbol_short!("poison"), entity_id);
+    match &effects.poisoned {
+        Some(poison) => storage.set(&poison_key, poison),
+        None => storage.remove(&poison_key),
+    }
+    let shield_key = (symbol_short!("shield"), entity_id);
+    match &effects.shielded {
+        Some(shield) => storage.set(&shield_key, shield),
+        None => storage.remove(&shield_key),
+    }
+    let boost_key = (symbol_short!("boost"), entity_id);
+    match &effects.speed_boost {
+        Some(boost) => storage.set(&boost_key, boost),
+        None => storage.remove(&boost_key),
+    }
+}
+
+/// Removes every auxiliary component stored for an entity
+///
+/// Called when an entity dies or is despawned so that a later spawn reusing
+/// the same ID starts without stale components.
+pub fn remove_entity_components(env: &Env, entity_id: u32) {
+    env.storage().persistent().remove(&(symbol_short!("owner"), entity_id));
+    env.storage().persistent().remove(&(symbol_short!("speed"), entity_id));
+    env.storage().persistent().remove(&(symbol_short!("station"), entity_id));
+    env.storage().persistent().remove(&(symbol_short!("proj"), entity_id));
+    env.storage().persistent().remove(&(symbol_short!("regen"), entity_id));
+    env.storage().persistent().remove(&(symbol_short!("max_hp"), entity_id));
+    env.storage().persistent().remove(&(symbol_short!("loot"), entity_id));
+    env.storage().persistent().remove(&(symbol_short!("inventory"), entity_id));
+    env.storage().persistent().remove(&(symbol_short!("interact"), entity_id));
+    env.storage().persistent().remove(&(symbol_short!("boss"), entity_id));
+    env.storage().persistent().remove(&(symbol_short!("energy"), entity_id));
+    env.storage().persistent().remove(&(symbol_short!("heal_pwr"), entity_id));
+    env.storage().persistent().remove(&(symbol_short!("redeemed"), entity_id));
+    set_shield(env, entity_id, None);
+    set_tile_blocked(env, entity_id, None);
+    set_boss_listed(env, entity_id, false);
+    remove_entity_path(env, entity_id);
+    remove_entity_parent(env, entity_id);
+    remove_entity_rider(env, entity_id);
+    set_entity_team(env, entity_id, None);
+    set_status_effects(env, entity_id, &StatusEffects::default());
+}
+
+/// Layout version written into new checkpoints
+pub const CHECKPOINT_VERSION: u32 = 1;
+
+/// Saved state of a single entity inside a checkpoint
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EntitySnapshot {
+    /// The entity's `(id, x, y, health)` tuple
+    pub entity_data: (u32, u32, u32, u32),
+    /// Stored components keyed by their storage symbol
+    pub components: Map<Symbol, Val>,
+}
+
+/// A named snapshot of every entity and the world counters
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Checkpoint {
+    /// Layout version, see `CHECKPOINT_VERSION`
+    pub version: u32,
+    /// Ledger sequence at which the snapshot was taken
+    pub ledger: u32,
+    pub entity_count: u32,
+    pub dead_entity_count: u32,
+    pub next_entity_id: u32,
+    pub entities: Map<u32, EntitySnapshot>,
+}
+
+/// Storage symbols of the per-entity components captured by checkpoints
+///
+/// Team membership is captured through the `team` component; the member
+/// lists are rebuilt on restore.
+fn checkpoint_component_keys() -> [Symbol; 20] {
+    [
+        symbol_short!("owner"),
+        symbol_short!("speed"),
+        symbol_short!("parent"),
+        symbol_short!("rider"),
+        symbol_short!("station"),
+        symbol_short!("poison"),
+        symbol_short!("shield"),
+        symbol_short!("boost"),
+        symbol_short!("team"),
+        symbol_short!("proj"),
+        symbol_short!("regen"),
+        symbol_short!("max_hp"),
+        symbol_short!("loot"),
+        symbol_short!("inventory"),
+        symbol_short!("path"),
+        symbol_short!("interact"),
+        symbol_short!("boss"),
+        symbol_short!("energy"),
+        symbol_short!("heal_pwr"),
+        symbol_short!("barrier"),
+    ]
+}
+
+/// Retrieves a named checkpoint
+pub fn get_checkpoint(env: &Env, name: &Symbol) -> Option<Checkpoint> {
+    let key = (symbol_short!("ckpt"), name.clone());
+    env.storage().persistent().get(&key)
+}
+
+/// Stores a named checkpoint, replacing any earlier one with the same name
+pub fn set_checkpoint(env: &Env, name: &Symbol, checkpoint: &Checkpoint) {
+    let key = (symbol_short!("ckpt"), name.clone());
+    env.storage().persistent().set(&key, checkpoint);
+    env.storage().persistent().extend_ttl(&key, TTL_EXTEND_TO, TTL_EXTEND_TO);
+}
+
+/// Captures every live entity with its components and the world counters
+pub fn snapshot_world(env: &Env) -> Checkpoint {
+    let mut entities = Map::new(env);
+    for entity_id in get_all_entity_ids(env).iter() {
+        if let Some(snapshot) = snapshot_entity(env, entity_id) {
+            entities.set(entity_id, snapshot);
+        }
+    }
+
+    Checkpoint {
+        version: CHECKPOINT_VERSION,
+        ledger: env.ledger().sequence(),
+        entity_count: get_entity_count(env),
+        dead_entity_count: get_dead_entity_count(env),
+        next_entity_id: get_next_entity_id(env),
+        entities,
+    }
+}
+
+/// Replaces the world with the contents of a checkpoint
+///
+/// Every current entity is removed with its components before the snapshot
+/// is written back, closed doors block their tiles again and bosses rejoin
+/// the boss list. Entities the checkpoint doesn't hold that were redeemed
+/// from an item since it was taken are kept as they are, since their item
+/// is already burned. The ID counter never moves backwards, so IDs handed
+/// out after the checkpoint are not issued again. Entities tokenized since
+/// the checkpoint stay in their items and only come back through redeeming.
+pub fn restore_world(env: &Env, checkpoint: &Checkpoint) {
+    let mut live = 0;
+    for entity_id in get_all_entity_ids(env).iter() {
+        if !checkpoint.entities.contains_key(entity_id)
+            && get_entity_redeemed(env, entity_id).is_some_and(|ledger| ledger >= checkpoint.ledger)
+        {
+            live += 1;
+            continue;
+        }
+        remove_entity_components(env, entity_id);
+        delete_entity(env, entity_id);
+    }
+
+    for (entity_id, snapshot) in checkpoint.entities.iter() {
+        if is_entity_tokenized(env, entity_id) {
+            continue;
+        }
+        write_entity(env, entity_id, snapshot.entity_data.into_val(env));
+        restore_entity_components(env, entity_id, &snapshot);
+        live += 1;
+    }
+
+    set_entity_count(env, live);
+    set_dead_entity_count(env, checkpoint.dead_entity_count);
+    set_next_entity_id(env, get_next_entity_id(env).max(checkpoint.next_entity_id));
+}
+
+/// Captures one live entity with its components
+pub fn snapshot_entity(env: &Env, entity_id: u32) -> Option<EntitySnapshot> {
+    let entity_data = get_entity_data(env, entity_id)
+        .and_then(|data| <(u32, u32, u32, u32)>::try_from_val(env, &data).ok())?;
+    let mut components = Map::new(env);
+    for component in checkpoint_component_keys() {
+        let key = (component.clone(), entity_id);
+        if let Some(value) = env.storage().persistent().get::<_, Val>(&key) {
+            components.set(component, value);
+        }
+    }
+    Some(EntitySnapshot { entity_data, components })
+}
+
+/// Writes the components of a snapshot back for an entity whose data is already stored
+///
+/// Closed doors block their tile again, bosses rejoin the boss list and
+/// team members rejoin their team.
+pub fn restore_entity_components(env: &Env, entity_id: u32, snapshot: &EntitySnapshot) {
+    for (component, value) in snapshot.components.iter() {
+        if component == symbol_short!("team") {
+            if let Ok(team) = Team::try_from_val(env, &value) {
+                set_entity_team(env, entity_id, Some(&team));
+            }
+        } else {
+            if component == symbol_short!("interact")
+                && Interactable::try_from_val(env, &value).is_ok_and(|door| InteractionSystem::blocks_tile(&door))
+            {
+                let (_, x, y, _) = snapshot.entity_data;
+                set_tile_blocked(env, entity_id, Some(&Position(x, y)));
+            }
+            if component == symbol_short!("boss") {
+                set_boss_listed(env, entity_id, true);
+            }
+            env.storage().persistent().set(&(component, entity_id), &value);
+        }
+    }
+    clear_entity_dead(env, entity_id);
+}
@@ -7,6 +7,15 @@
 //!
 //! - `MovementSystem`: Updates entity positions based on movement deltas
 //! - `CombatSystem`: Modifies entity health based on combat actions
+//! - `EffectSystem`: Applies and expires timed status effects
+//! - `ProjectileSystem`: Moves projectiles and counts down their lifetime
+//! - `RegenSystem`: Restores health over ledger time up to a cap
+//! - `LootSystem`: Rolls loot drops and moves loot into inventories
+//! - `WeatherSystem`: Looks up and rotates world weather modifiers
+//! - `InteractionSystem`: Resolves doors, switches and chests
+//! - `WorldEventSystem`: Validates scheduled world events and their effects
+//! - `BossSystem`: Tracks boss phases, targeting and reward shares
+//! - `SupportSystem`: Resolves ally heals and the shields that absorb damage
 //!
 //! # Usage Example
 //!
@@ -31,7 +40,12 @@
 //! - Systems use saturating arithmetic to prevent overflow/underflow
 //! - Systems are stateless and operate purely on component data
 
-use crate::components::{Position, Health};
+use soroban_sdk::{Env, Vec};
+
+use crate::components::{
+    Boss, BossBehavior, BossPhase, Energy, Position, Health, Interactable, InteractableKind, Inventory, Loot, MaxHealth, Poisoned, Projectile, Regeneration,
+    Shield, Shielded, Speed, SpeedBoost, StatusEffects, Team, Weather, WeatherModifiers, WorldEvent, WorldEventKind, WorldWeather, XpWindow,
+};
 
 /// Movement system for updating entity positions
 ///
@@ -148,6 +162,45 @@ impl MovementSystem {
         };
         dx.saturating_add(dy)
     }
+
+    /// Checks whether two positions are on the same or neighbouring tiles
+    ///
+    /// Neighbouring includes diagonals, so every position has eight
+    /// adjacent tiles.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// assert!(MovementSystem::is_adjacent(&Position(5, 5), &Position(6, 6)));
+    /// assert!(!MovementSystem::is_adjacent(&Position(5, 5), &Position(7, 5)));
+    /// ```
+    pub fn is_adjacent(pos1: &Position, pos2: &Position) -> bool {
+        pos1.0.abs_diff(pos2.0) <= 1 && pos1.1.abs_diff(pos2.1) <= 1
+    }
+
+    /// Scales movement deltas by a speed multiplier
+    ///
+    /// # Arguments
+    ///
+    /// * `speed` - The speed component of the moving entity
+    /// * `dx` - The requested change in x-coordinate
+    /// * `dy` - The requested change in y-coordinate
+    ///
+    /// # Returns
+    ///
+    /// The scaled `(dx, dy)` pair. Uses saturating multiplication so extreme
+    /// speeds cannot overflow.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let (dx, dy) = MovementSystem::apply_speed(&Speed(3), 1, -2);
+    /// assert_eq!((dx, dy), (3, -6));
+    /// ```
+    pub fn apply_speed(speed: &Speed, dx: i32, dy: i32) -> (i32, i32) {
+        let factor = speed.0.min(i32::MAX as u32) as i32;
+        (dx.saturating_mul(factor), dy.saturating_mul(factor))
+    }
 }
 
 /// Combat system for managing entity health and damage
@@ -172,6 +225,16 @@ impl MovementSystem {
 pub struct CombatSystem;
 
 impl CombatSystem {
+    /// Damage dealt by the standard attack action
+    pub const BASE_ATTACK_DAMAGE: u32 = 10;
+    /// Furthest distance in tiles a ranged attack reaches, measured 8-way
+    pub const RANGED_ATTACK_RANGE: u32 = 6;
+
+    /// Checks whether `target` lies within ranged attack range of `attacker`
+    pub fn in_range(attacker: &Position, target: &Position) -> bool {
+        attacker.0.abs_diff(target.0).max(attacker.1.abs_diff(target.1)) <= Self::RANGED_ATTACK_RANGE
+    }
+
     /// Applies damage to an entity's health
     ///
     /// # Arguments
@@ -224,7 +287,14 @@ impl CombatSystem {
     /// assert_eq!(new_health.0, 90);
     /// ```
     pub fn attack(health: &Health) -> Health {
-        Self::apply_damage(health, 10)
+        Self::apply_damage(health, Self::BASE_ATTACK_DAMAGE)
+    }
+
+    /// Checks whether an attack would hit an ally
+    ///
+    /// Entities without a team have no allies.
+    pub fn is_friendly_fire(attacker: Option<&Team>, target: Option<&Team>) -> bool {
+        matches!((attacker, target), (Some(a), Some(t)) if a == t)
     }
 
     /// Heals an entity's health
@@ -349,6 +419,644 @@ impl CombatSystem {
     }
 }
 
+/// Effect system for timed status effects
+///
+/// Status effects expire based on ledger timestamps rather than block
+/// counts, so every function takes the current time explicitly and stays
+/// deterministic and storage-free.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use game::components::{Health, Poisoned};
+/// use game::systems::EffectSystem;
+///
+/// let poison = Poisoned { damage: 3, last_tick: 0, expires_at: 100 };
+/// let (health, poison) = EffectSystem::tick_poison(&poison, &Health(50), 10);
+/// assert_eq!(health, Health(44)); // two 5-second intervals elapsed
+/// assert_eq!(poison.last_tick, 10);
+/// ```
+pub struct EffectSystem;
+
+impl EffectSystem {
+    /// Seconds of ledger time between two poison damage applications
+    pub const POISON_INTERVAL_SECS: u64 = 5;
+
+    /// Largest poison damage, shield reduction or speed bonus an effect may have
+    pub const MAX_MAGNITUDE: u32 = 50;
+
+    /// Longest an effect may last, in ledger seconds
+    pub const MAX_DURATION_SECS: u64 = 60 * 60;
+
+    /// Checks whether an effect expiring at `expires_at` is still active at `now`
+    pub fn is_active(expires_at: u64, now: u64) -> bool {
+        now < expires_at
+    }
+
+    /// Applies the poison damage accrued since the last tick
+    ///
+    /// Only whole intervals are applied; the remainder carries over to the
+    /// next tick through the returned `last_tick`. Damage stops accruing at
+    /// `expires_at`.
+    pub fn tick_poison(poison: &Poisoned, health: &Health, now: u64) -> (Health, Poisoned) {
+        let until = now.min(poison.expires_at);
+        let intervals = until.saturating_sub(poison.last_tick) / Self::POISON_INTERVAL_SECS;
+        let damage = poison.damage.saturating_mul(intervals.min(u32::MAX as u64) as u32);
+
+        let updated = Poisoned {
+            damage: poison.damage,
+            last_tick: poison.last_tick + intervals * Self::POISON_INTERVAL_SECS,
+            expires_at: poison.expires_at,
+        };
+        (CombatSystem::apply_damage(health, damage), updated)
+    }
+
+    /// Reduces incoming damage by an active shield
+    pub fn reduce_damage(shield: Option<&Shielded>, damage: u32, now: u64) -> u32 {
+        match shield {
+            Some(shield) if Self::is_active(shield.expires_at, now) => damage.saturating_sub(shield.reduction),
+            _ => damage,
+        }
+    }
+
+    /// Adds an active speed boost to an entity's base speed
+    pub fn boosted_speed(boost: Option<&SpeedBoost>, speed: &Speed, now: u64) -> Speed {
+        match boost {
+            Some(boost) if Self::is_active(boost.expires_at, now) => Speed(speed.0.saturating_add(boost.bonus)),
+            _ => speed.clone(),
+        }
+    }
+
+    /// Advances all status effects on an entity to `now`
+    ///
+    /// Applies pending poison damage and drops every effect that has
+    /// expired.
+    ///
+    /// # Returns
+    ///
+    /// The entity's new health and the effects that remain active
+    pub fn tick(effects: &StatusEffects, health: &Health, now: u64) -> (Health, StatusEffects) {
+        let mut health = health.clone();
+        let poisoned = match &effects.poisoned {
+            Some(poison) => {
+                let (new_health, poison) = Self::tick_poison(poison, &health, now);
+                health = new_health;
+                Some(poison).filter(|p| Self::is_active(p.expires_at, now))
+            }
+            None => None,
+        };
+
+        let remaining = StatusEffects {
+            poisoned,
+            shielded: effects.shielded.clone().filter(|s| Self::is_active(s.expires_at, now)),
+            speed_boost: effects.speed_boost.clone().filter(|b| Self::is_active(b.expires_at, now)),
+        };
+        (health, remaining)
+    }
+}
+
+/// Projectile system for entities spawned with a Projectile component
+///
+/// Projectiles move in discrete steps, so they only hit entities on the
+/// tile they land on, not those they pass over.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use game::components::{Position, Projectile};
+/// use game::systems::ProjectileSystem;
+///
+/// let arrow = Projectile { dx: 2, dy: 0, damage: 15, ttl: 3 };
+/// let (position, arrow) = ProjectileSystem::advance(&Position(0, 0), &arrow);
+/// assert_eq!(position, Position(2, 0));
+/// assert_eq!(arrow.ttl, 2);
+/// ```
+pub struct ProjectileSystem;
+
+impl ProjectileSystem {
+    /// Moves a projectile one step and consumes one tick of its lifetime
+    pub fn advance(position: &Position, projectile: &Projectile) -> (Position, Projectile) {
+        let new_position = MovementSystem::update_position(position, projectile.dx, projectile.dy);
+        let updated = Projectile {
+            ttl: projectile.ttl.saturating_sub(1),
+            ..projectile.clone()
+        };
+        (new_position, updated)
+    }
+
+    /// Checks whether a projectile at `position` hits an entity at `target`
+    pub fn hits(position: &Position, target: &Position) -> bool {
+        position == target
+    }
+
+    /// Checks whether a projectile has run out of lifetime
+    pub fn is_expired(projectile: &Projectile) -> bool {
+        projectile.ttl == 0
+    }
+}
+
+/// Regeneration system for entities with a Regeneration component
+///
+/// Regeneration is applied lazily: nothing happens until the entity is read
+/// or ticked, at which point all whole minutes since `last_update` are
+/// credited at once.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use game::components::{Health, MaxHealth, Regeneration};
+/// use game::systems::RegenSystem;
+///
+/// let regen = Regeneration { rate_per_minute: 5, last_update: 0 };
+/// let (health, regen) = RegenSystem::regenerate(&Health(50), &regen, &MaxHealth(100), 150);
+/// assert_eq!(health, Health(60)); // two full minutes elapsed
+/// assert_eq!(regen.last_update, 120);
+/// ```
+pub struct RegenSystem;
+
+impl RegenSystem {
+    /// Seconds of ledger time per regeneration step
+    pub const SECS_PER_MINUTE: u64 = 60;
+
+    /// Credits the health regenerated since the last update
+    ///
+    /// The partial minute carries over through the returned `last_update`.
+    /// An entity already at or above `max_health` does not bank regeneration
+    /// for later.
+    pub fn regenerate(health: &Health, regen: &Regeneration, max_health: &MaxHealth, now: u64) -> (Health, Regeneration) {
+        if health.0 >= max_health.0 {
+            let updated = Regeneration { last_update: now.max(regen.last_update), ..regen.clone() };
+            return (health.clone(), updated);
+        }
+
+        let minutes = now.saturating_sub(regen.last_update) / Self::SECS_PER_MINUTE;
+        let healed = regen.rate_per_minute.saturating_mul(minutes.min(u32::MAX as u64) as u32);
+        let new_health = CombatSystem::heal(health, healed);
+        let updated = Regeneration {
+            rate_per_minute: regen.rate_per_minute,
+            last_update: regen.last_update + minutes * Self::SECS_PER_MINUTE,
+        };
+        (Health(new_health.0.min(max_health.0)), updated)
+    }
+}
+
+/// Loot system for drops left behind by dead entities
+///
+/// Rolls are derived from a caller-supplied seed, so the system itself stays
+/// deterministic; the contract seeds it from the ledger PRNG.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use game::systems::LootSystem;
+///
+/// let loot = LootSystem::roll(0x0102_0304);
+/// assert!(loot.currency >= 1 && loot.currency <= LootSystem::MAX_CURRENCY);
+/// ```
+pub struct LootSystem;
+
+impl LootSystem {
+    /// Number of distinct items that can drop
+    pub const LOOT_TABLE_SIZE: u32 = 8;
+    /// Largest currency amount in a single drop
+    pub const MAX_CURRENCY: u32 = 50;
+    /// Largest item stack in a single drop
+    pub const MAX_QUANTITY: u32 = 3;
+
+    /// Derives a drop from a random seed
+    ///
+    /// Every drop contains at least one currency and one item.
+    pub fn roll(seed: u64) -> Loot {
+        Loot {
+            currency: 1 + (seed % Self::MAX_CURRENCY as u64) as u32,
+            item_id: ((seed >> 16) % Self::LOOT_TABLE_SIZE as u64) as u32,
+            quantity: 1 + ((seed >> 32) % Self::MAX_QUANTITY as u64) as u32,
+        }
+    }
+
+    /// Adds the contents of a drop to an inventory
+    pub fn collect(inventory: &Inventory, loot: &Loot) -> Inventory {
+        let mut updated = inventory.clone();
+        updated.currency = updated.currency.saturating_add(loot.currency);
+        let held = updated.items.get(loot.item_id).unwrap_or(0);
+        updated.items.set(loot.item_id, held.saturating_add(loot.quantity));
+        updated
+    }
+}
+
+/// Weather system for world-level modifiers
+///
+/// Each weather maps to a fixed table of percentage modifiers that the
+/// movement, combat and AI logic look up. Rotation walks the weathers in
+/// declaration order, so every node agrees on the weather at any time.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use game::components::Weather;
+/// use game::systems::WeatherSystem;
+///
+/// assert_eq!(WeatherSystem::scale_movement(Weather::Storm, 4, -2), (2, -1));
+/// assert_eq!(WeatherSystem::aggro_radius(Weather::Night, 3), 6);
+/// ```
+pub struct WeatherSystem;
+
+impl WeatherSystem {
+    /// Returns the modifier table of a weather
+    pub fn modifiers(weather: Weather) -> WeatherModifiers {
+        let (movement_percent, damage_percent, aggro_percent) = match weather {
+            Weather::Clear => (100, 100, 100),
+            Weather::Storm => (50, 100, 100),
+            Weather::Night => (100, 100, 200),
+            Weather::Fog => (100, 75, 50),
+        };
+        WeatherModifiers { movement_percent, damage_percent, aggro_percent }
+    }
+
+    /// Scales movement deltas by the weather's movement modifier
+    ///
+    /// Magnitudes round up, so a slowed entity can still move one tile.
+    pub fn scale_movement(weather: Weather, dx: i32, dy: i32) -> (i32, i32) {
+        let percent = Self::modifiers(weather).movement_percent as u64;
+        let scale = |delta: i32| {
+            let magnitude = (delta.unsigned_abs() as u64 * percent).div_ceil(100).min(i32::MAX as u64) as i32;
+            if delta < 0 { -magnitude } else { magnitude }
+        };
+        (scale(dx), scale(dy))
+    }
+
+    /// Scales attack damage by the weather's damage modifier, rounding down
+    pub fn scale_damage(weather: Weather, damage: u32) -> u32 {
+        Self::apply_percent(damage, Self::modifiers(weather).damage_percent)
+    }
+
+    /// Scales an NPC aggro radius by the weather's aggro modifier, rounding down
+    pub fn aggro_radius(weather: Weather, base_radius: u32) -> u32 {
+        Self::apply_percent(base_radius, Self::modifiers(weather).aggro_percent)
+    }
+
+    /// Returns the weather that follows `weather` in the rotation
+    pub fn next(weather: Weather) -> Weather {
+        match weather {
+            Weather::Clear => Weather::Storm,
+            Weather::Storm => Weather::Night,
+            Weather::Night => Weather::Fog,
+            Weather::Fog => Weather::Clear,
+        }
+    }
+
+    /// Advances the weather by every rotation period elapsed up to `now`
+    pub fn rotate(world: &WorldWeather, now: u64) -> WorldWeather {
+        if world.rotation_period == 0 || now < world.changed_at {
+            return world.clone();
+        }
+        let periods = (now - world.changed_at) / world.rotation_period;
+        let mut weather = world.weather;
+        for _ in 0..periods % 4 {
+            weather = Self::next(weather);
+        }
+        WorldWeather {
+            weather,
+            rotation_period: world.rotation_period,
+            changed_at: world.changed_at + periods * world.rotation_period,
+        }
+    }
+
+    fn apply_percent(value: u32, percent: u32) -> u32 {
+        (value as u64 * percent as u64 / 100).min(u32::MAX as u64) as u32
+    }
+}
+
+/// Interaction system for doors, switches and chests
+///
+/// Decides whether an entity may use an interactable and which state it is
+/// left in. Only closed doors block their tile.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use game::components::{Interactable, InteractableKind};
+/// use game::systems::InteractionSystem;
+///
+/// let door = Interactable { kind: InteractableKind::Door, active: false, key_item: None, linked: None, contents: vec![&env] };
+/// assert!(InteractionSystem::blocks_tile(&door));
+/// assert!(!InteractionSystem::blocks_tile(&InteractionSystem::toggle(&door)));
+/// ```
+pub struct InteractionSystem;
+
+impl InteractionSystem {
+    /// Returns true if `inventory` holds the key item the interactable requires
+    pub fn has_key(interactable: &Interactable, inventory: Option<&Inventory>) -> bool {
+        match interactable.key_item {
+            None => true,
+            Some(item_id) => inventory.is_some_and(|inventory| inventory.items.get(item_id).unwrap_or(0) > 0),
+        }
+    }
+
+    /// Opens or closes a door or chest, or flips a switch
+    pub fn toggle(interactable: &Interactable) -> Interactable {
+        Interactable {
+            active: !interactable.active,
+            ..interactable.clone()
+        }
+    }
+
+    /// Takes the contents out of an open chest, leaving it empty
+    pub fn take_contents(env: &Env, interactable: &mut Interactable) -> Vec<Loot> {
+        if interactable.kind == InteractableKind::Chest && interactable.active {
+            core::mem::replace(&mut interactable.contents, Vec::new(env))
+        } else {
+            Vec::new(env)
+        }
+    }
+
+    /// Returns true if the interactable keeps entities off its tile
+    pub fn blocks_tile(interactable: &Interactable) -> bool {
+        interactable.kind == InteractableKind::Door && !interactable.active
+    }
+}
+
+/// World event system for admin-scripted events
+///
+/// Checks scheduled events against the parameters of their kind, decides
+/// when they are due and describes what they leave in the world. Placing
+/// meteors is left to the contract, which owns the PRNG.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use game::components::{WorldEvent, WorldEventKind, XpWindow};
+/// use game::systems::WorldEventSystem;
+///
+/// let window = XpWindow { percent: 200, ends_seq: 150 };
+/// assert_eq!(WorldEventSystem::xp_percent(Some(&window), 149), 200);
+/// assert_eq!(WorldEventSystem::xp_percent(Some(&window), 150), 100);
+/// ```
+pub struct WorldEventSystem;
+
+impl WorldEventSystem {
+    /// Most meteors a single meteor shower drops
+    pub const MAX_METEORS: u32 = 10;
+
+    /// Ticks a meteor stays on its tile before burning out
+    pub const METEOR_TTL_TICKS: u32 = 3;
+
+    /// Returns true if the event's parameters fit its kind
+    pub fn is_valid(event: &WorldEvent) -> bool {
+        let param = |index: u32| event.params.get(index).unwrap_or(0);
+        match event.kind {
+            WorldEventKind::MeteorShower => {
+                event.params.len() == 6
+                    && param(2) > 0
+                    && param(3) > 0
+                    && (1..=Self::MAX_METEORS).contains(&param(4))
+                    && param(5) > 0
+            }
+            WorldEventKind::DoubleXp => event.params.len() == 2 && param(0) > 0 && param(1) >= 100,
+            WorldEventKind::BossSpawn => event.params.len() == 3 && param(2) > 0,
+        }
+    }
+
+    /// Returns true once the ledger has reached the event's start
+    pub fn is_due(event: &WorldEvent, sequence: u32) -> bool {
+        sequence >= event.start_seq
+    }
+
+    /// Returns the XP percentage in effect at `sequence`, 100 outside a window
+    pub fn xp_percent(window: Option<&XpWindow>, sequence: u32) -> u32 {
+        match window {
+            Some(window) if sequence < window.ends_seq => window.percent,
+            _ => 100,
+        }
+    }
+
+    /// Returns the stationary hazard a meteor leaves on its tile
+    pub fn meteor(damage: u32) -> Projectile {
+        Projectile { dx: 0, dy: 0, damage, ttl: Self::METEOR_TTL_TICKS }
+    }
+}
+
+/// Boss system for phase-based boss fights
+///
+/// A boss moves through its phases as its health drops and never returns
+/// to an earlier one. Damage is attributed to the attacking entities so
+/// the reward can be split among them in proportion when the boss dies.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use game::components::{Boss, BossBehavior};
+/// use game::systems::BossSystem;
+///
+/// let boss = Boss { max_health: 1000, phases: BossSystem::default_phases(&env, 20), phase: 0, reward_currency: 100, damage_by: Map::new(&env) };
+/// assert_eq!(BossSystem::phase_for(&boss, 500), 1);
+/// assert_eq!(BossSystem::phase_for(&boss, 200), 2);
+/// ```
+pub struct BossSystem;
+
+impl BossSystem {
+    /// Most phases a boss can have
+    pub const MAX_PHASES: u32 = 5;
+
+    /// Most entities whose damage is attributed to a single boss
+    ///
+    /// Damage from further attackers still hurts the boss but earns no share.
+    pub const MAX_CONTRIBUTORS: u32 = 10;
+
+    /// Radius within which an enraged boss strikes, before weather scaling
+    pub const AGGRO_RADIUS: u32 = 3;
+
+    /// Most targets an enraged boss strikes in one tick, lowest IDs first
+    ///
+    /// Keeps a crowd around the boss from pushing the tick over the
+    /// transaction footprint limit.
+    pub const MAX_ENRAGED_TARGETS: u32 = 3;
+
+    /// Returns true if a freshly spawned boss is well formed
+    ///
+    /// The first phase must start at 100%, thresholds must strictly
+    /// descend and every phase must deal damage.
+    pub fn is_valid(boss: &Boss) -> bool {
+        if boss.max_health == 0
+            || boss.phase != 0
+            || !boss.damage_by.is_empty()
+            || boss.phases.is_empty()
+            || boss.phases.len() > Self::MAX_PHASES
+        {
+            return false;
+        }
+        let mut previous = 101;
+        for phase in boss.phases.iter() {
+            if phase.threshold_percent >= previous || phase.damage == 0 {
+                return false;
+            }
+            previous = phase.threshold_percent;
+        }
+        boss.phases.get(0).is_some_and(|first| first.threshold_percent == 100)
+    }
+
+    /// Returns the phases used by bosses spawned by world events
+    ///
+    /// The boss fights in melee, turns to ranged attacks at half health and
+    /// enrages below a fifth of its health, hitting harder each time.
+    pub fn default_phases(env: &Env, damage: u32) -> Vec<BossPhase> {
+        Vec::from_array(
+            env,
+            [
+                BossPhase { threshold_percent: 100, behavior: BossBehavior::Melee, damage },
+                BossPhase { threshold_percent: 50, behavior: BossBehavior::Ranged, damage: damage.saturating_mul(3) / 2 },
+                BossPhase { threshold_percent: 20, behavior: BossBehavior::Enraged, damage: damage.saturating_mul(2) },
+            ],
+        )
+    }
+
+    /// Returns the phase a boss is in at `health`, never earlier than its current phase
+    pub fn phase_for(boss: &Boss, health: u32) -> u32 {
+        let mut phase = boss.phase;
+        for (index, candidate) in boss.phases.iter().enumerate() {
+            let index = index as u32;
+            if index > phase
+                && health as u64 * 100 <= candidate.threshold_percent as u64 * boss.max_health as u64
+            {
+                phase = index;
+            }
+        }
+        phase
+    }
+
+    /// Returns the boss's current phase
+    pub fn current_phase(boss: &Boss) -> Option<BossPhase> {
+        boss.phases.get(boss.phase)
+    }
+
+    /// Attributes `damage` dealt by `attacker_id` to the boss
+    pub fn record_damage(boss: &Boss, attacker_id: u32, damage: u32) -> Boss {
+        let mut updated = boss.clone();
+        match updated.damage_by.get(attacker_id) {
+            Some(dealt) => updated.damage_by.set(attacker_id, dealt.saturating_add(damage)),
+            None if damage > 0 && updated.damage_by.len() < Self::MAX_CONTRIBUTORS => {
+                updated.damage_by.set(attacker_id, damage)
+            }
+            None => {}
+        }
+        updated
+    }
+
+    /// Returns true if a boss in `behavior` can strike a target at `target`
+    pub fn reaches(behavior: BossBehavior, boss: &Position, target: &Position, aggro_radius: u32) -> bool {
+        match behavior {
+            BossBehavior::Melee => MovementSystem::is_adjacent(boss, target),
+            BossBehavior::Ranged => CombatSystem::in_range(boss, target),
+            BossBehavior::Enraged => boss.0.abs_diff(target.0).max(boss.1.abs_diff(target.1)) <= aggro_radius,
+        }
+    }
+
+    /// Splits the boss's reward among its contributors in proportion to their damage
+    ///
+    /// Returns `(entity_id, damage, currency)` per contributor in ID order.
+    /// The remainder of the integer division goes to the top contributor,
+    /// so the shares always add up to the full reward.
+    pub fn split_rewards(env: &Env, boss: &Boss) -> Vec<(u32, u32, u32)> {
+        let mut shares = Vec::new(env);
+        let total: u64 = boss.damage_by.values().iter().map(|damage| damage as u64).sum();
+        if total == 0 {
+            return shares;
+        }
+        let mut paid = 0;
+        let mut top = 0;
+        for (entity_id, damage) in boss.damage_by.iter() {
+            let currency = (boss.reward_currency as u64 * damage as u64 / total) as u32;
+            paid += currency;
+            if shares.get(top).is_some_and(|(_, top_damage, _)| damage > top_damage) {
+                top = shares.len();
+            }
+            shares.push_back((entity_id, damage, currency));
+        }
+        if let Some((entity_id, damage, currency)) = shares.get(top) {
+            shares.set(top, (entity_id, damage, currency + (boss.reward_currency - paid)));
+        }
+        shares
+    }
+}
+
+/// Support system for heals and shields cast on allies
+///
+/// Support actions cost the caster energy and are limited by its
+/// HealingPower. A heal never lifts health above the target's MaxHealth,
+/// and a shield absorbs damage before Health until it is used up or fades.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use game::components::{Energy, Shield};
+/// use game::systems::SupportSystem;
+///
+/// let energy = SupportSystem::spend_energy(&Energy(25), SupportSystem::HEAL_ENERGY_COST);
+/// assert_eq!(energy, Some(Energy(15)));
+///
+/// let shield = Shield { amount: 8, expires_at: 100 };
+/// let (damage, shield) = SupportSystem::absorb(Some(&shield), 10, 50);
+/// assert_eq!((damage, shield), (2, None));
+/// ```
+pub struct SupportSystem;
+
+impl SupportSystem {
+    /// Furthest Manhattan distance a support action reaches
+    pub const SUPPORT_RANGE: u32 = 3;
+    /// Energy spent by one heal
+    pub const HEAL_ENERGY_COST: u32 = 10;
+    /// Energy spent by one cast shield
+    pub const SHIELD_ENERGY_COST: u32 = 15;
+    /// Seconds of ledger time a cast shield lasts
+    pub const SHIELD_DURATION_SECS: u64 = 300;
+
+    /// Checks whether a target is within support range of the caster
+    pub fn in_range(caster: &Position, target: &Position) -> bool {
+        MovementSystem::manhattan_distance(caster, target) <= Self::SUPPORT_RANGE
+    }
+
+    /// Checks whether two entities are on the same team
+    ///
+    /// Entities without a team have no allies.
+    pub fn are_allies(caster: Option<&Team>, target: Option<&Team>) -> bool {
+        matches!((caster, target), (Some(caster), Some(target)) if caster == target)
+    }
+
+    /// Deducts `cost` from an entity's energy, or `None` if it has too little
+    pub fn spend_energy(energy: &Energy, cost: u32) -> Option<Energy> {
+        energy.0.checked_sub(cost).map(Energy)
+    }
+
+    /// Restores `amount` health, up to `max_health` if the target has one
+    pub fn heal(health: &Health, amount: u32, max_health: Option<&MaxHealth>) -> Health {
+        let healed = CombatSystem::heal(health, amount);
+        match max_health {
+            Some(max_health) => Health(healed.0.min(max_health.0.max(health.0))),
+            None => healed,
+        }
+    }
+
+    /// Lets an active shield absorb as much of `damage` as it can
+    ///
+    /// # Returns
+    ///
+    /// The damage left for Health and the shield that remains, `None` once
+    /// it is used up or has faded
+    pub fn absorb(shield: Option<&Shield>, damage: u32, now: u64) -> (u32, Option<Shield>) {
+        match shield {
+            Some(shield) if EffectSystem::is_active(shield.expires_at, now) => {
+                let absorbed = damage.min(shield.amount);
+                let remaining = Shield {
+                    amount: shield.amount - absorbed,
+                    expires_at: shield.expires_at,
+                };
+                (damage - absorbed, Some(remaining).filter(|shield| shield.amount > 0))
+            }
+            _ => (damage, None),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -392,6 +1100,21 @@ mod tests {
         assert_eq!(MovementSystem::manhattan_distance(&pos3, &pos4), 0);
     }
 
+    #[test]
+    fn test_is_adjacent() {
+        assert!(MovementSystem::is_adjacent(&Position(5, 5), &Position(5, 5)));
+        assert!(MovementSystem::is_adjacent(&Position(5, 5), &Position(6, 4)));
+        assert!(!MovementSystem::is_adjacent(&Position(5, 5), &Position(7, 5)));
+        assert!(!MovementSystem::is_adjacent(&Position(0, 0), &Position(0, 2)));
+    }
+
+    #[test]
+    fn test_apply_speed() {
+        assert_eq!(MovementSystem::apply_speed(&Speed(3), 1, -2), (3, -6));
+        assert_eq!(MovementSystem::apply_speed(&Speed(1), 4, 5), (4, 5));
+        assert_eq!(MovementSystem::apply_speed(&Speed(u32::MAX), 2, 0), (i32::MAX, 0));
+    }
+
     // CombatSystem tests
     #[test]
     fn test_apply_damage() {
@@ -468,4 +1191,325 @@ mod tests {
         
         assert!(CombatSystem::is_alive(&health));
     }
+
+    #[test]
+    fn test_is_friendly_fire() {
+        assert!(CombatSystem::is_friendly_fire(Some(&Team(1)), Some(&Team(1))));
+        assert!(!CombatSystem::is_friendly_fire(Some(&Team(1)), Some(&Team(2))));
+        assert!(!CombatSystem::is_friendly_fire(Some(&Team(1)), None));
+        assert!(!CombatSystem::is_friendly_fire(None, None));
+    }
+
+    // EffectSystem tests
+    #[test]
+    fn test_poison_applies_whole_intervals() {
+        let poison = Poisoned { damage: 3, last_tick: 0, expires_at: 100 };
+        let (health, poison) = EffectSystem::tick_poison(&poison, &Health(50), 12);
+        assert_eq!(health, Health(44));
+        assert_eq!(poison.last_tick, 10);
+    }
+
+    #[test]
+    fn test_poison_stops_at_expiry() {
+        let poison = Poisoned { damage: 1, last_tick: 0, expires_at: 20 };
+        let (health, _) = EffectSystem::tick_poison(&poison, &Health(100), 1_000);
+        assert_eq!(health, Health(96));
+    }
+
+    #[test]
+    fn test_shield_reduces_damage_until_expiry() {
+        let shield = Shielded { reduction: 4, expires_at: 50 };
+        assert_eq!(EffectSystem::reduce_damage(Some(&shield), 10, 10), 6);
+        assert_eq!(EffectSystem::reduce_damage(Some(&shield), 3, 10), 0);
+        assert_eq!(EffectSystem::reduce_damage(Some(&shield), 10, 50), 10);
+        assert_eq!(EffectSystem::reduce_damage(None, 10, 10), 10);
+    }
+
+    #[test]
+    fn test_speed_boost() {
+        let boost = SpeedBoost { bonus: 2, expires_at: 30 };
+        assert_eq!(EffectSystem::boosted_speed(Some(&boost), &Speed(1), 0), Speed(3));
+        assert_eq!(EffectSystem::boosted_speed(Some(&boost), &Speed(1), 30), Speed(1));
+    }
+
+    // ProjectileSystem tests
+    #[test]
+    fn test_projectile_advance() {
+        let arrow = Projectile { dx: 3, dy: -1, damage: 5, ttl: 2 };
+        let (position, arrow) = ProjectileSystem::advance(&Position(1, 1), &arrow);
+        assert_eq!(position, Position(4, 0));
+        assert_eq!(arrow.ttl, 1);
+        assert!(!ProjectileSystem::is_expired(&arrow));
+
+        let (_, arrow) = ProjectileSystem::advance(&position, &arrow);
+        assert!(ProjectileSystem::is_expired(&arrow));
+    }
+
+    #[test]
+    fn test_projectile_hits() {
+        assert!(ProjectileSystem::hits(&Position(2, 3), &Position(2, 3)));
+        assert!(!ProjectileSystem::hits(&Position(2, 3), &Position(3, 3)));
+    }
+
+    // RegenSystem tests
+    #[test]
+    fn test_regenerate_whole_minutes() {
+        let regen = Regeneration { rate_per_minute: 4, last_update: 100 };
+        let (health, regen) = RegenSystem::regenerate(&Health(10), &regen, &MaxHealth(100), 250);
+        assert_eq!(health, Health(18));
+        assert_eq!(regen.last_update, 220);
+    }
+
+    #[test]
+    fn test_regenerate_capped_at_max_health() {
+        let regen = Regeneration { rate_per_minute: 50, last_update: 0 };
+        let (health, _) = RegenSystem::regenerate(&Health(90), &regen, &MaxHealth(100), 600);
+        assert_eq!(health, Health(100));
+
+        // No regeneration is banked while at full health
+        let (health, regen) = RegenSystem::regenerate(&Health(100), &regen, &MaxHealth(100), 600);
+        assert_eq!(health, Health(100));
+        assert_eq!(regen.last_update, 600);
+    }
+
+    // LootSystem tests
+    #[test]
+    fn test_loot_roll_is_deterministic_and_bounded() {
+        for seed in [0u64, 1, 49, 50, u64::MAX, 0x1234_5678_9abc_def0] {
+            let loot = LootSystem::roll(seed);
+            assert_eq!(loot, LootSystem::roll(seed));
+            assert!((1..=LootSystem::MAX_CURRENCY).contains(&loot.currency));
+            assert!(loot.item_id < LootSystem::LOOT_TABLE_SIZE);
+            assert!((1..=LootSystem::MAX_QUANTITY).contains(&loot.quantity));
+        }
+    }
+
+    #[test]
+    fn test_loot_collect() {
+        let env = soroban_sdk::Env::default();
+        let loot = Loot { currency: 10, item_id: 2, quantity: 3 };
+
+        let inventory = LootSystem::collect(&Inventory::new(&env), &loot);
+        let inventory = LootSystem::collect(&inventory, &loot);
+        assert_eq!(inventory.currency, 20);
+        assert_eq!(inventory.items.get(2), Some(6));
+    }
+
+    #[test]
+    fn test_effect_tick_drops_expired_effects() {
+        let effects = StatusEffects {
+            poisoned: Some(Poisoned { damage: 2, last_tick: 0, expires_at: 10 }),
+            shielded: Some(Shielded { reduction: 1, expires_at: 100 }),
+            speed_boost: Some(SpeedBoost { bonus: 1, expires_at: 5 }),
+        };
+        let (health, remaining) = EffectSystem::tick(&effects, &Health(20), 10);
+        assert_eq!(health, Health(16));
+        assert_eq!(remaining.poisoned, None);
+        assert_eq!(remaining.shielded, effects.shielded);
+        assert_eq!(remaining.speed_boost, None);
+    }
+
+    #[test]
+    fn test_weather_modifiers() {
+        assert_eq!(WeatherSystem::scale_movement(Weather::Clear, 3, -3), (3, -3));
+        assert_eq!(WeatherSystem::scale_movement(Weather::Storm, 4, -4), (2, -2));
+        assert_eq!(WeatherSystem::scale_movement(Weather::Storm, 1, -3), (1, -2));
+        assert_eq!(WeatherSystem::scale_movement(Weather::Storm, 0, 0), (0, 0));
+        assert_eq!(WeatherSystem::scale_damage(Weather::Clear, 10), 10);
+        assert_eq!(WeatherSystem::scale_damage(Weather::Fog, 10), 7);
+        assert_eq!(WeatherSystem::aggro_radius(Weather::Night, 3), 6);
+        assert_eq!(WeatherSystem::aggro_radius(Weather::Fog, 3), 1);
+    }
+
+    #[test]
+    fn test_weather_rotation() {
+        let world = WorldWeather { weather: Weather::Clear, rotation_period: 100, changed_at: 1_000 };
+        assert_eq!(WeatherSystem::rotate(&world, 1_099), world);
+
+        let rotated = WeatherSystem::rotate(&world, 1_250);
+        assert_eq!(rotated.weather, Weather::Night);
+        assert_eq!(rotated.changed_at, 1_200);
+
+        // A full cycle returns to the starting weather
+        assert_eq!(WeatherSystem::rotate(&world, 1_400).weather, Weather::Clear);
+
+        let held = WorldWeather { rotation_period: 0, ..world.clone() };
+        assert_eq!(WeatherSystem::rotate(&held, 1_000_000), held);
+    }
+
+    #[test]
+    fn test_interaction_system() {
+        let env = Env::default();
+        let door = Interactable { kind: InteractableKind::Door, active: false, key_item: Some(2), linked: None, contents: Vec::new(&env) };
+        assert!(InteractionSystem::blocks_tile(&door));
+        assert!(!InteractionSystem::blocks_tile(&InteractionSystem::toggle(&door)));
+
+        let mut inventory = Inventory::new(&env);
+        assert!(!InteractionSystem::has_key(&door, None));
+        assert!(!InteractionSystem::has_key(&door, Some(&inventory)));
+        inventory.items.set(2, 1);
+        assert!(InteractionSystem::has_key(&door, Some(&inventory)));
+
+        let loot = Loot { currency: 3, item_id: 1, quantity: 1 };
+        let chest = Interactable {
+            kind: InteractableKind::Chest,
+            active: false,
+            key_item: None,
+            linked: None,
+            contents: Vec::from_array(&env, [loot.clone()]),
+        };
+        assert!(!InteractionSystem::blocks_tile(&chest));
+        let mut closed = chest.clone();
+        assert!(InteractionSystem::take_contents(&env, &mut closed).is_empty());
+        let mut opened = InteractionSystem::toggle(&chest);
+        assert_eq!(InteractionSystem::take_contents(&env, &mut opened), Vec::from_array(&env, [loot]));
+        assert!(InteractionSystem::take_contents(&env, &mut opened).is_empty());
+    }
+
+    #[test]
+    fn test_ranged_attack_range() {
+        assert!(CombatSystem::in_range(&Position(0, 0), &Position(6, 6)));
+        assert!(CombatSystem::in_range(&Position(10, 10), &Position(4, 12)));
+        assert!(!CombatSystem::in_range(&Position(0, 0), &Position(7, 0)));
+    }
+
+    #[test]
+    fn test_world_event_validation() {
+        let env = Env::default();
+        let event = |kind, params: &[u32]| WorldEvent { kind, start_seq: 100, params: Vec::from_slice(&env, params) };
+
+        assert!(WorldEventSystem::is_valid(&event(WorldEventKind::MeteorShower, &[0, 0, 5, 5, 3, 20])));
+        assert!(!WorldEventSystem::is_valid(&event(WorldEventKind::MeteorShower, &[0, 0, 5, 5, 11, 20])));
+        assert!(!WorldEventSystem::is_valid(&event(WorldEventKind::MeteorShower, &[0, 0, 0, 5, 3, 20])));
+        assert!(WorldEventSystem::is_valid(&event(WorldEventKind::DoubleXp, &[50, 200])));
+        assert!(!WorldEventSystem::is_valid(&event(WorldEventKind::DoubleXp, &[50, 50])));
+        assert!(WorldEventSystem::is_valid(&event(WorldEventKind::BossSpawn, &[3, 4, 500])));
+        assert!(!WorldEventSystem::is_valid(&event(WorldEventKind::BossSpawn, &[3, 4])));
+
+        let boss = event(WorldEventKind::BossSpawn, &[3, 4, 500]);
+        assert!(!WorldEventSystem::is_due(&boss, 99));
+        assert!(WorldEventSystem::is_due(&boss, 100));
+    }
+
+    fn test_boss(env: &Env) -> Boss {
+        Boss {
+            max_health: 1000,
+            phases: BossSystem::default_phases(env, 20),
+            phase: 0,
+            reward_currency: 100,
+            damage_by: soroban_sdk::Map::new(env),
+        }
+    }
+
+    #[test]
+    fn test_boss_validation() {
+        let env = Env::default();
+        let boss = test_boss(&env);
+        assert!(BossSystem::is_valid(&boss));
+
+        let mut no_start = boss.clone();
+        no_start.phases.remove(0);
+        assert!(!BossSystem::is_valid(&no_start));
+
+        let mut unordered = boss.clone();
+        unordered.phases.push_back(BossPhase { threshold_percent: 30, behavior: BossBehavior::Melee, damage: 5 });
+        assert!(!BossSystem::is_valid(&unordered));
+
+        let mut harmless = boss.clone();
+        harmless.phases.set(1, BossPhase { threshold_percent: 50, behavior: BossBehavior::Ranged, damage: 0 });
+        assert!(!BossSystem::is_valid(&harmless));
+    }
+
+    #[test]
+    fn test_boss_phases() {
+        let env = Env::default();
+        let mut boss = test_boss(&env);
+        assert_eq!(BossSystem::phase_for(&boss, 501), 0);
+        assert_eq!(BossSystem::phase_for(&boss, 500), 1);
+        assert_eq!(BossSystem::phase_for(&boss, 150), 2);
+
+        boss.phase = 2;
+        assert_eq!(BossSystem::phase_for(&boss, 900), 2);
+        assert_eq!(BossSystem::current_phase(&boss).unwrap().behavior, BossBehavior::Enraged);
+    }
+
+    #[test]
+    fn test_boss_targeting() {
+        let boss = Position(10, 10);
+        assert!(BossSystem::reaches(BossBehavior::Melee, &boss, &Position(11, 9), 3));
+        assert!(!BossSystem::reaches(BossBehavior::Melee, &boss, &Position(12, 10), 3));
+        assert!(BossSystem::reaches(BossBehavior::Ranged, &boss, &Position(16, 4), 3));
+        assert!(BossSystem::reaches(BossBehavior::Enraged, &boss, &Position(13, 7), 3));
+        assert!(!BossSystem::reaches(BossBehavior::Enraged, &boss, &Position(14, 10), 3));
+    }
+
+    #[test]
+    fn test_boss_reward_split() {
+        let env = Env::default();
+        let mut boss = test_boss(&env);
+        boss = BossSystem::record_damage(&boss, 4, 10);
+        boss = BossSystem::record_damage(&boss, 2, 10);
+        boss = BossSystem::record_damage(&boss, 4, 20);
+        assert_eq!(boss.damage_by.get(4), Some(30));
+
+        let shares = BossSystem::split_rewards(&env, &boss);
+        assert_eq!(shares, Vec::from_array(&env, [(2, 10, 25), (4, 30, 75)]));
+
+        boss.reward_currency = 101;
+        let shares = BossSystem::split_rewards(&env, &boss);
+        assert_eq!(shares, Vec::from_array(&env, [(2, 10, 25), (4, 30, 76)]));
+    }
+
+    #[test]
+    fn test_boss_contributor_cap() {
+        let env = Env::default();
+        let mut boss = test_boss(&env);
+        for attacker_id in 0..BossSystem::MAX_CONTRIBUTORS + 2 {
+            boss = BossSystem::record_damage(&boss, attacker_id, 5);
+        }
+        assert_eq!(boss.damage_by.len(), BossSystem::MAX_CONTRIBUTORS);
+        assert_eq!(boss.damage_by.get(BossSystem::MAX_CONTRIBUTORS), None);
+    }
+
+    #[test]
+    fn test_xp_window() {
+        let window = XpWindow { percent: 200, ends_seq: 150 };
+        assert_eq!(WorldEventSystem::xp_percent(None, 100), 100);
+        assert_eq!(WorldEventSystem::xp_percent(Some(&window), 149), 200);
+        assert_eq!(WorldEventSystem::xp_percent(Some(&window), 150), 100);
+    }
+
+    // SupportSystem tests
+    #[test]
+    fn test_support_range_and_allies() {
+        assert!(SupportSystem::in_range(&Position(5, 5), &Position(7, 6)));
+        assert!(!SupportSystem::in_range(&Position(5, 5), &Position(8, 6)));
+
+        assert!(SupportSystem::are_allies(Some(&Team(1)), Some(&Team(1))));
+        assert!(!SupportSystem::are_allies(Some(&Team(1)), Some(&Team(2))));
+        assert!(!SupportSystem::are_allies(None, None));
+    }
+
+    #[test]
+    fn test_support_heal_and_energy() {
+        assert_eq!(SupportSystem::spend_energy(&Energy(10), 10), Some(Energy(0)));
+        assert_eq!(SupportSystem::spend_energy(&Energy(9), 10), None);
+
+        assert_eq!(SupportSystem::heal(&Health(90), 20, Some(&MaxHealth(100))), Health(100));
+        assert_eq!(SupportSystem::heal(&Health(90), 20, None), Health(110));
+        // Health above the cap is never lowered by a heal
+        assert_eq!(SupportSystem::heal(&Health(120), 5, Some(&MaxHealth(100))), Health(120));
+    }
+
+    #[test]
+    fn test_shield_absorbs_damage() {
+        let shield = Shield { amount: 8, expires_at: 100 };
+        assert_eq!(
+            SupportSystem::absorb(Some(&shield), 5, 50),
+            (0, Some(Shield { amount: 3, expires_at: 100 }))
+        );
+        assert_eq!(SupportSystem::absorb(Some(&shield), 10, 50), (2, None));
+        assert_eq!(SupportSystem::absorb(Some(&shield), 10, 100), (10, None));
+        assert_eq!(SupportSystem::absorb(None, 10, 50), (10, None));
+    }
 }
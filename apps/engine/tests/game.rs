@@ -18,7 +18,8 @@ use soroban_sdk::{testutils::*, Env};
 
 // Import the game contract and related types
 use game::{
-    CombatSystem, GamePosition, GameWorldContract, GameWorldContractClient, Health, MovementSystem,
+    CombatSystem, EffectKind, GameError, GamePosition, GameWorldContract, GameWorldContractClient,
+    Health, LootSystem, MovementSystem, MAX_TICK_SCAN,
 };
 
 /// Helper function to create a test environment with a deployed contract
@@ -39,6 +40,25 @@ fn setup_initialized_contract<'a>() -> (Env, GameWorldContractClient<'a>) {
     (env, client)
 }
 
+/// Helper function to create a contract with an admin, mocking all auths
+fn setup_admin_contract<'a>() -> (Env, GameWorldContractClient<'a>) {
+    let (env, client) = setup_initialized_contract();
+    env.mock_all_auths();
+    client.initialize(&soroban_sdk::Address::generate(&env));
+    (env, client)
+}
+
+/// Ticks the whole world, one page after another, returning the entities processed
+fn tick(client: &GameWorldContractClient) -> u32 {
+    let mut page = client.game_tick(&0, &MAX_TICK_SCAN);
+    let mut processed = page.processed;
+    while let Some(next_id) = page.next_id {
+        page = client.game_tick(&next_id, &MAX_TICK_SCAN);
+        processed += page.processed;
+    }
+    processed
+}
+
 #[cfg(test)]
 mod contract_initialization_tests {
     use super::*;
@@ -186,8 +206,7 @@ mod entity_management_tests {
         );
 
         // Despawn the entity
-        let despawn_result = client.despawn_entity(&entity_id);
-        assert!(despawn_result, "Despawning should succeed");
+        client.despawn_entity(&entity_id);
 
         // Verify entity count decreased
         assert_eq!(
@@ -224,8 +243,7 @@ mod movement_system_tests {
         let entity_id = client.spawn_entity(&50, &50);
 
         // Move entity by (10, -5)
-        let move_result = client.move_entity(&entity_id, &10, &-5);
-        assert!(move_result, "Movement should succeed");
+        client.move_entity(&entity_id, &10, &-5);
 
         // Verify new position
         let position = client.get_entity_position(&entity_id).unwrap();
@@ -245,11 +263,7 @@ mod movement_system_tests {
         let entity_id = client.spawn_entity(&5, &3);
 
         // Try to move by (-10, -10) which would result in negative coordinates
-        let move_result = client.move_entity(&entity_id, &-10, &-10);
-        assert!(
-            move_result,
-            "Movement should succeed even with negative result"
-        );
+        client.move_entity(&entity_id, &-10, &-10);
 
         // Verify coordinates are clamped to 0
         let position = client.get_entity_position(&entity_id).unwrap();
@@ -271,11 +285,7 @@ mod movement_system_tests {
         let entity_id = client.spawn_entity(&large_x, &large_y);
 
         // Move by reasonable amounts
-        let move_result = client.move_entity(&entity_id, &1000, &-1000);
-        assert!(
-            move_result,
-            "Movement with large coordinates should succeed"
-        );
+        client.move_entity(&entity_id, &1000, &-1000);
 
         // Verify calculation is correct
         let position = client.get_entity_position(&entity_id).unwrap();
@@ -302,8 +312,7 @@ mod movement_system_tests {
         let entity_id = client.spawn_entity(&100, &200);
 
         // Move by (0, 0)
-        let move_result = client.move_entity(&entity_id, &0, &0);
-        assert!(move_result, "Zero movement should succeed");
+        client.move_entity(&entity_id, &0, &0);
 
         // Verify position unchanged
         let position = client.get_entity_position(&entity_id).unwrap();
@@ -328,8 +337,7 @@ mod combat_system_tests {
         let entity_id = client.spawn_entity(&10, &10);
 
         // Attack the entity (should reduce health by 10)
-        let attack_result = client.attack_entity(&entity_id);
-        assert!(attack_result, "Attack should succeed");
+        client.attack_entity(&entity_id);
 
         // Verify health was reduced
         let health = client.get_entity_health(&entity_id).unwrap();
@@ -356,8 +364,7 @@ mod combat_system_tests {
 
         // Perform multiple attacks
         for i in 1..=5 {
-            let attack_result = client.attack_entity(&entity_id);
-            assert!(attack_result, "Attack {} should succeed", i);
+            client.attack_entity(&entity_id);
 
             let expected_health = 100 - (i * 10);
             let health = client.get_entity_health(&entity_id).unwrap();
@@ -387,9 +394,8 @@ mod combat_system_tests {
         let entity_id = client.spawn_entity(&30, &40);
 
         // Attack entity until it dies (10 attacks = 100 damage)
-        for i in 1..=10 {
-            let attack_result = client.attack_entity(&entity_id);
-            assert!(attack_result, "Attack {} should succeed", i);
+        for _ in 1..=10 {
+            client.attack_entity(&entity_id);
         }
 
         // Verify entity is dead (removed from world)
@@ -406,8 +412,8 @@ mod combat_system_tests {
             "Dead entity count should be 1"
         );
 
-        // Verify total entity count decreased
-        assert_eq!(client.entity_count(), 0, "Living entity count should be 0");
+        // Plain entities leave no loot drop behind
+        assert_eq!(client.entity_count(), 0, "No entities should remain");
     }
 
     /// Test: CombatSystem health underflow protection
@@ -431,8 +437,7 @@ mod combat_system_tests {
         assert_eq!(health.0, 10, "Entity should have 10 health remaining");
 
         // One more attack should kill the entity (10 - 10 = 0)
-        let attack_result = client.attack_entity(&entity_id);
-        assert!(attack_result, "Final attack should succeed");
+        client.attack_entity(&entity_id);
 
         // Entity should now be dead
         assert!(
@@ -458,12 +463,20 @@ mod error_handling_tests {
         let invalid_entity_id = 999u32;
 
         // Test movement on non-existent entity
-        let move_result = client.move_entity(&invalid_entity_id, &10, &20);
-        assert!(!move_result, "Movement on non-existent entity should fail");
+        let move_result = client.try_move_entity(&invalid_entity_id, &10, &20);
+        assert_eq!(
+            move_result,
+            Err(Ok(GameError::EntityNotFound)),
+            "Movement on non-existent entity should fail"
+        );
 
         // Test attack on non-existent entity
-        let attack_result = client.attack_entity(&invalid_entity_id);
-        assert!(!attack_result, "Attack on non-existent entity should fail");
+        let attack_result = client.try_attack_entity(&invalid_entity_id);
+        assert_eq!(
+            attack_result,
+            Err(Ok(GameError::EntityNotFound)),
+            "Attack on non-existent entity should fail"
+        );
 
         // Test position retrieval on non-existent entity
         let position = client.get_entity_position(&invalid_entity_id);
@@ -480,9 +493,10 @@ mod error_handling_tests {
         );
 
         // Test despawning non-existent entity
-        let despawn_result = client.despawn_entity(&invalid_entity_id);
-        assert!(
-            !despawn_result,
+        let despawn_result = client.try_despawn_entity(&invalid_entity_id);
+        assert_eq!(
+            despawn_result,
+            Err(Ok(GameError::EntityNotFound)),
             "Despawning non-existent entity should fail"
         );
     }
@@ -510,14 +524,26 @@ mod error_handling_tests {
         );
 
         // Try operations on dead entity
-        let move_result = client.move_entity(&entity_id, &5, &5);
-        assert!(!move_result, "Movement on dead entity should fail");
+        let move_result = client.try_move_entity(&entity_id, &5, &5);
+        assert_eq!(
+            move_result,
+            Err(Ok(GameError::EntityDead)),
+            "Movement on dead entity should fail"
+        );
 
-        let attack_result = client.attack_entity(&entity_id);
-        assert!(!attack_result, "Attack on dead entity should fail");
+        let attack_result = client.try_attack_entity(&entity_id);
+        assert_eq!(
+            attack_result,
+            Err(Ok(GameError::EntityDead)),
+            "Attack on dead entity should fail"
+        );
 
-        let despawn_result = client.despawn_entity(&entity_id);
-        assert!(!despawn_result, "Despawning dead entity should fail");
+        let despawn_result = client.try_despawn_entity(&entity_id);
+        assert_eq!(
+            despawn_result,
+            Err(Ok(GameError::EntityDead)),
+            "Despawning dead entity should fail"
+        );
     }
 
     /// Test: Edge case with maximum entity IDs
@@ -530,8 +556,12 @@ mod error_handling_tests {
         // Test with maximum u32 value as entity ID
         let max_entity_id = u32::MAX;
 
-        let move_result = client.move_entity(&max_entity_id, &0, &0);
-        assert!(!move_result, "Movement on max entity ID should fail");
+        let move_result = client.try_move_entity(&max_entity_id, &0, &0);
+        assert_eq!(
+            move_result,
+            Err(Ok(GameError::EntityNotFound)),
+            "Movement on max entity ID should fail"
+        );
 
         let position = client.get_entity_position(&max_entity_id);
         assert!(position.is_none(), "Max entity ID should not exist");
@@ -563,11 +593,19 @@ mod world_state_tests {
         );
 
         // Operations on empty world should fail gracefully
-        let move_result = client.move_entity(&0, &10, &10);
-        assert!(!move_result, "Movement in empty world should fail");
+        let move_result = client.try_move_entity(&0, &10, &10);
+        assert_eq!(
+            move_result,
+            Err(Ok(GameError::EntityNotFound)),
+            "Movement in empty world should fail"
+        );
 
-        let attack_result = client.attack_entity(&0);
-        assert!(!attack_result, "Attack in empty world should fail");
+        let attack_result = client.try_attack_entity(&0);
+        assert_eq!(
+            attack_result,
+            Err(Ok(GameError::EntityNotFound)),
+            "Attack in empty world should fail"
+        );
     }
 
     /// Test: World state consistency after multiple operations
@@ -650,13 +688,15 @@ mod world_state_tests {
             client.attack_entity(&entity_ids[3]);
         }
 
-        // Spawn new entities – IDs may reuse freed slots
+        // Spawn new entities – IDs of killed entities are never reused
         let new_id1 = client.spawn_entity(&100, &200);
         let new_id2 = client.spawn_entity(&300, &400);
+        assert_eq!(new_id1, 5);
+        assert_eq!(new_id2, 6);
 
         // ✅ Check that new IDs are unique among *current* live entities
         let mut all_ids = Vec::new();
-        for i in 0..client.entity_count() {
+        for i in 0..=new_id2 {
             if let Some(_) = client.get_entity_position(&i) {
                 all_ids.push(i);
             }
@@ -748,7 +788,7 @@ mod integration_tests {
         }
 
         // Verify final state
-        assert_eq!(client.entity_count(), 1, "Only player should remain alive");
+        assert_eq!(client.entity_count(), 1, "Only the player should remain");
         assert_eq!(client.dead_entity_count(), 2, "Should have 2 dead entities");
 
         let final_player_health = client.get_entity_health(&player_id).unwrap();
@@ -959,3 +999,2371 @@ mod component_system_tests {
         assert_eq!(still_zero.0, 0, "Zero health should remain zero");
     }
 }
+
+#[cfg(test)]
+mod mount_system_tests {
+    use super::*;
+
+    /// Helper that spawns an owned rider next to an owned mount
+    fn setup_rider_and_mount<'a>(
+        speed: u32,
+    ) -> (Env, GameWorldContractClient<'a>, soroban_sdk::Address, u32, u32) {
+        let (env, client) = setup_admin_contract();
+
+        let owner = soroban_sdk::Address::generate(&env);
+        let rider_id = client.spawn_entity(&10, &10);
+        client.set_entity_owner(&rider_id, &owner);
+        let mount_id = client.spawn_mount(&owner, &11, &10, &speed);
+
+        (env, client, owner, rider_id, mount_id)
+    }
+
+    /// Test: Mounting parents the rider and snaps it onto the mount
+    #[test]
+    fn test_mount_parents_rider() {
+        let (_env, client, _owner, rider_id, mount_id) = setup_rider_and_mount(2);
+
+        client.mount(&rider_id, &mount_id);
+
+        assert_eq!(client.get_mount(&rider_id), Some(mount_id));
+        let rider_pos = client.get_entity_position(&rider_id).unwrap();
+        assert_eq!(rider_pos, GamePosition(11, 10), "Rider should sit on the mount's tile");
+    }
+
+    /// Test: Moving either entity moves the pair at the mount's speed
+    #[test]
+    fn test_mounted_movement_uses_mount_speed() {
+        let (_env, client, _owner, rider_id, mount_id) = setup_rider_and_mount(3);
+        client.mount(&rider_id, &mount_id);
+
+        // Rider steers: 1 step at speed 3 covers 3 tiles
+        client.move_entity(&rider_id, &1, &0);
+        assert_eq!(client.get_entity_position(&mount_id).unwrap(), GamePosition(14, 10));
+        assert_eq!(client.get_entity_position(&rider_id).unwrap(), GamePosition(14, 10));
+
+        // Moving the mount directly carries the rider too
+        client.move_entity(&mount_id, &0, &2);
+        assert_eq!(client.get_entity_position(&mount_id).unwrap(), GamePosition(14, 16));
+        assert_eq!(client.get_entity_position(&rider_id).unwrap(), GamePosition(14, 16));
+    }
+
+    /// Test: Dismounting leaves the rider where the mount stands
+    #[test]
+    fn test_dismount() {
+        let (_env, client, _owner, rider_id, mount_id) = setup_rider_and_mount(2);
+        client.mount(&rider_id, &mount_id);
+        client.move_entity(&rider_id, &1, &1);
+
+        client.dismount(&rider_id);
+        assert_eq!(client.get_mount(&rider_id), None);
+
+        // Rider now moves on its own at normal speed
+        client.move_entity(&rider_id, &1, &0);
+        assert_eq!(client.get_entity_position(&rider_id).unwrap(), GamePosition(14, 12));
+        assert_eq!(client.get_entity_position(&mount_id).unwrap(), GamePosition(13, 12));
+
+        assert_eq!(client.try_dismount(&rider_id), Err(Ok(GameError::NotMounted)));
+    }
+
+    /// Test: Mounting enforces adjacency, ownership, and mount type
+    #[test]
+    fn test_mount_validation() {
+        let (env, client, owner, rider_id, mount_id) = setup_rider_and_mount(2);
+
+        // Too far away
+        let far_mount = client.spawn_mount(&owner, &20, &20, &2);
+        assert_eq!(client.try_mount(&rider_id, &far_mount), Err(Ok(GameError::NotAdjacent)));
+
+        // Mount belongs to someone else
+        let stranger = soroban_sdk::Address::generate(&env);
+        let foreign_mount = client.spawn_mount(&stranger, &9, &10, &2);
+        assert_eq!(client.try_mount(&rider_id, &foreign_mount), Err(Ok(GameError::Unauthorized)));
+
+        // Plain entities cannot be ridden
+        let plain = client.spawn_entity(&10, &11);
+        client.set_entity_owner(&plain, &owner);
+        assert_eq!(client.try_mount(&rider_id, &plain), Err(Ok(GameError::NotAMount)));
+
+        // Cannot mount itself or double-mount
+        assert_eq!(client.try_mount(&rider_id, &rider_id), Err(Ok(GameError::InvalidTarget)));
+        client.mount(&rider_id, &mount_id);
+        assert_eq!(client.try_mount(&plain, &mount_id), Err(Ok(GameError::AlreadyMounted)));
+    }
+
+    /// Test: A mount's death releases its rider
+    #[test]
+    fn test_mount_death_releases_rider() {
+        let (_env, client, _owner, rider_id, mount_id) = setup_rider_and_mount(2);
+        client.mount(&rider_id, &mount_id);
+
+        for _ in 1..=10 {
+            client.attack_entity(&mount_id);
+        }
+
+        assert_eq!(client.get_mount(&rider_id), None, "Rider should be unparented");
+        client.move_entity(&rider_id, &1, &0);
+        assert_eq!(client.get_entity_position(&rider_id).unwrap(), GamePosition(12, 10));
+    }
+}
+
+#[cfg(test)]
+mod crafting_station_tests {
+    use super::*;
+    use soroban_sdk::testutils::Address as _;
+    use game::CraftingInterface;
+    use soroban_sdk::{contract, contractimpl, symbol_short, Address};
+
+    /// Minimal crafting contract recording the last request it received
+    #[contract]
+    pub struct MockCrafting;
+
+    #[contractimpl]
+    impl CraftingInterface for MockCrafting {
+        fn craft(env: Env, crafter: Address, recipe_id: u32, station_type: u32) {
+            if recipe_id == 0 {
+                panic!("unknown recipe");
+            }
+            env.storage()
+                .instance()
+                .set(&symbol_short!("last"), &(crafter, recipe_id, station_type));
+        }
+    }
+
+    #[contractimpl]
+    impl MockCrafting {
+        pub fn last_craft(env: Env) -> Option<(Address, u32, u32)> {
+            env.storage().instance().get(&symbol_short!("last"))
+        }
+    }
+
+    fn setup_station<'a>() -> (
+        Env,
+        GameWorldContractClient<'a>,
+        MockCraftingClient<'a>,
+        Address,
+        u32,
+        u32,
+    ) {
+        let (env, client) = setup_admin_contract();
+
+        let crafting_id = env.register(MockCrafting, ());
+        let crafting = MockCraftingClient::new(&env, &crafting_id);
+
+        let owner = Address::generate(&env);
+        let player_id = client.spawn_entity(&10, &10);
+        client.set_entity_owner(&player_id, &owner);
+        let station_id = client.spawn_station(&owner, &11, &11, &7, &crafting_id);
+
+        (env, client, crafting, owner, player_id, station_id)
+    }
+
+    /// Test: Crafting next to a station forwards the recipe to the crafting contract
+    #[test]
+    fn test_craft_at_adjacent_station() {
+        let (_env, client, crafting, owner, player_id, station_id) = setup_station();
+
+        client.craft_at_station(&player_id, &station_id, &3);
+
+        assert_eq!(crafting.last_craft(), Some((owner, 3, 7)));
+        assert_eq!(client.get_station(&station_id).unwrap().station_type, 7);
+    }
+
+    /// Test: Crafting requires adjacency and a real station
+    #[test]
+    fn test_craft_validation() {
+        let (_env, client, crafting, _owner, player_id, station_id) = setup_station();
+
+        client.move_entity(&player_id, &-5, &0);
+        assert_eq!(
+            client.try_craft_at_station(&player_id, &station_id, &3),
+            Err(Ok(GameError::NotAdjacent))
+        );
+
+        let rock = client.spawn_entity(&4, &10);
+        assert_eq!(
+            client.try_craft_at_station(&player_id, &rock, &3),
+            Err(Ok(GameError::NotAStation))
+        );
+        assert_eq!(crafting.last_craft(), None);
+    }
+
+    /// Test: Recipe failures in the crafting contract surface as CraftingFailed
+    #[test]
+    fn test_craft_failure_is_reported() {
+        let (_env, client, _crafting, _owner, player_id, station_id) = setup_station();
+
+        assert_eq!(
+            client.try_craft_at_station(&player_id, &station_id, &0),
+            Err(Ok(GameError::CraftingFailed))
+        );
+    }
+}
+
+#[cfg(test)]
+mod effect_system_tests {
+    use super::*;
+
+    /// Test: Poison deals periodic damage driven by ledger time
+    #[test]
+    fn test_poison_ticks_with_ledger_time() {
+        let (env, client) = setup_admin_contract();
+        env.ledger().set_timestamp(1_000);
+        let entity_id = client.spawn_entity(&0, &0);
+
+        client.apply_effect(&entity_id, &EffectKind::Poisoned, &4, &60);
+        assert!(client.get_effects(&entity_id).iter().any(|e| e.kind == EffectKind::Poisoned));
+
+        // Three 5-second intervals elapse
+        env.ledger().set_timestamp(1_015);
+        client.tick_effects(&entity_id);
+        assert_eq!(client.get_entity_health(&entity_id).unwrap().0, 88);
+
+        // Poison expires after 60 seconds (12 intervals in total)
+        env.ledger().set_timestamp(2_000);
+        client.tick_effects(&entity_id);
+        assert_eq!(client.get_entity_health(&entity_id).unwrap().0, 52);
+        assert!(client.get_effects(&entity_id).is_empty());
+    }
+
+    /// Test: Lethal poison kills the entity
+    #[test]
+    fn test_poison_can_kill() {
+        let (env, client) = setup_admin_contract();
+        let entity_id = client.spawn_entity(&0, &0);
+
+        client.apply_effect(&entity_id, &EffectKind::Poisoned, &50, &100);
+        env.ledger().set_timestamp(env.ledger().timestamp() + 10);
+        client.tick_effects(&entity_id);
+
+        assert!(client.get_entity_health(&entity_id).is_none());
+        assert_eq!(client.dead_entity_count(), 1);
+    }
+
+    /// Test: Shield reduces attack damage until it expires
+    #[test]
+    fn test_shield_reduces_attack_damage() {
+        let (env, client) = setup_admin_contract();
+        let entity_id = client.spawn_entity(&0, &0);
+
+        client.apply_effect(&entity_id, &EffectKind::Shielded, &6, &30);
+        client.attack_entity(&entity_id);
+        assert_eq!(client.get_entity_health(&entity_id).unwrap().0, 96);
+
+        env.ledger().set_timestamp(env.ledger().timestamp() + 30);
+        client.attack_entity(&entity_id);
+        assert_eq!(client.get_entity_health(&entity_id).unwrap().0, 86);
+        assert!(!client.get_effects(&entity_id).iter().any(|e| e.kind == EffectKind::Shielded));
+    }
+
+    /// Test: Speed boost grants extra movement
+    #[test]
+    fn test_speed_boost_extra_movement() {
+        let (env, client) = setup_admin_contract();
+        let entity_id = client.spawn_entity(&10, &10);
+
+        client.apply_effect(&entity_id, &EffectKind::SpeedBoost, &1, &20);
+        client.move_entity(&entity_id, &2, &-1);
+        assert_eq!(client.get_entity_position(&entity_id).unwrap(), GamePosition(14, 8));
+
+        env.ledger().set_timestamp(env.ledger().timestamp() + 20);
+        client.move_entity(&entity_id, &2, &0);
+        assert_eq!(client.get_entity_position(&entity_id).unwrap(), GamePosition(16, 8));
+    }
+
+    /// Test: Zero, oversized or overlong effects are rejected
+    #[test]
+    fn test_invalid_effect() {
+        let (_env, client) = setup_admin_contract();
+        let entity_id = client.spawn_entity(&0, &0);
+
+        assert_eq!(
+            client.try_apply_effect(&entity_id, &EffectKind::Shielded, &0, &10),
+            Err(Ok(GameError::InvalidEffect))
+        );
+        assert_eq!(
+            client.try_apply_effect(&entity_id, &EffectKind::Poisoned, &5, &0),
+            Err(Ok(GameError::InvalidEffect))
+        );
+        assert_eq!(
+            client.try_apply_effect(&entity_id, &EffectKind::Poisoned, &u32::MAX, &10),
+            Err(Ok(GameError::InvalidEffect))
+        );
+        assert_eq!(
+            client.try_apply_effect(&entity_id, &EffectKind::SpeedBoost, &1, &(60 * 60 + 1)),
+            Err(Ok(GameError::InvalidEffect))
+        );
+    }
+
+    /// Test: Without an admin nobody can apply effects
+    #[test]
+    fn test_apply_effect_requires_admin() {
+        let (env, client) = setup_initialized_contract();
+        env.mock_all_auths();
+        let entity_id = client.spawn_entity(&0, &0);
+
+        assert_eq!(
+            client.try_apply_effect(&entity_id, &EffectKind::Poisoned, &5, &10),
+            Err(Ok(GameError::Unauthorized))
+        );
+    }
+}
+
+#[cfg(test)]
+mod game_event_tests {
+    use super::*;
+    use soroban_sdk::{vec, IntoVal, Map, Symbol, Val};
+
+    /// Builds the `(contract, topics, data)` tuple a game event is expected to publish
+    fn event(
+        env: &Env,
+        client: &GameWorldContractClient,
+        name: &str,
+        entity_id: u32,
+        data: &[(&str, u32)],
+    ) -> (soroban_sdk::Address, soroban_sdk::Vec<Val>, Val) {
+        let mut fields = Map::<Symbol, Val>::new(env);
+        for (key, value) in data {
+            fields.set(Symbol::new(env, key), (*value).into());
+        }
+        (
+            client.address.clone(),
+            (Symbol::new(env, name), entity_id).into_val(env),
+            fields.into_val(env),
+        )
+    }
+
+    /// Test: Spawning an entity emits entity_spawned with its coordinates
+    #[test]
+    fn test_spawn_emits_event() {
+        let (env, client) = setup_initialized_contract();
+        let entity_id = client.spawn_entity(&3, &4);
+
+        assert_eq!(
+            env.events().all(),
+            vec![&env, event(&env, &client, "entity_spawned", entity_id, &[("x", 3), ("y", 4)])]
+        );
+    }
+
+    /// Test: Moving an entity emits entity_moved with the new coordinates
+    #[test]
+    fn test_move_emits_event() {
+        let (env, client) = setup_initialized_contract();
+        let entity_id = client.spawn_entity(&3, &4);
+        client.move_entity(&entity_id, &2, &-1);
+
+        assert_eq!(
+            env.events().all(),
+            vec![&env, event(&env, &client, "entity_moved", entity_id, &[("x", 5), ("y", 3)])]
+        );
+    }
+
+    /// Test: A non-lethal attack emits entity_damaged only
+    #[test]
+    fn test_attack_emits_damage_event() {
+        let (env, client) = setup_initialized_contract();
+        let entity_id = client.spawn_entity(&0, &0);
+        client.attack_entity(&entity_id);
+
+        assert_eq!(
+            env.events().all(),
+            vec![
+                &env,
+                event(&env, &client, "entity_damaged", entity_id, &[("damage", 10), ("health", 90)])
+            ]
+        );
+    }
+
+    /// Test: A lethal attack emits entity_damaged, entity_died and the loot drop's spawn
+    #[test]
+    fn test_lethal_attack_emits_death_event() {
+        let (env, client) = setup_admin_contract();
+        let entity_id = client.spawn_entity(&7, &8);
+        client.set_entity_owner(&entity_id, &soroban_sdk::Address::generate(&env));
+        // Ten hits of 10 damage; only the last invocation's events are recorded
+        for _ in 0..10 {
+            client.attack_entity(&entity_id);
+        }
+
+        assert_eq!(
+            env.events().all(),
+            vec![
+                &env,
+                event(&env, &client, "entity_damaged", entity_id, &[("damage", 10), ("health", 0)]),
+                event(&env, &client, "entity_died", entity_id, &[("x", 7), ("y", 8)]),
+                event(&env, &client, "entity_spawned", entity_id + 1, &[("x", 7), ("y", 8)]),
+            ]
+        );
+    }
+
+    /// Test: Despawning an entity emits entity_despawned
+    #[test]
+    fn test_despawn_emits_event() {
+        let (env, client) = setup_initialized_contract();
+        let entity_id = client.spawn_entity(&0, &0);
+        client.despawn_entity(&entity_id);
+
+        assert_eq!(
+            env.events().all(),
+            vec![&env, event(&env, &client, "entity_despawned", entity_id, &[])]
+        );
+    }
+}
+
+#[cfg(test)]
+mod game_tick_tests {
+    use super::*;
+
+    /// Test: A tick applies status effects to every entity in one pass
+    #[test]
+    fn test_tick_processes_all_entities() {
+        let (env, client) = setup_admin_contract();
+        let healthy = client.spawn_entity(&0, &0);
+        let poisoned = client.spawn_entity(&1, &1);
+        let shielded = client.spawn_entity(&2, &2);
+
+        client.apply_effect(&poisoned, &EffectKind::Poisoned, &3, &60);
+        client.apply_effect(&shielded, &EffectKind::Shielded, &5, &10);
+        env.ledger().set_timestamp(env.ledger().timestamp() + 10);
+
+        assert_eq!(tick(&client), 3);
+        assert_eq!(client.get_entity_health(&healthy).unwrap().0, 100);
+        assert_eq!(client.get_entity_health(&poisoned).unwrap().0, 94);
+        assert_eq!(client.get_entity_health(&shielded).unwrap().0, 100);
+        assert!(client.get_effects(&shielded).is_empty());
+        assert_eq!(client.get_entity_position(&poisoned).unwrap(), GamePosition(1, 1));
+    }
+
+    /// Test: Entities killed during a tick are removed and counted
+    #[test]
+    fn test_tick_kills_entities() {
+        let (env, client) = setup_admin_contract();
+        let survivor = client.spawn_entity(&0, &0);
+        let victim = client.spawn_entity(&1, &1);
+
+        client.apply_effect(&victim, &EffectKind::Poisoned, &50, &60);
+        env.ledger().set_timestamp(env.ledger().timestamp() + 10);
+
+        assert_eq!(tick(&client), 2);
+        assert!(client.get_entity_health(&victim).is_none());
+        assert_eq!(client.get_entity_health(&survivor).unwrap().0, 100);
+        assert_eq!(client.dead_entity_count(), 1);
+        // Only the survivor is left
+        assert_eq!(tick(&client), 1);
+    }
+
+    /// Test: A tick pass visits a page of entity IDs per call
+    #[test]
+    fn test_tick_in_pages() {
+        let (env, client) = setup_admin_contract();
+        let mut entities = soroban_sdk::Vec::new(&env);
+        for i in 0..MAX_TICK_SCAN + 2 {
+            entities.push_back(client.spawn_entity(&i, &i));
+        }
+        client.despawn_entity(&entities.get(1).unwrap());
+        let last = entities.last().unwrap();
+        client.apply_effect(&last, &EffectKind::Poisoned, &3, &60);
+        env.ledger().set_timestamp(env.ledger().timestamp() + 10);
+
+        // The first page skips the despawned ID, and later pages are bounded by the limit
+        let page = client.game_tick(&0, &MAX_TICK_SCAN);
+        assert_eq!((page.processed, page.next_id), (MAX_TICK_SCAN - 1, Some(MAX_TICK_SCAN)));
+        assert_eq!(client.get_entity_health(&last).unwrap().0, 100);
+        let page = client.game_tick(&MAX_TICK_SCAN, &1);
+        assert_eq!((page.processed, page.next_id), (1, Some(MAX_TICK_SCAN + 1)));
+        let page = client.game_tick(&(MAX_TICK_SCAN + 1), &100);
+        assert_eq!((page.processed, page.next_id), (1, None));
+        assert_eq!(client.get_entity_health(&last).unwrap().0, 94);
+    }
+
+    /// Test: A full page of busy entities in a crowded area fits in one transaction
+    #[test]
+    fn test_tick_page_in_crowd() {
+        let (env, client) = setup_admin_contract();
+        let owner = soroban_sdk::Address::generate(&env);
+        let phases = soroban_sdk::vec![
+            &env,
+            game::BossPhase { threshold_percent: 100, behavior: game::BossBehavior::Enraged, damage: 5 },
+        ];
+        client.spawn_boss(&5, &5, &1000, &phases, &100);
+        client.spawn_projectile(&owner, &5, &4, &1, &0, &5, &5);
+        for i in 0..8 {
+            let player = client.spawn_entity(&(3 + i % 5), &(3 + i / 5));
+            client.set_entity_owner(&player, &soroban_sdk::Address::generate(&env));
+            client.set_regeneration(&player, &60, &100);
+            client.apply_effect(&player, &EffectKind::Poisoned, &1, &60);
+            client.apply_effect(&player, &EffectKind::Shielded, &1, &60);
+        }
+        env.ledger().set_timestamp(env.ledger().timestamp() + 10);
+
+        let page = client.game_tick(&0, &MAX_TICK_SCAN);
+        assert_eq!((page.processed, page.next_id), (MAX_TICK_SCAN, Some(MAX_TICK_SCAN)));
+        assert!(client.get_world_stats().total_damage > 0);
+    }
+
+    /// Test: Ticking an empty world does nothing
+    #[test]
+    fn test_tick_empty_world() {
+        let (_env, client) = setup_initialized_contract();
+        assert_eq!(tick(&client), 0);
+    }
+}
+
+#[cfg(test)]
+mod admin_tests {
+    use super::*;
+    use soroban_sdk::testutils::{Address as _, MockAuth, MockAuthInvoke};
+    use soroban_sdk::{Address, BytesN, IntoVal};
+
+    /// Test: initialize sets the admin and keeps existing entities
+    #[test]
+    fn test_initialize_sets_admin() {
+        let (env, client) = setup_initialized_contract();
+        env.mock_all_auths();
+        let entity_id = client.spawn_entity(&1, &2);
+        let admin = Address::generate(&env);
+
+        client.initialize(&admin);
+
+        assert_eq!(client.get_admin(), Some(admin));
+        assert_eq!(client.entity_count(), 1);
+        assert_eq!(client.get_entity_position(&entity_id).unwrap(), GamePosition(1, 2));
+    }
+
+    /// Test: A stranger cannot claim an admin-spawned entity, only the admin assigns a first owner
+    #[test]
+    fn test_stranger_cannot_claim_entity() {
+        let (env, client) = setup_admin_contract();
+        let phases = soroban_sdk::vec![
+            &env,
+            game::BossPhase { threshold_percent: 100, behavior: game::BossBehavior::Melee, damage: 5 },
+        ];
+        let boss = client.spawn_boss(&5, &5, &100, &phases, &0);
+        let stranger = Address::generate(&env);
+
+        env.mock_auths(&[MockAuth {
+            address: &stranger,
+            invoke: &MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "set_entity_owner",
+                args: (boss, stranger.clone()).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]);
+        assert!(client.try_set_entity_owner(&boss, &stranger).is_err());
+        assert_eq!(client.get_entity_owner(&boss), None);
+
+        env.mock_all_auths();
+        client.set_entity_owner(&boss, &stranger);
+        assert_eq!(client.get_entity_owner(&boss), Some(stranger));
+    }
+
+    /// Test: Moving, attacking or despawning an owned entity needs its owner's auth
+    #[test]
+    fn test_owned_entity_actions_require_owner() {
+        let (env, client) = setup_admin_contract();
+        let owner = Address::generate(&env);
+        let owned = client.spawn_entity(&1, &1);
+        let unowned = client.spawn_entity(&5, &5);
+        client.set_entity_owner(&owned, &owner);
+
+        env.set_auths(&[]);
+        assert!(client.try_move_entity(&owned, &1, &0).is_err());
+        assert!(client.try_attack_entity(&owned).is_err());
+        assert!(client.try_despawn_entity(&owned).is_err());
+        assert_eq!(client.get_entity_position(&owned).unwrap(), GamePosition(1, 1));
+        assert_eq!(client.get_entity_health(&owned).unwrap(), Health(100));
+
+        // Unowned entities stay open to anyone
+        client.move_entity(&unowned, &1, &0);
+        client.attack_entity(&unowned);
+        client.despawn_entity(&unowned);
+
+        env.mock_all_auths();
+        client.move_entity(&owned, &1, &0);
+        assert_eq!(env.auths()[0].0, owner);
+        client.despawn_entity(&owned);
+        assert_eq!(client.entity_count(), 0);
+    }
+
+    /// Test: initialize can only run once
+    #[test]
+    fn test_initialize_twice_fails() {
+        let (env, client) = setup_test_contract();
+        env.mock_all_auths();
+        let admin = Address::generate(&env);
+
+        client.initialize(&admin);
+        assert_eq!(
+            client.try_initialize(&Address::generate(&env)),
+            Err(Ok(GameError::AlreadyInitialized))
+        );
+        assert_eq!(client.get_admin(), Some(admin));
+    }
+
+    /// Test: The current admin can hand the role over
+    #[test]
+    fn test_set_admin() {
+        let (env, client) = setup_test_contract();
+        env.mock_all_auths();
+        let admin = Address::generate(&env);
+        let new_admin = Address::generate(&env);
+
+        client.initialize(&admin);
+        client.set_admin(&new_admin);
+
+        assert_eq!(env.auths()[0].0, admin);
+        assert_eq!(client.get_admin(), Some(new_admin));
+    }
+
+    /// Test: Admin endpoints fail before an admin is set
+    #[test]
+    fn test_admin_endpoints_require_admin() {
+        let (env, client) = setup_initialized_contract();
+        env.mock_all_auths();
+
+        assert_eq!(
+            client.try_set_admin(&Address::generate(&env)),
+            Err(Ok(GameError::Unauthorized))
+        );
+        assert_eq!(
+            client.try_upgrade(&BytesN::from_array(&env, &[0; 32])),
+            Err(Ok(GameError::Unauthorized))
+        );
+    }
+}
+
+#[cfg(test)]
+mod pause_tests {
+    use super::*;
+    use soroban_sdk::testutils::Address as _;
+    use soroban_sdk::Address;
+
+    /// Test: Mutating endpoints are rejected while paused and work again after unpause
+    #[test]
+    fn test_pause_blocks_mutations() {
+        let (env, client) = setup_test_contract();
+        env.mock_all_auths();
+        client.initialize(&Address::generate(&env));
+        let entity_id = client.spawn_entity(&0, &0);
+
+        client.pause();
+        assert!(client.is_paused());
+        assert_eq!(client.try_spawn_entity(&1, &1), Err(Ok(GameError::Paused)));
+        assert_eq!(client.try_move_entity(&entity_id, &1, &0), Err(Ok(GameError::Paused)));
+        assert_eq!(client.try_attack_entity(&entity_id), Err(Ok(GameError::Paused)));
+        assert_eq!(client.try_despawn_entity(&entity_id), Err(Ok(GameError::Paused)));
+        assert_eq!(client.try_game_tick(&0, &MAX_TICK_SCAN), Err(Ok(GameError::Paused)));
+
+        // Reads keep working during the pause
+        assert_eq!(client.get_entity_health(&entity_id).unwrap().0, 100);
+
+        client.unpause();
+        assert!(!client.is_paused());
+        client.move_entity(&entity_id, &1, &0);
+        assert_eq!(client.get_entity_position(&entity_id).unwrap(), GamePosition(1, 0));
+    }
+
+    /// Test: Only an initialized admin can pause the world
+    #[test]
+    fn test_pause_requires_admin() {
+        let (env, client) = setup_initialized_contract();
+        env.mock_all_auths();
+
+        assert_eq!(client.try_pause(), Err(Ok(GameError::Unauthorized)));
+        assert!(!client.is_paused());
+    }
+}
+
+#[cfg(test)]
+mod ttl_tests {
+    use super::*;
+    use soroban_sdk::symbol_short;
+    use soroban_sdk::testutils::storage::Persistent as _;
+
+    /// Test: Explicit world extensions are reported by get_ttl_info
+    #[test]
+    fn test_ttl_info_tracks_extensions() {
+        let (env, client) = setup_initialized_contract();
+        env.ledger().set_sequence_number(100);
+        let entity_id = client.spawn_entity(&0, &0);
+        assert_eq!(client.get_ttl_info().last_extended_ledger, 0);
+
+        assert_eq!(client.extend_world_ttl(&0, &10), None);
+        let info = client.get_ttl_info();
+        assert_eq!(info.last_extended_ledger, 100);
+        assert_eq!(info.live_until_ledger, 100 + game::TTL_EXTEND_TO);
+
+        env.ledger().set_sequence_number(5_000);
+        client.extend_world_ttl(&0, &10);
+        let info = client.get_ttl_info();
+        assert_eq!(info.current_ledger, 5_000);
+        assert_eq!(info.last_extended_ledger, 5_000);
+
+        let entity_ttl = env.as_contract(&client.address, || {
+            env.storage().persistent().get_ttl(&(symbol_short!("ent"), entity_id))
+        });
+        assert_eq!(entity_ttl, game::TTL_EXTEND_TO);
+    }
+
+    /// Test: Large worlds are extended over several calls, recorded once the pass completes
+    #[test]
+    fn test_extend_world_ttl_in_pages() {
+        let (env, client) = setup_initialized_contract();
+        for _ in 0..5 {
+            client.spawn_entity(&0, &0);
+        }
+        client.despawn_entity(&1);
+
+        env.ledger().set_sequence_number(100);
+        assert_eq!(client.extend_world_ttl(&0, &2), Some(2));
+        assert_eq!(client.get_ttl_info().last_extended_ledger, 0);
+        env.ledger().set_sequence_number(200);
+        assert_eq!(client.extend_world_ttl(&2, &u32::MAX), None);
+        // The pass is dated from the ledger its first page ran at
+        assert_eq!(client.get_ttl_info().last_extended_ledger, 100);
+
+        // Pages that don't continue a pass extend entries but don't complete one
+        env.ledger().set_sequence_number(300);
+        assert_eq!(client.extend_world_ttl(&3, &10), None);
+        assert_eq!(client.get_ttl_info().last_extended_ledger, 100);
+        assert_eq!(client.extend_world_ttl(&50, &10), None);
+        assert_eq!(client.get_ttl_info().last_extended_ledger, 100);
+
+        let cell_ttl = env.as_contract(&client.address, || {
+            env.storage().persistent().get_ttl(&(symbol_short!("cell"), 0u32, 0u32))
+        });
+        assert_eq!(cell_ttl, game::TTL_EXTEND_TO);
+    }
+
+    /// Test: A pass extends an entity's components and its team's member list
+    #[test]
+    fn test_extend_world_ttl_covers_components() {
+        let (env, client) = setup_admin_contract();
+        env.ledger().set_sequence_number(100);
+        let entity_id = client.spawn_entity(&0, &0);
+        client.set_entity_owner(&entity_id, &soroban_sdk::Address::generate(&env));
+        client.set_entity_team(&entity_id, &Some(7));
+        client.set_regeneration(&entity_id, &60, &100);
+
+        env.ledger().set_sequence_number(5_000);
+        assert_eq!(client.extend_world_ttl(&0, &game::MAX_TTL_SCAN), None);
+
+        env.as_contract(&client.address, || {
+            let persistent = env.storage().persistent();
+            for component in ["owner", "team", "regen", "max_hp"] {
+                let key = (soroban_sdk::Symbol::new(&env, component), entity_id);
+                assert_eq!(persistent.get_ttl(&key), game::TTL_EXTEND_TO, "{component}");
+            }
+            let members = (symbol_short!("team_mem"), 7u32);
+            assert_eq!(persistent.get_ttl(&members), game::TTL_EXTEND_TO);
+        });
+    }
+
+    /// Test: Reading an entity close to archival extends its entry
+    #[test]
+    fn test_read_bumps_entity_ttl() {
+        let (env, client) = setup_initialized_contract();
+        env.ledger().set_sequence_number(100);
+        let entity_id = client.spawn_entity(&0, &0);
+
+        let entity_ttl = || {
+            env.as_contract(&client.address, || {
+                env.storage().persistent().get_ttl(&(symbol_short!("ent"), entity_id))
+            })
+        };
+        assert_eq!(entity_ttl(), game::TTL_EXTEND_TO);
+
+        // Far from archival: reads leave the TTL alone
+        env.ledger().set_sequence_number(1_100);
+        client.get_entity_position(&entity_id);
+        assert_eq!(entity_ttl(), game::TTL_EXTEND_TO - 1_000);
+
+        // Below the threshold: the read tops the TTL back up
+        env.ledger().set_sequence_number(100 + game::TTL_EXTEND_TO - game::TTL_THRESHOLD + 1);
+        client.get_entity_position(&entity_id);
+        assert_eq!(entity_ttl(), game::TTL_EXTEND_TO);
+    }
+}
+
+#[cfg(test)]
+mod entity_storage_tests {
+    use super::*;
+    use soroban_sdk::testutils::Address as _;
+    use soroban_sdk::{symbol_short, Address, IntoVal, Map, Val};
+
+    /// Test: The entity index follows spawns and removals, one bucket per 64 IDs
+    #[test]
+    fn test_entity_index_tracks_live_entities() {
+        let (env, client) = setup_initialized_contract();
+        let first = client.spawn_entity(&0, &0);
+        let second = client.spawn_entity(&1, &1);
+        let third = client.spawn_entity(&2, &2);
+
+        client.despawn_entity(&second);
+        assert_eq!(tick(&client), 2);
+
+        let bucket = |index: u32| {
+            env.as_contract(&client.address, || {
+                env.storage()
+                    .persistent()
+                    .get::<_, u64>(&(symbol_short!("ent_idx"), index))
+            })
+        };
+        assert_eq!(bucket(0), Some((1 << first) | (1 << third)));
+
+        // ID 64 opens the second bucket, which goes away with its last entity
+        while client.spawn_entity(&0, &0) < 64 {}
+        assert_eq!(bucket(1), Some(1));
+        client.despawn_entity(&64);
+        assert_eq!(bucket(1), None);
+        assert_eq!(client.entity_count(), 63);
+    }
+
+    /// Test: Entities stored in the legacy map are moved to per-entity keys
+    #[test]
+    fn test_migrate_legacy_entity_map() {
+        let (env, client) = setup_test_contract();
+        env.mock_all_auths();
+        client.initialize(&Address::generate(&env));
+
+        env.as_contract(&client.address, || {
+            let mut legacy: Map<u32, Val> = Map::new(&env);
+            legacy.set(0, (0u32, 4u32, 5u32, 80u32).into_val(&env));
+            legacy.set(1, (1u32, 6u32, 7u32, 100u32).into_val(&env));
+            env.storage().persistent().set(&symbol_short!("entities"), &legacy);
+        });
+        assert!(client.get_entity_position(&0).is_none());
+
+        assert_eq!(client.migrate_entity_storage(), 2);
+        assert_eq!(client.get_entity_position(&0).unwrap(), GamePosition(4, 5));
+        assert_eq!(client.get_entity_health(&0).unwrap().0, 80);
+        assert_eq!(client.get_entity_position(&1).unwrap(), GamePosition(6, 7));
+
+        // The legacy map is gone, so a second run does nothing
+        assert_eq!(client.migrate_entity_storage(), 0);
+        // New entities are numbered after the migrated ones
+        assert_eq!(client.spawn_entity(&0, &0), 2);
+    }
+
+    /// Test: Migration is admin-only
+    #[test]
+    fn test_migrate_requires_admin() {
+        let (_env, client) = setup_initialized_contract();
+        assert_eq!(client.try_migrate_entity_storage(), Err(Ok(GameError::Unauthorized)));
+    }
+}
+
+#[cfg(test)]
+mod team_tests {
+    use super::*;
+    use soroban_sdk::testutils::Address as _;
+    use soroban_sdk::Address;
+
+    /// Spawns an entity owned by `owner` on `team_id`
+    fn spawn_on_team(client: &GameWorldContractClient, owner: &Address, team_id: u32) -> u32 {
+        let entity_id = client.spawn_entity(&0, &0);
+        client.set_entity_owner(&entity_id, owner);
+        client.set_entity_team(&entity_id, &Some(team_id));
+        entity_id
+    }
+
+    /// Test: Team membership is tracked per team and updated on changes
+    #[test]
+    fn test_team_membership() {
+        let (env, client) = setup_admin_contract();
+        let owner = Address::generate(&env);
+        let a = spawn_on_team(&client, &owner, 1);
+        let b = spawn_on_team(&client, &owner, 1);
+
+        assert_eq!(client.get_team_members(&1).len(), 2);
+        assert_eq!(client.get_entity_team(&a), Some(game::Team(1)));
+
+        client.set_entity_team(&b, &Some(2));
+        assert_eq!(client.get_team_members(&1), soroban_sdk::vec![&env, a]);
+        assert_eq!(client.get_team_members(&2), soroban_sdk::vec![&env, b]);
+
+        client.set_entity_team(&b, &None);
+        assert!(client.get_team_members(&2).is_empty());
+        assert_eq!(client.get_entity_team(&b), None);
+
+        // Leaving the world also leaves the team
+        client.despawn_entity(&a);
+        assert!(client.get_team_members(&1).is_empty());
+    }
+
+    /// Test: Attacks between teammates are rejected unless friendly fire is enabled
+    #[test]
+    fn test_friendly_fire_rules() {
+        let (env, client) = setup_test_contract();
+        env.mock_all_auths();
+        client.initialize(&Address::generate(&env));
+        let owner = Address::generate(&env);
+        let attacker = spawn_on_team(&client, &owner, 1);
+        let ally = spawn_on_team(&client, &owner, 1);
+        let enemy = spawn_on_team(&client, &owner, 2);
+
+        assert_eq!(client.try_attack_with(&attacker, &ally), Err(Ok(GameError::FriendlyFire)));
+        client.attack_with(&attacker, &enemy);
+        assert_eq!(client.get_entity_health(&enemy).unwrap().0, 90);
+
+        client.set_friendly_fire(&true);
+        client.attack_with(&attacker, &ally);
+        assert_eq!(client.get_entity_health(&ally).unwrap().0, 90);
+    }
+
+    /// Test: Team changes and team attacks need an owned entity
+    #[test]
+    fn test_team_actions_require_owner() {
+        let (env, client) = setup_initialized_contract();
+        env.mock_all_auths();
+        let unowned = client.spawn_entity(&0, &0);
+        let target = client.spawn_entity(&1, &0);
+
+        assert_eq!(client.try_set_entity_team(&unowned, &Some(1)), Err(Ok(GameError::Unauthorized)));
+        assert_eq!(client.try_attack_with(&unowned, &target), Err(Ok(GameError::Unauthorized)));
+        assert_eq!(client.try_attack_with(&target, &target), Err(Ok(GameError::InvalidTarget)));
+    }
+}
+
+#[cfg(test)]
+mod projectile_tests {
+    use super::*;
+    use soroban_sdk::testutils::Address as _;
+    use soroban_sdk::Address;
+
+    /// Test: A projectile travels each tick and damages the entity it lands on
+    #[test]
+    fn test_projectile_hits_target() {
+        let (env, client) = setup_initialized_contract();
+        env.mock_all_auths();
+        let target = client.spawn_entity(&4, &0);
+        let arrow = client.spawn_projectile(&Address::generate(&env), &0, &0, &2, &0, &25, &3);
+
+        tick(&client);
+        assert_eq!(client.get_entity_position(&arrow).unwrap(), GamePosition(2, 0));
+        assert_eq!(client.get_projectile(&arrow).unwrap().ttl, 2);
+
+        tick(&client);
+        assert_eq!(client.get_entity_health(&target).unwrap().0, 75);
+        assert!(client.get_projectile(&arrow).is_none());
+        assert!(client.get_entity_position(&arrow).is_none());
+        assert_eq!(client.entity_count(), 1);
+    }
+
+    /// Test: A projectile that hits nothing despawns when its lifetime ends
+    #[test]
+    fn test_projectile_expires() {
+        let (env, client) = setup_initialized_contract();
+        env.mock_all_auths();
+        let arrow = client.spawn_projectile(&Address::generate(&env), &0, &0, &1, &1, &10, &2);
+
+        tick(&client);
+        assert_eq!(client.get_entity_position(&arrow).unwrap(), GamePosition(1, 1));
+        tick(&client);
+        assert!(client.get_entity_position(&arrow).is_none());
+        assert_eq!(client.entity_count(), 0);
+    }
+
+    /// Test: Projectiles pass over entities with the same owner
+    #[test]
+    fn test_projectile_ignores_owner_entities() {
+        let (env, client) = setup_admin_contract();
+        let owner = Address::generate(&env);
+        let own_entity = client.spawn_entity(&1, &0);
+        client.set_entity_owner(&own_entity, &owner);
+        client.spawn_projectile(&owner, &0, &0, &1, &0, &50, &1);
+
+        tick(&client);
+        assert_eq!(client.get_entity_health(&own_entity).unwrap().0, 100);
+        assert_eq!(client.entity_count(), 1);
+    }
+
+    /// Test: Harmless or zero-lifetime projectiles are rejected
+    #[test]
+    fn test_invalid_projectile() {
+        let (env, client) = setup_initialized_contract();
+        env.mock_all_auths();
+        let owner = Address::generate(&env);
+
+        assert_eq!(
+            client.try_spawn_projectile(&owner, &0, &0, &1, &0, &0, &3),
+            Err(Ok(GameError::InvalidProjectile))
+        );
+        assert_eq!(
+            client.try_spawn_projectile(&owner, &0, &0, &1, &0, &10, &0),
+            Err(Ok(GameError::InvalidProjectile))
+        );
+    }
+}
+
+#[cfg(test)]
+mod regeneration_tests {
+    use super::*;
+    use soroban_sdk::testutils::Address as _;
+    use soroban_sdk::Address;
+
+    /// Sets up an admin-initialized contract with one damaged, regenerating entity
+    fn setup_regenerating_entity<'a>() -> (Env, GameWorldContractClient<'a>, u32) {
+        let (env, client) = setup_test_contract();
+        env.mock_all_auths();
+        client.initialize(&Address::generate(&env));
+        env.ledger().set_timestamp(1_000);
+
+        let entity_id = client.spawn_entity(&0, &0);
+        for _ in 0..5 {
+            client.attack_entity(&entity_id);
+        }
+        client.set_regeneration(&entity_id, &4, &100);
+        (env, client, entity_id)
+    }
+
+    /// Test: Reads include regeneration accrued over ledger time
+    #[test]
+    fn test_regeneration_on_read() {
+        let (env, client, entity_id) = setup_regenerating_entity();
+        assert_eq!(client.get_entity_health(&entity_id).unwrap().0, 50);
+
+        env.ledger().set_timestamp(1_000 + 150);
+        assert_eq!(client.get_entity_health(&entity_id).unwrap().0, 58);
+
+        // Capped at MaxHealth
+        env.ledger().set_timestamp(1_000 + 60 * 60);
+        assert_eq!(client.get_entity_health(&entity_id).unwrap().0, 100);
+    }
+
+    /// Test: Mutations and ticks persist regeneration before applying changes
+    #[test]
+    fn test_regeneration_persisted_on_update() {
+        let (env, client, entity_id) = setup_regenerating_entity();
+
+        env.ledger().set_timestamp(1_000 + 5 * 60);
+        client.attack_entity(&entity_id);
+        assert_eq!(client.get_entity_health(&entity_id).unwrap().0, 60);
+        assert_eq!(client.get_regeneration(&entity_id).unwrap().last_update, 1_000 + 5 * 60);
+
+        env.ledger().set_timestamp(1_000 + 7 * 60);
+        tick(&client);
+        assert_eq!(client.get_entity_health(&entity_id).unwrap().0, 68);
+        assert_eq!(client.get_regeneration(&entity_id).unwrap().last_update, 1_000 + 7 * 60);
+    }
+
+    /// Test: Only the admin can grant regeneration
+    #[test]
+    fn test_set_regeneration_requires_admin() {
+        let (_env, client) = setup_initialized_contract();
+        let entity_id = client.spawn_entity(&0, &0);
+        assert_eq!(
+            client.try_set_regeneration(&entity_id, &1, &100),
+            Err(Ok(GameError::Unauthorized))
+        );
+    }
+}
+
+#[cfg(test)]
+mod loot_tests {
+    use super::*;
+    use soroban_sdk::testutils::Address as _;
+    use soroban_sdk::Address;
+
+    /// Kills an owned entity at (5, 5) and returns the ID of its loot drop
+    fn kill_at_five(env: &Env, client: &GameWorldContractClient) -> u32 {
+        let victim = client.spawn_entity(&5, &5);
+        client.set_entity_owner(&victim, &Address::generate(env));
+        for _ in 0..10 {
+            client.attack_entity(&victim);
+        }
+        victim + 1
+    }
+
+    /// Test: A dying entity leaves a loot drop at its death position
+    #[test]
+    fn test_death_drops_loot() {
+        let (env, client) = setup_admin_contract();
+        let loot_id = kill_at_five(&env, &client);
+
+        assert_eq!(client.get_entity_position(&loot_id).unwrap(), GamePosition(5, 5));
+        let loot = client.get_loot(&loot_id).unwrap();
+        assert!(loot.currency >= 1 && loot.currency <= LootSystem::MAX_CURRENCY);
+        assert!(loot.item_id < LootSystem::LOOT_TABLE_SIZE);
+        assert!(loot.quantity >= 1 && loot.quantity <= LootSystem::MAX_QUANTITY);
+    }
+
+    /// Test: Destroying a loot drop does not drop more loot
+    #[test]
+    fn test_loot_does_not_drop_loot() {
+        let (env, client) = setup_admin_contract();
+        let loot_id = kill_at_five(&env, &client);
+        for _ in 0..10 {
+            client.attack_entity(&loot_id);
+        }
+
+        assert!(client.get_entity_position(&loot_id).is_none());
+        assert_eq!(client.entity_count(), 0);
+        assert_eq!(client.dead_entity_count(), 2);
+    }
+
+    /// Test: Only combatants drop loot, not plain entities or projectiles
+    #[test]
+    fn test_non_combatants_do_not_drop_loot() {
+        let (env, client) = setup_admin_contract();
+        let plain = client.spawn_entity(&5, &5);
+        let projectile = client.spawn_projectile(&Address::generate(&env), &8, &8, &1, &0, &5, &5);
+        for _ in 0..10 {
+            client.attack_entity(&plain);
+            client.attack_entity(&projectile);
+        }
+
+        assert_eq!(client.dead_entity_count(), 2);
+        assert_eq!(client.entity_count(), 0);
+        assert!(client.get_loot(&(projectile + 1)).is_none());
+    }
+
+    /// Test: Picking up loot moves its contents into the collector's inventory
+    #[test]
+    fn test_pick_up_loot() {
+        let (env, client) = setup_admin_contract();
+        let collector = client.spawn_entity(&4, &4);
+        client.set_entity_owner(&collector, &Address::generate(&env));
+        let first = kill_at_five(&env, &client);
+        let second = kill_at_five(&env, &client);
+        let first_loot = client.get_loot(&first).unwrap();
+        let second_loot = client.get_loot(&second).unwrap();
+
+        assert!(client.get_inventory(&collector).is_none());
+        client.pick_up_loot(&collector, &first);
+        client.pick_up_loot(&collector, &second);
+
+        let inventory = client.get_inventory(&collector).unwrap();
+        assert_eq!(inventory.currency, first_loot.currency + second_loot.currency);
+        let held: u32 = inventory.items.values().iter().sum();
+        assert_eq!(held, first_loot.quantity + second_loot.quantity);
+        assert!(client.get_entity_position(&first).is_none());
+        assert!(client.get_loot(&first).is_none());
+        assert_eq!(client.entity_count(), 1);
+    }
+
+    /// Test: Loot can only be picked up by an owned collector standing next to it
+    #[test]
+    fn test_pick_up_loot_errors() {
+        let (env, client) = setup_admin_contract();
+        let collector = client.spawn_entity(&0, &0);
+        let loot_id = kill_at_five(&env, &client);
+        assert_eq!(
+            client.try_pick_up_loot(&collector, &loot_id),
+            Err(Ok(GameError::Unauthorized))
+        );
+
+        client.set_entity_owner(&collector, &Address::generate(&env));
+        assert_eq!(
+            client.try_pick_up_loot(&collector, &loot_id),
+            Err(Ok(GameError::NotAdjacent))
+        );
+
+        let bystander = client.spawn_entity(&1, &1);
+        assert_eq!(
+            client.try_pick_up_loot(&collector, &bystander),
+            Err(Ok(GameError::NotLoot))
+        );
+        assert_eq!(
+            client.try_pick_up_loot(&collector, &99),
+            Err(Ok(GameError::EntityNotFound))
+        );
+    }
+}
+
+#[cfg(test)]
+mod region_query_tests {
+    use super::*;
+    use game::{MAX_REGION_CELLS, MAX_REGION_PAGE_SIZE, REGION_CELL_SIZE};
+
+    /// Test: Only entities inside the rectangle are returned, with positions
+    #[test]
+    fn test_region_filters_by_rectangle() {
+        let (_env, client) = setup_initialized_contract();
+        let inside = client.spawn_entity(&2, &3);
+        client.spawn_entity(&20, &3);
+        let corner = client.spawn_entity(&5, &5);
+        client.spawn_entity(&2, &30);
+
+        let page = client.get_entities_in_region(&0, &0, &5, &5, &0, &10);
+        assert_eq!(page.entities.len(), 2);
+        assert_eq!(page.entities.get(0).unwrap(), (inside, GamePosition(2, 3)));
+        assert_eq!(page.entities.get(1).unwrap(), (corner, GamePosition(5, 5)));
+        assert_eq!(page.next_cursor, None);
+
+        // Corners given in reverse order describe the same region
+        assert_eq!(client.get_entities_in_region(&5, &5, &0, &0, &0, &10), page);
+    }
+
+    /// Test: Large worlds are scanned page by page through the cursor
+    #[test]
+    fn test_region_pagination() {
+        let (_env, client) = setup_initialized_contract();
+        for i in 0..5 {
+            client.spawn_entity(&i, &0);
+        }
+
+        // The first page scans entities 0 and 1, only one of which matches
+        let first = client.get_entities_in_region(&1, &0, &10, &0, &0, &2);
+        assert_eq!(first.entities.len(), 1);
+        assert_eq!(first.entities.get(0).unwrap().0, 1);
+        assert_eq!(first.next_cursor, Some(2));
+
+        let second = client.get_entities_in_region(&1, &0, &10, &0, &2, &2);
+        assert_eq!(second.entities.len(), 2);
+        assert_eq!(second.next_cursor, Some(4));
+
+        let third = client.get_entities_in_region(&1, &0, &10, &0, &4, &2);
+        assert_eq!(third.entities.len(), 1);
+        assert_eq!(third.next_cursor, None);
+    }
+
+    /// Test: Removed entities are skipped and the page size is capped
+    #[test]
+    fn test_region_skips_removed_and_caps_limit() {
+        let (_env, client) = setup_initialized_contract();
+        for _ in 0..MAX_REGION_PAGE_SIZE + 5 {
+            client.spawn_entity(&0, &0);
+        }
+        client.despawn_entity(&0);
+
+        let page = client.get_entities_in_region(&0, &0, &0, &0, &0, &u32::MAX);
+        assert_eq!(page.entities.len(), MAX_REGION_PAGE_SIZE);
+        assert_eq!(page.entities.get(0).unwrap().0, 1);
+        assert_eq!(page.next_cursor, Some(MAX_REGION_PAGE_SIZE));
+    }
+
+    /// Test: Only the cells the region overlaps are scanned, and entities follow their moves
+    #[test]
+    fn test_region_reads_overlapping_cells() {
+        let (_env, client) = setup_initialized_contract();
+        let edge = client.spawn_entity(&(REGION_CELL_SIZE - 1), &0);
+        let far = client.spawn_entity(&(REGION_CELL_SIZE * 3), &0);
+        let next_cell = client.spawn_entity(&REGION_CELL_SIZE, &1);
+
+        // The far entity's cell is outside the region, so it isn't even scanned
+        let page = client.get_entities_in_region(&0, &0, &REGION_CELL_SIZE, &1, &0, &2);
+        assert_eq!(page.entities.len(), 2);
+        assert_eq!(page.entities.get(0).unwrap().0, edge);
+        assert_eq!(page.entities.get(1).unwrap().0, next_cell);
+        assert_eq!(page.next_cursor, None);
+
+        // Stepping over a cell border moves the entity to the next cell
+        client.move_entity(&edge, &1, &0);
+        let page = client.get_entities_in_region(&0, &0, &(REGION_CELL_SIZE - 1), &1, &0, &10);
+        assert!(page.entities.is_empty());
+        let page = client.get_entities_in_region(&REGION_CELL_SIZE, &0, &REGION_CELL_SIZE, &1, &0, &10);
+        assert_eq!(page.entities.len(), 2);
+        assert_eq!(page.entities.get(0).unwrap().0, next_cell);
+        assert_eq!(page.entities.get(1).unwrap(), (edge, GamePosition(REGION_CELL_SIZE, 0)));
+
+        // Despawned entities leave their cell
+        client.despawn_entity(&far);
+        let page = client.get_entities_in_region(&0, &0, &(REGION_CELL_SIZE * 4), &0, &0, &10);
+        assert_eq!(page.entities.len(), 1);
+        assert_eq!(page.entities.get(0).unwrap().0, edge);
+    }
+
+    /// Test: Regions spanning too many cells are refused
+    #[test]
+    fn test_region_too_large() {
+        let (_env, client) = setup_initialized_contract();
+        let edge = REGION_CELL_SIZE * MAX_REGION_CELLS - 1;
+        assert!(client.try_get_entities_in_region(&0, &0, &edge, &0, &0, &10).is_ok());
+        assert_eq!(
+            client.try_get_entities_in_region(&0, &0, &(edge + 1), &0, &0, &10),
+            Err(Ok(GameError::RegionTooLarge))
+        );
+        assert_eq!(
+            client.try_get_entities_in_region(&0, &0, &u32::MAX, &u32::MAX, &0, &10),
+            Err(Ok(GameError::RegionTooLarge))
+        );
+    }
+}
+
+#[cfg(test)]
+mod pathfinding_tests {
+    use super::*;
+    use game::Grid;
+    use soroban_sdk::{vec, Vec as SorobanVec};
+
+    /// A 6x6 map with a wall at x = 2 that is open only at y = 4
+    fn walled_grid(env: &Env) -> Grid {
+        Grid {
+            width: 6,
+            height: 6,
+            blocked: vec![env, GamePosition(2, 0), GamePosition(2, 1), GamePosition(2, 2), GamePosition(2, 3), GamePosition(2, 5)],
+        }
+    }
+
+    /// Test: find_path exposes the A* Pathfinder
+    #[test]
+    fn test_find_path() {
+        let (env, client) = setup_initialized_contract();
+        let path = client
+            .find_path(&walled_grid(&env), &GamePosition(0, 0), &GamePosition(4, 0), &100)
+            .unwrap();
+        assert!(path.contains(GamePosition(2, 4)));
+        assert_eq!(path.last().unwrap(), GamePosition(4, 0));
+        assert!(client
+            .find_path(&walled_grid(&env), &GamePosition(0, 0), &GamePosition(2, 0), &100)
+            .is_none());
+    }
+
+    /// Test: An entity following a path takes one step per tick until it arrives
+    #[test]
+    fn test_move_along_path_one_step_per_tick() {
+        let (env, client) = setup_initialized_contract();
+        let entity_id = client.spawn_entity(&0, &0);
+        let steps = client.move_along_path(&entity_id, &walled_grid(&env), &GamePosition(4, 0), &100);
+        let path: SorobanVec<GamePosition> = client.get_entity_path(&entity_id).unwrap();
+        assert_eq!(path.len(), steps);
+
+        for step in path.iter() {
+            tick(&client);
+            assert_eq!(client.get_entity_position(&entity_id).unwrap(), step);
+        }
+        assert_eq!(client.get_entity_position(&entity_id).unwrap(), GamePosition(4, 0));
+        assert!(client.get_entity_path(&entity_id).is_none());
+
+        // Arrived entities stay put
+        tick(&client);
+        assert_eq!(client.get_entity_position(&entity_id).unwrap(), GamePosition(4, 0));
+    }
+
+    /// Test: Moving an entity off its path cancels it
+    #[test]
+    fn test_manual_move_interrupts_path() {
+        let (env, client) = setup_initialized_contract();
+        let entity_id = client.spawn_entity(&0, &0);
+        client.move_along_path(&entity_id, &walled_grid(&env), &GamePosition(0, 5), &100);
+        tick(&client);
+        client.move_entity(&entity_id, &5, &0);
+        tick(&client);
+
+        assert_eq!(client.get_entity_position(&entity_id).unwrap(), GamePosition(5, 1));
+        assert!(client.get_entity_path(&entity_id).is_none());
+    }
+
+    /// Test: Unreachable goals are rejected without touching the entity
+    #[test]
+    fn test_move_along_path_no_path() {
+        let (env, client) = setup_initialized_contract();
+        let entity_id = client.spawn_entity(&0, &0);
+        assert_eq!(
+            client.try_move_along_path(&entity_id, &walled_grid(&env), &GamePosition(5, 0), &3),
+            Err(Ok(GameError::NoPath))
+        );
+        assert_eq!(
+            client.try_move_along_path(&entity_id, &walled_grid(&env), &GamePosition(2, 0), &100),
+            Err(Ok(GameError::NoPath))
+        );
+        assert!(client.get_entity_path(&entity_id).is_none());
+    }
+}
+
+#[cfg(test)]
+mod checkpoint_tests {
+    use super::*;
+    use game::{CHECKPOINT_VERSION, MAX_CHECKPOINT_ENTITIES};
+    use soroban_sdk::testutils::Address as _;
+    use soroban_sdk::{symbol_short, vec, Address, IntoVal, Map, Symbol, Val};
+
+    /// Test: Restoring rolls back positions and health
+    #[test]
+    fn test_restore_rolls_world_back() {
+        let (_env, client) = setup_admin_contract();
+        let mover = client.spawn_entity(&1, &1);
+        client.attack_entity(&mover);
+        client.create_checkpoint(&symbol_short!("start"));
+
+        client.move_entity(&mover, &3, &0);
+        client.attack_entity(&mover);
+        client.restore_checkpoint(&symbol_short!("start"));
+
+        assert_eq!(client.get_entity_position(&mover).unwrap(), GamePosition(1, 1));
+        assert_eq!(client.get_entity_health(&mover).unwrap().0, 90);
+        assert_eq!(client.entity_count(), 1);
+    }
+
+    /// Test: Restoring rolls back deaths, spawns and counters
+    ///
+    /// Kept apart from the position test, since a restore touches every
+    /// component key of every entity and the footprint grows with the world.
+    #[test]
+    fn test_restore_rolls_deaths_back() {
+        let (env, client) = setup_admin_contract();
+        let victim = client.spawn_entity(&5, &5);
+        client.set_entity_owner(&victim, &soroban_sdk::Address::generate(&env));
+        client.create_checkpoint(&symbol_short!("start"));
+
+        for _ in 0..10 {
+            client.attack_entity(&victim);
+        }
+        // The victim's loot drop is the only entity created since the checkpoint
+        let late = victim + 1;
+        assert!(client.get_loot(&late).is_some());
+        client.restore_checkpoint(&symbol_short!("start"));
+
+        assert_eq!(client.get_entity_position(&victim).unwrap(), GamePosition(5, 5));
+        assert!(client.get_entity_position(&late).is_none());
+        assert_eq!(client.entity_count(), 1);
+        assert_eq!(client.dead_entity_count(), 0);
+        assert_eq!(client.get_entities_in_region(&5, &5, &5, &5, &0, &10).entities.len(), 1);
+
+        // The victim can be attacked again and IDs issued after the checkpoint stay retired
+        client.attack_entity(&victim);
+        assert_eq!(client.get_entity_health(&victim).unwrap().0, 90);
+        assert!(client.spawn_entity(&0, &0) > late);
+    }
+
+    /// Test: Components and team membership are restored with their entities
+    #[test]
+    fn test_restore_components() {
+        let (env, client) = setup_admin_contract();
+        let owner = Address::generate(&env);
+        let entity_id = client.spawn_entity(&0, &0);
+        client.set_entity_owner(&entity_id, &owner);
+        client.set_entity_team(&entity_id, &Some(3));
+        client.apply_effect(&entity_id, &EffectKind::Shielded, &5, &1_000);
+        client.create_checkpoint(&symbol_short!("teams"));
+
+        client.despawn_entity(&entity_id);
+        assert!(client.get_team_members(&3).is_empty());
+        client.restore_checkpoint(&symbol_short!("teams"));
+
+        assert_eq!(client.get_entity_owner(&entity_id), Some(owner));
+        assert_eq!(client.get_entity_team(&entity_id).unwrap().0, 3);
+        assert_eq!(client.get_team_members(&3), vec![&env, entity_id]);
+        assert_eq!(client.get_effects(&entity_id).len(), 1);
+    }
+
+    /// Test: A restore emits checkpoint_restored
+    #[test]
+    fn test_restore_emits_event() {
+        let (env, client) = setup_admin_contract();
+        client.spawn_entity(&0, &0);
+        client.create_checkpoint(&symbol_short!("save"));
+        client.restore_checkpoint(&symbol_short!("save"));
+
+        let mut data = Map::<Symbol, Val>::new(&env);
+        data.set(Symbol::new(&env, "entity_count"), 1u32.into());
+        data.set(Symbol::new(&env, "version"), CHECKPOINT_VERSION.into());
+        assert_eq!(
+            env.events().all(),
+            vec![
+                &env,
+                (
+                    client.address.clone(),
+                    (Symbol::new(&env, "checkpoint_restored"), symbol_short!("save")).into_val(&env),
+                    data.into_val(&env),
+                )
+            ]
+        );
+        assert_eq!(client.get_checkpoint(&symbol_short!("save")).unwrap().version, CHECKPOINT_VERSION);
+    }
+
+    /// Test: A restore touching as many entities as allowed fits in one transaction
+    #[test]
+    fn test_restore_at_entity_limit() {
+        let (env, client) = setup_admin_contract();
+        let mut saved = vec![&env];
+        for i in 1..MAX_CHECKPOINT_ENTITIES {
+            saved.push_back(client.spawn_entity(&i, &0));
+        }
+        client.create_checkpoint(&symbol_short!("full"));
+        for entity_id in saved.iter() {
+            client.despawn_entity(&entity_id);
+        }
+        let late = client.spawn_entity(&0, &40);
+
+        client.restore_checkpoint(&symbol_short!("full"));
+        assert_eq!(client.entity_count(), MAX_CHECKPOINT_ENTITIES - 1);
+        assert!(client.get_entity_position(&late).is_none());
+    }
+
+    /// Test: Checkpoints are admin-only and must exist to be restored
+    #[test]
+    fn test_checkpoint_errors() {
+        let (_env, client) = setup_admin_contract();
+        assert_eq!(
+            client.try_restore_checkpoint(&symbol_short!("missing")),
+            Err(Ok(GameError::CheckpointNotFound))
+        );
+
+        let (_env, client) = setup_initialized_contract();
+        assert_eq!(
+            client.try_create_checkpoint(&symbol_short!("save")),
+            Err(Ok(GameError::Unauthorized))
+        );
+
+        // Worlds too large to save or restore in one transaction are refused
+        let (_env, client) = setup_admin_contract();
+        client.create_checkpoint(&symbol_short!("save"));
+        for i in 0..=MAX_CHECKPOINT_ENTITIES {
+            client.spawn_entity(&i, &0);
+        }
+        assert_eq!(
+            client.try_create_checkpoint(&symbol_short!("save")),
+            Err(Ok(GameError::WorldTooLarge))
+        );
+        assert_eq!(
+            client.try_restore_checkpoint(&symbol_short!("save")),
+            Err(Ok(GameError::WorldTooLarge))
+        );
+    }
+}
+
+#[cfg(test)]
+mod world_stats_tests {
+    use super::*;
+
+    /// Test: A fresh world reports zeroed statistics
+    #[test]
+    fn test_empty_world_stats() {
+        let (_env, client) = setup_initialized_contract();
+        let stats = client.get_world_stats();
+        assert_eq!(stats.live_entities, 0);
+        assert_eq!(stats.dead_entities, 0);
+        assert_eq!(stats.total_spawns, 0);
+        assert_eq!(stats.total_moves, 0);
+        assert_eq!(stats.total_damage, 0);
+        assert_eq!(stats.last_tick, 0);
+    }
+
+    /// Test: Spawns, moves, damage, deaths and ticks update the counters
+    #[test]
+    fn test_world_stats_track_systems() {
+        let (env, client) = setup_admin_contract();
+        let walker = client.spawn_entity(&0, &0);
+        let victim = client.spawn_entity(&5, &5);
+        client.move_entity(&walker, &1, &0);
+        client.move_entity(&walker, &0, &1);
+        client.attack_entity(&walker);
+        for _ in 0..10 {
+            client.attack_entity(&victim);
+        }
+
+        env.ledger().set_timestamp(4_242);
+        client.apply_effect(&walker, &EffectKind::Poisoned, &2, &60);
+        env.ledger().set_timestamp(4_252);
+        tick(&client);
+
+        let stats = client.get_world_stats();
+        assert_eq!(stats.total_spawns, 2);
+        assert_eq!(stats.live_entities, 1);
+        assert_eq!(stats.dead_entities, 1);
+        assert_eq!(stats.total_moves, 2);
+        // One attack, the lethal hits on the victim and two poison ticks of 2
+        assert_eq!(stats.total_damage, 10 + 100 + 4);
+        assert_eq!(stats.last_tick, 4_252);
+    }
+}
+
+mod weather_tests {
+    use super::*;
+    use game::{Weather, WeatherSystem};
+
+    /// Test: The world starts clear and leaves movement and damage unchanged
+    #[test]
+    fn test_clear_weather_by_default() {
+        let (_env, client) = setup_initialized_contract();
+        let weather = client.get_weather();
+        assert_eq!(weather.weather, Weather::Clear);
+        assert_eq!(weather.rotation_period, 0);
+
+        let entity = client.spawn_entity(&0, &0);
+        client.move_entity(&entity, &4, &2);
+        client.attack_entity(&entity);
+        assert_eq!(client.get_entity_position(&entity), Some(GamePosition(4, 2)));
+        assert_eq!(client.get_entity_health(&entity), Some(Health(90)));
+    }
+
+    /// Test: A storm halves movement and fog weakens attacks
+    #[test]
+    fn test_weather_modifies_systems() {
+        let (_env, client) = setup_admin_contract();
+        let entity = client.spawn_entity(&0, &0);
+
+        client.set_weather(&Weather::Storm);
+        client.move_entity(&entity, &4, &2);
+        assert_eq!(client.get_entity_position(&entity), Some(GamePosition(2, 1)));
+        client.attack_entity(&entity);
+        assert_eq!(client.get_entity_health(&entity), Some(Health(90)));
+
+        client.set_weather(&Weather::Fog);
+        client.attack_entity(&entity);
+        assert_eq!(client.get_entity_health(&entity), Some(Health(83)));
+        assert_eq!(WeatherSystem::aggro_radius(Weather::Night, 5), 10);
+    }
+
+    /// Test: Ticks rotate the weather once each period has elapsed
+    #[test]
+    fn test_tick_rotates_weather() {
+        let (env, client) = setup_admin_contract();
+        env.ledger().set_timestamp(1_000);
+        client.set_weather_rotation(&60);
+
+        env.ledger().set_timestamp(1_059);
+        tick(&client);
+        assert_eq!(client.get_weather().weather, Weather::Clear);
+
+        env.ledger().set_timestamp(1_060);
+        tick(&client);
+        assert_eq!(client.get_weather().weather, Weather::Storm);
+
+        env.ledger().set_timestamp(1_200);
+        tick(&client);
+        let weather = client.get_weather();
+        assert_eq!(weather.weather, Weather::Fog);
+        assert_eq!(weather.changed_at, 1_180);
+    }
+
+    /// Test: Only the admin can change the weather
+    #[test]
+    fn test_set_weather_requires_admin() {
+        let (_env, client) = setup_initialized_contract();
+        assert_eq!(client.try_set_weather(&Weather::Night), Err(Ok(GameError::Unauthorized)));
+        assert_eq!(client.get_weather().weather, Weather::Clear);
+    }
+}
+
+mod interactable_tests {
+    use super::*;
+    use game::{Grid, Interactable, InteractableKind, Loot};
+    use soroban_sdk::testutils::Address as _;
+    use soroban_sdk::{symbol_short, vec, Address, Vec};
+
+    /// Spawns an owned entity at `(x, y)`
+    fn spawn_player(env: &Env, client: &GameWorldContractClient, x: u32, y: u32) -> u32 {
+        let player = client.spawn_entity(&x, &y);
+        client.set_entity_owner(&player, &Address::generate(env));
+        player
+    }
+
+    fn interactable(env: &Env, kind: InteractableKind) -> Interactable {
+        Interactable {
+            kind,
+            active: false,
+            key_item: None,
+            linked: None,
+            contents: Vec::new(env),
+        }
+    }
+
+    /// A 3x3 room split by a wall at x = 1, with a door at (1, 1)
+    fn walled_grid(env: &Env) -> Grid {
+        Grid {
+            width: 3,
+            height: 3,
+            blocked: vec![env, GamePosition(1, 0), GamePosition(1, 2)],
+        }
+    }
+
+    /// Test: A closed door blocks pathfinding until it is opened
+    #[test]
+    fn test_door_blocks_tile_until_opened() {
+        let (env, client) = setup_admin_contract();
+        let door = client.spawn_interactable(&1, &1, &interactable(&env, InteractableKind::Door));
+        let player = spawn_player(&env, &client, 0, 1);
+        assert_eq!(client.get_blocked_tiles(), vec![&env, GamePosition(1, 1)]);
+        assert!(client.find_path(&walled_grid(&env), &GamePosition(0, 1), &GamePosition(2, 1), &50).is_none());
+
+        assert!(client.interact(&player, &door));
+        assert!(client.get_interactable(&door).unwrap().active);
+        assert!(client.get_blocked_tiles().is_empty());
+        let path = client.find_path(&walled_grid(&env), &GamePosition(0, 1), &GamePosition(2, 1), &50).unwrap();
+        assert_eq!(path, vec![&env, GamePosition(1, 1), GamePosition(2, 1)]);
+
+        assert!(!client.interact(&player, &door));
+        assert_eq!(client.get_blocked_tiles(), vec![&env, GamePosition(1, 1)]);
+    }
+
+    /// Test: Closing a door stops entities whose path leads through it
+    #[test]
+    fn test_closing_door_interrupts_path() {
+        let (env, client) = setup_admin_contract();
+        let mut open_door = interactable(&env, InteractableKind::Door);
+        open_door.active = true;
+        let door = client.spawn_interactable(&1, &1, &open_door);
+        let player = spawn_player(&env, &client, 0, 0);
+        let walker = client.spawn_entity(&0, &1);
+
+        assert_eq!(client.move_along_path(&walker, &walled_grid(&env), &GamePosition(2, 1), &50), 2);
+        client.interact(&player, &door);
+        tick(&client);
+        assert_eq!(client.get_entity_position(&walker), Some(GamePosition(0, 1)));
+        assert!(client.get_entity_path(&walker).is_none());
+        assert_eq!(
+            client.try_move_along_path(&walker, &walled_grid(&env), &GamePosition(2, 1), &50),
+            Err(Ok(GameError::NoPath))
+        );
+    }
+
+    /// Test: A switch toggles its linked door along with itself
+    #[test]
+    fn test_switch_toggles_linked_door() {
+        let (env, client) = setup_admin_contract();
+        let door = client.spawn_interactable(&5, &5, &interactable(&env, InteractableKind::Door));
+        let mut lever = interactable(&env, InteractableKind::Switch);
+        lever.linked = Some(door);
+        let lever = client.spawn_interactable(&0, &0, &lever);
+        let player = spawn_player(&env, &client, 1, 0);
+
+        assert!(client.interact(&player, &lever));
+        assert!(client.get_interactable(&door).unwrap().active);
+        assert!(client.get_blocked_tiles().is_empty());
+
+        assert!(!client.interact(&player, &lever));
+        assert!(!client.get_interactable(&door).unwrap().active);
+        assert_eq!(client.get_blocked_tiles(), vec![&env, GamePosition(5, 5)]);
+    }
+
+    /// Test: Chests hand out their contents once and may require a key item
+    #[test]
+    fn test_chest_contents_and_keys() {
+        let (env, client) = setup_admin_contract();
+        let mut key_chest = interactable(&env, InteractableKind::Chest);
+        key_chest.contents = vec![&env, Loot { currency: 5, item_id: 7, quantity: 1 }];
+        let key_chest = client.spawn_interactable(&0, &0, &key_chest);
+        let mut vault = interactable(&env, InteractableKind::Chest);
+        vault.key_item = Some(7);
+        vault.contents = vec![&env, Loot { currency: 100, item_id: 3, quantity: 2 }];
+        let vault = client.spawn_interactable(&2, &0, &vault);
+        let player = spawn_player(&env, &client, 1, 0);
+
+        assert_eq!(client.try_interact(&player, &vault), Err(Ok(GameError::MissingKey)));
+        client.interact(&player, &key_chest);
+        assert!(client.get_interactable(&key_chest).unwrap().contents.is_empty());
+        client.interact(&player, &vault);
+
+        let inventory = client.get_inventory(&player).unwrap();
+        assert_eq!(inventory.currency, 105);
+        assert_eq!(inventory.items.get(7), Some(1));
+        assert_eq!(inventory.items.get(3), Some(2));
+
+        // Closing and reopening an emptied chest grants nothing more
+        client.interact(&player, &vault);
+        client.interact(&player, &vault);
+        assert_eq!(client.get_inventory(&player).unwrap().currency, 105);
+        assert!(client.get_blocked_tiles().is_empty());
+    }
+
+    /// Test: Interactions require an owned, adjacent entity and an interactable target
+    #[test]
+    fn test_interact_errors() {
+        let (env, client) = setup_admin_contract();
+        let door = client.spawn_interactable(&5, &5, &interactable(&env, InteractableKind::Door));
+        let player = spawn_player(&env, &client, 0, 0);
+        let unowned = client.spawn_entity(&4, &4);
+        let rock = client.spawn_entity(&1, &1);
+
+        assert_eq!(client.try_interact(&player, &door), Err(Ok(GameError::NotAdjacent)));
+        assert_eq!(client.try_interact(&unowned, &door), Err(Ok(GameError::Unauthorized)));
+        assert_eq!(client.try_interact(&player, &rock), Err(Ok(GameError::NotInteractable)));
+        assert_eq!(client.try_interact(&player, &player), Err(Ok(GameError::InvalidTarget)));
+        assert_eq!(client.try_interact(&player, &99), Err(Ok(GameError::EntityNotFound)));
+    }
+
+    /// Test: Only the admin can place interactables
+    #[test]
+    fn test_spawn_interactable_requires_admin() {
+        let (env, client) = setup_initialized_contract();
+        let door = interactable(&env, InteractableKind::Door);
+        assert_eq!(client.try_spawn_interactable(&0, &0, &door), Err(Ok(GameError::Unauthorized)));
+    }
+
+    /// Test: Removing a door or restoring a checkpoint keeps blocked tiles in sync
+    #[test]
+    fn test_blocked_tiles_follow_despawn_and_restore() {
+        let (env, client) = setup_admin_contract();
+        let door = client.spawn_interactable(&1, &1, &interactable(&env, InteractableKind::Door));
+        client.create_checkpoint(&symbol_short!("closed"));
+
+        client.despawn_entity(&door);
+        assert!(client.get_blocked_tiles().is_empty());
+
+        client.restore_checkpoint(&symbol_short!("closed"));
+        assert_eq!(client.get_blocked_tiles(), vec![&env, GamePosition(1, 1)]);
+        assert!(client.get_interactable(&door).is_some());
+    }
+}
+
+mod line_of_sight_tests {
+    use super::*;
+    use game::{Interactable, InteractableKind};
+    use soroban_sdk::testutils::Address as _;
+    use soroban_sdk::{vec, Address, Vec};
+
+    /// Spawns an owned archer at (0, 1) and a target at (4, 1)
+    fn setup_duel(env: &Env, client: &GameWorldContractClient) -> (u32, u32) {
+        let archer = client.spawn_entity(&0, &1);
+        client.set_entity_owner(&archer, &Address::generate(env));
+        let target = client.spawn_entity(&4, &1);
+        (archer, target)
+    }
+
+    /// Test: A ranged attack hits a target in the open within range
+    #[test]
+    fn test_ranged_attack_in_open() {
+        let (env, client) = setup_admin_contract();
+        let (archer, target) = setup_duel(&env, &client);
+        client.ranged_attack(&archer, &target);
+        assert_eq!(client.get_entity_health(&target), Some(Health(90)));
+
+        let distant = client.spawn_entity(&0, &8);
+        assert_eq!(client.try_ranged_attack(&archer, &distant), Err(Ok(GameError::OutOfRange)));
+    }
+
+    /// Test: Walls block ranged attacks, but only tiles on the line matter
+    #[test]
+    fn test_walls_block_ranged_attacks() {
+        let (env, client) = setup_admin_contract();
+        let (archer, target) = setup_duel(&env, &client);
+        // A wall at x = 2 with a gap at y = 3
+        for y in [0, 1, 2, 4] {
+            client.set_wall(&2, &y, &true);
+        }
+
+        assert_eq!(client.try_ranged_attack(&archer, &target), Err(Ok(GameError::NoLineOfSight)));
+        let through_gap = client.spawn_entity(&4, &5);
+        client.ranged_attack(&archer, &through_gap);
+        assert_eq!(client.get_entity_health(&through_gap), Some(Health(90)));
+
+        client.set_wall(&2, &1, &false);
+        client.ranged_attack(&archer, &target);
+        assert_eq!(client.get_entity_health(&target), Some(Health(90)));
+        assert_eq!(client.get_blocked_tiles().len(), 3);
+    }
+
+    /// Test: A closed door blocks sight until it is opened
+    #[test]
+    fn test_doors_block_ranged_attacks() {
+        let (env, client) = setup_admin_contract();
+        let (archer, target) = setup_duel(&env, &client);
+        let door = Interactable {
+            kind: InteractableKind::Door,
+            active: false,
+            key_item: None,
+            linked: None,
+            contents: Vec::new(&env),
+        };
+        let door = client.spawn_interactable(&1, &1, &door);
+
+        assert_eq!(client.try_ranged_attack(&archer, &target), Err(Ok(GameError::NoLineOfSight)));
+        client.interact(&archer, &door);
+        client.ranged_attack(&archer, &target);
+        assert_eq!(client.get_entity_health(&target), Some(Health(90)));
+    }
+
+    /// Test: Projectiles stop at walls, even when they would fly past them
+    #[test]
+    fn test_walls_stop_projectiles() {
+        let (env, client) = setup_admin_contract();
+        let owner = Address::generate(&env);
+        let target = client.spawn_entity(&4, &0);
+        client.set_wall(&2, &0, &true);
+        let projectile = client.spawn_projectile(&owner, &0, &0, &4, &0, &25, &3);
+
+        tick(&client);
+        assert!(client.get_entity_position(&projectile).is_none());
+        assert_eq!(client.get_entity_health(&target), Some(Health(100)));
+        assert_eq!(client.get_blocked_tiles(), vec![&env, GamePosition(2, 0)]);
+    }
+
+    /// Test: Only the admin can place walls
+    #[test]
+    fn test_set_wall_requires_admin() {
+        let (_env, client) = setup_initialized_contract();
+        assert_eq!(client.try_set_wall(&1, &1, &true), Err(Ok(GameError::Unauthorized)));
+    }
+}
+
+#[cfg(test)]
+mod world_event_tests {
+    use super::*;
+    use game::WorldEventKind;
+    use soroban_sdk::vec;
+
+    /// Test: A boss spawns on the first tick at or after its start ledger
+    #[test]
+    fn test_boss_spawns_at_start_ledger() {
+        let (env, client) = setup_admin_contract();
+        env.ledger().set_sequence_number(5);
+        let event_id = client.schedule_world_event(&WorldEventKind::BossSpawn, &10, &vec![&env, 3, 4, 500]);
+        assert_eq!(client.get_world_events().get(event_id).unwrap().start_seq, 10);
+
+        env.ledger().set_sequence_number(9);
+        tick(&client);
+        assert_eq!(client.entity_count(), 0);
+
+        env.ledger().set_sequence_number(12);
+        tick(&client);
+        assert_eq!(client.entity_count(), 1);
+        assert_eq!(client.get_entity_position(&0), Some(GamePosition(3, 4)));
+        assert_eq!(client.get_entity_health(&0), Some(Health(500)));
+        assert_eq!(client.get_boss(&0).unwrap().reward_currency, 50);
+        assert!(client.get_world_events().is_empty());
+    }
+
+    /// Test: A double-XP window lasts for its duration in ledgers
+    #[test]
+    fn test_double_xp_window() {
+        let (env, client) = setup_admin_contract();
+        client.schedule_world_event(&WorldEventKind::DoubleXp, &0, &vec![&env, 10, 200]);
+        assert_eq!(client.get_xp_multiplier(), 100);
+
+        env.ledger().set_sequence_number(20);
+        tick(&client);
+        assert_eq!(client.get_xp_multiplier(), 200);
+        env.ledger().set_sequence_number(30);
+        assert_eq!(client.get_xp_multiplier(), 100);
+    }
+
+    /// Test: Meteors strike entities on their tile and then burn out
+    #[test]
+    fn test_meteor_shower_damages_entities() {
+        let (env, client) = setup_admin_contract();
+        let entity = client.spawn_entity(&2, &2);
+        client.schedule_world_event(&WorldEventKind::MeteorShower, &0, &vec![&env, 2, 2, 1, 1, 1, 30]);
+
+        tick(&client);
+        assert_eq!(client.get_entity_health(&entity), Some(Health(70)));
+        assert_eq!(client.entity_count(), 1);
+    }
+
+    /// Test: Invalid parameters, past start ledgers and non-admins are rejected
+    #[test]
+    fn test_schedule_world_event_errors() {
+        let (env, client) = setup_admin_contract();
+        env.ledger().set_sequence_number(10);
+        assert_eq!(
+            client.try_schedule_world_event(&WorldEventKind::BossSpawn, &9, &vec![&env, 3, 4, 500]),
+            Err(Ok(GameError::InvalidWorldEvent))
+        );
+        assert_eq!(
+            client.try_schedule_world_event(&WorldEventKind::MeteorShower, &10, &vec![&env, 0, 0, 5, 5, 50, 10]),
+            Err(Ok(GameError::InvalidWorldEvent))
+        );
+
+        let (env, client) = setup_initialized_contract();
+        assert_eq!(
+            client.try_schedule_world_event(&WorldEventKind::DoubleXp, &0, &vec![&env, 10, 200]),
+            Err(Ok(GameError::Unauthorized))
+        );
+    }
+}
+
+#[cfg(test)]
+mod boss_tests {
+    use super::*;
+    use game::{BossBehavior, BossPhase, BossSystem};
+    use soroban_sdk::testutils::Address as _;
+    use soroban_sdk::{vec, Address, Vec};
+
+    /// Spawns an entity at `(x, y)` owned by a fresh address
+    fn spawn_player(env: &Env, client: &GameWorldContractClient, x: u32, y: u32) -> u32 {
+        let entity_id = client.spawn_entity(&x, &y);
+        client.set_entity_owner(&entity_id, &Address::generate(env));
+        entity_id
+    }
+
+    fn phase(threshold_percent: u32, behavior: BossBehavior, damage: u32) -> BossPhase {
+        BossPhase { threshold_percent, behavior, damage }
+    }
+
+    /// Test: Damage is attributed per attacker and the boss enters its next phase at the threshold
+    #[test]
+    fn test_boss_phase_and_attribution() {
+        let (env, client) = setup_admin_contract();
+        let boss = client.spawn_boss(&5, &5, &100, &BossSystem::default_phases(&env, 10), &100);
+        let first = spawn_player(&env, &client, 5, 6);
+        let second = spawn_player(&env, &client, 6, 6);
+
+        client.attack_with(&first, &boss);
+        for _ in 0..3 {
+            client.attack_with(&second, &boss);
+        }
+        assert_eq!(client.get_boss(&boss).unwrap().phase, 0);
+        client.attack_with(&second, &boss);
+
+        let state = client.get_boss(&boss).unwrap();
+        assert_eq!(client.get_entity_health(&boss), Some(Health(50)));
+        assert_eq!(state.phase, 1);
+        assert_eq!(state.damage_by.get(first), Some(10));
+        assert_eq!(state.damage_by.get(second), Some(40));
+
+        // Unattributed damage hurts the boss without earning a share
+        client.attack_entity(&boss);
+        assert_eq!(client.get_boss(&boss).unwrap().damage_by.len(), 2);
+    }
+
+    /// Test: A dead boss splits its reward among contributors by damage dealt
+    #[test]
+    fn test_boss_rewards_contributors() {
+        let (env, client) = setup_admin_contract();
+        let boss = client.spawn_boss(&5, &5, &40, &vec![&env, phase(100, BossBehavior::Melee, 5)], &100);
+        let first = spawn_player(&env, &client, 5, 6);
+        let second = spawn_player(&env, &client, 6, 6);
+
+        client.attack_with(&first, &boss);
+        for _ in 0..3 {
+            client.attack_with(&second, &boss);
+        }
+
+        assert_eq!(client.get_entity_health(&boss), None);
+        assert_eq!(client.get_boss(&boss), None);
+        assert_eq!(client.get_inventory(&first).unwrap().currency, 25);
+        assert_eq!(client.get_inventory(&second).unwrap().currency, 75);
+    }
+
+    /// Test: A melee boss strikes one adjacent owned entity per tick
+    #[test]
+    fn test_melee_boss_strikes_adjacent() {
+        let (env, client) = setup_admin_contract();
+        let boss = client.spawn_boss(&5, &5, &100, &BossSystem::default_phases(&env, 10), &0);
+        let near = spawn_player(&env, &client, 6, 5);
+        let also_near = spawn_player(&env, &client, 4, 5);
+        let far = spawn_player(&env, &client, 9, 5);
+        let unowned = client.spawn_entity(&5, &4);
+
+        tick(&client);
+        assert_eq!(client.get_entity_health(&near), Some(Health(90)));
+        assert_eq!(client.get_entity_health(&also_near), Some(Health(100)));
+        assert_eq!(client.get_entity_health(&far), Some(Health(100)));
+        assert_eq!(client.get_entity_health(&unowned), Some(Health(100)));
+        assert_eq!(client.get_entity_health(&boss), Some(Health(100)));
+    }
+
+    /// Test: A ranged boss needs line of sight and an enraged boss hits everyone in its radius
+    #[test]
+    fn test_boss_behavior_changes_with_phase() {
+        let (env, client) = setup_admin_contract();
+        let phases: Vec<BossPhase> = vec![
+            &env,
+            phase(100, BossBehavior::Ranged, 5),
+            phase(50, BossBehavior::Enraged, 7),
+        ];
+        let boss = client.spawn_boss(&5, &5, &100, &phases, &0);
+        let distant = spawn_player(&env, &client, 8, 5);
+        let close = spawn_player(&env, &client, 5, 7);
+        let outside = spawn_player(&env, &client, 9, 9);
+
+        client.set_wall(&6, &5, &true);
+        tick(&client);
+        assert_eq!(client.get_entity_health(&distant), Some(Health(100)));
+        assert_eq!(client.get_entity_health(&close), Some(Health(95)));
+
+        for _ in 0..5 {
+            client.attack_entity(&boss);
+        }
+        assert_eq!(client.get_boss(&boss).unwrap().phase, 1);
+        tick(&client);
+        assert_eq!(client.get_entity_health(&distant), Some(Health(93)));
+        assert_eq!(client.get_entity_health(&close), Some(Health(88)));
+        assert_eq!(client.get_entity_health(&outside), Some(Health(100)));
+    }
+
+    /// Test: Malformed phases and non-admins are rejected
+    #[test]
+    fn test_spawn_boss_errors() {
+        let (env, client) = setup_admin_contract();
+        let late_start = vec![&env, phase(90, BossBehavior::Melee, 5)];
+        assert_eq!(client.try_spawn_boss(&0, &0, &100, &late_start, &0), Err(Ok(GameError::InvalidBoss)));
+        let rising = vec![&env, phase(100, BossBehavior::Melee, 5), phase(100, BossBehavior::Enraged, 5)];
+        assert_eq!(client.try_spawn_boss(&0, &0, &100, &rising, &0), Err(Ok(GameError::InvalidBoss)));
+        let healthy = BossSystem::default_phases(&env, 10);
+        assert_eq!(client.try_spawn_boss(&0, &0, &0, &healthy, &0), Err(Ok(GameError::InvalidBoss)));
+
+        let (env, client) = setup_initialized_contract();
+        assert_eq!(
+            client.try_spawn_boss(&0, &0, &100, &BossSystem::default_phases(&env, 10), &0),
+            Err(Ok(GameError::Unauthorized))
+        );
+    }
+}
+
+#[cfg(test)]
+mod entity_item_tests {
+    use super::*;
+    use soroban_sdk::testutils::Address as _;
+    use soroban_sdk::{contract, contractimpl, symbol_short, Address};
+
+    /// Minimal inventory contract tracking item holders
+    #[contract]
+    pub struct MockInventory;
+
+    #[contractimpl]
+    impl MockInventory {
+        pub fn mint_entity_item(env: Env, owner: Address, _entity_id: u32) -> u64 {
+            let item_id: u64 = env.storage().instance().get(&symbol_short!("next")).unwrap_or(1);
+            env.storage().instance().set(&symbol_short!("next"), &(item_id + 1));
+            env.storage().instance().set(&item_id, &owner);
+            item_id
+        }
+
+        pub fn owner_of(env: Env, item_id: u64) -> Address {
+            env.storage().instance().get(&item_id).expect("no such item")
+        }
+
+        pub fn burn_item(env: Env, holder: Address, item_id: u64) {
+            let owner: Address = env.storage().instance().get(&item_id).expect("no such item");
+            assert_eq!(owner, holder);
+            env.storage().instance().remove(&item_id);
+        }
+
+        pub fn transfer(env: Env, item_id: u64, to: Address) {
+            env.storage().instance().set(&item_id, &to);
+        }
+    }
+
+    fn setup_inventory<'a>() -> (Env, GameWorldContractClient<'a>, MockInventoryClient<'a>, Address, u32) {
+        let (env, client) = setup_test_contract();
+        env.mock_all_auths();
+        client.initialize(&Address::generate(&env));
+
+        let inventory_id = env.register(MockInventory, ());
+        let inventory = MockInventoryClient::new(&env, &inventory_id);
+        client.set_inventory_contract(&inventory_id);
+
+        let owner = Address::generate(&env);
+        let hero_id = client.spawn_entity(&3, &3);
+        client.set_entity_owner(&hero_id, &owner);
+
+        (env, client, inventory, owner, hero_id)
+    }
+
+    /// Test: A tokenized entity leaves the world and comes back with the same ID and stats
+    #[test]
+    fn test_tokenize_and_redeem_round_trip() {
+        let (_env, client, _inventory, owner, hero_id) = setup_inventory();
+        client.set_entity_team(&hero_id, &Some(2));
+        client.attack_entity(&hero_id);
+        let health = client.get_entity_health(&hero_id).unwrap();
+        let team = client.get_entity_team(&hero_id);
+
+        let item_id = client.tokenize_entity(&hero_id);
+        assert_eq!(client.get_entity_position(&hero_id), None);
+        assert_eq!(client.get_tokenized_entity(&item_id).unwrap().snapshot.entity_data.0, hero_id);
+
+        assert_eq!(client.redeem_entity(&item_id, &8, &9), hero_id);
+        assert_eq!(client.get_entity_position(&hero_id), Some(GamePosition(8, 9)));
+        assert_eq!(client.get_entity_health(&hero_id), Some(health));
+        assert_eq!(client.get_entity_team(&hero_id), team);
+        assert_eq!(client.get_entity_owner(&hero_id), Some(owner));
+        assert_eq!(client.get_tokenized_entity(&item_id), None);
+    }
+
+    /// Test: Whoever holds the item redeems it and becomes the owner
+    #[test]
+    fn test_redeem_by_new_holder() {
+        let (env, client, inventory, _owner, hero_id) = setup_inventory();
+        let buyer = Address::generate(&env);
+
+        let item_id = client.tokenize_entity(&hero_id);
+        inventory.transfer(&item_id, &buyer);
+
+        client.redeem_entity(&item_id, &1, &1);
+        assert_eq!(client.get_entity_owner(&hero_id), Some(buyer));
+        assert_eq!(
+            client.try_redeem_entity(&item_id, &1, &1),
+            Err(Ok(GameError::ItemNotFound))
+        );
+    }
+
+    /// Test: Restoring a checkpoint from before tokenizing doesn't bring the entity back twice
+    #[test]
+    fn test_restore_skips_tokenized_entity() {
+        let (_env, client, _inventory, owner, hero_id) = setup_inventory();
+        client.create_checkpoint(&symbol_short!("before"));
+        let item_id = client.tokenize_entity(&hero_id);
+
+        client.restore_checkpoint(&symbol_short!("before"));
+        assert_eq!(client.get_entity_position(&hero_id), None);
+        assert_eq!(client.entity_count(), 0);
+
+        client.redeem_entity(&item_id, &4, &4);
+        assert_eq!(client.get_entity_position(&hero_id), Some(GamePosition(4, 4)));
+        assert_eq!(client.get_entity_owner(&hero_id), Some(owner));
+        assert_eq!(client.entity_count(), 1);
+
+        client.restore_checkpoint(&symbol_short!("before"));
+        assert_eq!(client.get_entity_position(&hero_id), Some(GamePosition(3, 3)));
+        assert_eq!(client.entity_count(), 1);
+    }
+
+    /// Test: An entity tokenized before a checkpoint and redeemed after it survives the restore
+    #[test]
+    fn test_restore_keeps_entity_redeemed_since() {
+        let (env, client, _inventory, owner, hero_id) = setup_inventory();
+        let item_id = client.tokenize_entity(&hero_id);
+        env.ledger().set_sequence_number(env.ledger().sequence() + 1);
+        client.create_checkpoint(&symbol_short!("after"));
+        env.ledger().set_sequence_number(env.ledger().sequence() + 1);
+        client.redeem_entity(&item_id, &4, &4);
+
+        client.restore_checkpoint(&symbol_short!("after"));
+        assert_eq!(client.get_entity_position(&hero_id), Some(GamePosition(4, 4)));
+        assert_eq!(client.get_entity_owner(&hero_id), Some(owner));
+        assert_eq!(client.entity_count(), 1);
+    }
+
+    /// Test: Tokenizing needs an owner and an inventory contract, and redeeming a free tile
+    #[test]
+    fn test_tokenize_validation() {
+        let (_env, client, _inventory, _owner, hero_id) = setup_inventory();
+
+        let rock = client.spawn_entity(&0, &0);
+        assert_eq!(client.try_tokenize_entity(&rock), Err(Ok(GameError::Unauthorized)));
+
+        let item_id = client.tokenize_entity(&hero_id);
+        client.set_wall(&5, &5, &true);
+        assert_eq!(
+            client.try_redeem_entity(&item_id, &5, &5),
+            Err(Ok(GameError::TileBlocked))
+        );
+        assert_eq!(client.try_tokenize_entity(&hero_id), Err(Ok(GameError::EntityNotFound)));
+    }
+
+    /// Test: Without an inventory contract nothing can be tokenized
+    #[test]
+    fn test_tokenize_requires_inventory_contract() {
+        let (env, client) = setup_admin_contract();
+        let hero_id = client.spawn_entity(&3, &3);
+        client.set_entity_owner(&hero_id, &Address::generate(&env));
+
+        assert_eq!(
+            client.try_tokenize_entity(&hero_id),
+            Err(Ok(GameError::NoInventoryContract))
+        );
+    }
+
+    /// Test: Bosses and world objects stay in the world even when they have an owner
+    #[test]
+    fn test_tokenize_rejects_world_objects() {
+        let (env, client, _inventory, owner, _hero_id) = setup_inventory();
+        let phases = soroban_sdk::vec![
+            &env,
+            game::BossPhase { threshold_percent: 100, behavior: game::BossBehavior::Melee, damage: 5 },
+        ];
+        let boss = client.spawn_boss(&5, &5, &100, &phases, &0);
+        let door = client.spawn_interactable(
+            &6,
+            &6,
+            &game::Interactable {
+                kind: game::InteractableKind::Door,
+                active: false,
+                key_item: None,
+                linked: None,
+                contents: soroban_sdk::Vec::new(&env),
+            },
+        );
+
+        for entity_id in [boss, door] {
+            client.set_entity_owner(&entity_id, &owner);
+            assert_eq!(
+                client.try_tokenize_entity(&entity_id),
+                Err(Ok(GameError::NotTokenizable))
+            );
+            assert!(client.get_entity_position(&entity_id).is_some());
+        }
+    }
+}
+
+#[cfg(test)]
+mod support_tests {
+    use super::*;
+    use soroban_sdk::testutils::Address as _;
+    use soroban_sdk::Address;
+
+    /// Sets up a healer and a damaged ally on team 1, two tiles apart
+    fn setup_allies<'a>() -> (Env, GameWorldContractClient<'a>, u32, u32) {
+        let (env, client) = setup_test_contract();
+        env.mock_all_auths();
+        client.initialize(&Address::generate(&env));
+        env.ledger().set_timestamp(1_000);
+
+        let healer_id = client.spawn_entity(&0, &0);
+        let ally_id = client.spawn_entity(&2, &0);
+        let owner = Address::generate(&env);
+        for entity_id in [healer_id, ally_id] {
+            client.set_entity_owner(&entity_id, &owner);
+            client.set_entity_team(&entity_id, &Some(1));
+        }
+        client.set_support_stats(&healer_id, &40, &30);
+        for _ in 0..5 {
+            client.attack_entity(&ally_id);
+        }
+        (env, client, healer_id, ally_id)
+    }
+
+    /// Test: Healing restores the ally's health up to its MaxHealth and costs energy
+    #[test]
+    fn test_heal_ally() {
+        let (_env, client, healer_id, ally_id) = setup_allies();
+        client.heal_ally(&healer_id, &ally_id, &20);
+        assert_eq!(client.get_entity_health(&ally_id).unwrap().0, 70);
+        assert_eq!(client.get_energy(&healer_id).unwrap().0, 30);
+
+        client.set_regeneration(&ally_id, &0, &80);
+        client.heal_ally(&healer_id, &ally_id, &30);
+        assert_eq!(client.get_entity_health(&ally_id).unwrap().0, 80);
+    }
+
+    /// Test: Healing needs a teammate in range, a valid amount and enough energy
+    #[test]
+    fn test_heal_ally_errors() {
+        let (env, client, healer_id, ally_id) = setup_allies();
+        assert_eq!(
+            client.try_heal_ally(&healer_id, &healer_id, &10),
+            Err(Ok(GameError::InvalidTarget))
+        );
+        assert_eq!(
+            client.try_heal_ally(&healer_id, &ally_id, &31),
+            Err(Ok(GameError::InvalidSupportAmount))
+        );
+
+        let stranger = client.spawn_entity(&1, &0);
+        assert_eq!(
+            client.try_heal_ally(&healer_id, &stranger, &10),
+            Err(Ok(GameError::NotAllies))
+        );
+        client.set_entity_owner(&stranger, &Address::generate(&env));
+        client.set_entity_team(&stranger, &Some(1));
+        client.move_entity(&stranger, &8, &9);
+        assert_eq!(
+            client.try_heal_ally(&healer_id, &stranger, &10),
+            Err(Ok(GameError::OutOfRange))
+        );
+
+        for _ in 0..4 {
+            client.heal_ally(&healer_id, &ally_id, &1);
+        }
+        assert_eq!(
+            client.try_heal_ally(&healer_id, &ally_id, &1),
+            Err(Ok(GameError::NotEnoughEnergy))
+        );
+    }
+
+    /// Test: A shield absorbs damage before Health until it runs out or expires
+    #[test]
+    fn test_shield_absorbs_damage() {
+        let (env, client, healer_id, ally_id) = setup_allies();
+        client.cast_shield(&healer_id, &ally_id, &15);
+        assert_eq!(client.get_energy(&healer_id).unwrap().0, 25);
+
+        client.attack_entity(&ally_id);
+        assert_eq!(client.get_entity_health(&ally_id).unwrap().0, 50);
+        assert_eq!(client.get_shield(&ally_id).unwrap().amount, 5);
+
+        client.attack_entity(&ally_id);
+        assert_eq!(client.get_entity_health(&ally_id).unwrap().0, 45);
+        assert_eq!(client.get_shield(&ally_id), None);
+
+        client.cast_shield(&healer_id, &healer_id, &30);
+        env.ledger().set_timestamp(1_000 + 301);
+        assert_eq!(client.get_shield(&healer_id), None);
+        client.attack_entity(&healer_id);
+        assert_eq!(client.get_entity_health(&healer_id).unwrap().0, 90);
+    }
+}